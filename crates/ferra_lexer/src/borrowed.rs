@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright (c) 2025 Ferra Language Project Contributors
+
+//! Tokens that borrow their text from the source.
+//!
+//! [`Lexer::lex_borrowed`] scans without copying lexemes or computing literal
+//! values. A token's value is worked out from its lexeme only when asked for,
+//! and string contents without escapes are handed out as slices of the source.
+
+use crate::{nfc_name, Lexer, LiteralValue, Span, Symbol, TokenKind};
+use std::borrow::Cow;
+
+/// A token whose lexeme is a slice of the source it was lexed from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Token<'src> {
+    pub kind: TokenKind,
+    /// The exact slice of source text.
+    pub lexeme: &'src str,
+    /// Source‐location span for diagnostics.
+    pub span: Span,
+}
+
+impl<'src> Token<'src> {
+    /// Whether this is a raw identifier such as `r#match`.
+    pub fn is_raw_identifier(&self) -> bool {
+        self.kind == TokenKind::Identifier && self.lexeme.starts_with("r#")
+    }
+
+    /// Interned name of an identifier token, without the `r#` of a raw identifier.
+    pub fn symbol(&self) -> Option<Symbol> {
+        if self.kind != TokenKind::Identifier {
+            return None;
+        }
+        let name = self.lexeme.strip_prefix("r#").unwrap_or(self.lexeme);
+        Some(Symbol::intern(&nfc_name(name)))
+    }
+
+    /// Literal value of the token, computed from its lexeme. It matches the
+    /// literal of the owned token; error tokens have none, as their messages
+    /// come with the [`crate::LexError`]s.
+    pub fn literal(&self) -> Option<LiteralValue> {
+        match self.kind {
+            TokenKind::Identifier if self.is_raw_identifier() => {
+                self.symbol().map(LiteralValue::RawIdentifier)
+            }
+            TokenKind::Identifier => self.symbol().map(LiteralValue::Identifier),
+            TokenKind::True => Some(LiteralValue::Boolean(true)),
+            TokenKind::False => Some(LiteralValue::Boolean(false)),
+            TokenKind::IntegerLiteral
+            | TokenKind::FloatLiteral
+            | TokenKind::StringLiteral
+            | TokenKind::CharacterLiteral
+            | TokenKind::ByteLiteral
+            | TokenKind::RawStringLiteral
+            | TokenKind::MultiLineStringLiteral
+            | TokenKind::InterpolatedString => self.relex(),
+            _ => None,
+        }
+    }
+
+    /// Contents of a string literal with escapes processed. Literals without
+    /// escapes borrow from the source.
+    pub fn unescaped(&self) -> Option<Cow<'src, str>> {
+        match self.kind {
+            TokenKind::StringLiteral if !self.lexeme.contains('\\') => {
+                Some(Cow::Borrowed(&self.lexeme[1..self.lexeme.len() - 1]))
+            }
+            TokenKind::RawStringLiteral => {
+                let hashes =
+                    self.lexeme[1..].len() - self.lexeme[1..].trim_start_matches('#').len();
+                Some(Cow::Borrowed(
+                    &self.lexeme[hashes + 2..self.lexeme.len() - hashes - 1],
+                ))
+            }
+            TokenKind::StringLiteral | TokenKind::MultiLineStringLiteral => match self.literal() {
+                Some(LiteralValue::String(text)) => Some(Cow::Owned(text)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Owned copy of the token, with its literal value computed.
+    pub fn into_owned(self) -> crate::Token {
+        crate::Token {
+            kind: self.kind,
+            lexeme: match self.kind {
+                TokenKind::Identifier if !self.is_raw_identifier() => {
+                    nfc_name(self.lexeme).into_owned()
+                }
+                _ => self.lexeme.to_string(),
+            },
+            literal: self.literal(),
+            span: self.span,
+        }
+    }
+
+    /// Lex the literal on its own. Literals do not depend on their context,
+    /// so this yields the value the owned lexer computes.
+    fn relex(&self) -> Option<LiteralValue> {
+        let (line, column) = (self.span.start.line, self.span.start.column);
+        let (tokens, _, _) = Lexer::embedded(self.lexeme, line, column).scan();
+        let mut literal = tokens.into_iter().next()?.literal?;
+        // Interpolated expressions carry spans into the whole source.
+        if let LiteralValue::Interpolated(segments) = &mut literal {
+            for segment in segments {
+                if let crate::StringSegment::Expression { tokens, span } = segment {
+                    span.start.offset += self.span.start.offset;
+                    span.end.offset += self.span.start.offset;
+                    for token in tokens {
+                        token.span.start.offset += self.span.start.offset;
+                        token.span.end.offset += self.span.start.offset;
+                    }
+                }
+            }
+        }
+        Some(literal)
+    }
+}
+
+impl From<Token<'_>> for crate::Token {
+    fn from(token: Token<'_>) -> Self {
+        token.into_owned()
+    }
+}
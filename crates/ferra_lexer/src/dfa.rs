@@ -0,0 +1,421 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright (c) 2025 Ferra Language Project Contributors
+
+//! Table-driven scanner behind [`LexerBackend::Dfa`](crate::LexerBackend).
+//!
+//! Tokens are specified declaratively in [`TOKEN_SPEC`] as patterns in a small
+//! regular-expression language. On first use the patterns are compiled into a
+//! single deterministic automaton over bytes (Thompson construction, then
+//! subset construction), stored as a transition table indexed by state and
+//! byte class. Scanning takes the longest match; ties go to the earlier rule.
+//!
+//! The automaton covers the common ASCII tokens. Constructs whose lexing
+//! depends on more than a regular language (string and char literals,
+//! comments, number suffixes, non-ASCII identifiers) are matched by
+//! [`Action::Defer`] rules and left to the hand-written routines, so both
+//! backends produce the same tokens.
+
+use crate::TokenKind;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::OnceLock;
+
+/// What the lexer does with the text a rule matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Action {
+    /// Hand the input to the hand-written lexer from the start of the match.
+    Defer,
+    /// Whitespace inside a line.
+    Skip,
+    /// Line break, `\n` or `\r\n`.
+    Newline,
+    /// Identifier or keyword, unless a non-ASCII character continues it.
+    Identifier,
+    /// Decimal integer, unless a suffix, fraction or exponent follows.
+    Integer,
+    /// Fixed token such as an operator or punctuation.
+    Token(TokenKind),
+}
+
+/// The token specification, in priority order.
+const TOKEN_SPEC: &[(&str, Action)] = &[
+    // Comments, literals with escapes, lifetimes and prefixed literals
+    (r"//|/\*", Action::Defer),
+    (r#"["']"#, Action::Defer),
+    (r#"[bf]["']|r[#"]"#, Action::Defer),
+    (r"\.[0-9]", Action::Defer),
+    (r"[ \t]+", Action::Skip),
+    (r"\r?\n", Action::Newline),
+    (r"_", Action::Token(TokenKind::Underscore)),
+    (r"[A-Za-z_][A-Za-z0-9_]*", Action::Identifier),
+    (r"[0-9]+", Action::Integer),
+    // Operators and punctuation
+    (r"<<=", Action::Token(TokenKind::ShiftLeftEqual)),
+    (r">>=", Action::Token(TokenKind::ShiftRightEqual)),
+    (r"==", Action::Token(TokenKind::EqualEqual)),
+    (r"!=", Action::Token(TokenKind::NotEqual)),
+    (r"<=", Action::Token(TokenKind::LessEqual)),
+    (r">=", Action::Token(TokenKind::GreaterEqual)),
+    (r"&&", Action::Token(TokenKind::LogicalAnd)),
+    (r"\|\|", Action::Token(TokenKind::LogicalOr)),
+    (r"\+=", Action::Token(TokenKind::PlusEqual)),
+    (r"-=", Action::Token(TokenKind::MinusEqual)),
+    (r"\*=", Action::Token(TokenKind::StarEqual)),
+    (r"/=", Action::Token(TokenKind::SlashEqual)),
+    (r"%=", Action::Token(TokenKind::PercentEqual)),
+    (r"&=", Action::Token(TokenKind::BitAndEqual)),
+    (r"\|=", Action::Token(TokenKind::BitOrEqual)),
+    (r"\^=", Action::Token(TokenKind::CaretEqual)),
+    (r"<<", Action::Token(TokenKind::ShiftLeft)),
+    (r">>", Action::Token(TokenKind::ShiftRight)),
+    (r"->", Action::Token(TokenKind::Arrow)),
+    (r"=>", Action::Token(TokenKind::FatArrow)),
+    (r"\.\.=", Action::Token(TokenKind::DotDotEqual)),
+    (r"\.\.", Action::Token(TokenKind::DotDot)),
+    (r"::", Action::Token(TokenKind::PathSep)),
+    (r"\?\?", Action::Token(TokenKind::Coalesce)),
+    (r"=", Action::Token(TokenKind::Equal)),
+    (r";", Action::Token(TokenKind::Semicolon)),
+    (r"\(", Action::Token(TokenKind::LParen)),
+    (r"\)", Action::Token(TokenKind::RParen)),
+    (r"\{", Action::Token(TokenKind::LBrace)),
+    (r"\}", Action::Token(TokenKind::RBrace)),
+    (r"\[", Action::Token(TokenKind::LBracket)),
+    (r"\]", Action::Token(TokenKind::RBracket)),
+    (r",", Action::Token(TokenKind::Comma)),
+    (r":", Action::Token(TokenKind::Colon)),
+    (r"\+", Action::Token(TokenKind::Plus)),
+    (r"-", Action::Token(TokenKind::Minus)),
+    (r"\*", Action::Token(TokenKind::Star)),
+    (r"/", Action::Token(TokenKind::Slash)),
+    (r"<", Action::Token(TokenKind::Less)),
+    (r">", Action::Token(TokenKind::Greater)),
+    (r"!", Action::Token(TokenKind::Bang)),
+    (r"\.", Action::Token(TokenKind::Dot)),
+    (r"&", Action::Token(TokenKind::BitAnd)),
+    (r"\|", Action::Token(TokenKind::BitOr)),
+    (r"\^", Action::Token(TokenKind::Caret)),
+    (r"%", Action::Token(TokenKind::Percent)),
+    (r"\?", Action::Token(TokenKind::Question)),
+    (r"\$", Action::Token(TokenKind::Dollar)),
+    (r"#", Action::Token(TokenKind::Hash)),
+    (r"@", Action::Token(TokenKind::At)),
+];
+
+/// State with no way forward.
+const DEAD: u16 = 0;
+/// State before any input.
+const START: u16 = 1;
+
+/// Compiled form of [`TOKEN_SPEC`].
+pub(crate) struct Dfa {
+    /// Byte class of each byte; bytes in one class are never told apart.
+    classes: [u8; 256],
+    class_count: usize,
+    /// `transitions[state * class_count + class]` is the next state.
+    transitions: Vec<u16>,
+    /// Rule accepted in each state, if any.
+    accepting: Vec<Option<u16>>,
+}
+
+/// The automaton for [`TOKEN_SPEC`], built on first use.
+pub(crate) fn dfa() -> &'static Dfa {
+    static DFA: OnceLock<Dfa> = OnceLock::new();
+    DFA.get_or_init(|| Dfa::build(TOKEN_SPEC.iter().map(|(pattern, _)| *pattern)))
+}
+
+impl Dfa {
+    /// Action and length of the longest match at the start of `input`.
+    pub(crate) fn longest_match(&self, input: &[u8]) -> Option<(Action, usize)> {
+        let mut state = START as usize;
+        let mut matched = None;
+        for (index, &byte) in input.iter().enumerate() {
+            let class = self.classes[byte as usize] as usize;
+            state = self.transitions[state * self.class_count + class] as usize;
+            if state == DEAD as usize {
+                break;
+            }
+            if let Some(rule) = self.accepting[state] {
+                matched = Some((TOKEN_SPEC[rule as usize].1, index + 1));
+            }
+        }
+        matched
+    }
+
+    fn build<'p>(patterns: impl Iterator<Item = &'p str>) -> Dfa {
+        let mut nfa = Nfa::default();
+        let start = nfa.add_state();
+        for (rule, pattern) in patterns.enumerate() {
+            let fragment = Parser::new(pattern, &mut nfa).parse();
+            let accept = nfa.add_state();
+            nfa.states[fragment.end].epsilon.push(accept);
+            nfa.states[accept].accepts = Some(rule as u16);
+            nfa.states[start].epsilon.push(fragment.start);
+        }
+
+        let (classes, class_count, representatives) = nfa.byte_classes();
+
+        // Subset construction; state sets are keyed by their sorted members.
+        let mut sets: Vec<BTreeSet<usize>> = vec![BTreeSet::new()];
+        let mut ids: HashMap<BTreeSet<usize>, u16> = HashMap::new();
+        ids.insert(BTreeSet::new(), DEAD);
+        let initial = nfa.closure([start]);
+        ids.insert(initial.clone(), START);
+        sets.push(initial);
+
+        let mut transitions = vec![DEAD; class_count];
+        let mut index = START as usize;
+        while index < sets.len() {
+            for &byte in &representatives {
+                let targets = nfa.closure(
+                    sets[index]
+                        .iter()
+                        .flat_map(|&state| &nfa.states[state].edges)
+                        .filter(|(bytes, _)| bytes.contains(byte))
+                        .map(|&(_, target)| target),
+                );
+                let next = match ids.get(&targets) {
+                    Some(&id) => id,
+                    None => {
+                        let id = u16::try_from(sets.len()).expect("token DFA too large");
+                        ids.insert(targets.clone(), id);
+                        sets.push(targets);
+                        id
+                    }
+                };
+                transitions.push(next);
+            }
+            index += 1;
+        }
+
+        let accepting = sets
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .filter_map(|&state| nfa.states[state].accepts)
+                    .min()
+            })
+            .collect();
+        Dfa {
+            classes,
+            class_count,
+            transitions,
+            accepting,
+        }
+    }
+}
+
+/// A set of bytes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct ByteSet([u128; 2]);
+
+impl ByteSet {
+    const EMPTY: ByteSet = ByteSet([0; 2]);
+
+    fn insert(&mut self, byte: u8) {
+        self.0[byte as usize / 128] |= 1 << (byte % 128);
+    }
+
+    fn contains(&self, byte: u8) -> bool {
+        self.0[byte as usize / 128] & (1 << (byte % 128)) != 0
+    }
+}
+
+#[derive(Default)]
+struct NfaState {
+    edges: Vec<(ByteSet, usize)>,
+    epsilon: Vec<usize>,
+    accepts: Option<u16>,
+}
+
+#[derive(Default)]
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+/// Part of an NFA with one entry and one exit state.
+struct Fragment {
+    start: usize,
+    end: usize,
+}
+
+impl Nfa {
+    fn add_state(&mut self) -> usize {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    /// States reachable from `states` through epsilon edges.
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> BTreeSet<usize> {
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        let mut set = BTreeSet::new();
+        while let Some(state) = stack.pop() {
+            if set.insert(state) {
+                stack.extend(&self.states[state].epsilon);
+            }
+        }
+        set
+    }
+
+    /// Split the bytes into classes that every edge treats alike. Returns the
+    /// class of each byte, the class count and one byte per class.
+    fn byte_classes(&self) -> ([u8; 256], usize, Vec<u8>) {
+        let sets: Vec<ByteSet> = self
+            .states
+            .iter()
+            .flat_map(|state| state.edges.iter().map(|(bytes, _)| *bytes))
+            .collect();
+        let mut signatures: HashMap<Vec<bool>, u8> = HashMap::new();
+        let mut classes = [0u8; 256];
+        let mut representatives = Vec::new();
+        for byte in 0..=255u8 {
+            let signature: Vec<bool> = sets.iter().map(|set| set.contains(byte)).collect();
+            let next = signatures.len() as u8;
+            let class = *signatures.entry(signature).or_insert_with(|| {
+                representatives.push(byte);
+                next
+            });
+            classes[byte as usize] = class;
+        }
+        (classes, representatives.len(), representatives)
+    }
+}
+
+/// Parser for the pattern language: literals, `\`-escapes, `[...]` classes
+/// with ranges, grouping, `|`, `*`, `+` and `?`.
+struct Parser<'p, 'n> {
+    pattern: &'p [u8],
+    position: usize,
+    nfa: &'n mut Nfa,
+}
+
+impl<'p, 'n> Parser<'p, 'n> {
+    fn new(pattern: &'p str, nfa: &'n mut Nfa) -> Self {
+        Parser {
+            pattern: pattern.as_bytes(),
+            position: 0,
+            nfa,
+        }
+    }
+
+    fn parse(mut self) -> Fragment {
+        let fragment = self.alternation();
+        assert!(
+            self.position == self.pattern.len(),
+            "unexpected `)` in token pattern"
+        );
+        fragment
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.pattern.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> u8 {
+        let byte = self.peek().expect("unexpected end of token pattern");
+        self.position += 1;
+        byte
+    }
+
+    fn alternation(&mut self) -> Fragment {
+        let first = self.sequence();
+        if self.peek() != Some(b'|') {
+            return first;
+        }
+        let (start, end) = (self.nfa.add_state(), self.nfa.add_state());
+        let mut branch = first;
+        loop {
+            self.nfa.states[start].epsilon.push(branch.start);
+            self.nfa.states[branch.end].epsilon.push(end);
+            if self.peek() != Some(b'|') {
+                return Fragment { start, end };
+            }
+            self.position += 1;
+            branch = self.sequence();
+        }
+    }
+
+    fn sequence(&mut self) -> Fragment {
+        let start = self.nfa.add_state();
+        let mut end = start;
+        while !matches!(self.peek(), None | Some(b'|') | Some(b')')) {
+            let atom = self.repetition();
+            self.nfa.states[end].epsilon.push(atom.start);
+            end = atom.end;
+        }
+        Fragment { start, end }
+    }
+
+    fn repetition(&mut self) -> Fragment {
+        let atom = self.atom();
+        let operator = match self.peek() {
+            Some(operator @ (b'*' | b'+' | b'?')) => operator,
+            _ => return atom,
+        };
+        self.position += 1;
+        let (start, end) = (self.nfa.add_state(), self.nfa.add_state());
+        self.nfa.states[start].epsilon.push(atom.start);
+        self.nfa.states[atom.end].epsilon.push(end);
+        if operator != b'+' {
+            self.nfa.states[start].epsilon.push(end);
+        }
+        if operator != b'?' {
+            self.nfa.states[atom.end].epsilon.push(atom.start);
+        }
+        Fragment { start, end }
+    }
+
+    fn atom(&mut self) -> Fragment {
+        let bytes = match self.next() {
+            b'(' => {
+                let group = self.alternation();
+                assert_eq!(self.next(), b')', "unclosed `(` in token pattern");
+                return group;
+            }
+            b'[' => self.class(),
+            b'\\' => Self::single(self.escaped()),
+            byte => Self::single(byte),
+        };
+        let (start, end) = (self.nfa.add_state(), self.nfa.add_state());
+        self.nfa.states[start].edges.push((bytes, end));
+        Fragment { start, end }
+    }
+
+    fn class(&mut self) -> ByteSet {
+        let mut bytes = ByteSet::EMPTY;
+        loop {
+            let low = match self.next() {
+                b']' => return bytes,
+                b'\\' => self.escaped(),
+                byte => byte,
+            };
+            let high = if self.peek() == Some(b'-')
+                && self.pattern.get(self.position + 1) != Some(&b']')
+            {
+                self.position += 1;
+                self.next()
+            } else {
+                low
+            };
+            for byte in low..=high {
+                bytes.insert(byte);
+            }
+        }
+    }
+
+    /// Byte written after a `\`: `\n`, `\r` and `\t` are control characters,
+    /// anything else stands for itself.
+    fn escaped(&mut self) -> u8 {
+        match self.next() {
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            byte => byte,
+        }
+    }
+
+    fn single(byte: u8) -> ByteSet {
+        let mut bytes = ByteSet::EMPTY;
+        bytes.insert(byte);
+        bytes
+    }
+}
@@ -1,15 +1,22 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright (c) 2025 Ferra Language Project Contributors
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::iter::Peekable;
 use std::str::CharIndices;
 use unicode_ident::{is_xid_continue, is_xid_start};
 use unicode_normalization::UnicodeNormalization;
 
+pub mod borrowed;
+mod dfa;
+pub mod symbol;
+pub use symbol::*;
+
 /// All the different token kinds the Ferra lexer can emit.
 /// Marked non_exhaustive so we can add new variants (raw strings, etc.) later.
 #[non_exhaustive]
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum TokenKind {
     // Keywords
     Let,
@@ -30,6 +37,10 @@ pub enum TokenKind {
     Continue,
     Pub,
     Unsafe,
+    Macro,
+    Extern,
+    Static,
+    Where,
 
     // Identifiers
     Identifier,
@@ -43,6 +54,7 @@ pub enum TokenKind {
     ByteLiteral,            // e.g. b'a', b"foo"
     RawStringLiteral,       // r"..." or r#"..."#
     MultiLineStringLiteral, // """..."""
+    InterpolatedString,     // f"Hello, {name}!"
 
     // Comments (skipped by parser but useful for tooling)
     // LineComment,  // `// ...`
@@ -97,11 +109,16 @@ pub enum TokenKind {
     DotDotEqual,     // ..=
     PathSep,         // ::
     Underscore,      // _
+    Dollar,          // $ (macro metavariables)
+    Hash,            // # (attributes)
+    At,              // @ (binding patterns)
+    Apostrophe,      // ' (lifetimes such as 'a, followed by an Identifier)
 
     // Structural tokens
     Indent,
     Dedent,
     Newline,
+    Shebang, // #!/usr/bin/env ferra, only as the first line
     Eof,
 
     // Fallback for unrecognized input
@@ -130,17 +147,187 @@ pub enum LiteralValue {
     Char(char),
     Boolean(bool),
     Byte(u8),
+    /// Contents of a byte string, e.g. b"foo"
+    ByteString(Vec<u8>),
+    /// Integer literal too wide for `i64`
+    WideInteger(WideInteger),
+    /// Integer literal with a type suffix, e.g. 255u8. Signed literals may
+    /// hold the magnitude of the type's minimum, which is only in range
+    /// when negated.
+    TypedInteger {
+        value: u128,
+        suffix: NumericSuffix,
+    },
+    /// Float literal with a type suffix, e.g. 1.5f32
+    TypedFloat {
+        value: f64,
+        suffix: NumericSuffix,
+    },
+    /// Segments of an interpolated string, e.g. f"Hello, {name}!"
+    Interpolated(Vec<StringSegment>),
+    /// Interned name of an identifier
+    Identifier(Symbol),
+    /// Name of a raw identifier without its `r#` prefix, e.g. `match` for
+    /// `r#match`. The token kind stays `Identifier`.
+    RawIdentifier(Symbol),
 }
 
-/// Precise span (start/end positions) in the source file.
+/// Piece of an interpolated string literal.
 #[derive(Debug, PartialEq, Clone)]
+pub enum StringSegment {
+    /// Literal text, with escapes processed and `{{`/`}}` collapsed.
+    Text(String),
+    /// Embedded expression, lexed with spans into the enclosing source.
+    Expression { tokens: Vec<Token>, span: Span },
+}
+
+/// Numeric type named by a literal suffix, e.g. the `u8` in `255u8`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NumericSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    F32,
+    F64,
+}
+
+impl NumericSuffix {
+    /// All suffixes, in the order they are listed in diagnostics.
+    pub const ALL: [NumericSuffix; 14] = [
+        NumericSuffix::I8,
+        NumericSuffix::I16,
+        NumericSuffix::I32,
+        NumericSuffix::I64,
+        NumericSuffix::I128,
+        NumericSuffix::Isize,
+        NumericSuffix::U8,
+        NumericSuffix::U16,
+        NumericSuffix::U32,
+        NumericSuffix::U64,
+        NumericSuffix::U128,
+        NumericSuffix::Usize,
+        NumericSuffix::F32,
+        NumericSuffix::F64,
+    ];
+
+    /// Look up a suffix by its name, e.g. "u8".
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|suffix| suffix.name() == name)
+    }
+
+    /// The type name as written in source.
+    pub fn name(self) -> &'static str {
+        match self {
+            NumericSuffix::I8 => "i8",
+            NumericSuffix::I16 => "i16",
+            NumericSuffix::I32 => "i32",
+            NumericSuffix::I64 => "i64",
+            NumericSuffix::I128 => "i128",
+            NumericSuffix::Isize => "isize",
+            NumericSuffix::U8 => "u8",
+            NumericSuffix::U16 => "u16",
+            NumericSuffix::U32 => "u32",
+            NumericSuffix::U64 => "u64",
+            NumericSuffix::U128 => "u128",
+            NumericSuffix::Usize => "usize",
+            NumericSuffix::F32 => "f32",
+            NumericSuffix::F64 => "f64",
+        }
+    }
+
+    pub fn is_float(self) -> bool {
+        matches!(self, NumericSuffix::F32 | NumericSuffix::F64)
+    }
+
+    pub fn is_signed(self) -> bool {
+        !matches!(
+            self,
+            NumericSuffix::U8
+                | NumericSuffix::U16
+                | NumericSuffix::U32
+                | NumericSuffix::U64
+                | NumericSuffix::U128
+                | NumericSuffix::Usize
+        )
+    }
+
+    /// Width in bits; `isize` and `usize` are 64 bits wide.
+    pub fn bits(self) -> u32 {
+        match self {
+            NumericSuffix::I8 | NumericSuffix::U8 => 8,
+            NumericSuffix::I16 | NumericSuffix::U16 => 16,
+            NumericSuffix::I32 | NumericSuffix::U32 | NumericSuffix::F32 => 32,
+            NumericSuffix::I64
+            | NumericSuffix::U64
+            | NumericSuffix::Isize
+            | NumericSuffix::Usize
+            | NumericSuffix::F64 => 64,
+            NumericSuffix::I128 | NumericSuffix::U128 => 128,
+        }
+    }
+
+    /// Largest positive value of an integer type.
+    pub fn max_value(self) -> u128 {
+        if self.is_signed() {
+            (1u128 << (self.bits() - 1)) - 1
+        } else {
+            u128::MAX >> (128 - self.bits())
+        }
+    }
+
+    /// Magnitude of the smallest value of an integer type (0 for unsigned).
+    pub fn min_magnitude(self) -> u128 {
+        if self.is_signed() {
+            1u128 << (self.bits() - 1)
+        } else {
+            0
+        }
+    }
+
+    /// The range of an integer type, e.g. "-128..=127".
+    pub fn range(self) -> String {
+        if self.is_signed() {
+            format!("-{}..={}", self.min_magnitude(), self.max_value())
+        } else {
+            format!("0..={}", self.max_value())
+        }
+    }
+}
+
+/// Integer literal kept as its digits, for values that do not fit `i64`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WideInteger {
+    /// Digits without base prefix or underscores, e.g. "ffffffffffffffff".
+    pub digits: String,
+    /// Radix the literal was written in: 2, 8, 10 or 16.
+    pub radix: u32,
+}
+
+impl WideInteger {
+    /// The value, if it fits in 128 bits.
+    pub fn to_u128(&self) -> Option<u128> {
+        u128::from_str_radix(&self.digits, self.radix).ok()
+    }
+}
+
+/// Precise span (start/end positions) in the source file.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Span {
     pub start: Position,
     pub end: Position,
 }
 
 /// A single position: line, column, and byte offset.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Position {
     pub line: usize,
     pub column: usize,
@@ -165,7 +352,553 @@ impl Span {
     }
 }
 
+/// Category of a lexical error. Each kind has a stable code from
+/// docs/diagnostic_codes.md (E001–E099 are reserved for the lexer).
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LexErrorKind {
+    InvalidCharacter,
+    UnterminatedString,
+    UnterminatedBlockComment,
+    MixedIndentation,
+    UnexpectedDedent,
+    InvalidEscape,
+    EmptyCharLiteral,
+    MultiCharLiteral,
+    UnterminatedCharLiteral,
+    InvalidNumber,
+    NumberOutOfRange,
+    ReservedKeyword,
+    InvalidRawIdentifier,
+    InvalidInterpolation,
+    MalformedRawString,
+    ForbiddenIndentation,
+    NestingTooDeep,
+    BidiControl,
+}
+
+impl LexErrorKind {
+    /// Every kind, in code order.
+    pub const ALL: &'static [LexErrorKind] = &[
+        LexErrorKind::InvalidCharacter,
+        LexErrorKind::UnterminatedString,
+        LexErrorKind::UnterminatedBlockComment,
+        LexErrorKind::MixedIndentation,
+        LexErrorKind::UnexpectedDedent,
+        LexErrorKind::InvalidEscape,
+        LexErrorKind::EmptyCharLiteral,
+        LexErrorKind::MultiCharLiteral,
+        LexErrorKind::UnterminatedCharLiteral,
+        LexErrorKind::InvalidNumber,
+        LexErrorKind::NumberOutOfRange,
+        LexErrorKind::ReservedKeyword,
+        LexErrorKind::InvalidRawIdentifier,
+        LexErrorKind::InvalidInterpolation,
+        LexErrorKind::MalformedRawString,
+        LexErrorKind::ForbiddenIndentation,
+        LexErrorKind::NestingTooDeep,
+        LexErrorKind::BidiControl,
+    ];
+
+    /// Stable diagnostic code, e.g. `E002`.
+    pub fn code(self) -> &'static str {
+        match self {
+            LexErrorKind::InvalidCharacter => "E001",
+            LexErrorKind::UnterminatedString => "E002",
+            LexErrorKind::UnterminatedBlockComment => "E003",
+            LexErrorKind::MixedIndentation => "E004",
+            LexErrorKind::UnexpectedDedent => "E005",
+            LexErrorKind::InvalidEscape => "E006",
+            LexErrorKind::EmptyCharLiteral => "E007",
+            LexErrorKind::MultiCharLiteral => "E008",
+            LexErrorKind::UnterminatedCharLiteral => "E009",
+            LexErrorKind::InvalidNumber => "E010",
+            LexErrorKind::NumberOutOfRange => "E011",
+            LexErrorKind::ReservedKeyword => "E012",
+            LexErrorKind::InvalidRawIdentifier => "E013",
+            LexErrorKind::InvalidInterpolation => "E014",
+            LexErrorKind::MalformedRawString => "E015",
+            LexErrorKind::ForbiddenIndentation => "E016",
+            LexErrorKind::NestingTooDeep => "E017",
+            LexErrorKind::BidiControl => "E018",
+        }
+    }
+
+    /// Short description, as listed in the diagnostic code registry.
+    pub fn description(self) -> &'static str {
+        match self {
+            LexErrorKind::InvalidCharacter => "invalid character in source",
+            LexErrorKind::UnterminatedString => "unterminated string literal",
+            LexErrorKind::UnterminatedBlockComment => "unterminated block comment",
+            LexErrorKind::MixedIndentation => "mixed tabs and spaces in indentation",
+            LexErrorKind::UnexpectedDedent => "dedent to an unexpected indentation level",
+            LexErrorKind::InvalidEscape => "invalid escape sequence",
+            LexErrorKind::EmptyCharLiteral => "empty character literal",
+            LexErrorKind::MultiCharLiteral => "character literal with more than one character",
+            LexErrorKind::UnterminatedCharLiteral => "unterminated character literal",
+            LexErrorKind::InvalidNumber => "malformed numeric literal",
+            LexErrorKind::NumberOutOfRange => "numeric literal out of range for its type",
+            LexErrorKind::ReservedKeyword => "reserved keyword used as an identifier",
+            LexErrorKind::InvalidRawIdentifier => "invalid raw identifier",
+            LexErrorKind::InvalidInterpolation => "malformed interpolated string",
+            LexErrorKind::MalformedRawString => "raw string prefix without an opening quote",
+            LexErrorKind::ForbiddenIndentation => {
+                "indentation character forbidden by the lexer config"
+            }
+            LexErrorKind::NestingTooDeep => "nesting deeper than the configured limit",
+            LexErrorKind::BidiControl => "bidirectional control character in source",
+        }
+    }
+
+    /// Suggested fix shown with the error.
+    pub fn help(self) -> &'static str {
+        match self {
+            LexErrorKind::InvalidCharacter => "remove the character, or put it in a string literal",
+            LexErrorKind::UnterminatedString => "add the closing quote",
+            LexErrorKind::UnterminatedBlockComment => "add `*/` for every open `/*`",
+            LexErrorKind::MixedIndentation => "indent with either spaces or tabs, not both",
+            LexErrorKind::UnexpectedDedent => "dedent to the indentation of an enclosing block",
+            LexErrorKind::InvalidEscape => {
+                "valid escapes are \\n, \\t, \\r, \\0, \\\\, \\', \\\" and \\u{...}"
+            }
+            LexErrorKind::EmptyCharLiteral => "write a character between the quotes, e.g. 'a'",
+            LexErrorKind::MultiCharLiteral => "use double quotes for a string",
+            LexErrorKind::UnterminatedCharLiteral => "add the closing `'`",
+            LexErrorKind::InvalidNumber => "check the digits and underscores of the literal",
+            LexErrorKind::NumberOutOfRange => "use a wider suffix type or a smaller value",
+            LexErrorKind::ReservedKeyword => "prefix the name with `r#` to use it as an identifier",
+            LexErrorKind::InvalidRawIdentifier => "raw identifiers need a name, e.g. `r#match`",
+            LexErrorKind::InvalidInterpolation => {
+                "write `{{` and `}}` for literal braces, and put an expression between `{` and `}`"
+            }
+            LexErrorKind::MalformedRawString => "write the raw string as r\"...\" or r#\"...\"#",
+            LexErrorKind::ForbiddenIndentation => {
+                "indent with the character allowed by the project's indentation policy"
+            }
+            LexErrorKind::NestingTooDeep => {
+                "move the inner code into a separate function or variable"
+            }
+            LexErrorKind::BidiControl => {
+                "remove the character, or write it as an escape such as \\u{202E} in a string"
+            }
+        }
+    }
+}
+
+/// A lexical error. The lexer also leaves a `TokenKind::Error` placeholder
+/// token covering the same span, so parsing can continue past it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    /// Message specific to this occurrence.
+    pub message: String,
+    pub span: Span,
+    /// Suggested fix.
+    pub help: Option<String>,
+}
+
+impl LexError {
+    /// Stable diagnostic code of this error's kind.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+}
+
+impl std::fmt::Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "error[{}]: {} at {}:{}",
+            self.code(),
+            self.message,
+            self.span.start.line,
+            self.span.start.column
+        )
+    }
+}
+
+impl std::error::Error for LexError {}
+
+/// Category of a lexical warning. Warnings never change the token stream.
+/// Codes come from docs/diagnostic_codes.md (W001–W099 are reserved for the lexer).
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LexWarningKind {
+    NonNormalizedIdentifier,
+    ConfusableIdentifier,
+    MixedScriptIdentifier,
+}
+
+impl LexWarningKind {
+    /// Every kind, in code order.
+    pub const ALL: &'static [LexWarningKind] = &[
+        LexWarningKind::NonNormalizedIdentifier,
+        LexWarningKind::ConfusableIdentifier,
+        LexWarningKind::MixedScriptIdentifier,
+    ];
+
+    /// Stable diagnostic code, e.g. `W001`.
+    pub fn code(self) -> &'static str {
+        match self {
+            LexWarningKind::NonNormalizedIdentifier => "W001",
+            LexWarningKind::ConfusableIdentifier => "W002",
+            LexWarningKind::MixedScriptIdentifier => "W003",
+        }
+    }
+
+    /// Short description, as listed in the diagnostic code registry.
+    pub fn description(self) -> &'static str {
+        match self {
+            LexWarningKind::NonNormalizedIdentifier => "identifier is not in Unicode NFC form",
+            LexWarningKind::ConfusableIdentifier => {
+                "identifier is visually confusable with another identifier"
+            }
+            LexWarningKind::MixedScriptIdentifier => {
+                "identifier mixes characters from several scripts"
+            }
+        }
+    }
+
+    /// Suggested fix shown with the warning.
+    pub fn help(self) -> &'static str {
+        match self {
+            LexWarningKind::NonNormalizedIdentifier => {
+                "save the file with NFC-normalized text so the name reads as it is spelled"
+            }
+            LexWarningKind::ConfusableIdentifier => {
+                "rename one of the identifiers so they cannot be mistaken for each other"
+            }
+            LexWarningKind::MixedScriptIdentifier => "spell the identifier in a single script",
+        }
+    }
+}
+
+/// A lexical warning about otherwise valid source, such as an identifier
+/// that can be mistaken for another one.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LexWarning {
+    pub kind: LexWarningKind,
+    /// Message specific to this occurrence.
+    pub message: String,
+    pub span: Span,
+    /// Suggested fix.
+    pub help: Option<String>,
+}
+
+impl LexWarning {
+    /// Stable diagnostic code of this warning's kind.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+}
+
+impl std::fmt::Display for LexWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "warning[{}]: {} at {}:{}",
+            self.code(),
+            self.message,
+            self.span.start.line,
+            self.span.start.column
+        )
+    }
+}
+
+/// NFC form of an identifier, borrowed when it is already normalized.
+pub(crate) fn nfc_name(ident: &str) -> Cow<'_, str> {
+    if ident.is_ascii() || unicode_normalization::is_nfc(ident) {
+        Cow::Borrowed(ident)
+    } else {
+        Cow::Owned(ident.nfc().collect())
+    }
+}
+
+/// Keyword token for `ident`, or `None` if it is an ordinary identifier.
+/// Keywords can still be used as names through raw identifiers (`r#match`).
+pub fn keyword_kind(ident: &str) -> Option<TokenKind> {
+    let kind = match ident {
+        "let" => TokenKind::Let,
+        "var" => TokenKind::Var,
+        "fn" => TokenKind::Fn,
+        "async" => TokenKind::Async,
+        "data" => TokenKind::Data,
+        "match" => TokenKind::Match,
+        "true" => TokenKind::True,
+        "false" => TokenKind::False,
+        "return" => TokenKind::Return,
+        "if" => TokenKind::If,
+        "else" => TokenKind::Else,
+        "while" => TokenKind::While,
+        "for" => TokenKind::For,
+        "in" => TokenKind::In,
+        "break" => TokenKind::Break,
+        "continue" => TokenKind::Continue,
+        "pub" => TokenKind::Pub,
+        "unsafe" => TokenKind::Unsafe,
+        "macro" => TokenKind::Macro,
+        "extern" => TokenKind::Extern,
+        "static" => TokenKind::Static,
+        "where" => TokenKind::Where,
+        "and" => TokenKind::LogicalAnd, // As per DESIGN_LEXER.md, `and` maps to `&&` token kind
+        "or" => TokenKind::LogicalOr,   // As per DESIGN_LEXER.md, `or` maps to `||` token kind
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Words set aside by SYNTAX_GRAMMAR §1.3.1 for features not designed yet.
+///
+/// They cannot be used as plain identifiers, but `r#loop` and friends are accepted.
+pub const RESERVED_KEYWORDS: &[&str] = &[
+    "loop", "import", "export", "type", "const", "super", "self", "Self", "crate", "mod", "use",
+    "impl", "trait", "enum", "struct", "union", "yield",
+];
+
+/// Whether `ident` is a reserved keyword (see [`RESERVED_KEYWORDS`]).
+pub fn is_reserved_keyword(ident: &str) -> bool {
+    RESERVED_KEYWORDS.contains(&ident)
+}
+
+/// Name of a bidirectional control character, or `None` for any other
+/// character. These can reorder how source is displayed without changing how
+/// it is lexed ("Trojan Source", CVE-2021-42574).
+pub fn bidi_control_name(c: char) -> Option<&'static str> {
+    let name = match c {
+        '\u{202A}' => "LEFT-TO-RIGHT EMBEDDING",
+        '\u{202B}' => "RIGHT-TO-LEFT EMBEDDING",
+        '\u{202C}' => "POP DIRECTIONAL FORMATTING",
+        '\u{202D}' => "LEFT-TO-RIGHT OVERRIDE",
+        '\u{202E}' => "RIGHT-TO-LEFT OVERRIDE",
+        '\u{2066}' => "LEFT-TO-RIGHT ISOLATE",
+        '\u{2067}' => "RIGHT-TO-LEFT ISOLATE",
+        '\u{2068}' => "FIRST STRONG ISOLATE",
+        '\u{2069}' => "POP DIRECTIONAL ISOLATE",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Writing system of an identifier character, as far as the
+/// mixed-script check needs to tell them apart.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+enum Script {
+    /// Digits, `_` and other characters shared by all scripts.
+    Common,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Han,
+    Other,
+}
+
+impl Script {
+    fn of(c: char) -> Script {
+        match c {
+            '0'..='9' | '_' => Script::Common,
+            'a'..='z' | 'A'..='Z' => Script::Latin,
+            // Combining marks take the script of the character they modify.
+            '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{20D0}'..='\u{20FF}' => {
+                Script::Common
+            }
+            '\u{00C0}'..='\u{024F}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}' => Script::Latin,
+            '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Script::Greek,
+            '\u{0400}'..='\u{052F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}' => Script::Cyrillic,
+            '\u{0530}'..='\u{058F}' => Script::Armenian,
+            '\u{0590}'..='\u{05FF}' => Script::Hebrew,
+            '\u{0600}'..='\u{06FF}' | '\u{0750}'..='\u{077F}' | '\u{08A0}'..='\u{08FF}' => {
+                Script::Arabic
+            }
+            '\u{0900}'..='\u{097F}' => Script::Devanagari,
+            '\u{0E00}'..='\u{0E7F}' => Script::Thai,
+            '\u{10A0}'..='\u{10FF}' | '\u{2D00}'..='\u{2D2F}' => Script::Georgian,
+            '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => {
+                Script::Hangul
+            }
+            '\u{3040}'..='\u{309F}' => Script::Hiragana,
+            // The prolonged sound mark is shared by Hiragana and Katakana.
+            '\u{30FC}' => Script::Common,
+            '\u{30A0}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => {
+                Script::Katakana
+            }
+            '\u{3100}'..='\u{312F}' | '\u{31A0}'..='\u{31BF}' => Script::Bopomofo,
+            '\u{3005}'
+            | '\u{3007}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FA1F}' => Script::Han,
+            c if c.is_ascii() => Script::Common,
+            _ => Script::Other,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Script::Common => "Common",
+            Script::Latin => "Latin",
+            Script::Greek => "Greek",
+            Script::Cyrillic => "Cyrillic",
+            Script::Armenian => "Armenian",
+            Script::Hebrew => "Hebrew",
+            Script::Arabic => "Arabic",
+            Script::Devanagari => "Devanagari",
+            Script::Thai => "Thai",
+            Script::Georgian => "Georgian",
+            Script::Hangul => "Hangul",
+            Script::Hiragana => "Hiragana",
+            Script::Katakana => "Katakana",
+            Script::Bopomofo => "Bopomofo",
+            Script::Han => "Han",
+            Script::Other => "other",
+        }
+    }
+}
+
+/// Scripts used by `ident`, in a fixed order, if it mixes scripts in a way
+/// UTS #39 "highly restrictive" identifiers do not allow. Latin may be
+/// combined with the scripts of Japanese, Chinese or Korean text.
+fn mixed_scripts(ident: &str) -> Option<Vec<Script>> {
+    let mut scripts: Vec<Script> = ident
+        .chars()
+        .map(Script::of)
+        .filter(|&script| script != Script::Common)
+        .collect();
+    scripts.sort();
+    scripts.dedup();
+    const ALLOWED: [&[Script]; 3] = [
+        &[
+            Script::Latin,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Han,
+        ],
+        &[Script::Latin, Script::Bopomofo, Script::Han],
+        &[Script::Latin, Script::Hangul, Script::Han],
+    ];
+    let allowed = scripts.len() <= 1
+        || ALLOWED
+            .iter()
+            .any(|set| scripts.iter().all(|script| set.contains(script)));
+    (!allowed).then_some(scripts)
+}
+
+/// Latin character that `c` is commonly mistaken for, following the
+/// prototypes of the UTS #39 confusables table.
+fn confusable_prototype(c: char) -> Option<char> {
+    let prototype = match c {
+        // Cyrillic
+        'а' => 'a',
+        'в' => 'B',
+        'е' => 'e',
+        'һ' => 'h',
+        'і' => 'i',
+        'ј' => 'j',
+        'к' => 'k',
+        'м' => 'M',
+        'н' => 'H',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'т' => 'T',
+        'у' => 'y',
+        'х' => 'x',
+        'ѕ' => 's',
+        'ԁ' => 'd',
+        'ԛ' => 'q',
+        'ԝ' => 'w',
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'І' => 'I',
+        'Ј' => 'J',
+        'Ѕ' => 'S',
+        // Greek
+        'α' => 'a',
+        'ι' => 'i',
+        'κ' => 'k',
+        'ν' => 'v',
+        'ο' => 'o',
+        'ρ' => 'p',
+        'τ' => 't',
+        'υ' => 'u',
+        'χ' => 'x',
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        // Latin lookalikes
+        'ı' => 'i',
+        'ſ' => 'f',
+        'ɑ' => 'a',
+        'ɡ' => 'g',
+        _ => return None,
+    };
+    Some(prototype)
+}
+
+/// UTS #39 skeleton of an identifier: two identifiers with the same skeleton
+/// look alike. Only the characters of [`confusable_prototype`] are mapped.
+fn skeleton(ident: &str) -> String {
+    ident
+        .nfd()
+        .map(|c| confusable_prototype(c).unwrap_or(c))
+        .nfd()
+        .collect()
+}
+
 impl Token {
+    /// Whether this is a raw identifier such as `r#match`.
+    pub fn is_raw_identifier(&self) -> bool {
+        matches!(self.literal, Some(LiteralValue::RawIdentifier(_)))
+    }
+
+    /// Interned name of an identifier token, without the `r#` of a raw identifier.
+    pub fn symbol(&self) -> Option<Symbol> {
+        match self.literal {
+            Some(LiteralValue::Identifier(symbol) | LiteralValue::RawIdentifier(symbol)) => {
+                Some(symbol)
+            }
+            _ => None,
+        }
+    }
+
     /// Helper to emit a bare EOF token in tests.
     pub fn eof_dummy() -> Self {
         Token {
@@ -177,61 +910,273 @@ impl Token {
     }
 }
 
+/// Which characters may be used to indent a line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum IndentPolicy {
+    /// Spaces or tabs, as long as a single line does not mix them.
+    #[default]
+    Any,
+    /// Only spaces; a tab in indentation is an error.
+    SpacesOnly,
+    /// Only tabs; a space in indentation is an error.
+    TabsOnly,
+}
+
+/// Scanner that recognizes tokens.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum LexerBackend {
+    /// Hand-written character-by-character scanner.
+    #[default]
+    HandWritten,
+    /// Table-driven DFA generated from a declarative token specification,
+    /// falling back to the hand-written scanner for literals and comments.
+    /// Produces the same tokens as [`LexerBackend::HandWritten`].
+    Dfa,
+}
+
+/// Lexer settings that affect indentation handling.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LexerConfig {
+    /// Columns a tab counts for when measuring indentation.
+    pub tab_width: usize,
+    pub indent_policy: IndentPolicy,
+    /// Maximum number of nested indentation levels, block comments or
+    /// interpolated strings.
+    pub max_nesting_depth: usize,
+    /// Emit a `Shebang` token for a leading `#!` line instead of skipping it.
+    pub emit_shebang: bool,
+    pub backend: LexerBackend,
+}
+
+impl Default for LexerConfig {
+    fn default() -> Self {
+        LexerConfig {
+            tab_width: 4,
+            indent_policy: IndentPolicy::Any,
+            max_nesting_depth: 64,
+            emit_shebang: false,
+            backend: LexerBackend::HandWritten,
+        }
+    }
+}
+
+impl LexerConfig {
+    /// Default configuration: tabs are 4 columns, either character may indent
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn with_indent_policy(mut self, indent_policy: IndentPolicy) -> Self {
+        self.indent_policy = indent_policy;
+        self
+    }
+
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    pub fn with_shebang_token(mut self, emit_shebang: bool) -> Self {
+        self.emit_shebang = emit_shebang;
+        self
+    }
+
+    pub fn with_backend(mut self, backend: LexerBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+}
+
 pub struct Lexer<'a> {
     input: &'a str,
     chars: Peekable<CharIndices<'a>>,
     line: usize,
     column: usize,
-    indent_stack: Vec<usize>, // track indentation levels
-    pending_dedents: usize,   // track dedents to emit
-    at_line_start: bool,      // are we at the start of a new line?
+    indent_stack: Vec<usize>,         // track indentation levels
+    pending_dedents: usize,           // track dedents to emit
+    misaligned_indent: Option<usize>, // width of the line last reported with E005
+    at_line_start: bool,              // are we at the start of a new line?
+    errors: Vec<LexError>,            // diagnostics for the Error tokens emitted so far
+    warnings: Vec<LexWarning>,
+    seen_identifiers: HashSet<String>, // spellings already checked for warnings
+    skeletons: HashMap<String, (String, Position)>, // first identifier per skeleton
+    config: LexerConfig,
+    shebang: Option<Scanned>, // leading `#!` line, emitted first if configured
+    eager: bool,              // compute literal values and identifier warnings while scanning
+    interpolation_depth: usize, // interpolated strings enclosing this embedded lexer
+}
+
+/// A token as scanned, before its text is taken from the source: [`Token`]
+/// copies the text and [`borrowed::Token`] borrows it.
+#[derive(Debug, Clone)]
+struct Scanned {
+    kind: TokenKind,
+    literal: Option<LiteralValue>,
+    span: Span,
+}
+
+impl Scanned {
+    fn into_token(self, input: &str) -> Token {
+        let source = &input[self.span.start.offset..self.span.end.offset];
+        // Identifiers read as their NFC form; raw identifiers keep their spelling.
+        let lexeme = match self.kind {
+            TokenKind::Identifier if !source.starts_with("r#") => nfc_name(source).into_owned(),
+            _ => source.to_string(),
+        };
+        Token {
+            kind: self.kind,
+            lexeme,
+            literal: self.literal,
+            span: self.span,
+        }
+    }
+
+    fn borrow(self, input: &str) -> borrowed::Token<'_> {
+        borrowed::Token {
+            kind: self.kind,
+            lexeme: &input[self.span.start.offset..self.span.end.offset],
+            span: self.span,
+        }
+    }
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
-        // Shebang handling: if input starts with "#!", skip the first line
-        let input = if input.starts_with("#!") {
-            match input.find('\n') {
-                Some(idx) => &input[idx + 1..],
-                None => "", // shebang is the whole file
-            }
-        } else {
-            input
-        };
-        Lexer {
+        Self::with_config(input, LexerConfig::default())
+    }
+
+    /// Lexer for a whole file. A leading UTF-8 byte order mark and `#!` line
+    /// are skipped without shifting offsets, so spans index the original input.
+    pub fn with_config(input: &'a str, config: LexerConfig) -> Self {
+        let mut lexer = Lexer {
             input,
             chars: input.char_indices().peekable(),
             line: 1,
             column: 1,
             indent_stack: vec![0],
             pending_dedents: 0,
+            misaligned_indent: None,
             at_line_start: true,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            seen_identifiers: HashSet::new(),
+            skeletons: HashMap::new(),
+            config,
+            shebang: None,
+            eager: true,
+            interpolation_depth: 0,
+        };
+        // The byte order mark takes no column.
+        if input.starts_with('\u{FEFF}') {
+            lexer.chars.next();
+        }
+        lexer.lex_shebang();
+        lexer
+    }
+
+    /// Consume a leading `#!` line. `#![` opens an inner attribute instead,
+    /// e.g. `#![allow(dead_code)]`.
+    fn lex_shebang(&mut self) {
+        let start_offset = self.current_offset();
+        let rest = &self.input[start_offset..];
+        if !rest.starts_with("#!") || rest.starts_with("#![") {
+            return;
+        }
+        while self.chars.peek().is_some() && !self.at_newline() {
+            self.advance_char();
+        }
+        let end_offset = self.current_offset();
+        let shebang = Scanned {
+            kind: TokenKind::Shebang,
+            literal: None,
+            span: Span {
+                start: Position {
+                    line: 1,
+                    column: 1,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: 1,
+                    column: self.column,
+                    offset: end_offset,
+                },
+            },
+        };
+        if self.config.emit_shebang {
+            self.shebang = Some(shebang);
+        } else {
+            // Drop the line break too, as if the file started on the next line.
+            while self.at_newline() {
+                self.advance_char();
+            }
+        }
+    }
+
+    /// Whether the cursor is on a line break: `\n` or `\r\n`.
+    fn at_newline(&mut self) -> bool {
+        match self.chars.peek() {
+            Some(&(_, '\n')) => true,
+            Some(&(_, '\r')) => self.peek_nth_char(1).is_some_and(|(_, c)| c == '\n'),
+            _ => false,
         }
     }
 
-    pub fn lex(mut self) -> Vec<Token> {
-        let mut tokens = Vec::new();
+    /// Lex the whole input. Errors show up as `TokenKind::Error` tokens whose
+    /// literal is the message; use [`Lexer::lex_with_errors`] for structured errors.
+    pub fn lex(self) -> Vec<Token> {
+        self.lex_with_errors().0
+    }
+
+    /// Lex the whole input, returning the tokens together with one [`LexError`]
+    /// per `TokenKind::Error` placeholder token, in source order.
+    pub fn lex_with_errors(self) -> (Vec<Token>, Vec<LexError>) {
+        let (tokens, errors, _) = self.lex_with_diagnostics();
+        (tokens, errors)
+    }
+
+    /// Like [`Lexer::lex_with_errors`], but also returns the [`LexWarning`]s
+    /// about suspicious identifiers, in source order.
+    pub fn lex_with_diagnostics(self) -> (Vec<Token>, Vec<LexError>, Vec<LexWarning>) {
+        let input = self.input;
+        let (tokens, errors, warnings) = self.scan();
+        let tokens = tokens
+            .into_iter()
+            .map(|token| token.into_token(input))
+            .collect();
+        (tokens, errors, warnings)
+    }
+
+    /// Lex the whole input into tokens that borrow their lexemes from it.
+    /// Literal values are not computed up front; see [`borrowed::Token::literal`].
+    pub fn lex_borrowed(self) -> Vec<borrowed::Token<'a>> {
+        self.lex_borrowed_with_errors().0
+    }
+
+    /// Like [`Lexer::lex_borrowed`], but also returns one [`LexError`] per
+    /// `TokenKind::Error` placeholder token, in source order.
+    pub fn lex_borrowed_with_errors(mut self) -> (Vec<borrowed::Token<'a>>, Vec<LexError>) {
+        self.eager = false;
+        let input = self.input;
+        let (tokens, errors, _) = self.scan();
+        let tokens = tokens
+            .into_iter()
+            .map(|token| token.borrow(input))
+            .collect();
+        (tokens, errors)
+    }
+
+    fn scan(mut self) -> (Vec<Scanned>, Vec<LexError>, Vec<LexWarning>) {
+        let mut tokens: Vec<Scanned> = self.shebang.take().into_iter().collect();
         while let Some(&(idx, ch)) = self.chars.peek() {
             // Handle dedents first
             if self.pending_dedents > 0 {
                 self.pending_dedents -= 1;
-                tokens.push(Token {
-                    kind: TokenKind::Dedent,
-                    lexeme: String::new(),
-                    literal: None,
-                    span: Span {
-                        start: Position {
-                            line: self.line,
-                            column: self.column,
-                            offset: idx,
-                        },
-                        end: Position {
-                            line: self.line,
-                            column: self.column,
-                            offset: idx,
-                        },
-                    },
-                });
+                tokens.push(self.dedent_token());
                 continue;
             }
             // Indentation logic at start of line
@@ -239,6 +1184,7 @@ impl<'a> Lexer<'a> {
                 let mut current_indent = 0;
                 let mut indent_char_type: Option<char> = None; // 's' for space, 't' for tab
                 let mut mixed_indent_error = false;
+                let mut forbidden_indent: Option<char> = None;
                 let indent_start_offset = self.chars.peek().map_or(0, |(i, _)| *i);
                 let indent_start_col = self.column;
 
@@ -248,6 +1194,9 @@ impl<'a> Lexer<'a> {
                         if indent_char_type == Some('t') {
                             mixed_indent_error = true;
                         }
+                        if self.config.indent_policy == IndentPolicy::TabsOnly {
+                            forbidden_indent.get_or_insert(ch);
+                        }
                         indent_char_type = Some('s');
                         current_indent += 1;
                         self.advance_char(); // Use normal advance_char
@@ -255,8 +1204,11 @@ impl<'a> Lexer<'a> {
                         if indent_char_type == Some('s') {
                             mixed_indent_error = true;
                         }
+                        if self.config.indent_policy == IndentPolicy::SpacesOnly {
+                            forbidden_indent.get_or_insert(ch);
+                        }
                         indent_char_type = Some('t');
-                        current_indent += 4; // Tabs are 4 spaces
+                        current_indent += self.config.tab_width;
                         self.advance_char();
                     } else {
                         break; // Not whitespace
@@ -267,69 +1219,112 @@ impl<'a> Lexer<'a> {
                     // Consume the rest of the mixed indent line up to non-whitespace or newline
                     let mut _error_lexeme_len = 0;
                     while let Some(&(_, ch_err)) = self.chars.peek() {
-                        if ch_err != '\n' && ch_err.is_whitespace() {
+                        if !self.at_newline() && ch_err.is_whitespace() {
                             _error_lexeme_len += ch_err.len_utf8();
                             self.advance_char();
                         } else {
                             break;
                         }
                     }
-                    // The error lexeme is from indent_start_offset to current pos of self.chars
-                    let error_lexeme = self
-                        .input
-                        .get(indent_start_offset..self.current_offset())
-                        .unwrap_or("")
-                        .to_string();
-
-                    tokens.push(Token {
-                        kind: TokenKind::Error,
-                        lexeme: error_lexeme,
-                        literal: Some(LiteralValue::String(
-                            "Mixed tabs and spaces in indentation are not allowed.".to_string(),
-                        )),
-                        span: Span {
-                            start: Position {
-                                line: self.line,
-                                column: indent_start_col,
-                                offset: indent_start_offset,
-                            },
-                            end: Position {
-                                line: self.line,
-                                column: self.column,
-                                offset: self.current_offset(),
-                            },
-                        },
-                    });
-                    // Skip to next line or handle rest of line as normal?
-                    // For now, let's assume the error token covers the bad indent, and lexing continues.
-                    // We need to ensure `at_line_start` is false now.
+                    // The error token covers the bad indent, and lexing continues.
+                    let error = self.literal_error(
+                        LexErrorKind::MixedIndentation,
+                        indent_start_offset,
+                        self.line,
+                        indent_start_col,
+                        "Mixed tabs and spaces in indentation are not allowed.".to_string(),
+                    );
+                    tokens.push(error);
                     self.at_line_start = false; // Processed the start of the line (even if it was an error)
                 } else {
-                    // Original indentation logic
-                    if current_indent > *self.indent_stack.last().unwrap() {
-                        self.indent_stack.push(current_indent);
-                        tokens.push(Token {
-                            kind: TokenKind::Indent,
-                            lexeme: String::new(),
-                            literal: None,
-                            span: Span {
-                                start: Position {
-                                    line: self.line,
-                                    column: 1,
-                                    offset: idx,
-                                },
-                                end: Position {
-                                    line: self.line,
-                                    column: current_indent + 1,
-                                    offset: idx + current_indent,
+                    if let Some(forbidden) = forbidden_indent {
+                        let message = if forbidden == '\t' {
+                            "Tabs are not allowed in indentation; this project indents with spaces."
+                        } else {
+                            "Spaces are not allowed in indentation; this project indents with tabs."
+                        };
+                        let error = self.literal_error(
+                            LexErrorKind::ForbiddenIndentation,
+                            indent_start_offset,
+                            self.line,
+                            indent_start_col,
+                            message.to_string(),
+                        );
+                        tokens.push(error);
+                    }
+                    let blank_line = self.chars.peek().is_none() || self.at_newline();
+                    let top = *self.indent_stack.last().unwrap();
+                    // Lines continuing a misaligned run already reported stay
+                    // at the level the first one was recovered to.
+                    let current_indent = match self.misaligned_indent {
+                        _ if blank_line => current_indent,
+                        Some(width) if width == current_indent => top,
+                        _ => {
+                            self.misaligned_indent = None;
+                            current_indent
+                        }
+                    };
+                    if current_indent > top {
+                        if self.indent_stack.len() > self.config.max_nesting_depth {
+                            // Too deep: stay at the enclosing level.
+                            let message = format!(
+                                "Indentation is nested deeper than the maximum of {} levels.",
+                                self.config.max_nesting_depth
+                            );
+                            let error = self.literal_error(
+                                LexErrorKind::NestingTooDeep,
+                                indent_start_offset,
+                                self.line,
+                                indent_start_col,
+                                message,
+                            );
+                            tokens.push(error);
+                        } else {
+                            self.indent_stack.push(current_indent);
+                            let indent_end_offset = self.current_offset();
+                            tokens.push(Scanned {
+                                kind: TokenKind::Indent,
+                                literal: None,
+                                span: Span {
+                                    start: Position {
+                                        line: self.line,
+                                        column: indent_start_col,
+                                        offset: indent_start_offset,
+                                    },
+                                    end: Position {
+                                        line: self.line,
+                                        column: self.column,
+                                        offset: indent_end_offset,
+                                    },
                                 },
-                            },
-                        });
-                    } else if current_indent < *self.indent_stack.last().unwrap() {
+                            });
+                        }
+                    } else if current_indent < top {
                         while current_indent < *self.indent_stack.last().unwrap() {
                             self.indent_stack.pop();
                             self.pending_dedents += 1;
                         }
+                        if current_indent != *self.indent_stack.last().unwrap() && !blank_line {
+                            // Close the blocks first, then report the line as
+                            // belonging to the nearest enclosing level.
+                            while self.pending_dedents > 0 {
+                                self.pending_dedents -= 1;
+                                tokens.push(self.dedent_token());
+                            }
+                            let message = self.unexpected_dedent_message(
+                                current_indent,
+                                indent_char_type == Some('t'),
+                            );
+                            let error = self.literal_error(
+                                LexErrorKind::UnexpectedDedent,
+                                indent_start_offset,
+                                self.line,
+                                indent_start_col,
+                                message,
+                            );
+                            tokens.push(error);
+                            self.misaligned_indent = Some(current_indent);
+                        }
                     }
                     // Don't advance chars again - we already consumed leading whitespace in indentation calculation above
                     self.at_line_start = false;
@@ -337,29 +1332,14 @@ impl<'a> Lexer<'a> {
                 // After indentation processing, re-peek to get the current character
                 continue;
             }
-            // Handle Newlines
-            if ch == '\n' {
-                let start_offset = idx;
-                let start_col = self.column;
-                self.advance_char();
-                tokens.push(Token {
-                    kind: TokenKind::Newline,
-                    lexeme: "\n".to_string(),
-                    literal: None,
-                    span: Span {
-                        start: Position {
-                            line: self.line - 1,
-                            column: start_col,
-                            offset: start_offset,
-                        },
-                        end: Position {
-                            line: self.line - 1,
-                            column: start_col + 1,
-                            offset: start_offset + 1,
-                        },
-                    },
-                });
-                self.at_line_start = true;
+            if self.config.backend == LexerBackend::Dfa && self.lex_with_dfa(idx, &mut tokens) {
+                continue;
+            }
+            // Handle Newlines; `\r\n` is a single line break
+            if self.at_newline() {
+                let width = if ch == '\r' { 2 } else { 1 };
+                let token = self.newline_token(idx, width);
+                tokens.push(token);
                 continue;
             }
             if ch.is_ascii_digit()
@@ -378,10 +1358,13 @@ impl<'a> Lexer<'a> {
                     // Line comment
                     self.advance_char(); // consume '/'
                     self.advance_char(); // consume '/'
-                    while let Some(&(_i, c)) = self.chars.peek() {
-                        if c == '\n' {
+                    while let Some(&(offset, c)) = self.chars.peek() {
+                        if self.at_newline() {
                             break; // End of line comment
                         }
+                        if bidi_control_name(c).is_some() {
+                            tokens.push(self.bidi_error(offset, c, "a comment"));
+                        }
                         self.advance_char();
                     }
                     continue;
@@ -396,14 +1379,35 @@ impl<'a> Lexer<'a> {
 
                     let mut nesting_level = 1;
                     let mut closed = false;
+                    let mut too_deep = false;
 
-                    while let Some(&(_i, c1)) = self.chars.peek() {
+                    while let Some(&(i, c1)) = self.chars.peek() {
+                        if bidi_control_name(c1).is_some() {
+                            tokens.push(self.bidi_error(i, c1, "a comment"));
+                        }
+                        let (c1_line, c1_col) = (self.line, self.column);
                         self.advance_char(); // Consume current char. THIS MUTATES THE REAL self.line/col
                         if c1 == '/' {
                             if let Some(&(_j, c2)) = self.chars.peek() {
                                 if c2 == '*' {
                                     self.advance_char();
                                     nesting_level += 1;
+                                    if nesting_level > self.config.max_nesting_depth && !too_deep {
+                                        // Report once, but keep counting so the comment ends where it should.
+                                        too_deep = true;
+                                        let message = format!(
+                                            "Block comment is nested deeper than the maximum of {} levels.",
+                                            self.config.max_nesting_depth
+                                        );
+                                        let error = self.literal_error(
+                                            LexErrorKind::NestingTooDeep,
+                                            i,
+                                            c1_line,
+                                            c1_col,
+                                            message,
+                                        );
+                                        tokens.push(error);
+                                    }
                                 }
                             }
                         } else if c1 == '*' {
@@ -421,9 +1425,10 @@ impl<'a> Lexer<'a> {
                     }
 
                     if !closed {
+                        let comment_end_offset = self.current_offset();
                         let error_lexeme_str = self
                             .input
-                            .get(comment_start_offset..self.current_offset())
+                            .get(comment_start_offset..comment_end_offset)
                             .unwrap_or("");
 
                         let mut calc_end_line = comment_start_line;
@@ -438,9 +1443,8 @@ impl<'a> Lexer<'a> {
                             }
                         }
 
-                        tokens.push(Token {
+                        tokens.push(self.error_token(LexErrorKind::UnterminatedBlockComment, Scanned {
                             kind: TokenKind::Error,
-                            lexeme: error_lexeme_str.to_string(),
                             literal: Some(LiteralValue::String(
                                 "Unterminated block comment: expected closing */ before end of file.".to_string(),
                             )),
@@ -453,10 +1457,10 @@ impl<'a> Lexer<'a> {
                                 end: Position {
                                     line: calc_end_line,
                                     column: calc_end_col,
-                                    offset: self.current_offset(),
+                                    offset: comment_end_offset,
                                 },
                             },
-                        });
+                        }));
                     }
                     self.at_line_start = self.line != comment_start_line;
                     continue;
@@ -476,16 +1480,26 @@ impl<'a> Lexer<'a> {
                 if self.peek_nth_char(1).is_some_and(|(_, c)| c == '"')
                     && self.peek_nth_char(2).is_some_and(|(_, c)| c == '"')
                 {
-                    tokens.push(self.lex_multiline_string_literal(idx));
+                    let token = self.lex_multiline_string_literal(idx);
+                    tokens.push(self.reject_bidi(token));
                 } else {
-                    tokens.push(self.lex_string_literal());
+                    let token = self.lex_string_literal();
+                    tokens.push(self.reject_bidi(token));
                 }
                 continue;
             }
 
+            // Lifetimes: 'a, 'static. A quote before an identifier that is not
+            // closed right after it (as in 'a' or 'ab') starts a lifetime.
+            if ch == '\'' && self.at_lifetime() {
+                self.lex_lifetime(idx, &mut tokens);
+                continue;
+            }
+
             // Character Literals: '...'
             if ch == '\'' {
-                tokens.push(self.lex_char_literal());
+                let token = self.lex_char_literal();
+                tokens.push(self.reject_bidi(token));
                 continue;
             }
 
@@ -498,9 +1512,8 @@ impl<'a> Lexer<'a> {
                         let start_col = self.column;
                         self.advance_char();
                         let end_offset = self.current_offset();
-                        tokens.push(Token {
+                        tokens.push(Scanned {
                             kind: TokenKind::Underscore,
-                            lexeme: "_".to_string(),
                             literal: None,
                             span: Span {
                                 start: Position {
@@ -523,9 +1536,8 @@ impl<'a> Lexer<'a> {
                     let start_col = self.column;
                     self.advance_char();
                     let end_offset = self.current_offset();
-                    tokens.push(Token {
+                    tokens.push(Scanned {
                         kind: TokenKind::Underscore,
-                        lexeme: "_".to_string(),
                         literal: None,
                         span: Span {
                             start: Position {
@@ -548,17 +1560,39 @@ impl<'a> Lexer<'a> {
             if ch == 'b' {
                 if let Some((_, next_ch)) = self.peek_nth_char(1) {
                     if next_ch == '\'' || next_ch == '"' {
-                        tokens.push(self.lex_byte_literal(idx)); // Pass idx for start_offset
+                        let token = self.lex_byte_literal(idx);
+                        tokens.push(self.reject_bidi(token));
                         continue;
                     }
                 }
             }
 
+            // Interpolated String Literals: f"Hello, {name}!"
+            if ch == 'f' && self.peek_nth_char(1).is_some_and(|(_, c)| c == '"') {
+                let token = self.lex_interpolated_string_literal(idx);
+                tokens.push(self.reject_bidi(token));
+                continue;
+            }
+
+            // Raw Identifiers: r#match
+            if ch == 'r'
+                && self.peek_nth_char(1).is_some_and(|(_, c)| c == '#')
+                && self
+                    .peek_nth_char(2)
+                    .is_some_and(|(_, c)| is_xid_start(c) || c == '_')
+            {
+                let token = self.lex_raw_identifier(idx);
+                self.check_identifier(&token);
+                tokens.push(token);
+                continue;
+            }
+
             // Raw String Literals: r"..." or r#"..."#
             if ch == 'r' {
                 if let Some((_, next_ch)) = self.peek_nth_char(1) {
                     if next_ch == '"' || next_ch == '#' {
-                        tokens.push(self.lex_raw_string_literal(idx));
+                        let token = self.lex_raw_string_literal(idx);
+                        tokens.push(self.reject_bidi(token));
                         continue;
                     }
                 }
@@ -567,72 +1601,14 @@ impl<'a> Lexer<'a> {
             // Identifier or Keyword
             if is_xid_start(ch) || ch == '_' {
                 // Allow leading underscore for identifiers
-                let start = idx;
                 let start_col = self.column;
-                let mut ident_str = String::new();
-                ident_str.push(ch);
                 self.advance_char();
 
-                while let Some(&(_, c)) = self.chars.peek() {
-                    if is_xid_continue(c) {
-                        ident_str.push(c);
-                        self.advance_char();
-                    } else {
-                        break;
-                    }
+                while self.chars.peek().is_some_and(|&(_, c)| is_xid_continue(c)) {
+                    self.advance_char();
                 }
-                let end_offset = self.current_offset();
-
-                // NFC Normalization
-                let normalized_ident: String = ident_str.nfc().collect();
-
-                let kind = match normalized_ident.as_str() {
-                    "let" => TokenKind::Let,
-                    "var" => TokenKind::Var,
-                    "fn" => TokenKind::Fn,
-                    "async" => TokenKind::Async,
-                    "data" => TokenKind::Data,
-                    "match" => TokenKind::Match,
-                    "true" => TokenKind::True,
-                    "false" => TokenKind::False,
-                    "return" => TokenKind::Return,
-                    "if" => TokenKind::If,
-                    "else" => TokenKind::Else,
-                    "while" => TokenKind::While,
-                    "for" => TokenKind::For,
-                    "in" => TokenKind::In,
-                    "break" => TokenKind::Break,
-                    "continue" => TokenKind::Continue,
-                    "pub" => TokenKind::Pub,
-                    "unsafe" => TokenKind::Unsafe,
-                    "and" => TokenKind::LogicalAnd, // As per DESIGN_LEXER.md, `and` maps to `&&` token kind
-                    "or" => TokenKind::LogicalOr, // As per DESIGN_LEXER.md, `or` maps to `||` token kind
-                    _ => TokenKind::Identifier,
-                };
-
-                let literal_value = match kind {
-                    TokenKind::True => Some(LiteralValue::Boolean(true)),
-                    TokenKind::False => Some(LiteralValue::Boolean(false)),
-                    _ => None,
-                };
-
-                tokens.push(Token {
-                    kind,
-                    lexeme: normalized_ident.to_string(),
-                    literal: literal_value,
-                    span: Span {
-                        start: Position {
-                            line: self.line,
-                            column: start_col,
-                            offset: start,
-                        },
-                        end: Position {
-                            line: self.line,
-                            column: self.column,
-                            offset: end_offset,
-                        },
-                    },
-                });
+                let token = self.identifier_token(idx, start_col);
+                tokens.push(token);
                 continue;
             }
 
@@ -672,9 +1648,8 @@ impl<'a> Lexer<'a> {
                         self.advance_char();
                     }
                     let end_offset = self.current_offset();
-                    tokens.push(Token {
-                        kind: kind.clone(),
-                        lexeme: op.to_string(),
+                    tokens.push(Scanned {
+                        kind: *kind,
                         literal: None,
                         span: Span {
                             start: Position {
@@ -705,6 +1680,8 @@ impl<'a> Lexer<'a> {
                 ')' => TokenKind::RParen,
                 '{' => TokenKind::LBrace,
                 '}' => TokenKind::RBrace,
+                '[' => TokenKind::LBracket,
+                ']' => TokenKind::RBracket,
                 ',' => TokenKind::Comma,
                 ':' => TokenKind::Colon,
                 '+' => TokenKind::Plus,
@@ -721,12 +1698,13 @@ impl<'a> Lexer<'a> {
                 '_' => TokenKind::Underscore,
                 '%' => TokenKind::Percent,
                 '?' => TokenKind::Question,
+                '$' => TokenKind::Dollar,
+                '#' => TokenKind::Hash,
+                '@' => TokenKind::At,
                 _ => TokenKind::Error,
             };
-            let lexeme = ch.to_string();
-            tokens.push(Token {
+            let token = Scanned {
                 kind,
-                lexeme: lexeme.clone(),
                 literal: None,
                 span: Span {
                     start: Position {
@@ -740,47 +1718,33 @@ impl<'a> Lexer<'a> {
                         offset: idx + ch.len_utf8(),
                     },
                 },
-            });
-            self.advance_char();
-
-            // At the very end, if nothing matched, emit Error for unrecognized input
-            if !ch.is_ascii() || ch.is_control() {
-                let start_offset = idx;
-                let start_col = self.column;
-                self.advance_char();
-                let end_offset = self.current_offset();
-                tokens.push(Token {
-                    kind: TokenKind::Error,
-                    lexeme: self
-                        .input
-                        .get(start_offset..end_offset)
-                        .unwrap_or("")
-                        .to_string(),
-                    literal: Some(LiteralValue::String("Unrecognized input".to_string())),
-                    span: Span {
-                        start: Position {
-                            line: self.line,
-                            column: start_col,
-                            offset: start_offset,
-                        },
-                        end: Position {
-                            line: self.line,
-                            column: self.column,
-                            offset: end_offset,
-                        },
-                    },
-                });
-                continue;
+            };
+            if bidi_control_name(ch).is_some() {
+                let token = self.bidi_error(idx, ch, "source code");
+                tokens.push(token);
+            } else if token.kind == TokenKind::Error {
+                // Unrecognized input
+                let token = Scanned {
+                    literal: Some(LiteralValue::String(format!(
+                        "Unrecognized character '{}' (U+{:04X})",
+                        ch.escape_default(),
+                        ch as u32
+                    ))),
+                    ..token
+                };
+                tokens.push(self.error_token(LexErrorKind::InvalidCharacter, token));
+            } else {
+                tokens.push(token);
             }
+            self.advance_char();
         }
         // At EOF, flush any remaining dedents
         while self.indent_stack.len() > 1 {
             self.indent_stack.pop();
             let current_offset = self.input.len(); // EOF is at the end of input
             let (line, col) = self.get_line_col_for_offset(current_offset);
-            tokens.push(Token {
+            tokens.push(Scanned {
                 kind: TokenKind::Dedent,
-                lexeme: String::new(),
                 literal: None,
                 span: Span {
                     start: Position {
@@ -800,37 +1764,449 @@ impl<'a> Lexer<'a> {
         let eof_offset = self.input.len();
         let (eof_line, eof_col) = self.get_line_col_for_offset(eof_offset);
 
-        tokens.push(Token {
+        tokens.push(Scanned {
             kind: TokenKind::Eof,
-            lexeme: String::new(),
             literal: None,
             span: Span {
                 start: Position {
-                    line: eof_line,
-                    column: eof_col,
-                    offset: eof_offset,
+                    line: eof_line,
+                    column: eof_col,
+                    offset: eof_offset,
+                },
+                end: Position {
+                    line: eof_line,
+                    column: eof_col,
+                    offset: eof_offset,
+                },
+            },
+        });
+        (tokens, self.errors, self.warnings)
+    }
+
+    /// Lexer for source embedded in a larger file, e.g. an expression inside
+    /// an interpolated string. Positions continue from `line` and `column`;
+    /// offsets stay relative to `input`.
+    fn embedded(input: &'a str, line: usize, column: usize) -> Self {
+        Lexer {
+            input,
+            chars: input.char_indices().peekable(),
+            line,
+            column,
+            indent_stack: vec![0],
+            pending_dedents: 0,
+            misaligned_indent: None,
+            at_line_start: false,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            seen_identifiers: HashSet::new(),
+            skeletons: HashMap::new(),
+            config: LexerConfig::default(),
+            shebang: None,
+            eager: true,
+            interpolation_depth: 0,
+        }
+    }
+
+    /// Scan the token at `start` with the DFA backend. Returns `false`, having
+    /// consumed nothing, when the hand-written scanner has to take over.
+    fn lex_with_dfa(&mut self, start: usize, tokens: &mut Vec<Scanned>) -> bool {
+        let rest = &self.input.as_bytes()[start..];
+        let Some((action, len)) = dfa::dfa().longest_match(rest) else {
+            return false;
+        };
+        let next = rest.get(len).copied();
+        let start_col = self.column;
+        match action {
+            dfa::Action::Defer => return false,
+            dfa::Action::Newline => {
+                let token = self.newline_token(start, len);
+                tokens.push(token);
+                return true;
+            }
+            // A non-ASCII character may continue the identifier.
+            dfa::Action::Identifier if next.is_some_and(|byte| !byte.is_ascii()) => return false,
+            // Wide, suffixed and fractional numbers take the full number scanner.
+            dfa::Action::Integer
+                if len > 18
+                    || next.is_some_and(|byte| {
+                        byte == b'.'
+                            || byte == b'_'
+                            || byte.is_ascii_alphanumeric()
+                            || !byte.is_ascii()
+                    }) =>
+            {
+                return false
+            }
+            _ => {}
+        }
+
+        // Other matches are ASCII without line breaks: one column per byte.
+        for _ in 0..len {
+            self.chars.next();
+        }
+        self.column += len;
+        let span = Span {
+            start: Position {
+                line: self.line,
+                column: start_col,
+                offset: start,
+            },
+            end: Position {
+                line: self.line,
+                column: self.column,
+                offset: start + len,
+            },
+        };
+        match action {
+            dfa::Action::Skip | dfa::Action::Defer | dfa::Action::Newline => {}
+            dfa::Action::Identifier => {
+                let token = self.identifier_token(start, start_col);
+                tokens.push(token);
+            }
+            dfa::Action::Integer => {
+                let digits = &self.input[start..start + len];
+                tokens.push(Scanned {
+                    kind: TokenKind::IntegerLiteral,
+                    literal: digits.parse().ok().map(LiteralValue::Integer),
+                    span,
+                });
+            }
+            dfa::Action::Token(kind) => tokens.push(Scanned {
+                kind,
+                literal: None,
+                span,
+            }),
+        }
+        true
+    }
+
+    /// Consume a line break of `width` bytes and return its `Newline` token.
+    fn newline_token(&mut self, start_offset: usize, width: usize) -> Scanned {
+        let start_col = self.column;
+        for _ in 0..width {
+            self.advance_char();
+        }
+        self.at_line_start = true;
+        Scanned {
+            kind: TokenKind::Newline,
+            literal: None,
+            span: Span {
+                start: Position {
+                    line: self.line - 1,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: self.line - 1,
+                    column: start_col + width,
+                    offset: start_offset + width,
+                },
+            },
+        }
+    }
+
+    /// Identifier, keyword or reserved-keyword error token for the name
+    /// consumed since `start_offset`.
+    fn identifier_token(&mut self, start_offset: usize, start_col: usize) -> Scanned {
+        let end_offset = self.current_offset();
+
+        // NFC Normalization
+        let normalized_ident = nfc_name(&self.input[start_offset..end_offset]);
+
+        let kind = if is_reserved_keyword(&normalized_ident) {
+            TokenKind::Error
+        } else {
+            keyword_kind(&normalized_ident).unwrap_or(TokenKind::Identifier)
+        };
+
+        let literal_value = match kind {
+            TokenKind::True => Some(LiteralValue::Boolean(true)),
+            TokenKind::False => Some(LiteralValue::Boolean(false)),
+            TokenKind::Error => Some(LiteralValue::String(format!(
+                "`{0}` is a reserved keyword, reserved for future use (write `r#{0}` to use it as an identifier)",
+                normalized_ident
+            ))),
+            TokenKind::Identifier if self.eager => {
+                Some(LiteralValue::Identifier(Symbol::intern(&normalized_ident)))
+            }
+            _ => None,
+        };
+
+        let token = Scanned {
+            kind,
+            literal: literal_value,
+            span: Span {
+                start: Position {
+                    line: self.line,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: self.line,
+                    column: self.column,
+                    offset: end_offset,
+                },
+            },
+        };
+        if token.kind == TokenKind::Error {
+            self.error_token(LexErrorKind::ReservedKeyword, token)
+        } else {
+            self.check_identifier(&token);
+            token
+        }
+    }
+
+    /// Zero-width `Dedent` token at the current position.
+    fn dedent_token(&mut self) -> Scanned {
+        let position = Position {
+            line: self.line,
+            column: self.column,
+            offset: self.current_offset(),
+        };
+        Scanned {
+            kind: TokenKind::Dedent,
+            literal: None,
+            span: Span {
+                start: position,
+                end: position,
+            },
+        }
+    }
+
+    /// Message for a dedent that lands between two enclosing levels, listing
+    /// the levels that would have been accepted.
+    fn unexpected_dedent_message(&self, found: usize, uses_tabs: bool) -> String {
+        let mut levels: Vec<String> = self
+            .indent_stack
+            .iter()
+            .rev()
+            .map(|level| level.to_string())
+            .collect();
+        let expected = match levels.len() {
+            1 => levels.remove(0),
+            _ => {
+                let last = levels.pop().unwrap();
+                format!("{} or {}", levels.join(", "), last)
+            }
+        };
+        let mut message = format!(
+            "Dedent to an unexpected level: found {found} columns of indentation, expected {expected}."
+        );
+        if uses_tabs {
+            message.push_str(&format!(
+                " A tab counts as {} columns.",
+                self.config.tab_width
+            ));
+        }
+        message
+    }
+
+    /// Record the diagnostic for an error placeholder token and return the token.
+    /// The token's literal carries the message.
+    fn error_token(&mut self, kind: LexErrorKind, token: Scanned) -> Scanned {
+        let message = match &token.literal {
+            Some(LiteralValue::String(message)) => message.clone(),
+            _ => kind.description().to_string(),
+        };
+        self.errors.push(LexError {
+            kind,
+            message,
+            span: token.span,
+            help: Some(kind.help().to_string()),
+        });
+        token
+    }
+
+    fn warn(&mut self, kind: LexWarningKind, message: String, span: &Span) {
+        self.warnings.push(LexWarning {
+            kind,
+            message,
+            span: *span,
+            help: Some(kind.help().to_string()),
+        });
+    }
+
+    /// Warn about an identifier that is not NFC-normalized, mixes scripts or
+    /// looks like an earlier identifier. Each spelling is checked once.
+    fn check_identifier(&mut self, token: &Scanned) {
+        if token.kind != TokenKind::Identifier || !self.eager {
+            return;
+        }
+        let source = &self.input[token.span.start.offset..token.span.end.offset];
+        let spelling = source.strip_prefix("r#").unwrap_or(source);
+        if !self.seen_identifiers.insert(spelling.to_string()) {
+            return;
+        }
+        let name: String = spelling.nfc().collect();
+
+        if !unicode_normalization::is_nfc(spelling) {
+            // Spell out the code points, since both forms render alike.
+            let escaped: String = spelling
+                .chars()
+                .map(|c| match c.is_ascii() {
+                    true => c.to_string(),
+                    false => c.escape_unicode().to_string(),
+                })
+                .collect();
+            let message = format!(
+                "Identifier `{escaped}` is not in Unicode normalization form C; it is read as `{name}`."
+            );
+            self.warn(
+                LexWarningKind::NonNormalizedIdentifier,
+                message,
+                &token.span,
+            );
+        }
+        if let Some(scripts) = mixed_scripts(&name) {
+            let scripts: Vec<&str> = scripts.iter().map(|script| script.name()).collect();
+            let message = format!(
+                "Identifier `{name}` mixes {} characters.",
+                scripts.join(" and ")
+            );
+            self.warn(LexWarningKind::MixedScriptIdentifier, message, &token.span);
+        }
+        let skeleton = skeleton(&name);
+        match self.skeletons.get(&skeleton) {
+            Some((other, position)) if *other != name => {
+                let message = format!(
+                    "Identifier `{name}` looks like `{other}` from {}:{}.",
+                    position.line, position.column
+                );
+                self.warn(LexWarningKind::ConfusableIdentifier, message, &token.span);
+            }
+            Some(_) => {}
+            None => {
+                self.skeletons.insert(skeleton, (name, token.span.start));
+            }
+        }
+    }
+
+    /// Error token for a bidirectional control character under the cursor,
+    /// which the caller then consumes.
+    fn bidi_error(&mut self, offset: usize, ch: char, context: &str) -> Scanned {
+        let name = bidi_control_name(ch).unwrap_or("bidirectional control");
+        let message = format!(
+            "Bidirectional control character U+{:04X} ({name}) in {context}; it can make the code display differently from how it compiles.",
+            ch as u32
+        );
+        self.error_token(
+            LexErrorKind::BidiControl,
+            Scanned {
+                kind: TokenKind::Error,
+                literal: Some(LiteralValue::String(message)),
+                span: Span {
+                    start: Position {
+                        line: self.line,
+                        column: self.column,
+                        offset,
+                    },
+                    end: Position {
+                        line: self.line,
+                        column: self.column + 1,
+                        offset: offset + ch.len_utf8(),
+                    },
+                },
+            },
+        )
+    }
+
+    /// Turn a literal containing a bidirectional control character into an
+    /// error token covering the literal.
+    fn reject_bidi(&mut self, token: Scanned) -> Scanned {
+        if token.kind == TokenKind::Error {
+            return token;
+        }
+        let source = &self.input[token.span.start.offset..token.span.end.offset];
+        let Some(ch) = source.chars().find(|&c| bidi_control_name(c).is_some()) else {
+            return token;
+        };
+        let message = format!(
+            "Bidirectional control character U+{:04X} ({}) in a literal; it can make the code display differently from how it compiles.",
+            ch as u32,
+            bidi_control_name(ch).unwrap_or_default()
+        );
+        self.error_token(
+            LexErrorKind::BidiControl,
+            Scanned {
+                kind: TokenKind::Error,
+                literal: Some(LiteralValue::String(message)),
+                span: token.span,
+            },
+        )
+    }
+
+    fn advance_char(&mut self) -> Option<(usize, char)> {
+        let next = self.chars.next();
+        if let Some((_, ch)) = next {
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        next
+    }
+
+    /// Whether the `'` under the cursor starts a lifetime rather than a char literal.
+    fn at_lifetime(&mut self) -> bool {
+        let mut rest = self.chars.clone().skip(1).map(|(_, c)| c);
+        if !rest.next().is_some_and(|c| is_xid_start(c) || c == '_') {
+            return false;
+        }
+        rest.find(|&c| !is_xid_continue(c)) != Some('\'')
+    }
+
+    /// Emit an `Apostrophe` followed by the lifetime name as an `Identifier`.
+    ///
+    /// The name is never a keyword, so `'static` names the static lifetime.
+    fn lex_lifetime(&mut self, start_offset: usize, tokens: &mut Vec<Scanned>) {
+        let start_col = self.column;
+        self.advance_char(); // consume '
+        tokens.push(Scanned {
+            kind: TokenKind::Apostrophe,
+            literal: None,
+            span: Span {
+                start: Position {
+                    line: self.line,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: self.line,
+                    column: self.column,
+                    offset: self.current_offset(),
+                },
+            },
+        });
+
+        let name_offset = self.current_offset();
+        let name_col = self.column;
+        while self.chars.peek().is_some_and(|&(_, c)| is_xid_continue(c)) {
+            self.advance_char();
+        }
+        let end_offset = self.current_offset();
+        let name = nfc_name(&self.input[name_offset..end_offset]);
+        let name = Scanned {
+            kind: TokenKind::Identifier,
+            literal: self
+                .eager
+                .then(|| LiteralValue::Identifier(Symbol::intern(&name))),
+            span: Span {
+                start: Position {
+                    line: self.line,
+                    column: name_col,
+                    offset: name_offset,
                 },
                 end: Position {
-                    line: eof_line,
-                    column: eof_col,
-                    offset: eof_offset,
+                    line: self.line,
+                    column: self.column,
+                    offset: end_offset,
                 },
             },
-        });
-        tokens
-    }
-
-    fn advance_char(&mut self) -> Option<(usize, char)> {
-        let next = self.chars.next();
-        if let Some((_, ch)) = next {
-            if ch == '\n' {
-                self.line += 1;
-                self.column = 1;
-            } else {
-                self.column += 1;
-            }
-        }
-        next
+        };
+        self.check_identifier(&name);
+        tokens.push(name);
     }
 
     fn peek_nth_char(&mut self, n: usize) -> Option<(usize, char)> {
@@ -845,7 +2221,7 @@ impl<'a> Lexer<'a> {
     }
 
     /// Scan an integer or float, honoring underscores and exponents.
-    fn lex_number(&mut self) -> Token {
+    fn lex_number(&mut self) -> Scanned {
         let start_offset = self.chars.peek().unwrap().0;
         let start_line = self.line;
         let start_col = self.column;
@@ -918,6 +2294,10 @@ impl<'a> Lexer<'a> {
                 }
                 // Float specific for base 10
                 '.' if base == 10 && !has_dot && !has_exp => {
+                    // `1..5` and `1..=5` are ranges, not the float `1.`
+                    if self.peek_nth_char(1).is_some_and(|(_, c)| c == '.') {
+                        break;
+                    }
                     // If next char is a digit, treat as float part
                     if self
                         .peek_nth_char(1)
@@ -953,44 +2333,62 @@ impl<'a> Lexer<'a> {
             }
         }
 
+        // Type suffix: 255u8, 1_000_i64, 1.5f32
+        let mut suffix = String::new();
+        if !lexeme.ends_with('.')
+            && self
+                .chars
+                .peek()
+                .is_some_and(|&(_, ch)| ch.is_ascii_alphabetic())
+        {
+            while let Some(&(_, ch)) = self.chars.peek() {
+                if !(ch.is_ascii_alphanumeric() || ch == '_') {
+                    break;
+                }
+                suffix.push(ch);
+                self.advance_char();
+            }
+        }
+
         // Validate that if a prefix was consumed, there are actual digits after it
         if (base == 16 && lexeme.eq_ignore_ascii_case("0x"))
             || (base == 8 && lexeme.eq_ignore_ascii_case("0o"))
             || (base == 2 && lexeme.eq_ignore_ascii_case("0b"))
         {
             let end_offset = self.current_offset();
-            return Token {
-                kind: TokenKind::Error,
-                lexeme: lexeme.clone(),
-                literal: Some(LiteralValue::String(format!(
-                    "Expected digits after base prefix '{}', but found none",
-                    &lexeme
-                ))),
-                span: Span {
-                    start: Position {
-                        line: start_line,
-                        column: start_col,
-                        offset: start_offset,
-                    },
-                    end: Position {
-                        line: self.line,
-                        column: self.column,
-                        offset: end_offset,
+            return self.error_token(
+                LexErrorKind::InvalidNumber,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(format!(
+                        "Expected digits after base prefix '{}', but found none",
+                        &lexeme
+                    ))),
+                    span: Span {
+                        start: Position {
+                            line: start_line,
+                            column: start_col,
+                            offset: start_offset,
+                        },
+                        end: Position {
+                            line: self.line,
+                            column: self.column,
+                            offset: end_offset,
+                        },
                     },
                 },
-            };
+            );
         }
-        if lexeme.ends_with('_') {
+        if lexeme.ends_with('_') && suffix.is_empty() {
             let end_offset = self.current_offset();
-            return Token {
+            return self.error_token(LexErrorKind::InvalidNumber, Scanned {
                 kind: TokenKind::Error,
-                lexeme: lexeme.clone(),
                 literal: Some(LiteralValue::String(format!("Number literal cannot end with an underscore: '{}'. Remove the trailing underscore.", lexeme))),
                 span: Span {
                     start: Position { line: start_line, column: start_col, offset: start_offset },
                     end: Position { line: self.line, column: self.column, offset: end_offset },
                 },
-            };
+            });
         }
 
         let end_offset = self.current_offset();
@@ -1009,20 +2407,58 @@ impl<'a> Lexer<'a> {
 
         let cleaned_lexeme: String = lexeme.chars().filter(|&c| c != '_').collect();
 
+        if !suffix.is_empty() {
+            let source = format!("{}{}", lexeme, suffix);
+            return match Self::suffixed_number_literal(
+                &cleaned_lexeme,
+                &source,
+                base,
+                has_dot,
+                &suffix,
+            ) {
+                Ok(literal) => Scanned {
+                    kind: if matches!(literal, LiteralValue::TypedFloat { .. }) {
+                        TokenKind::FloatLiteral
+                    } else {
+                        TokenKind::IntegerLiteral
+                    },
+                    literal: Some(literal),
+                    span,
+                },
+                Err((error_kind, message)) => self.error_token(
+                    error_kind,
+                    Scanned {
+                        kind: TokenKind::Error,
+                        literal: Some(LiteralValue::String(message)),
+                        span,
+                    },
+                ),
+            };
+        }
+
         if has_dot {
             match cleaned_lexeme.parse::<f64>() {
-                Ok(val) => Token {
+                Ok(val) if val.is_infinite() => {
+                    let (error_kind, message) = Self::float_out_of_range("f64", &lexeme);
+                    self.error_token(
+                        error_kind,
+                        Scanned {
+                            kind: TokenKind::Error,
+                            literal: Some(LiteralValue::String(message)),
+                            span,
+                        },
+                    )
+                }
+                Ok(val) => Scanned {
                     kind: TokenKind::FloatLiteral,
-                    lexeme: lexeme.clone(),
                     literal: Some(LiteralValue::Float(val)),
                     span,
                 },
-                Err(_) => Token {
+                Err(_) => self.error_token(LexErrorKind::InvalidNumber, Scanned {
                     kind: TokenKind::Error,
-                    lexeme: lexeme.clone(),
                     literal: Some(LiteralValue::String(format!("Invalid float literal: '{}'. Expected a valid float (e.g., 1.23, 4e5, 7.), but got an invalid format.", lexeme))),
                     span,
-                },
+                }),
             }
         } else {
             let value_str_to_parse = if base != 10 {
@@ -1042,37 +2478,140 @@ impl<'a> Lexer<'a> {
             };
 
             if value_str_to_parse.is_empty() && lexeme == "0" && base == 10 {
-                return Token {
+                return Scanned {
                     kind: TokenKind::IntegerLiteral,
-                    lexeme: "0".to_string(),
                     literal: Some(LiteralValue::Integer(0)),
                     span,
                 };
             }
             if value_str_to_parse.is_empty() && base != 10 {
-                return Token {
+                return self.error_token(LexErrorKind::InvalidNumber, Scanned {
                     kind: TokenKind::Error,
-                    lexeme: lexeme.clone(),
                     literal: Some(LiteralValue::String(format!("Invalid integer literal for base {}: '{}'. Expected only valid digits for this base.", base, lexeme))),
                     span,
-                };
+                });
             }
 
             match i64::from_str_radix(value_str_to_parse, base) {
-                Ok(val) => Token {
+                Ok(val) => Scanned {
                     kind: TokenKind::IntegerLiteral,
-                    lexeme: lexeme.clone(),
                     literal: Some(LiteralValue::Integer(val)),
                     span,
                 },
-                Err(_) => Token {
+                // Too wide for i64: keep the digits and radix
+                Err(e) if *e.kind() == std::num::IntErrorKind::PosOverflow => Scanned {
+                    kind: TokenKind::IntegerLiteral,
+                    literal: Some(LiteralValue::WideInteger(WideInteger {
+                        digits: value_str_to_parse.trim_start_matches('0').to_ascii_lowercase(),
+                        radix: base,
+                    })),
+                    span,
+                },
+                Err(_) => self.error_token(LexErrorKind::InvalidNumber, Scanned {
                     kind: TokenKind::Error,
-                    lexeme: lexeme.clone(),
                     literal: Some(LiteralValue::String(format!("Invalid integer literal for base {}: '{}'. Expected only valid digits for this base.", base, lexeme))),
                     span,
-                },
+                }),
+            }
+        }
+    }
+
+    /// Value of a number literal with a type suffix, or the diagnostic for
+    /// an unknown suffix or an out-of-range value.
+    ///
+    /// Signed integers accept the magnitude of their minimum, e.g. `128i8`,
+    /// so that `-128i8` can be written; the parser reports it when not negated.
+    fn suffixed_number_literal(
+        digits: &str,
+        source: &str,
+        base: u32,
+        is_float: bool,
+        suffix: &str,
+    ) -> Result<LiteralValue, (LexErrorKind, String)> {
+        let Some(numeric) = NumericSuffix::from_name(suffix) else {
+            let valid: Vec<_> = NumericSuffix::ALL.iter().map(|s| s.name()).collect();
+            return Err((
+                LexErrorKind::InvalidNumber,
+                format!(
+                    "Invalid suffix '{}' for number literal '{}'. Valid suffixes are {}.",
+                    suffix,
+                    source,
+                    valid.join(", ")
+                ),
+            ));
+        };
+
+        if numeric.is_float() {
+            if base != 10 {
+                return Err((
+                    LexErrorKind::InvalidNumber,
+                    format!(
+                        "Float suffix '{}' cannot be used with a base {} literal '{}'.",
+                        suffix, base, source
+                    ),
+                ));
             }
+            let value: f64 = digits.parse().map_err(|_| {
+                (
+                    LexErrorKind::InvalidNumber,
+                    format!("Invalid float literal: '{}'.", source),
+                )
+            })?;
+            let infinite = match numeric {
+                NumericSuffix::F32 => (value as f32).is_infinite(),
+                _ => value.is_infinite(),
+            };
+            if infinite {
+                return Err(Self::float_out_of_range(numeric.name(), source));
+            }
+            return Ok(LiteralValue::TypedFloat {
+                value,
+                suffix: numeric,
+            });
+        }
+
+        if is_float {
+            return Err((
+                LexErrorKind::InvalidNumber,
+                format!(
+                    "Invalid suffix '{}' for float literal '{}'. Float literals accept f32 or f64.",
+                    suffix, source
+                ),
+            ));
+        }
+
+        let digits = if base == 10 { digits } else { &digits[2..] };
+        let out_of_range = || {
+            (
+                LexErrorKind::NumberOutOfRange,
+                format!(
+                    "Literal out of range for '{}': '{}' does not fit in {}.",
+                    suffix,
+                    source,
+                    numeric.range()
+                ),
+            )
+        };
+        let value = u128::from_str_radix(digits, base).map_err(|_| out_of_range())?;
+        if value > numeric.max_value().max(numeric.min_magnitude()) {
+            return Err(out_of_range());
         }
+        Ok(LiteralValue::TypedInteger {
+            value,
+            suffix: numeric,
+        })
+    }
+
+    /// Diagnostic for a float literal too large for `ty`, which would
+    /// otherwise become infinity.
+    fn float_out_of_range(ty: &str, source: &str) -> (LexErrorKind, String) {
+        (
+            LexErrorKind::NumberOutOfRange,
+            format!(
+                "Literal out of range for '{}': '{}' exceeds the largest {} value.",
+                ty, source, ty
+            ),
+        )
     }
 
     // Helper function to parse \u{...} escape sequences
@@ -1081,13 +2620,14 @@ impl<'a> Lexer<'a> {
     // Assumes '\\' and 'u' have already been consumed by the caller.
     // lit_start_offset, lit_start_line, lit_start_col are for the *entire literal* being parsed (e.g. string or char literal)
     // escape_start_offset, escape_start_line, escape_start_col are for the beginning of the \u sequence itself.
+    #[allow(clippy::result_large_err)]
     fn parse_unicode_escape(
         &mut self,
         _lit_start_offset: usize,
         _lit_start_line: usize,
         _lit_start_col: usize,
         lit_kind: &str,
-    ) -> Result<char, Token> {
+    ) -> Result<char, Scanned> {
         // 'u' has been consumed. current_offset points to char after 'u'. self.column is col after 'u'.
         let escape_u_offset = self.current_offset() - 'u'.len_utf8();
         let escape_u_line = self.line; // Line of 'u'
@@ -1102,32 +2642,29 @@ impl<'a> Lexer<'a> {
                 offset: self.current_offset(),
             };
             // Lexeme should be like \uX or \u<EOF>
-            let err_lexeme = self
-                .input
-                .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                .unwrap_or("\\u")
-                .to_string();
             let found_char = self
                 .chars
                 .peek()
                 .map(|(_, c)| c.to_string())
                 .unwrap_or_else(|| "EOF".to_string());
-            return Err(Token {
-                kind: TokenKind::Error,
-                lexeme: err_lexeme,
-                literal: Some(LiteralValue::String(format!(
+            return Err(self.error_token(
+                LexErrorKind::InvalidEscape,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(format!(
                     "Invalid Unicode escape in {} literal: expected '{{' after \\u, found '{}'.",
                     lit_kind, found_char
                 ))),
-                span: Span {
-                    start: Position {
-                        line: escape_u_line,
-                        column: escape_u_col - 1,
-                        offset: escape_u_offset - '\\'.len_utf8(),
-                    }, // Span for \u sequence
-                    end: err_tok_end_pos,
+                    span: Span {
+                        start: Position {
+                            line: escape_u_line,
+                            column: escape_u_col - 1,
+                            offset: escape_u_offset - '\\'.len_utf8(),
+                        }, // Span for \u sequence
+                        end: err_tok_end_pos,
+                    },
                 },
-            });
+            ));
         }
 
         let mut hex_digits = String::new();
@@ -1149,17 +2686,11 @@ impl<'a> Lexer<'a> {
                         column: self.column,
                         offset: self.current_offset(),
                     };
-                    let err_lexeme = self
-                        .input
-                        .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                        .unwrap_or("")
-                        .to_string();
-                    return Err(Token {
+                    return Err(self.error_token(LexErrorKind::InvalidEscape, Scanned {
                         kind: TokenKind::Error,
-                        lexeme: err_lexeme,
                         literal: Some(LiteralValue::String(format!("Invalid Unicode escape in {} literal: too many hex digits (max 6) in \\u{{{}}}{{'.", lit_kind, hex_digits))),
                         span: Span { start: Position {line: escape_u_line, column: escape_u_col -1, offset: escape_u_offset - '\\'.len_utf8()}, end: err_tok_end_pos },
-                    });
+                    }));
                 }
             } else if ch == '}' {
                 break;
@@ -1170,17 +2701,11 @@ impl<'a> Lexer<'a> {
                     column: self.column,
                     offset: self.current_offset(),
                 };
-                let err_lexeme = self
-                    .input
-                    .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                    .unwrap_or("")
-                    .to_string();
-                return Err(Token {
+                return Err(self.error_token(LexErrorKind::InvalidEscape, Scanned {
                     kind: TokenKind::Error,
-                    lexeme: err_lexeme,
                     literal: Some(LiteralValue::String(format!("Invalid Unicode escape in {} literal: unexpected character '{}' in \\u{{{}}} sequence.", lit_kind, ch, hex_digits))),
                     span: Span { start: Position {line: escape_u_line, column: escape_u_col -1, offset: escape_u_offset - '\\'.len_utf8()}, end: err_tok_end_pos },
-                });
+                }));
             }
         }
 
@@ -1191,17 +2716,11 @@ impl<'a> Lexer<'a> {
                 column: self.column,
                 offset: self.current_offset(),
             };
-            let err_lexeme = self
-                .input
-                .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                .unwrap_or("")
-                .to_string();
-            return Err(Token {
+            return Err(self.error_token(LexErrorKind::InvalidEscape, Scanned {
                 kind: TokenKind::Error,
-                lexeme: err_lexeme,
                 literal: Some(LiteralValue::String(format!("Invalid Unicode escape in {} literal: unclosed \\u{{{}}} sequence, missing '}}'.", lit_kind, hex_digits))),
                 span: Span { start: Position {line: escape_u_line, column: escape_u_col-1, offset: escape_u_offset - '\\'.len_utf8()}, end: err_tok_end_pos },
-            });
+            }));
         }
         self.advance_char(); // consume '}'
 
@@ -1211,27 +2730,24 @@ impl<'a> Lexer<'a> {
                 column: self.column,
                 offset: self.current_offset(),
             };
-            let err_lexeme = self
-                .input
-                .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                .unwrap_or("")
-                .to_string();
-            return Err(Token {
-                kind: TokenKind::Error,
-                lexeme: err_lexeme,
-                literal: Some(LiteralValue::String(format!(
-                    "Invalid Unicode escape in {} literal: empty hex code \\u{{}}.",
-                    lit_kind
-                ))),
-                span: Span {
-                    start: Position {
-                        line: escape_u_line,
-                        column: escape_u_col - 1,
-                        offset: escape_u_offset - '\\'.len_utf8(),
+            return Err(self.error_token(
+                LexErrorKind::InvalidEscape,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(format!(
+                        "Invalid Unicode escape in {} literal: empty hex code \\u{{}}.",
+                        lit_kind
+                    ))),
+                    span: Span {
+                        start: Position {
+                            line: escape_u_line,
+                            column: escape_u_col - 1,
+                            offset: escape_u_offset - '\\'.len_utf8(),
+                        },
+                        end: err_tok_end_pos,
                     },
-                    end: err_tok_end_pos,
                 },
-            });
+            ));
         }
 
         match u32::from_str_radix(&hex_digits, 16) {
@@ -1243,17 +2759,11 @@ impl<'a> Lexer<'a> {
                         column: self.column,
                         offset: self.current_offset(),
                     };
-                    let err_lexeme = self
-                        .input
-                        .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                        .unwrap_or("")
-                        .to_string();
-                    Err(Token {
+                    Err(self.error_token(LexErrorKind::InvalidEscape, Scanned {
                         kind: TokenKind::Error,
-                        lexeme: err_lexeme,
                         literal: Some(LiteralValue::String(format!("Invalid Unicode escape in {} literal: '\\u{{{}}}' is not a valid Unicode codepoint.", lit_kind, hex_digits))),
                         span: Span { start: Position {line: escape_u_line, column: escape_u_col-1, offset: escape_u_offset - '\\'.len_utf8()}, end: err_tok_end_pos },
-                    })
+                    }))
                 }
             },
             Err(_) => {
@@ -1263,36 +2773,35 @@ impl<'a> Lexer<'a> {
                     column: self.column,
                     offset: self.current_offset(),
                 };
-                let err_lexeme = self
-                    .input
-                    .get(escape_u_offset - '\\'.len_utf8()..err_tok_end_pos.offset)
-                    .unwrap_or("")
-                    .to_string();
-                Err(Token {
-                    kind: TokenKind::Error,
-                    lexeme: err_lexeme,
-                    literal: Some(LiteralValue::String(format!(
-                        "Internal error parsing hex '{}' for {} literal.",
-                        hex_digits, lit_kind
-                    ))),
-                    span: Span {
-                        start: Position {
-                            line: escape_u_line,
-                            column: escape_u_col - 1,
-                            offset: escape_u_offset - '\\'.len_utf8(),
+                Err(self.error_token(
+                    LexErrorKind::InvalidEscape,
+                    Scanned {
+                        kind: TokenKind::Error,
+                        literal: Some(LiteralValue::String(format!(
+                            "Internal error parsing hex '{}' for {} literal.",
+                            hex_digits, lit_kind
+                        ))),
+                        span: Span {
+                            start: Position {
+                                line: escape_u_line,
+                                column: escape_u_col - 1,
+                                offset: escape_u_offset - '\\'.len_utf8(),
+                            },
+                            end: err_tok_end_pos,
                         },
-                        end: err_tok_end_pos,
                     },
-                })
+                ))
             }
         }
     }
 
-    fn lex_string_literal(&mut self) -> Token {
+    fn lex_string_literal(&mut self) -> Scanned {
         let start_offset = self.current_offset();
         let start_line = self.line;
         let start_col = self.column;
 
+        // Contents are only collected when the literal value is wanted.
+        let literals = self.eager;
         let mut content = String::new();
 
         self.advance_char(); // consume the opening quote
@@ -1310,19 +2819,27 @@ impl<'a> Lexer<'a> {
                     if let Some(&(_escaped_idx, next_ch)) = self.chars.peek() {
                         match next_ch {
                             'n' => {
-                                content.push('\n');
+                                if literals {
+                                    content.push('\n');
+                                }
                                 self.advance_char();
                             }
                             't' => {
-                                content.push('\t');
+                                if literals {
+                                    content.push('\t');
+                                }
                                 self.advance_char();
                             }
                             '\\' => {
-                                content.push('\\');
+                                if literals {
+                                    content.push('\\');
+                                }
                                 self.advance_char();
                             }
                             '"' => {
-                                content.push('"');
+                                if literals {
+                                    content.push('"');
+                                }
                                 self.advance_char();
                             }
                             'u' => {
@@ -1333,97 +2850,350 @@ impl<'a> Lexer<'a> {
                                     start_col,
                                     "string",
                                 ) {
-                                    Ok(uc) => content.push(uc),
+                                    Ok(uc) if literals => content.push(uc),
+                                    Ok(_) => {}
                                     Err(token) => return token,
                                 }
                             }
                             _ => {
                                 let specific_error_lexeme = format!("\\{}", next_ch);
                                 self.advance_char();
-                                let error_token_lexeme = self
-                                    .input
-                                    .get(start_offset..self.current_offset())
-                                    .unwrap_or("")
-                                    .to_string();
-                                return Token {
+                                let end_offset = self.current_offset();
+                                return self.error_token(LexErrorKind::InvalidEscape, Scanned {
                                     kind: TokenKind::Error,
-                                    lexeme: error_token_lexeme,
                                     literal: Some(LiteralValue::String(format!(
                                         "Invalid escape sequence in string literal: {}. Only valid escapes are \\n, \\t, \\\\, \\\" and \\u{{...}}.",
                                         specific_error_lexeme
                                     ))),
                                     span: Span {
                                         start: Position { line: start_line, column: start_col, offset: start_offset },
-                                        end: Position { line: self.line, column: self.column, offset: self.current_offset() },
+                                        end: Position { line: self.line, column: self.column, offset: end_offset },
                                     },
-                                };
+                                });
+                            }
+                        }
+                    } else {
+                        // Unterminated escape sequence at EOF
+                        let current_lex_end_offset = self.current_offset();
+                        let current_lex_end_col = self.column;
+                        let current_lex_end_line = self.line;
+                        return self.error_token(LexErrorKind::UnterminatedString, Scanned {
+                            kind: TokenKind::Error,
+                            literal: Some(LiteralValue::String(
+                                "Unterminated escape sequence at end of string literal: expected character after \\".to_string(),
+                            )),
+                            span: Span {
+                                start: Position { line: start_line, column: start_col, offset: start_offset },
+                                end: Position { line: current_lex_end_line, column: current_lex_end_col, offset: current_lex_end_offset },
+                            },
+                        });
+                    }
+                }
+                '\n' => {
+                    // An unescaped newline character.
+                    // According to DESIGN_LEXER.md, string literals are single line unless escaped.
+                    // This signifies an unterminated string.
+                    // The newline character itself is NOT part of the string literal's lexeme or content.
+                    break;
+                }
+                _ => {
+                    if literals {
+                        content.push(ch);
+                    }
+                    self.advance_char();
+                }
+            }
+        }
+
+        // current_offset() reflects the position *after* the last consumed character.
+        // If closed, it's after the closing quote. If not closed, it's where it stopped.
+        let current_lex_end_offset = self.current_offset();
+        let current_lex_end_col = self.column;
+        let current_lex_end_line = self.line;
+
+        if !closed {
+            return self.error_token(LexErrorKind::UnterminatedString, Scanned {
+                kind: TokenKind::Error,
+                literal: Some(LiteralValue::String(
+                    r#"Unterminated string literal: expected closing quote " before end of line or file."#.to_string(),
+                )),
+                span: Span {
+                    start: Position { line: start_line, column: start_col, offset: start_offset },
+                    end: Position { line: current_lex_end_line, column: current_lex_end_col, offset: current_lex_end_offset },
+                },
+            });
+        }
+
+        Scanned {
+            kind: TokenKind::StringLiteral,
+            literal: literals.then_some(LiteralValue::String(content)),
+            span: Span {
+                start: Position {
+                    line: start_line,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: current_lex_end_line,
+                    column: current_lex_end_col,
+                    offset: current_lex_end_offset,
+                },
+            },
+        }
+    }
+
+    /// Lex an interpolated string such as f"Hello, {name}!" into text and
+    /// expression segments. `{{` and `}}` stand for literal braces, and
+    /// embedded expressions may contain nested braces and string literals.
+    fn lex_interpolated_string_literal(&mut self, start_offset: usize) -> Scanned {
+        let start_line = self.line;
+        let start_col = self.column;
+        self.advance_char(); // consume 'f'
+        self.advance_char(); // consume the opening quote
+
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        loop {
+            let Some(&(_, ch)) = self.chars.peek() else {
+                return self.literal_error(LexErrorKind::UnterminatedString, start_offset, start_line, start_col, "Unterminated interpolated string literal: expected closing quote \" before end of line or file.".to_string());
+            };
+            match ch {
+                '"' => {
+                    self.advance_char();
+                    break;
+                }
+                '\n' => {
+                    return self.literal_error(LexErrorKind::UnterminatedString, start_offset, start_line, start_col, "Unterminated interpolated string literal: expected closing quote \" before end of line or file.".to_string());
+                }
+                '{' | '}' if self.peek_nth_char(1).is_some_and(|(_, c)| c == ch) => {
+                    self.advance_char();
+                    self.advance_char();
+                    text.push(ch);
+                }
+                '}' => {
+                    self.advance_char();
+                    return self.literal_error(LexErrorKind::InvalidInterpolation, start_offset, start_line, start_col, "Unmatched '}' in interpolated string literal. Use '}}' for a literal brace.".to_string());
+                }
+                '{' => {
+                    if !text.is_empty() {
+                        segments.push(StringSegment::Text(std::mem::take(&mut text)));
+                    }
+                    self.advance_char();
+                    match self.lex_interpolated_expression(start_offset, start_line, start_col) {
+                        Ok(segment) => segments.push(segment),
+                        Err(token) => return token,
+                    }
+                }
+                '\\' => {
+                    self.advance_char();
+                    let escaped = match self.chars.peek().map(|&(_, c)| c) {
+                        Some('n') => '\n',
+                        Some('t') => '\t',
+                        Some('\\') => '\\',
+                        Some('"') => '"',
+                        Some('u') => {
+                            self.advance_char();
+                            match self.parse_unicode_escape(
+                                start_offset,
+                                start_line,
+                                start_col,
+                                "interpolated string",
+                            ) {
+                                Ok(uc) => {
+                                    text.push(uc);
+                                    continue;
+                                }
+                                Err(token) => return token,
                             }
                         }
-                    } else {
-                        // Unterminated escape sequence at EOF
-                        let current_lex_end_offset = self.current_offset();
-                        let current_lex_end_col = self.column;
-                        let current_lex_end_line = self.line;
-                        let lexeme = self
-                            .input
-                            .get(start_offset..current_lex_end_offset)
-                            .unwrap_or("")
-                            .to_string();
-                        return Token {
-                            kind: TokenKind::Error,
-                            lexeme,
-                            literal: Some(LiteralValue::String(
-                                "Unterminated escape sequence at end of string literal: expected character after \\".to_string(),
-                            )),
-                            span: Span {
-                                start: Position { line: start_line, column: start_col, offset: start_offset },
-                                end: Position { line: current_lex_end_line, column: current_lex_end_col, offset: current_lex_end_offset },
-                            },
-                        };
+                        Some(other) => {
+                            self.advance_char();
+                            return self.literal_error(LexErrorKind::InvalidEscape, start_offset, start_line, start_col, format!(
+                                "Invalid escape sequence in interpolated string literal: \\{}. Only valid escapes are \\n, \\t, \\\\, \\\" and \\u{{...}}; use {{{{ and }}}} for braces.",
+                                other
+                            ));
+                        }
+                        None => {
+                            return self.literal_error(LexErrorKind::UnterminatedString, start_offset, start_line, start_col, "Unterminated escape sequence at end of interpolated string literal: expected character after \\".to_string());
+                        }
+                    };
+                    self.advance_char();
+                    text.push(escaped);
+                }
+                _ => {
+                    text.push(ch);
+                    self.advance_char();
+                }
+            }
+        }
+        if !text.is_empty() {
+            segments.push(StringSegment::Text(text));
+        }
+
+        let end_offset = self.current_offset();
+        Scanned {
+            kind: TokenKind::InterpolatedString,
+            literal: Some(LiteralValue::Interpolated(segments)),
+            span: Span {
+                start: Position {
+                    line: start_line,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: self.line,
+                    column: self.column,
+                    offset: end_offset,
+                },
+            },
+        }
+    }
+
+    /// Lex the expression after an interpolation `{` through its closing `}`.
+    /// On failure the error token covers the literal up to the failure, or is
+    /// the first error token found inside the expression.
+    #[allow(clippy::result_large_err)]
+    fn lex_interpolated_expression(
+        &mut self,
+        lit_start_offset: usize,
+        lit_start_line: usize,
+        lit_start_col: usize,
+    ) -> Result<StringSegment, Scanned> {
+        let start = Position {
+            line: self.line,
+            column: self.column,
+            offset: self.current_offset(),
+        };
+        let mut depth = 0usize;
+        loop {
+            match self.chars.peek().map(|&(_, c)| c) {
+                None | Some('\n') => {
+                    return Err(self.literal_error(
+                        LexErrorKind::InvalidInterpolation,
+                        lit_start_offset,
+                        lit_start_line,
+                        lit_start_col,
+                        "Unterminated expression in interpolated string literal: expected '}'."
+                            .to_string(),
+                    ));
+                }
+                Some('}') if depth == 0 => break,
+                Some('{') => {
+                    depth += 1;
+                    self.advance_char();
+                }
+                Some('}') => {
+                    depth -= 1;
+                    self.advance_char();
+                }
+                Some('"') => {
+                    // Nested string literal: its braces and quotes are not ours
+                    self.advance_char();
+                    while let Some(&(_, c)) = self.chars.peek() {
+                        if c == '\n' {
+                            break;
+                        }
+                        self.advance_char();
+                        if c == '"' {
+                            break;
+                        }
+                        if c == '\\' {
+                            self.advance_char();
+                        }
                     }
                 }
-                '\n' => {
-                    // An unescaped newline character.
-                    // According to DESIGN_LEXER.md, string literals are single line unless escaped.
-                    // This signifies an unterminated string.
-                    // The newline character itself is NOT part of the string literal's lexeme or content.
-                    break;
+                Some('\'') => {
+                    // Char literal such as '}' or '\''
+                    self.advance_char();
+                    if self.chars.peek().is_some_and(|&(_, c)| c == '\\') {
+                        self.advance_char();
+                        self.advance_char();
+                        if self.chars.peek().is_some_and(|&(_, c)| c == '\'') {
+                            self.advance_char();
+                        }
+                    } else if self.peek_nth_char(1).is_some_and(|(_, c)| c == '\'') {
+                        self.advance_char();
+                        self.advance_char();
+                    }
                 }
-                _ => {
-                    content.push(ch);
+                Some(_) => {
                     self.advance_char();
                 }
             }
         }
+        let end = Position {
+            line: self.line,
+            column: self.column,
+            offset: self.current_offset(),
+        };
+        self.advance_char(); // consume the closing '}'
+
+        let source = &self.input[start.offset..end.offset];
+        if source.trim().is_empty() {
+            return Err(self.literal_error(
+                LexErrorKind::InvalidInterpolation,
+                lit_start_offset,
+                lit_start_line,
+                lit_start_col,
+                "Empty expression in interpolated string literal. Use '{{' for a literal brace."
+                    .to_string(),
+            ));
+        }
 
-        // current_offset() reflects the position *after* the last consumed character.
-        // If closed, it's after the closing quote. If not closed, it's where it stopped.
-        let current_lex_end_offset = self.current_offset();
-        let current_lex_end_col = self.column;
-        let current_lex_end_line = self.line;
-
-        if !closed {
-            return Token {
-                kind: TokenKind::Error,
-                lexeme: self.input.get(start_offset..current_lex_end_offset).unwrap_or("").to_string(),
-                literal: Some(LiteralValue::String(
-                    r#"Unterminated string literal: expected closing quote " before end of line or file."#.to_string(),
-                )),
-                span: Span {
-                    start: Position { line: start_line, column: start_col, offset: start_offset },
-                    end: Position { line: current_lex_end_line, column: current_lex_end_col, offset: current_lex_end_offset },
-                },
-            };
+        if self.interpolation_depth >= self.config.max_nesting_depth {
+            let message = format!(
+                "Interpolated strings are nested deeper than the maximum of {} levels.",
+                self.config.max_nesting_depth
+            );
+            return Err(self.literal_error(
+                LexErrorKind::NestingTooDeep,
+                lit_start_offset,
+                lit_start_line,
+                lit_start_col,
+                message,
+            ));
+        }
+        let mut lexer = Lexer::embedded(source, start.line, start.column);
+        lexer.config.max_nesting_depth = self.config.max_nesting_depth;
+        lexer.interpolation_depth = self.interpolation_depth + 1;
+        let (mut tokens, errors, _) = lexer.scan();
+        tokens.retain(|token| token.kind != TokenKind::Eof);
+        for token in &mut tokens {
+            token.span.start.offset += start.offset;
+            token.span.end.offset += start.offset;
+        }
+        for token in &tokens {
+            self.check_identifier(token);
         }
+        if let Some(mut error) = errors.into_iter().next() {
+            error.span.start.offset += start.offset;
+            error.span.end.offset += start.offset;
+            self.errors.push(error);
+            let token = tokens.iter().find(|token| token.kind == TokenKind::Error);
+            return Err(token.cloned().expect("lex errors have placeholder tokens"));
+        }
+        Ok(StringSegment::Expression {
+            tokens: tokens
+                .into_iter()
+                .map(|token| token.into_token(self.input))
+                .collect(),
+            span: Span { start, end },
+        })
+    }
 
-        Token {
-            kind: TokenKind::StringLiteral,
-            lexeme: self
-                .input
-                .get(start_offset..current_lex_end_offset)
-                .unwrap_or("")
-                .to_string(),
-            literal: Some(LiteralValue::String(content)),
+    /// Error token covering the literal from its start to the current position.
+    fn literal_error(
+        &mut self,
+        kind: LexErrorKind,
+        start_offset: usize,
+        start_line: usize,
+        start_col: usize,
+        message: String,
+    ) -> Scanned {
+        let end_offset = self.current_offset();
+        let token = Scanned {
+            kind: TokenKind::Error,
+            literal: Some(LiteralValue::String(message)),
             span: Span {
                 start: Position {
                     line: start_line,
@@ -1431,15 +3201,16 @@ impl<'a> Lexer<'a> {
                     offset: start_offset,
                 },
                 end: Position {
-                    line: current_lex_end_line,
-                    column: current_lex_end_col,
-                    offset: current_lex_end_offset,
+                    line: self.line,
+                    column: self.column,
+                    offset: end_offset,
                 },
             },
-        }
+        };
+        self.error_token(kind, token)
     }
 
-    fn lex_char_literal(&mut self) -> Token {
+    fn lex_char_literal(&mut self) -> Scanned {
         let start_offset = self.current_offset();
         let start_line = self.line;
         let start_col = self.column;
@@ -1449,6 +3220,7 @@ impl<'a> Lexer<'a> {
         let mut char_val: Option<char> = None;
         let mut closed = false;
         let mut error_msg: Option<String> = None;
+        let mut error_kind = LexErrorKind::UnterminatedCharLiteral;
         let mut consumed_char_count = 0;
 
         if let Some(&(_idx, ch)) = self.chars.peek() {
@@ -1456,6 +3228,7 @@ impl<'a> Lexer<'a> {
                 '\'' => {
                     // Empty char literal: ''
                     error_msg = Some("Empty character literal ".to_string());
+                    error_kind = LexErrorKind::EmptyCharLiteral;
                     self.advance_char(); // consume the closing quote
                     closed = true;
                 }
@@ -1479,13 +3252,8 @@ impl<'a> Lexer<'a> {
                                         if ch == '\'' {
                                             self.advance_char(); // consume closing quote
                                             let cv = char_val.unwrap();
-                                            let final_lexeme = self
-                                                .input
-                                                .get(start_offset..self.current_offset())
-                                                .unwrap_or("");
-                                            return Token {
+                                            return Scanned {
                                                 kind: TokenKind::CharacterLiteral,
-                                                lexeme: final_lexeme.to_string(),
                                                 literal: Some(LiteralValue::Char(cv)),
                                                 span: Span {
                                                     start: Position {
@@ -1503,6 +3271,7 @@ impl<'a> Lexer<'a> {
                                         } else {
                                             // Not a closing quote: multi-character or unterminated
                                             error_msg = Some("Multi-character literal or unterminated (in character literal)".to_string());
+                                            error_kind = LexErrorKind::MultiCharLiteral;
                                         }
                                     } else {
                                         // EOF before closing quote
@@ -1534,7 +3303,8 @@ impl<'a> Lexer<'a> {
                                 }
                                 // Error message for char lit needs to be specific for invalid simple escape
                                 Err(msg_str) => {
-                                    error_msg = Some(format!("{} (in character literal)", msg_str))
+                                    error_msg = Some(format!("{} (in character literal)", msg_str));
+                                    error_kind = LexErrorKind::InvalidEscape;
                                 }
                             }
                         }
@@ -1572,13 +3342,18 @@ impl<'a> Lexer<'a> {
                     closed = true;
                     if consumed_char_count > 1 {
                         error_msg = Some("Multi-character literal ".to_string());
+                        error_kind = LexErrorKind::MultiCharLiteral;
                     } else if consumed_char_count == 0 && char_val.is_none() {
                         error_msg = Some("Empty character literal ".to_string());
+                        error_kind = LexErrorKind::EmptyCharLiteral;
                     }
                 } else {
                     // Expected closing quote, found something else or too many chars
                     if consumed_char_count >= 1 {
                         error_msg = Some("Multi-character literal or unterminated ".to_string());
+                        if ch != '\n' {
+                            error_kind = LexErrorKind::MultiCharLiteral;
+                        }
                     } else {
                         error_msg = Some("Unterminated character literal ".to_string());
                     }
@@ -1594,11 +3369,6 @@ impl<'a> Lexer<'a> {
         let current_lex_end_col = self.column;
         let current_lex_end_line = self.line;
 
-        let final_lexeme = self
-            .input
-            .get(start_offset..current_lex_end_offset)
-            .unwrap_or("");
-
         if let Some(mut msg) = error_msg {
             if !msg.ends_with("(in character literal)") {
                 if msg.ends_with(' ') {
@@ -1607,53 +3377,56 @@ impl<'a> Lexer<'a> {
                     msg.push_str(" (in character literal)");
                 }
             }
-            return Token {
-                kind: TokenKind::Error,
-                lexeme: final_lexeme.to_string(),
-                literal: Some(LiteralValue::String(msg)),
-                span: Span {
-                    start: Position {
-                        line: start_line,
-                        column: start_col,
-                        offset: start_offset,
-                    },
-                    end: Position {
-                        line: current_lex_end_line,
-                        column: current_lex_end_col,
-                        offset: current_lex_end_offset,
+            return self.error_token(
+                error_kind,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(msg)),
+                    span: Span {
+                        start: Position {
+                            line: start_line,
+                            column: start_col,
+                            offset: start_offset,
+                        },
+                        end: Position {
+                            line: current_lex_end_line,
+                            column: current_lex_end_col,
+                            offset: current_lex_end_offset,
+                        },
                     },
                 },
-            };
+            );
         }
         // If we have an error, return an Error token
         if !closed {
-            return Token {
-                kind: TokenKind::Error,
-                lexeme: final_lexeme.to_string(),
-                literal: Some(LiteralValue::String(
-                    "Unterminated character literal (in character literal)".to_string(),
-                )),
-                span: Span {
-                    start: Position {
-                        line: start_line,
-                        column: start_col,
-                        offset: start_offset,
-                    },
-                    end: Position {
-                        line: current_lex_end_line,
-                        column: current_lex_end_col,
-                        offset: current_lex_end_offset,
+            return self.error_token(
+                LexErrorKind::UnterminatedCharLiteral,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(
+                        "Unterminated character literal (in character literal)".to_string(),
+                    )),
+                    span: Span {
+                        start: Position {
+                            line: start_line,
+                            column: start_col,
+                            offset: start_offset,
+                        },
+                        end: Position {
+                            line: current_lex_end_line,
+                            column: current_lex_end_col,
+                            offset: current_lex_end_offset,
+                        },
                     },
                 },
-            };
+            );
         }
 
         if let Some(cv) = char_val {
             if consumed_char_count == 1 {
                 // Double check, though error_msg should catch multi-char or empty.
-                Token {
+                Scanned {
                     kind: TokenKind::CharacterLiteral,
-                    lexeme: final_lexeme.to_string(),
                     literal: Some(LiteralValue::Char(cv)),
                     span: Span {
                         start: Position {
@@ -1669,12 +3442,36 @@ impl<'a> Lexer<'a> {
                     },
                 }
             } else {
-                Token {
+                self.error_token(
+                    LexErrorKind::MultiCharLiteral,
+                    Scanned {
+                        kind: TokenKind::Error,
+                        literal: Some(LiteralValue::String(
+                            "Multi-character literal or unterminated (in character literal)"
+                                .to_string(),
+                        )),
+                        span: Span {
+                            start: Position {
+                                line: start_line,
+                                column: start_col,
+                                offset: start_offset,
+                            },
+                            end: Position {
+                                line: current_lex_end_line,
+                                column: current_lex_end_col,
+                                offset: current_lex_end_offset,
+                            },
+                        },
+                    },
+                )
+            }
+        } else {
+            self.error_token(
+                LexErrorKind::UnterminatedCharLiteral,
+                Scanned {
                     kind: TokenKind::Error,
-                    lexeme: final_lexeme.to_string(),
                     literal: Some(LiteralValue::String(
-                        "Multi-character literal or unterminated (in character literal)"
-                            .to_string(),
+                        "Unterminated character literal (in character literal)".to_string(),
                     )),
                     span: Span {
                         start: Position {
@@ -1688,32 +3485,58 @@ impl<'a> Lexer<'a> {
                             offset: current_lex_end_offset,
                         },
                     },
-                }
-            }
-        } else {
-            Token {
-                kind: TokenKind::Error,
-                lexeme: final_lexeme.to_string(),
-                literal: Some(LiteralValue::String(
-                    "Unterminated character literal (in character literal)".to_string(),
-                )),
-                span: Span {
-                    start: Position {
-                        line: start_line,
-                        column: start_col,
-                        offset: start_offset,
-                    },
-                    end: Position {
-                        line: current_lex_end_line,
-                        column: current_lex_end_col,
-                        offset: current_lex_end_offset,
-                    },
                 },
+            )
+        }
+    }
+
+    /// Lex `r#name` as an identifier, even when `name` is a keyword.
+    fn lex_raw_identifier(&mut self, start_offset: usize) -> Scanned {
+        let start_line = self.line;
+        let start_col = self.column;
+        self.advance_char(); // consume 'r'
+        self.advance_char(); // consume '#'
+
+        let mut name = String::new();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if is_xid_continue(c) {
+                name.push(c);
+                self.advance_char();
+            } else {
+                break;
             }
         }
+        let name: String = name.nfc().collect();
+        if name == "_" {
+            return self.literal_error(
+                LexErrorKind::InvalidRawIdentifier,
+                start_offset,
+                start_line,
+                start_col,
+                "'_' cannot be a raw identifier.".to_string(),
+            );
+        }
+
+        let end_offset = self.current_offset();
+        Scanned {
+            kind: TokenKind::Identifier,
+            literal: Some(LiteralValue::RawIdentifier(Symbol::intern(&name))),
+            span: Span {
+                start: Position {
+                    line: start_line,
+                    column: start_col,
+                    offset: start_offset,
+                },
+                end: Position {
+                    line: self.line,
+                    column: self.column,
+                    offset: end_offset,
+                },
+            },
+        }
     }
 
-    fn lex_raw_string_literal(&mut self, start_idx: usize) -> Token {
+    fn lex_raw_string_literal(&mut self, start_idx: usize) -> Scanned {
         let start_line = self.line;
         let start_col = self.column;
 
@@ -1733,30 +3556,28 @@ impl<'a> Lexer<'a> {
         // Expect opening quote
         if !matches!(self.chars.peek(), Some(&(_, '"'))) {
             let end_offset = self.current_offset();
-            return Token {
-                kind: TokenKind::Error,
-                lexeme: self
-                    .input
-                    .get(start_idx..end_offset)
-                    .unwrap_or("")
-                    .to_string(),
-                literal: Some(LiteralValue::String(
-                    "Expected '\"' after 'r' and hash characters in raw string literal."
-                        .to_string(),
-                )),
-                span: Span {
-                    start: Position {
-                        line: start_line,
-                        column: start_col,
-                        offset: start_idx,
-                    },
-                    end: Position {
-                        line: self.line,
-                        column: self.column,
-                        offset: end_offset,
+            return self.error_token(
+                LexErrorKind::MalformedRawString,
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(
+                        "Expected '\"' after 'r' and hash characters in raw string literal."
+                            .to_string(),
+                    )),
+                    span: Span {
+                        start: Position {
+                            line: start_line,
+                            column: start_col,
+                            offset: start_idx,
+                        },
+                        end: Position {
+                            line: self.line,
+                            column: self.column,
+                            offset: end_offset,
+                        },
                     },
                 },
-            };
+            );
         }
 
         self.advance_char(); // consume opening '"'
@@ -1815,12 +3636,11 @@ impl<'a> Lexer<'a> {
         };
 
         if !closed {
-            Token {
+            self.error_token(LexErrorKind::UnterminatedString, Scanned {
                 kind: TokenKind::Error,
-                lexeme: self.input.get(start_idx..end_offset).unwrap_or("").to_string(),
                 literal: Some(LiteralValue::String("Unterminated raw string literal: expected closing quote and matching hash characters before end of file.".to_string())),
                 span,
-            }
+            })
         } else {
             // Extract the content without the delimiters
             let actual_content = self
@@ -1830,26 +3650,22 @@ impl<'a> Lexer<'a> {
                         ..(end_offset - '"'.len_utf8() - hash_count * '#'.len_utf8()),
                 )
                 .unwrap_or("");
-            Token {
+            Scanned {
                 kind: TokenKind::RawStringLiteral,
-                lexeme: self
-                    .input
-                    .get(start_idx..end_offset)
-                    .unwrap_or("")
-                    .to_string(),
                 literal: Some(LiteralValue::String(actual_content.to_string())),
                 span,
             }
         }
     }
 
-    fn lex_byte_literal(&mut self, start_offset_param: usize) -> Token {
+    fn lex_byte_literal(&mut self, start_offset_param: usize) -> Scanned {
         let start_line = self.line;
         let start_col = self.column;
 
         self.advance_char(); // consume 'b'
         let quote = self.advance_char().unwrap().1; // consume quote
-        let mut content = String::new();
+        let mut content = Vec::new();
+        let mut non_ascii = false;
         let mut closed = false;
         while let Some(&(_, c)) = self.chars.peek() {
             if c == quote {
@@ -1857,34 +3673,54 @@ impl<'a> Lexer<'a> {
                 closed = true;
                 break;
             } else if c == '\\' {
-                // Basic escape handling for byte strings (e.g., b"\\", b"\'", b"\"")
                 self.advance_char(); // consume backslash
                 if let Some(&(_, esc_char)) = self.chars.peek() {
+                    self.advance_char(); // consume the escaped char
                     match esc_char {
-                        'n' | 't' | 'r' | '0' | '\\' | '\'' | '"' => {
-                            // Common escapes, store as is
-                            content.push('\\');
-                            content.push(esc_char);
+                        'n' => content.push(b'\n'),
+                        't' => content.push(b'\t'),
+                        'r' => content.push(b'\r'),
+                        '0' => content.push(0),
+                        '\\' | '\'' | '"' => content.push(esc_char as u8),
+                        'x' => {
+                            // \xHH: exactly two hex digits, any byte value
+                            let hex: String = (0..2)
+                                .map_while(|_| {
+                                    let digit = self.chars.peek().map(|&(_, d)| d)?;
+                                    digit.is_ascii_hexdigit().then(|| {
+                                        self.advance_char();
+                                        digit
+                                    })
+                                })
+                                .collect();
+                            match u8::from_str_radix(&hex, 16) {
+                                Ok(byte) if hex.len() == 2 => content.push(byte),
+                                _ => {
+                                    content.push(b'\\');
+                                    content.push(b'x');
+                                    content.extend(hex.bytes());
+                                }
+                            }
                         }
-                        // Hex escapes like \xHH could be added here if desired for byte strings
+                        // Unknown escapes are kept literally: \ and the char
                         _ => {
-                            // For byte strings, unknown escapes might be stored literally or be an error
-                            // Storing literally: \ and the char
-                            content.push('\\');
-                            content.push(esc_char);
+                            content.push(b'\\');
+                            non_ascii |= !esc_char.is_ascii();
+                            let mut buffer = [0; 4];
+                            content.extend(esc_char.encode_utf8(&mut buffer).bytes());
                         }
                     }
-                    self.advance_char(); // consume the escaped char
                 } else {
                     break; // Unterminated escape at EOF
                 }
             } else {
-                content.push(c);
+                non_ascii |= !c.is_ascii();
+                let mut buffer = [0; 4];
+                content.extend(c.encode_utf8(&mut buffer).bytes());
                 self.advance_char();
             }
         }
         let end_offset = self.current_offset();
-        let lexeme = self.input.get(start_offset_param..end_offset).unwrap_or("");
         let span = Span {
             start: Position {
                 line: start_line,
@@ -1899,57 +3735,60 @@ impl<'a> Lexer<'a> {
         };
 
         if !closed {
-            Token {
+            self.error_token(
+                if quote == '\'' {
+                    LexErrorKind::UnterminatedCharLiteral
+                } else {
+                    LexErrorKind::UnterminatedString
+                },
+                Scanned {
+                    kind: TokenKind::Error,
+                    literal: Some(LiteralValue::String(
+                        "Unterminated byte literal".to_string(),
+                    )),
+                    span,
+                },
+            )
+        } else if non_ascii {
+            self.error_token(LexErrorKind::InvalidCharacter, Scanned {
                 kind: TokenKind::Error,
-                lexeme: lexeme.to_string(),
                 literal: Some(LiteralValue::String(
-                    "Unterminated byte literal".to_string(),
+                    "Byte literals may only contain ASCII characters; use a \\xHH escape for other byte values."
+                        .to_string(),
                 )),
                 span,
-            }
+            })
         } else if quote == '\'' {
-            // Single-quoted byte literal, b'...'
-            // As per RFC-001 (implicitly, via char literal def), char/byte literals are single char/byte.
-            // For b'...', the `content` after escape processing must be a single byte representation.
-            // This requires more robust escape processing if e.g. b'\n' is to become a single byte 10.
-            // Current simple escape stores \ and n. For b'\n', content is "\n".len() = 2.
-            // For now, if it's b'c', content is "c". If b'\\ ', content is "\\".
-            // A simple check: if content contains \, it's likely an escape not yet processed to a single byte.
-            // Or, if content.chars().count() != 1 for simple chars.
-            // Let's refine this: Ferra design implies byte literals are simple for now.
-            // b'a' -> byte 'a'. b'\'' -> byte '''. b'\\' -> byte '\\'.
-            // What about b'\n'? The current escape logic makes `content` = "\n".
-            // This is tricky for b'X'. For now, assume content should be 1 char unless it's a known single-byte escape.
-
-            if content.chars().count() == 1 {
-                Token {
+            // Single-quoted byte literal, b'...': exactly one byte after escapes
+            if content.len() == 1 {
+                Scanned {
                     kind: TokenKind::ByteLiteral,
-                    lexeme: lexeme.to_string(),
-                    literal: Some(LiteralValue::Byte(content.as_bytes()[0])),
+                    literal: Some(LiteralValue::Byte(content[0])),
                     span,
                 }
             } else {
-                Token {
-                    kind: TokenKind::Error,
-                    lexeme: lexeme.to_string(),
-                    literal: Some(LiteralValue::String(
-                        "Byte literal b'...' must represent a single byte after escapes."
-                            .to_string(),
-                    )),
-                    span,
-                }
+                self.error_token(
+                    LexErrorKind::MultiCharLiteral,
+                    Scanned {
+                        kind: TokenKind::Error,
+                        literal: Some(LiteralValue::String(
+                            "Byte literal b'...' must represent a single byte after escapes."
+                                .to_string(),
+                        )),
+                        span,
+                    },
+                )
             }
         } else {
-            Token {
+            Scanned {
                 kind: TokenKind::ByteLiteral,
-                lexeme: lexeme.to_string(),
-                literal: Some(LiteralValue::String(content)),
+                literal: Some(LiteralValue::ByteString(content)),
                 span,
             }
         }
     }
 
-    fn lex_multiline_string_literal(&mut self, start_idx: usize) -> Token {
+    fn lex_multiline_string_literal(&mut self, start_idx: usize) -> Scanned {
         let start_line = self.line;
         let start_col = self.column;
 
@@ -2014,24 +3853,18 @@ impl<'a> Lexer<'a> {
         };
 
         if !closed {
-            return Token {
+            return self.error_token(LexErrorKind::UnterminatedString, Scanned {
                 kind: TokenKind::Error,
-                lexeme: self.input.get(start_idx..end_offset).unwrap_or("").to_string(),
                 literal: Some(LiteralValue::String("Unterminated multiline string literal: expected closing \"\"\" before end of file.".to_string())),
                 span,
-            };
+            });
         }
 
         // Apply indent stripping algorithm
         let processed_content = self.strip_common_indentation(&lines);
 
-        Token {
+        Scanned {
             kind: TokenKind::MultiLineStringLiteral,
-            lexeme: self
-                .input
-                .get(start_idx..end_offset)
-                .unwrap_or("")
-                .to_string(),
             literal: Some(LiteralValue::String(processed_content)),
             span,
         }
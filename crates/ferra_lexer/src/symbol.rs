@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright (c) 2025 Ferra Language Project Contributors
+
+//! Interned identifiers.
+//!
+//! A [`Symbol`] is a 4-byte handle to a string stored once in a global,
+//! thread-safe interner, so comparing and hashing names is O(1) and copying
+//! them never allocates.
+//!
+//! The interner is never cleared: every distinct string interned stays in
+//! memory until the process exits, so the count grows with the distinct
+//! names seen, not with the amount of input lexed. Reading a symbol's string
+//! takes no lock; only interning a new string does.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{OnceLock, RwLock};
+
+/// Handle to an interned string. Two symbols are equal exactly when their
+/// strings are equal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+/// Slots in the first chunk of string storage; each later chunk doubles.
+const FIRST_CHUNK: u64 = 1024;
+/// Enough chunks to give every `u32` index a slot.
+const CHUNKS: usize = 23;
+
+type Chunk = Box<[OnceLock<Box<str>>]>;
+
+struct Interner {
+    symbols: RwLock<HashMap<&'static str, Symbol>>,
+    /// The strings, indexed by symbol. Chunks are allocated on demand and
+    /// never moved, and each slot is written once while holding the write
+    /// lock on `symbols`, so reads need no lock.
+    chunks: [OnceLock<Chunk>; CHUNKS],
+}
+
+fn interner() -> &'static Interner {
+    static INTERNER: OnceLock<Interner> = OnceLock::new();
+    INTERNER.get_or_init(|| Interner {
+        symbols: RwLock::default(),
+        chunks: std::array::from_fn(|_| OnceLock::new()),
+    })
+}
+
+impl Interner {
+    /// Storage slot for the string of `symbol`.
+    fn slot(&'static self, symbol: u32) -> &'static OnceLock<Box<str>> {
+        let position = u64::from(symbol) + FIRST_CHUNK;
+        let chunk = (position.ilog2() - FIRST_CHUNK.ilog2()) as usize;
+        let offset = (position - (FIRST_CHUNK << chunk)) as usize;
+        let slots = self.chunks[chunk]
+            .get_or_init(|| (0..FIRST_CHUNK << chunk).map(|_| OnceLock::new()).collect());
+        &slots[offset]
+    }
+}
+
+impl Symbol {
+    /// Symbol for `string`, interning it on first use.
+    pub fn intern(string: &str) -> Symbol {
+        let interner = interner();
+        if let Some(&symbol) = interner.symbols.read().unwrap().get(string) {
+            return symbol;
+        }
+        let mut symbols = interner.symbols.write().unwrap();
+        // Another thread may have interned it since the read lock was released.
+        if let Some(&symbol) = symbols.get(string) {
+            return symbol;
+        }
+        let symbol = Symbol(u32::try_from(symbols.len()).expect("too many symbols"));
+        let slot = interner.slot(symbol.0);
+        let stored = slot.get_or_init(|| string.into());
+        symbols.insert(stored, symbol);
+        symbol
+    }
+
+    /// The interned string.
+    pub fn as_str(self) -> &'static str {
+        interner()
+            .slot(self.0)
+            .get()
+            .expect("symbols are only created once their string is stored")
+    }
+
+    /// Index of this symbol in the interner, stable for the program's lifetime.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Number of distinct strings interned so far.
+    pub fn interned_count() -> usize {
+        interner().symbols.read().unwrap().len()
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Symbols order by their strings, so sorted names stay alphabetical.
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self == other {
+            return std::cmp::Ordering::Equal;
+        }
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Formats like the string itself, so `{:?}` output matches a `String` name.
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(string: &str) -> Self {
+        Symbol::intern(string)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(string: &String) -> Self {
+        Symbol::intern(string)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(string: String) -> Self {
+        Symbol::intern(&string)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.as_str().to_string()
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Symbol {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<Symbol> for str {
+    fn eq(&self, other: &Symbol) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Symbol> for &str {
+    fn eq(&self, other: &Symbol) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<Symbol> for String {
+    fn eq(&self, other: &Symbol) -> bool {
+        self == other.as_str()
+    }
+}
@@ -90,7 +90,7 @@ fn test_raw_string_hash_mismatch() {
 
 #[test]
 fn test_raw_string_invalid_no_quote() {
-    let input = "r#hello"; // Missing quote after hash
+    let input = "r##hello"; // Missing quote after hashes (r#hello is a raw identifier)
     let tokens = lex_all(input);
 
     assert_eq!(tokens[0].kind, TokenKind::Error);
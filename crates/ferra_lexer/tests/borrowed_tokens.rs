@@ -0,0 +1,103 @@
+use ferra_lexer::*;
+use std::borrow::Cow;
+
+const SOURCES: &[&str] = &[
+    "let x = 42;\nlet y = x + 1;\n",
+    "if x:\r\n    y = 1 // comment\r\n    z = 2\r\nw\r\n",
+    "let s = \"héllo\\n\\u{1F600}\" /* block */ + 'é' + '\\t' + b'a' + b\"bytes\";",
+    "let r = r#\"raw \"quoted\"\"#;\nlet m = \"\"\"\n    multi\n    line\n    \"\"\";",
+    "let f = f\"{name}: {value + 1} {{x}}\"; let n = 0xFFu8 + 1_000i64 + 2.5e3 + 99999999999999999999;",
+    "fn r#match<'a>(x: &'a Int) -> Int where T: Copy { true && false }",
+    "let cafe\u{301} = café; let ｘ = 1;",
+    "let bad = ` + \"unterminated\nlet e = '';\n0x",
+];
+
+#[test]
+fn test_borrowed_tokens_match_owned_tokens() {
+    for source in SOURCES {
+        let (owned, owned_errors) = Lexer::new(source).lex_with_errors();
+        let (borrowed, errors) = Lexer::new(source).lex_borrowed_with_errors();
+        assert_eq!(errors, owned_errors, "{source:?}");
+        assert_eq!(borrowed.len(), owned.len(), "{source:?}");
+
+        for (token, expected) in borrowed.iter().zip(&owned) {
+            assert_eq!(token.kind, expected.kind, "{source:?}");
+            assert_eq!(token.span, expected.span, "{source:?}");
+            if token.kind != TokenKind::Error {
+                assert_eq!(&token.into_owned(), expected, "{source:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lexemes_borrow_from_source() {
+    let source = "let greeting = \"hello\";";
+    let range = source.as_bytes().as_ptr_range();
+    for token in Lexer::new(source).lex_borrowed() {
+        assert!(range.contains(&token.lexeme.as_ptr()) || token.lexeme.is_empty());
+        assert_eq!(
+            token.lexeme,
+            &source[token.span.start.offset..token.span.end.offset]
+        );
+    }
+}
+
+#[test]
+fn test_tokens_are_copy() {
+    let tokens = Lexer::new("a + b").lex_borrowed();
+    let first = tokens[0];
+    let span = first.span;
+    assert_eq!(first, tokens[0]);
+    assert_eq!(span.end.offset, 1);
+}
+
+#[test]
+fn test_literals_computed_on_demand() {
+    let tokens = Lexer::new("x 42 2.5 'c' true r#fn").lex_borrowed();
+    assert_eq!(
+        tokens[0].literal(),
+        Some(LiteralValue::Identifier("x".into()))
+    );
+    assert_eq!(tokens[1].literal(), Some(LiteralValue::Integer(42)));
+    assert_eq!(tokens[2].literal(), Some(LiteralValue::Float(2.5)));
+    assert_eq!(tokens[3].literal(), Some(LiteralValue::Char('c')));
+    assert_eq!(tokens[4].literal(), Some(LiteralValue::Boolean(true)));
+    assert!(tokens[5].is_raw_identifier());
+    assert_eq!(tokens[5].symbol(), Some(Symbol::intern("fn")));
+}
+
+#[test]
+fn test_unescaped_borrows_without_escapes() {
+    let tokens = Lexer::new(r##""plain" "tab\t" r#"raw \n"# 1"##).lex_borrowed();
+    assert!(matches!(
+        tokens[0].unescaped(),
+        Some(Cow::Borrowed("plain"))
+    ));
+    match tokens[1].unescaped() {
+        Some(Cow::Owned(text)) => assert_eq!(text, "tab\t"),
+        other => panic!("expected an owned string, got {other:?}"),
+    }
+    assert!(matches!(
+        tokens[2].unescaped(),
+        Some(Cow::Borrowed("raw \\n"))
+    ));
+    assert_eq!(tokens[3].unescaped(), None);
+}
+
+#[test]
+fn test_error_tokens_have_no_literal() {
+    let (tokens, errors) = Lexer::new("let ` = 1;").lex_borrowed_with_errors();
+    assert_eq!(tokens[1].kind, TokenKind::Error);
+    assert_eq!(tokens[1].lexeme, "`");
+    assert_eq!(tokens[1].literal(), None);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span, tokens[1].span);
+}
+
+#[test]
+fn test_owned_identifiers_are_normalized() {
+    let tokens = Lexer::new("cafe\u{301}").lex_borrowed();
+    assert_eq!(tokens[0].lexeme, "cafe\u{301}");
+    assert_eq!(Token::from(tokens[0]).lexeme, "caf\u{e9}");
+}
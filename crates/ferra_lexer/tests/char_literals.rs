@@ -118,10 +118,11 @@ fn test_char_literal_unterminated_eof_after_opening() {
 
 #[test]
 fn test_char_literal_unterminated_eof_after_char() {
-    let tokens = lex_all("'a");
+    // `'a` alone is a lifetime, so use a character that cannot start one
+    let tokens = lex_all("'1");
     assert_eq!(tokens.len(), 2);
     assert_eq!(tokens[0].kind, TokenKind::Error);
-    assert_eq!(tokens[0].lexeme, "'a");
+    assert_eq!(tokens[0].lexeme, "'1");
     assert_eq!(
         tokens[0].literal,
         Some(LiteralValue::String(
@@ -147,11 +148,11 @@ fn test_char_literal_unterminated_eof_after_backslash() {
 
 #[test]
 fn test_char_literal_unterminated_by_newline() {
-    let tokens = lex_all("'a\n"); // 'a then newline
+    let tokens = lex_all("'1\n"); // '1 then newline
                                   // Should produce an error token for unterminated char literal, then a Newline token, then EOF
     assert!(tokens.len() >= 2);
     assert_eq!(tokens[0].kind, TokenKind::Error);
-    assert_eq!(tokens[0].lexeme, "'a"); // Consumes 'a, stops at \n
+    assert_eq!(tokens[0].lexeme, "'1"); // Consumes '1, stops at \n
     assert_eq!(
         tokens[0].literal,
         Some(LiteralValue::String(
@@ -1,4 +1,4 @@
-use ferra_lexer::{Lexer, TokenKind};
+use ferra_lexer::{LexErrorKind, Lexer, LexerConfig, TokenKind};
 
 #[test]
 fn test_line_comment() {
@@ -107,3 +107,45 @@ fn test_multi_line_span_precision() {
     assert!(error_token.lexeme.contains("multi-line"));
     assert_eq!(error_token.lexeme.len(), src.len() - 8); // everything after "let x = "
 }
+
+#[test]
+fn test_block_comment_nesting_limit() {
+    let config = LexerConfig::new().with_max_nesting_depth(2);
+    let (tokens, errors) = Lexer::with_config("/* a /* b */ */ x", config).lex_with_errors();
+    assert!(errors.is_empty());
+    assert_eq!(tokens[0].kind, TokenKind::Identifier);
+
+    let src = "/* a /* b /* c */ */ */ x";
+    let (tokens, errors) = Lexer::with_config(src, config).lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::NestingTooDeep);
+    assert_eq!(errors[0].code(), "E017");
+    assert_eq!(
+        errors[0].message,
+        "Block comment is nested deeper than the maximum of 2 levels."
+    );
+    assert_eq!(errors[0].span.start.offset, src.find("/* c").unwrap());
+    // The comment still ends at its matching `*/`
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+    assert_eq!(tokens[1].kind, TokenKind::Identifier);
+    assert_eq!(tokens[1].lexeme, "x");
+}
+
+#[test]
+fn test_pathologically_nested_block_comment() {
+    let src = format!("{}{} x", "/*".repeat(100_000), "*/".repeat(100_000));
+    let (tokens, errors) = Lexer::new(&src).lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::NestingTooDeep);
+    assert_eq!(tokens[1].lexeme, "x");
+
+    let (_, errors) = Lexer::new(&"/*".repeat(100_000)).lex_with_errors();
+    let kinds: Vec<_> = errors.iter().map(|error| error.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            LexErrorKind::NestingTooDeep,
+            LexErrorKind::UnterminatedBlockComment
+        ]
+    );
+}
@@ -1,4 +1,4 @@
-use ferra_lexer::Lexer;
+use ferra_lexer::{Lexer, LexerBackend, LexerConfig};
 use proptest::prelude::*;
 
 proptest! {
@@ -48,3 +48,19 @@ proptest! {
         let _ = Lexer::new(&input).lex();
     }
 }
+
+proptest! {
+    #[test]
+    fn dfa_backend_matches_hand_written(
+        s in prop_oneof![
+            ".{0,256}",
+            "[a-z0-9_ .+\\-*/=<>!&|^%?:;,(){}\\[\\]#@$'\"\\\\\n\té]{0,256}",
+        ]
+    ) {
+        let config = LexerConfig::new().with_backend(LexerBackend::Dfa);
+        prop_assert_eq!(
+            Lexer::with_config(&s, config).lex_with_diagnostics(),
+            Lexer::new(&s).lex_with_diagnostics()
+        );
+    }
+}
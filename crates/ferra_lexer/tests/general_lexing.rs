@@ -31,10 +31,10 @@ fn test_indentation_with_whitespace() {
 
 #[test]
 fn test_unrecognized_character() {
-    let tokens = lex_all("$");
+    let tokens = lex_all("`");
     assert_eq!(tokens.len(), 2);
     assert_eq!(tokens[0].kind, TokenKind::Error);
-    assert_eq!(tokens[0].lexeme, "$".to_string());
+    assert_eq!(tokens[0].lexeme, "`".to_string());
     assert_eq!(tokens[1].kind, TokenKind::Eof);
 }
 
@@ -0,0 +1,247 @@
+use ferra_lexer::*;
+
+fn kinds(tokens: &[Token]) -> Vec<TokenKind> {
+    tokens.iter().map(|t| t.kind).collect()
+}
+
+fn lex_with(input: &str, config: LexerConfig) -> (Vec<Token>, Vec<LexError>) {
+    Lexer::with_config(input, config).lex_with_errors()
+}
+
+#[test]
+fn test_dedent_to_enclosing_level_is_accepted() {
+    let (tokens, errors) = Lexer::new("a\n    b\n        c\n    d\ne").lex_with_errors();
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(
+        kinds(&tokens),
+        vec![
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Indent,
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Indent,
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Dedent,
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Dedent,
+            TokenKind::Identifier,
+            TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_dedent_to_unknown_level() {
+    let source = "a\n    b\n        c\n      d";
+    let (tokens, errors) = Lexer::new(source).lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    let error = &errors[0];
+    assert_eq!(error.kind, LexErrorKind::UnexpectedDedent);
+    assert_eq!(error.code(), "E005");
+    assert_eq!(
+        error.message,
+        "Dedent to an unexpected level: found 6 columns of indentation, expected 4 or 0."
+    );
+    assert_eq!(
+        &source[error.span.start.offset..error.span.end.offset],
+        "      "
+    );
+    assert_eq!(error.span.start.line, 4);
+    assert_eq!(error.span.start.column, 1);
+
+    // The inner block is closed before the error, and `d` stays in the outer block.
+    let tail: Vec<TokenKind> = kinds(&tokens)[7..].to_vec();
+    assert_eq!(
+        tail,
+        vec![
+            TokenKind::Newline,
+            TokenKind::Dedent,
+            TokenKind::Error,
+            TokenKind::Identifier,
+            TokenKind::Dedent,
+            TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_lines_after_unknown_dedent_stay_in_recovered_block() {
+    let source = "a:\n    b:\n        c\n      d\n      e\n";
+    let (tokens, errors) = Lexer::new(source).lex_with_errors();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!(errors[0].code(), "E005");
+
+    // `e` is at the same level as `d`, not a block nested inside it.
+    let error_at = tokens
+        .iter()
+        .position(|t| t.kind == TokenKind::Error)
+        .unwrap();
+    let tail: Vec<TokenKind> = kinds(&tokens)[error_at + 1..].to_vec();
+    assert_eq!(
+        tail,
+        vec![
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Identifier,
+            TokenKind::Newline,
+            TokenKind::Dedent,
+            TokenKind::Eof,
+        ]
+    );
+
+    // A line back at a known level ends the recovered run.
+    let (tokens, errors) =
+        Lexer::new("a:\n    b:\n        c\n      d\n    e\n      f").lex_with_errors();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!(
+        kinds(&tokens)
+            .iter()
+            .filter(|k| **k == TokenKind::Indent)
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn test_dedent_below_first_level_lists_single_level() {
+    let (_, errors) = Lexer::new("a\n    b\n  c").lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "Dedent to an unexpected level: found 2 columns of indentation, expected 0."
+    );
+}
+
+#[test]
+fn test_blank_line_with_partial_indent_is_not_an_error() {
+    let (_, errors) = Lexer::new("a\n        b\n  \n        c").lex_with_errors();
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_tab_width_is_configurable() {
+    // With 8-column tabs, one tab closes back to the 8-space block.
+    let source = "a\n    b\n        c\n\td";
+    let (_, errors) = lex_with(source, LexerConfig::new().with_tab_width(8));
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+
+    // With the default 4-column tabs the same line lands on the 4-space block.
+    let (tokens, errors) = Lexer::new(source).lex_with_errors();
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+    assert_eq!(
+        tokens
+            .iter()
+            .filter(|t| t.kind == TokenKind::Dedent)
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn test_dedent_message_mentions_tab_width() {
+    let (_, errors) = lex_with(
+        "a\n            b\n\t\tc",
+        LexerConfig::new().with_tab_width(3),
+    );
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::UnexpectedDedent);
+    assert_eq!(
+        errors[0].message,
+        "Dedent to an unexpected level: found 6 columns of indentation, expected 0. \
+         A tab counts as 3 columns."
+    );
+}
+
+#[test]
+fn test_tabs_forbidden() {
+    let config = LexerConfig::new().with_indent_policy(IndentPolicy::SpacesOnly);
+    let (_, errors) = lex_with("a\n    b", config);
+    assert!(errors.is_empty());
+
+    let source = "a\n\tb";
+    let (tokens, errors) = lex_with(source, config);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::ForbiddenIndentation);
+    assert_eq!(errors[0].code(), "E016");
+    assert_eq!(
+        &source[errors[0].span.start.offset..errors[0].span.end.offset],
+        "\t"
+    );
+    // Indentation is still tracked so the block structure survives.
+    assert!(tokens.iter().any(|t| t.kind == TokenKind::Indent));
+}
+
+#[test]
+fn test_spaces_forbidden() {
+    let config = LexerConfig::new().with_indent_policy(IndentPolicy::TabsOnly);
+    let (_, errors) = lex_with("a\n\tb", config);
+    assert!(errors.is_empty());
+
+    let (_, errors) = lex_with("a\n  b", config);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::ForbiddenIndentation);
+    assert!(errors[0].message.contains("indents with tabs"));
+}
+
+#[test]
+fn test_mixed_indentation_still_reported_under_policy() {
+    let config = LexerConfig::new().with_indent_policy(IndentPolicy::SpacesOnly);
+    let (_, errors) = lex_with("a\n \tb", config);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::MixedIndentation);
+}
+
+#[test]
+fn test_max_nesting_depth() {
+    let source = "a\n b\n  c\n   d\n  e";
+    let (_, errors) = lex_with(source, LexerConfig::new().with_max_nesting_depth(3));
+    assert!(errors.is_empty());
+
+    let (tokens, errors) = lex_with(source, LexerConfig::new().with_max_nesting_depth(2));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::NestingTooDeep);
+    assert_eq!(errors[0].code(), "E017");
+    assert_eq!(
+        errors[0].message,
+        "Indentation is nested deeper than the maximum of 2 levels."
+    );
+    assert_eq!(errors[0].span.start.line, 4);
+    let indents = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Indent)
+        .count();
+    let dedents = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Dedent)
+        .count();
+    assert_eq!(indents, 2);
+    assert_eq!(dedents, 2);
+}
+
+#[test]
+fn test_default_config() {
+    let config = LexerConfig::default();
+    assert_eq!(config.tab_width, 4);
+    assert_eq!(config.indent_policy, IndentPolicy::Any);
+    assert_eq!(config.max_nesting_depth, 64);
+    assert_eq!(LexerConfig::new(), config);
+}
+
+#[test]
+fn test_pathologically_deep_indentation() {
+    let source: String = (0..200)
+        .map(|depth| format!("{}a\n", " ".repeat(depth)))
+        .collect();
+    let (tokens, errors) = lex_with(&source, LexerConfig::new());
+    assert!(errors
+        .iter()
+        .all(|error| error.kind == LexErrorKind::NestingTooDeep));
+    let indents = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Indent)
+        .count();
+    assert_eq!(indents, 64);
+}
@@ -0,0 +1,179 @@
+use ferra_lexer::*;
+
+fn lex_all(input: &str) -> Vec<Token> {
+    Lexer::new(input).lex()
+}
+
+fn segments(input: &str) -> Vec<StringSegment> {
+    let tokens = lex_all(input);
+    assert_eq!(
+        tokens[0].kind,
+        TokenKind::InterpolatedString,
+        "{:?}",
+        tokens[0]
+    );
+    match &tokens[0].literal {
+        Some(LiteralValue::Interpolated(segments)) => segments.clone(),
+        other => panic!("Expected interpolated segments, got {:?}", other),
+    }
+}
+
+fn error_message(input: &str) -> String {
+    let tokens = lex_all(input);
+    assert_eq!(tokens[0].kind, TokenKind::Error, "{:?}", tokens[0]);
+    match &tokens[0].literal {
+        Some(LiteralValue::String(message)) => message.clone(),
+        other => panic!("Expected error message, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolated_string_segments() {
+    let input = r#"f"Hello, {name}!""#;
+    let tokens = lex_all(input);
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].lexeme, input);
+
+    let segments = segments(input);
+    assert_eq!(segments.len(), 3);
+    assert_eq!(segments[0], StringSegment::Text("Hello, ".to_string()));
+    assert_eq!(segments[2], StringSegment::Text("!".to_string()));
+    match &segments[1] {
+        StringSegment::Expression { tokens, span } => {
+            assert_eq!(tokens.len(), 1);
+            assert_eq!(tokens[0].kind, TokenKind::Identifier);
+            assert_eq!(&input[span.start.offset..span.end.offset], "name");
+            assert_eq!(
+                &input[tokens[0].span.start.offset..tokens[0].span.end.offset],
+                "name"
+            );
+            assert_eq!(tokens[0].span.start.column, 11);
+        }
+        other => panic!("Expected expression segment, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolated_string_spans_on_later_lines() {
+    let input = "let a = 1\nlet s = f\"{a + 1}\"";
+    let tokens = lex_all(input);
+    let literal = tokens
+        .iter()
+        .find(|token| token.kind == TokenKind::InterpolatedString)
+        .unwrap();
+    match &literal.literal {
+        Some(LiteralValue::Interpolated(segments)) => match &segments[0] {
+            StringSegment::Expression { tokens, .. } => {
+                let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+                assert_eq!(
+                    kinds,
+                    vec![
+                        TokenKind::Identifier,
+                        TokenKind::Plus,
+                        TokenKind::IntegerLiteral
+                    ]
+                );
+                for token in tokens {
+                    assert_eq!(token.span.start.line, 2);
+                    assert_eq!(
+                        &input[token.span.start.offset..token.span.end.offset],
+                        token.lexeme
+                    );
+                }
+                assert_eq!(tokens[2].span.start.column, 16);
+            }
+            other => panic!("Expected expression segment, got {:?}", other),
+        },
+        other => panic!("Expected interpolated segments, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolated_string_escapes_and_nesting() {
+    // Doubled braces and escapes stay in the text
+    assert_eq!(
+        segments(r#"f"{{x}}\t\"\u{41}""#),
+        vec![StringSegment::Text("{x}\t\"A".to_string())]
+    );
+
+    // Braces and quotes inside the expression belong to it
+    let input = r#"f"{ map[{"}"}] } and {'}'}""#;
+    let segments = segments(input);
+    assert_eq!(segments.len(), 3);
+    match &segments[0] {
+        StringSegment::Expression { span, tokens } => {
+            assert_eq!(
+                &input[span.start.offset..span.end.offset],
+                r#" map[{"}"}] "#
+            );
+            assert!(tokens
+                .iter()
+                .any(|token| token.literal == Some(LiteralValue::String("}".to_string()))));
+        }
+        other => panic!("Expected expression segment, got {:?}", other),
+    }
+    assert_eq!(segments[1], StringSegment::Text(" and ".to_string()));
+
+    // Nested interpolated strings are lexed recursively
+    match &self::segments(r#"f"{f"{x}"}""#)[0] {
+        StringSegment::Expression { tokens, .. } => {
+            assert_eq!(tokens[0].kind, TokenKind::InterpolatedString)
+        }
+        other => panic!("Expected expression segment, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolated_string_errors() {
+    assert!(error_message(r#"f"a } b""#).contains("Unmatched '}'"));
+    assert!(error_message(r#"f"{}""#).contains("Empty expression"));
+    assert!(error_message(r#"f"{name""#).contains("Unterminated expression"));
+    assert!(error_message("f\"abc\nx").contains("Unterminated interpolated string"));
+    assert!(error_message(r#"f"\q""#).contains("Invalid escape sequence"));
+
+    // Errors inside the expression are reported with their own span
+    let input = r#"f"{a ~ b}""#;
+    let tokens = lex_all(input);
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+    assert_eq!(tokens[0].lexeme, "~");
+    assert_eq!(
+        &input[tokens[0].span.start.offset..tokens[0].span.end.offset],
+        "~"
+    );
+}
+
+#[test]
+fn test_interpolation_nesting_limit() {
+    let nested = |depth: usize| format!("{}x{}", "f\"{".repeat(depth), "}\"".repeat(depth));
+    let config = LexerConfig::new().with_max_nesting_depth(3);
+
+    let (tokens, errors) = Lexer::with_config(&nested(3), config).lex_with_errors();
+    assert!(errors.is_empty(), "{:?}", errors);
+    assert_eq!(tokens[0].kind, TokenKind::InterpolatedString);
+
+    let (tokens, errors) = Lexer::with_config(&nested(4), config).lex_with_errors();
+    assert_eq!(errors[0].kind, LexErrorKind::NestingTooDeep);
+    assert_eq!(
+        errors[0].message,
+        "Interpolated strings are nested deeper than the maximum of 3 levels."
+    );
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+}
+
+#[test]
+fn test_pathologically_nested_interpolation() {
+    let input = format!("{}x{}", "f\"{".repeat(10_000), "}\"".repeat(10_000));
+    let (tokens, errors) = Lexer::new(&input).lex_with_errors();
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+    assert_eq!(errors[0].kind, LexErrorKind::NestingTooDeep);
+}
+
+#[test]
+fn test_plain_strings_and_f_identifiers_are_unchanged() {
+    let tokens = lex_all(r#""Hello, {name}!""#);
+    assert_eq!(tokens[0].kind, TokenKind::StringLiteral);
+
+    let tokens = lex_all("f(x)");
+    assert_eq!(tokens[0].kind, TokenKind::Identifier);
+    assert_eq!(tokens[0].lexeme, "f");
+}
@@ -85,9 +85,9 @@ fn test_keywords_and_identifiers() {
         None,                               // continue
         None,                               // pub
         None,                               // unsafe
-        None,                               // ident1
-        None,                               // _ident2
-        None,                               // Eof
+        Some(LiteralValue::Identifier(Symbol::intern("ident1"))),
+        Some(LiteralValue::Identifier(Symbol::intern("_ident2"))),
+        None, // Eof
     ];
 
     assert_eq!(
@@ -128,6 +128,27 @@ fn test_and_or_keywords() {
     assert_eq!(tokens[2].kind, TokenKind::Eof);
 }
 
+#[test]
+fn test_macro_keyword_and_metavariable() {
+    let tokens = lex_all("macro square { ($x:expr) => { $x * $x } }");
+    let kinds: Vec<_> = tokens.iter().take(7).map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Macro,
+            TokenKind::Identifier,
+            TokenKind::LBrace,
+            TokenKind::LParen,
+            TokenKind::Dollar,
+            TokenKind::Identifier,
+            TokenKind::Colon,
+        ]
+    );
+    assert_eq!(tokens[0].lexeme, "macro");
+    assert_eq!(tokens[4].lexeme, "$");
+    assert_eq!(tokens[5].lexeme, "x");
+}
+
 #[test]
 fn test_unicode_identifiers() {
     // Identifier with a non-ASCII character (Greek letter Alpha)
@@ -275,3 +296,111 @@ fn test_keyword_like_identifier() {
     }
     assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
 }
+
+#[test]
+fn test_raw_identifiers() {
+    let tokens = lex_all("r#match r#data r#type r#foo");
+    assert_eq!(tokens.len(), 5);
+    for (token, name) in tokens.iter().zip(["match", "data", "type", "foo"]) {
+        assert_eq!(token.kind, TokenKind::Identifier);
+        assert!(token.is_raw_identifier());
+        assert_eq!(token.lexeme, format!("r#{}", name));
+        assert_eq!(
+            token.literal,
+            Some(LiteralValue::RawIdentifier(Symbol::intern(name)))
+        );
+    }
+    assert_eq!(tokens[0].span.start.offset, 0);
+    assert_eq!(tokens[0].span.end.offset, 7);
+
+    // Plain identifiers are not raw
+    assert!(!lex_all("r")[0].is_raw_identifier());
+    assert_eq!(keyword_kind("match"), Some(TokenKind::Match));
+    assert_eq!(keyword_kind("r#match"), None);
+}
+
+#[test]
+fn test_raw_identifiers_do_not_shadow_raw_strings() {
+    let tokens = lex_all(r##"r#"text"# r"x" r#in"##);
+    assert_eq!(tokens[0].kind, TokenKind::RawStringLiteral);
+    assert_eq!(tokens[1].kind, TokenKind::RawStringLiteral);
+    assert_eq!(tokens[2].kind, TokenKind::Identifier);
+    assert_eq!(
+        tokens[2].literal,
+        Some(LiteralValue::RawIdentifier(Symbol::intern("in")))
+    );
+
+    let tokens = lex_all("r#_");
+    assert_eq!(tokens[0].kind, TokenKind::Error);
+}
+
+#[test]
+fn test_extern_static_where_keywords() {
+    let tokens = lex_all("extern static where externs");
+    let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Extern,
+            TokenKind::Static,
+            TokenKind::Where,
+            TokenKind::Identifier,
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_reserved_keywords() {
+    for word in RESERVED_KEYWORDS {
+        let tokens = lex_all(word);
+        assert_eq!(tokens.len(), 2, "Failed for {}", word);
+        assert_eq!(tokens[0].kind, TokenKind::Error, "Failed for {}", word);
+        assert_eq!(tokens[0].lexeme, *word);
+        assert!(
+            matches!(&tokens[0].literal, Some(LiteralValue::String(msg)) if msg.contains("reserved for future use") && msg.contains(&format!("`{}`", word))),
+            "Unexpected diagnostic for {}: {:?}",
+            word,
+            tokens[0].literal
+        );
+    }
+
+    // The raw form is the escape hatch, and prefixes are ordinary identifiers
+    let tokens = lex_all("r#loop looping selfish");
+    assert_eq!(
+        tokens[0].literal,
+        Some(LiteralValue::RawIdentifier(Symbol::intern("loop")))
+    );
+    assert_eq!(tokens[1].kind, TokenKind::Identifier);
+    assert_eq!(tokens[2].kind, TokenKind::Identifier);
+    assert!(is_reserved_keyword("trait"));
+    assert!(!is_reserved_keyword("static"));
+}
+
+#[test]
+fn test_lifetimes() {
+    let tokens = lex_all("<'a, 'static> 'b' 'ab'");
+    let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(
+        &kinds[..7],
+        &[
+            TokenKind::Less,
+            TokenKind::Apostrophe,
+            TokenKind::Identifier,
+            TokenKind::Comma,
+            TokenKind::Apostrophe,
+            TokenKind::Identifier,
+            TokenKind::Greater,
+        ]
+    );
+    assert_eq!(tokens[1].span.start.offset, 1);
+    assert_eq!(tokens[2].lexeme, "a");
+    assert_eq!(tokens[2].span.start.offset, 2);
+    // Lifetime names are never keywords
+    assert_eq!(tokens[5].lexeme, "static");
+
+    // A closed quote is still a char literal, and a multi-character one is still an error
+    assert_eq!(tokens[7].kind, TokenKind::CharacterLiteral);
+    assert_eq!(tokens[7].literal, Some(LiteralValue::Char('b')));
+    assert_eq!(tokens[8].kind, TokenKind::Error);
+}
@@ -0,0 +1,176 @@
+use ferra_lexer::*;
+
+// Helper function to lex the entire input string
+fn lex_errors(input: &str) -> (Vec<Token>, Vec<LexError>) {
+    Lexer::new(input).lex_with_errors()
+}
+
+/// One source per lexer error path, with the kind it must report.
+const ERROR_PATHS: &[(&str, LexErrorKind)] = &[
+    // Unrecognized input
+    ("`", LexErrorKind::InvalidCharacter),
+    ("let x = \u{1F600};", LexErrorKind::InvalidCharacter),
+    ("b\"caf\u{e9}\"", LexErrorKind::InvalidCharacter),
+    // Strings
+    ("\"abc", LexErrorKind::UnterminatedString),
+    ("\"abc\\", LexErrorKind::UnterminatedString),
+    ("r#\"abc\"", LexErrorKind::UnterminatedString),
+    ("\"\"\"abc", LexErrorKind::UnterminatedString),
+    ("b\"abc", LexErrorKind::UnterminatedString),
+    ("f\"abc", LexErrorKind::UnterminatedString),
+    ("f\"abc\\", LexErrorKind::UnterminatedString),
+    // Comments and indentation
+    (
+        "/* open /* nested */",
+        LexErrorKind::UnterminatedBlockComment,
+    ),
+    ("if x:\n \tpass", LexErrorKind::MixedIndentation),
+    ("a\n    b\n  c", LexErrorKind::UnexpectedDedent),
+    // Escapes
+    ("\"\\q\"", LexErrorKind::InvalidEscape),
+    ("'\\q'", LexErrorKind::InvalidEscape),
+    ("f\"\\q\"", LexErrorKind::InvalidEscape),
+    ("\"\\u41\"", LexErrorKind::InvalidEscape),
+    ("\"\\u{1234567}\"", LexErrorKind::InvalidEscape),
+    ("\"\\u{4G}\"", LexErrorKind::InvalidEscape),
+    ("\"\\u{41\"", LexErrorKind::InvalidEscape),
+    ("\"\\u{}\"", LexErrorKind::InvalidEscape),
+    ("\"\\u{D800}\"", LexErrorKind::InvalidEscape),
+    // Character literals
+    ("''", LexErrorKind::EmptyCharLiteral),
+    ("'ab'", LexErrorKind::MultiCharLiteral),
+    ("'\\u{41}b'", LexErrorKind::MultiCharLiteral),
+    ("b'ab'", LexErrorKind::MultiCharLiteral),
+    ("'", LexErrorKind::UnterminatedCharLiteral),
+    ("'1", LexErrorKind::UnterminatedCharLiteral),
+    ("'1\n", LexErrorKind::UnterminatedCharLiteral),
+    ("'\n'", LexErrorKind::UnterminatedCharLiteral),
+    ("'\\", LexErrorKind::UnterminatedCharLiteral),
+    ("b'a", LexErrorKind::UnterminatedCharLiteral),
+    // Numbers
+    ("0x", LexErrorKind::InvalidNumber),
+    ("1_", LexErrorKind::InvalidNumber),
+    ("0o", LexErrorKind::InvalidNumber),
+    ("1u7", LexErrorKind::InvalidNumber),
+    ("1.5i32", LexErrorKind::InvalidNumber),
+    ("256u8", LexErrorKind::NumberOutOfRange),
+    ("1e39f32", LexErrorKind::NumberOutOfRange),
+    // Names
+    ("loop", LexErrorKind::ReservedKeyword),
+    ("r#_", LexErrorKind::InvalidRawIdentifier),
+    ("r##abc", LexErrorKind::MalformedRawString),
+    // Interpolation
+    ("f\"a } b\"", LexErrorKind::InvalidInterpolation),
+    ("f\"{ }\"", LexErrorKind::InvalidInterpolation),
+    ("f\"{x\"", LexErrorKind::InvalidInterpolation),
+    // Bidirectional control characters
+    ("let x = 1; // \u{202E} }", LexErrorKind::BidiControl),
+    ("/* \u{2066} */", LexErrorKind::BidiControl),
+    ("\"admin\u{202E}\"", LexErrorKind::BidiControl),
+    ("r\"\u{2067}\"", LexErrorKind::BidiControl),
+    ("f\"{x}\u{202B}\"", LexErrorKind::BidiControl),
+    ("'\u{202D}'", LexErrorKind::BidiControl),
+    ("x \u{2069} y", LexErrorKind::BidiControl),
+];
+
+#[test]
+fn test_every_error_path_reports_its_kind() {
+    for (source, kind) in ERROR_PATHS {
+        let (_, errors) = lex_errors(source);
+        assert!(
+            errors.iter().any(|error| error.kind == *kind),
+            "{source:?} should report {kind:?} ({}), got {errors:?}",
+            kind.code()
+        );
+    }
+}
+
+#[test]
+fn test_errors_match_their_placeholder_tokens() {
+    for (source, _) in ERROR_PATHS {
+        let (tokens, errors) = lex_errors(source);
+        let placeholders: Vec<&Token> = tokens
+            .iter()
+            .filter(|token| token.kind == TokenKind::Error)
+            .collect();
+        assert_eq!(
+            placeholders.len(),
+            errors.len(),
+            "{source:?}: one placeholder token per error"
+        );
+        for (token, error) in placeholders.iter().zip(&errors) {
+            assert_eq!(token.span, error.span, "{source:?}: span mismatch");
+            assert_eq!(
+                token.literal,
+                Some(LiteralValue::String(error.message.clone())),
+                "{source:?}: message mismatch"
+            );
+            assert_eq!(error.code(), error.kind.code());
+            assert!(error.help.is_some(), "{source:?}: missing help text");
+        }
+        // Lexing keeps going past the placeholder.
+        assert_eq!(tokens.last().unwrap().kind, TokenKind::Eof);
+    }
+}
+
+#[test]
+fn test_lex_keeps_placeholders_without_errors() {
+    let tokens = Lexer::new("let x = ` + 1").lex();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|token| token.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Let,
+            TokenKind::Identifier,
+            TokenKind::Equal,
+            TokenKind::Error,
+            TokenKind::Plus,
+            TokenKind::IntegerLiteral,
+            TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_valid_source_has_no_errors() {
+    let (_, errors) = lex_errors("let x = f\"{a + 1}\" + r#match + 255u8;");
+    assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+}
+
+#[test]
+fn test_error_codes_are_stable_and_unique() {
+    let codes: Vec<&str> = LexErrorKind::ALL.iter().map(|kind| kind.code()).collect();
+    let expected: Vec<String> = (1..=LexErrorKind::ALL.len())
+        .map(|n| format!("E{n:03}"))
+        .collect();
+    assert_eq!(codes, expected);
+    for kind in LexErrorKind::ALL {
+        assert!(!kind.description().is_empty());
+        assert!(!kind.help().is_empty());
+    }
+}
+
+#[test]
+fn test_error_display() {
+    let (_, errors) = lex_errors("let s = \"open");
+    assert_eq!(errors.len(), 1);
+    let error = &errors[0];
+    assert_eq!(error.kind, LexErrorKind::UnterminatedString);
+    assert_eq!(error.span.start.line, 1);
+    assert_eq!(error.span.start.column, 9);
+    assert_eq!(
+        error.to_string(),
+        format!("error[E002]: {} at 1:9", error.message)
+    );
+}
+
+#[test]
+fn test_interpolation_error_points_into_enclosing_source() {
+    let source = "f\"a {`} b\"";
+    let (_, errors) = lex_errors(source);
+    let error = errors
+        .iter()
+        .find(|error| error.kind == LexErrorKind::InvalidCharacter)
+        .expect("invalid character inside the interpolation");
+    assert_eq!(&source[error.span.start.offset..error.span.end.offset], "`");
+}
@@ -51,6 +51,6 @@ fn multi_char_operators() {
         TokenKind::Identifier,
         TokenKind::Eof,
     ];
-    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind.clone()).collect();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
     assert_eq!(kinds, expected);
 }
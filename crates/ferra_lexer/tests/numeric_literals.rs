@@ -173,3 +173,133 @@ fn test_leading_zero_decimal() {
     assert_eq!(tokens_float[0].kind, TokenKind::FloatLiteral);
     assert_eq!(tokens_float[0].literal, Some(LiteralValue::Float(0.5)));
 }
+
+#[test]
+fn test_wide_integer_literals() {
+    let tokens = lex_all("9223372036854775807 9223372036854775808 0xFFFF_FFFF_FFFF_FFFF 0b1");
+    assert_eq!(tokens[0].literal, Some(LiteralValue::Integer(i64::MAX)));
+
+    assert_eq!(tokens[1].kind, TokenKind::IntegerLiteral);
+    let Some(LiteralValue::WideInteger(decimal)) = &tokens[1].literal else {
+        panic!("Expected wide integer, got {:?}", tokens[1].literal);
+    };
+    assert_eq!(decimal.radix, 10);
+    assert_eq!(decimal.to_u128(), Some(i64::MAX as u128 + 1));
+
+    let Some(LiteralValue::WideInteger(hex)) = &tokens[2].literal else {
+        panic!("Expected wide integer, got {:?}", tokens[2].literal);
+    };
+    assert_eq!(hex.digits, "ffffffffffffffff");
+    assert_eq!(hex.radix, 16);
+    assert_eq!(hex.to_u128(), Some(u64::MAX as u128));
+    assert_eq!(tokens[3].literal, Some(LiteralValue::Integer(1)));
+}
+
+#[test]
+fn test_numeric_suffixes() {
+    let tokens = lex_all("255u8 1_000i64 1_000_i64 0xFFu16 1.5f32 2f64 1e3f32 0b1010_u8");
+    let expected = [
+        LiteralValue::TypedInteger {
+            value: 255,
+            suffix: NumericSuffix::U8,
+        },
+        LiteralValue::TypedInteger {
+            value: 1000,
+            suffix: NumericSuffix::I64,
+        },
+        LiteralValue::TypedInteger {
+            value: 1000,
+            suffix: NumericSuffix::I64,
+        },
+        LiteralValue::TypedInteger {
+            value: 255,
+            suffix: NumericSuffix::U16,
+        },
+        LiteralValue::TypedFloat {
+            value: 1.5,
+            suffix: NumericSuffix::F32,
+        },
+        LiteralValue::TypedFloat {
+            value: 2.0,
+            suffix: NumericSuffix::F64,
+        },
+        LiteralValue::TypedFloat {
+            value: 1000.0,
+            suffix: NumericSuffix::F32,
+        },
+        LiteralValue::TypedInteger {
+            value: 10,
+            suffix: NumericSuffix::U8,
+        },
+    ];
+    for (token, expected) in tokens.iter().zip(expected) {
+        assert_eq!(token.literal, Some(expected), "Lexeme: {}", token.lexeme);
+    }
+    assert_eq!(tokens[0].kind, TokenKind::IntegerLiteral);
+    assert_eq!(tokens[0].lexeme, "255u8");
+    assert_eq!(tokens[4].kind, TokenKind::FloatLiteral);
+}
+
+#[test]
+fn test_numeric_suffix_diagnostics() {
+    let cases = [
+        ("256u8", "Literal out of range for 'u8'"),
+        ("129i8", "does not fit in -128..=127"),
+        (
+            "340282366920938463463374607431768211456u128",
+            "out of range",
+        ),
+        ("1e39f32", "Literal out of range for 'f32'"),
+        ("1e400", "Literal out of range for 'f64'"),
+        ("1.5e400", "exceeds the largest f64 value"),
+        ("1e400f64", "Literal out of range for 'f64'"),
+        ("1.5i32", "Invalid suffix 'i32' for float literal"),
+        ("0o7u3", "Invalid suffix 'u3'"),
+        ("0b1f32", "cannot be used with a base 2 literal"),
+        ("7abc", "Invalid suffix 'abc'"),
+    ];
+    for (source, expected) in cases {
+        let tokens = lex_all(source);
+        assert_eq!(tokens[0].kind, TokenKind::Error, "{}", source);
+        assert_eq!(tokens[0].lexeme, source);
+        match &tokens[0].literal {
+            Some(LiteralValue::String(message)) => {
+                assert!(message.contains(expected), "{}: {}", source, message)
+            }
+            other => panic!("Expected diagnostic for {}, got {:?}", source, other),
+        }
+    }
+
+    // Float overflow is reported like integer overflow rather than becoming infinity
+    let (_, errors) = Lexer::new("1e400").lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code(), "E011");
+
+    // The minimum's magnitude is accepted here; only negation puts it in range
+    assert_eq!(
+        lex_all("128i8")[0].literal,
+        Some(LiteralValue::TypedInteger {
+            value: 128,
+            suffix: NumericSuffix::I8
+        })
+    );
+}
+
+#[test]
+fn test_integer_before_range_operator() {
+    for (input, op) in [
+        ("1..9", TokenKind::DotDot),
+        ("1..=9", TokenKind::DotDotEqual),
+    ] {
+        let tokens = lex_all(input);
+        assert_eq!(
+            tokens[0].kind,
+            TokenKind::IntegerLiteral,
+            "Failed for {}",
+            input
+        );
+        assert_eq!(tokens[0].literal, Some(LiteralValue::Integer(1)));
+        assert_eq!(tokens[1].kind, op, "Failed for {}", input);
+        assert_eq!(tokens[2].literal, Some(LiteralValue::Integer(9)));
+    }
+}
@@ -18,6 +18,8 @@ fn test_single_char_operators() {
         (")", TokenKind::RParen),
         ("{", TokenKind::LBrace),
         ("}", TokenKind::RBrace),
+        ("[", TokenKind::LBracket),
+        ("]", TokenKind::RBracket),
         (",", TokenKind::Comma),
         (":", TokenKind::Colon),
         // Add other single char ops as needed from TokenKind
@@ -31,6 +33,9 @@ fn test_single_char_operators() {
         (".", TokenKind::Dot),
         ("_", TokenKind::Underscore),
         ("%", TokenKind::Percent),
+        ("$", TokenKind::Dollar),
+        ("#", TokenKind::Hash),
+        ("@", TokenKind::At),
     ];
 
     for (op_str, kind) in ops {
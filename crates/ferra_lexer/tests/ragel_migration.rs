@@ -1,4 +1,4 @@
-use ferra_lexer::{Lexer, TokenKind};
+use ferra_lexer::{Lexer, LexerBackend, LexerConfig, TokenKind};
 
 /// Test suite to ensure compatibility when migrating to Ragel DFA lexer
 /// These tests verify that the current hand-written lexer produces the expected
@@ -159,3 +159,147 @@ fn test_ragel_compatibility_comments() {
     let actual_kinds: Vec<_> = tokens.iter().map(|t| &t.kind).collect();
     assert_eq!(actual_kinds, expected_kinds.iter().collect::<Vec<_>>());
 }
+
+/// Lex `src` with both backends and require identical output.
+fn assert_backends_agree(src: &str) {
+    let hand_written = Lexer::new(src).lex_with_diagnostics();
+    let config = LexerConfig::new().with_backend(LexerBackend::Dfa);
+    let dfa = Lexer::with_config(src, config).lex_with_diagnostics();
+    assert_eq!(dfa, hand_written, "backends differ on {src:?}");
+}
+
+#[test]
+fn test_dfa_backend_matches_on_compatibility_sources() {
+    let sources = [
+        "let x = 42 + 3.14;",
+        "x += y << z >> w && a || b ?? c",
+        "let αβγ = δεζ + ηθι;",
+        "a\n    b\n        c\n    d\ne",
+        r#""hello\nworld" r"raw\string" 'c'"#,
+        "42 0x1A 0o77 0b1010 3.14 1e10 1_000_000",
+        "let x = 5; // line comment\nlet y = /* block */ 10;",
+    ];
+    for src in sources {
+        assert_backends_agree(src);
+    }
+}
+
+#[test]
+fn test_dfa_backend_boundaries() {
+    let sources = [
+        // Identifiers continued by non-ASCII characters and prefixes
+        "abcé _é _ _x x_ r#match r#\"raw\"# b'a' b\"s\" f\"{x}\" br bx fx rx",
+        // Numbers next to ranges, suffixes, exponents and fields
+        "0..5 1..=2 7. 1.foo t.0 .5 255u8 1_000 1e3 99999999999999999999 0x 00 0",
+        // Operators sharing prefixes
+        "<<= << <= < >>= >> >= > -> - -= => == = .. ..= . :: : ?? ? || |= | && &= & ^= ^ %= % /= / *= *",
+        // Whitespace, tabs, CRLF, lone CR and indentation
+        "if x:\r\n\ty = 1\r\n\tz\t=\t2 \r w\n",
+        // Keywords, reserved words, booleans and lifetimes
+        "fn let var true false and or 'a 'static 'c' where",
+        // Errors and comments
+        "` ~ \\ /* unterminated",
+        "",
+    ];
+    for src in sources {
+        assert_backends_agree(src);
+    }
+}
+
+/// String literals in a Rust source file, with escapes processed.
+fn rust_string_literals(source: &str) -> Vec<String> {
+    let bytes = source.as_bytes();
+    let mut literals = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &source[i..];
+        let prev_is_ident = i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_');
+        if rest.starts_with("//") {
+            i += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(after) = rest.strip_prefix('\'') {
+            // Char literal, or the start of a lifetime
+            let len = match after.as_bytes().first() {
+                Some(b'\\') => after[1..].find('\'').map_or(1, |end| end + 3),
+                _ => match after.chars().next() {
+                    Some(c) if after[c.len_utf8()..].starts_with('\'') => c.len_utf8() + 2,
+                    _ => 1,
+                },
+            };
+            i += len;
+        } else if !prev_is_ident && (rest.starts_with("r\"") || rest.starts_with("r#")) {
+            let hashes = rest[1..].len() - rest[1..].trim_start_matches('#').len();
+            let body = &rest[1 + hashes..];
+            if !body.starts_with('"') {
+                i += 1;
+                continue;
+            }
+            let close = format!("\"{}", "#".repeat(hashes));
+            let end = body[1..].find(&close).expect("unterminated raw string") + 1;
+            literals.push(body[1..end].to_string());
+            i += 1 + hashes + end + close.len();
+        } else if let Some(after) = rest.strip_prefix('"') {
+            let mut text = String::new();
+            let mut chars = after.char_indices();
+            while let Some((offset, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        i += offset + 2;
+                        break;
+                    }
+                    '\\' => match chars.next().map(|(_, c)| c) {
+                        Some('n') => text.push('\n'),
+                        Some('r') => text.push('\r'),
+                        Some('t') => text.push('\t'),
+                        Some('0') => text.push('\0'),
+                        Some('u') => {
+                            let hex: String = chars
+                                .by_ref()
+                                .map(|(_, c)| c)
+                                .skip(1)
+                                .take_while(|&c| c != '}')
+                                .collect();
+                            text.extend(char::from_u32(u32::from_str_radix(&hex, 16).unwrap()));
+                        }
+                        Some('\n') => {
+                            // Line continuation skips the next line's indentation
+                            while chars.as_str().starts_with(char::is_whitespace) {
+                                chars.next();
+                            }
+                        }
+                        Some(c) => text.push(c),
+                        None => {}
+                    },
+                    c => text.push(c),
+                }
+            }
+            literals.push(text);
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    literals
+}
+
+#[test]
+fn test_dfa_backend_matches_on_whole_test_suite() {
+    let manifest_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let test_dirs = [
+        manifest_dir.join("tests"),
+        manifest_dir.join("../ferra_parser/tests"),
+    ];
+    let mut checked = 0;
+    for dir in test_dirs {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|extension| extension != "rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            for literal in rust_string_literals(&source) {
+                assert_backends_agree(&literal);
+                checked += 1;
+            }
+        }
+    }
+    assert!(checked > 1000, "only {checked} test inputs found");
+}
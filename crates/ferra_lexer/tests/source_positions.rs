@@ -0,0 +1,143 @@
+use ferra_lexer::*;
+
+/// Every token's span must slice its lexeme out of the original source.
+fn assert_spans_index_source(source: &str, tokens: &[Token]) {
+    for token in tokens {
+        let (start, end) = (token.span.start.offset, token.span.end.offset);
+        assert_eq!(
+            source.get(start..end),
+            Some(token.lexeme.as_str()),
+            "{:?} token at {}..{} in {source:?}",
+            token.kind,
+            start,
+            end
+        );
+    }
+}
+
+const SOURCES: &[&str] = &[
+    "let x = 42;\nlet y = x + 1;\n",
+    "let x = 42;\r\nlet y = x + 1;\r\n",
+    "\u{FEFF}let x = 1;",
+    "#!/usr/bin/env ferra\nlet x = 1;",
+    "#!/usr/bin/env ferra\r\nlet x = 1;\r\n",
+    "\u{FEFF}#!/usr/bin/env ferra\nfn main() {}",
+    "#![allow(dead_code)]\nfn main() {}",
+    "if x:\r\n    y = 1 // comment\r\n    z = 2\r\nw\r\n",
+    "if x:\n\ty = 1\n\t\tz = 2\nw",
+    "let s = \"héllo\" /* block\r\n comment */ + 'é' + b'a' + b\"bytes\";",
+    "let r = r#\"raw \"quoted\"\"#;\nlet m = \"\"\"\n    multi\n    line\n    \"\"\";",
+    "let f = f\"{name}: {value + 1}\"; let n = 0xFFu8 + 1_000i64 + 2.5e3;",
+    "fn r#match<'a>(x: &'a Int) -> Int where T: Copy { @ # $ }",
+    "let bad = ` + \"unterminated\r\nnext",
+    "let big = 300u8; let e = '';\r\nloop",
+];
+
+#[test]
+fn test_spans_index_original_source() {
+    for source in SOURCES {
+        let tokens = Lexer::new(source).lex();
+        assert_spans_index_source(source, &tokens);
+
+        let config = LexerConfig::new().with_shebang_token(true);
+        let tokens = Lexer::with_config(source, config).lex();
+        assert_spans_index_source(source, &tokens);
+    }
+}
+
+#[test]
+fn test_crlf_is_one_newline() {
+    let source = "a\r\nb\r\n\r\nc";
+    let tokens = Lexer::new(source).lex();
+    let newlines: Vec<&Token> = tokens
+        .iter()
+        .filter(|t| t.kind == TokenKind::Newline)
+        .collect();
+    assert_eq!(newlines.len(), 3);
+    assert!(newlines.iter().all(|t| t.lexeme == "\r\n"));
+    assert_eq!(newlines[0].span.start.offset, 1);
+    assert_eq!(newlines[0].span.end.offset, 3);
+
+    let c = tokens.iter().find(|t| t.lexeme == "c").unwrap();
+    assert_eq!(c.span.start.line, 4);
+    assert_eq!(c.span.start.column, 1);
+    assert_eq!(c.span.start.offset, 8);
+}
+
+#[test]
+fn test_crlf_matches_lf_token_kinds() {
+    let lf = "fn main() {\n    let x = 1 // note\n    if x:\n        y\n}\n";
+    let crlf = lf.replace('\n', "\r\n");
+    let kinds = |source: &str| -> Vec<TokenKind> {
+        Lexer::new(source)
+            .lex()
+            .into_iter()
+            .map(|t| t.kind)
+            .collect()
+    };
+    assert_eq!(kinds(lf), kinds(&crlf));
+}
+
+#[test]
+fn test_lone_carriage_return_is_whitespace() {
+    let tokens = Lexer::new("a\rb").lex();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![TokenKind::Identifier, TokenKind::Identifier, TokenKind::Eof]
+    );
+}
+
+#[test]
+fn test_byte_order_mark_is_skipped() {
+    let source = "\u{FEFF}let x = 1;";
+    let tokens = Lexer::new(source).lex();
+    assert_eq!(tokens[0].kind, TokenKind::Let);
+    assert_eq!(tokens[0].span.start.offset, 3);
+    assert_eq!(tokens[0].span.start.column, 1);
+    assert!(!tokens.iter().any(|t| t.kind == TokenKind::Error));
+}
+
+#[test]
+fn test_shebang_keeps_offsets_and_lines() {
+    let source = "#!/usr/bin/env ferra\nlet x = 42;";
+    let tokens = Lexer::new(source).lex();
+    assert_eq!(tokens[0].kind, TokenKind::Let);
+    assert_eq!(tokens[0].span.start.line, 2);
+    assert_eq!(tokens[0].span.start.offset, 21);
+}
+
+#[test]
+fn test_shebang_token() {
+    let source = "\u{FEFF}#!/usr/bin/env ferra\r\nlet x = 42;";
+    let config = LexerConfig::new().with_shebang_token(true);
+    let tokens = Lexer::with_config(source, config).lex();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(
+        kinds[..3],
+        [TokenKind::Shebang, TokenKind::Newline, TokenKind::Let]
+    );
+    assert_eq!(tokens[0].lexeme, "#!/usr/bin/env ferra");
+    assert_eq!(tokens[0].span.start.offset, 3);
+    assert_eq!(tokens[0].span.end.column, 21);
+}
+
+#[test]
+fn test_shebang_only_file() {
+    let config = LexerConfig::new().with_shebang_token(true);
+    let tokens = Lexer::with_config("#!/bin/ferra", config).lex();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind).collect();
+    assert_eq!(kinds, vec![TokenKind::Shebang, TokenKind::Eof]);
+
+    let tokens = Lexer::new("#!/bin/ferra").lex();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].span.start.offset, 12);
+}
+
+#[test]
+fn test_inner_attribute_is_not_a_shebang() {
+    let config = LexerConfig::new().with_shebang_token(true);
+    let tokens = Lexer::with_config("#![allow(dead_code)]", config).lex();
+    assert_eq!(tokens[0].kind, TokenKind::Hash);
+    assert_eq!(tokens[1].kind, TokenKind::Bang);
+}
@@ -0,0 +1,104 @@
+use ferra_lexer::*;
+use std::collections::HashSet;
+use std::thread;
+
+#[test]
+fn test_interning_is_idempotent() {
+    let a = Symbol::intern("interned_name");
+    let b = Symbol::intern(&String::from("interned_name"));
+    assert_eq!(a, b);
+    assert_eq!(a.as_u32(), b.as_u32());
+    assert_eq!(a.as_str(), "interned_name");
+    assert_ne!(a, Symbol::intern("other_name"));
+}
+
+#[test]
+fn test_symbol_compares_with_strings() {
+    let symbol: Symbol = "main".into();
+    assert_eq!(symbol, "main");
+    assert_eq!("main", symbol);
+    assert_eq!(symbol, String::from("main"));
+    assert_eq!(String::from(symbol), "main");
+    assert!(symbol.starts_with("ma"));
+    assert_eq!(symbol.len(), 4);
+}
+
+#[test]
+fn test_symbol_formatting_matches_string() {
+    let symbol = Symbol::intern("caf\u{e9}");
+    assert_eq!(symbol.to_string(), "caf\u{e9}");
+    assert_eq!(format!("{symbol:?}"), format!("{:?}", "caf\u{e9}"));
+}
+
+#[test]
+fn test_symbols_order_alphabetically() {
+    // Intern in reverse order so the handles are not alphabetical
+    let mut names: Vec<Symbol> = ["zeta_order", "mu_order", "alpha_order"]
+        .into_iter()
+        .map(Symbol::intern)
+        .collect();
+    names.sort();
+    assert_eq!(names, ["alpha_order", "mu_order", "zeta_order"]);
+}
+
+#[test]
+fn test_interning_across_threads() {
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            thread::spawn(|| {
+                (0..200)
+                    .map(|i| Symbol::intern(&format!("thread_name_{i}")))
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+    let results: Vec<Vec<Symbol>> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    for result in &results[1..] {
+        assert_eq!(result, &results[0]);
+    }
+    let distinct: HashSet<Symbol> = results[0].iter().copied().collect();
+    assert_eq!(distinct.len(), 200);
+    assert_eq!(results[0][7], "thread_name_7");
+}
+
+#[test]
+fn test_strings_stay_readable_as_storage_grows() {
+    // Enough names to fill several storage chunks
+    let symbols: Vec<Symbol> = (0..5000)
+        .map(|i| Symbol::intern(&format!("growth_name_{i}")))
+        .collect();
+    let reader = thread::spawn(move || {
+        symbols
+            .iter()
+            .enumerate()
+            .all(|(i, symbol)| *symbol == format!("growth_name_{i}"))
+    });
+    assert!(reader.join().unwrap());
+    assert!(Symbol::interned_count() >= 5000);
+}
+
+#[test]
+fn test_lexer_interns_identifiers() {
+    let tokens = Lexer::new("let total = total + r#match;").lex();
+    let symbols: Vec<Symbol> = tokens.iter().filter_map(Token::symbol).collect();
+    assert_eq!(symbols, ["total", "total", "match"]);
+    assert_eq!(symbols[0], symbols[1]);
+
+    // Keywords and literals carry no symbol
+    assert_eq!(tokens[0].symbol(), None);
+    assert_eq!(tokens[2].symbol(), None);
+}
+
+#[test]
+fn test_symbols_are_shared_between_lexers() {
+    let first = Lexer::new("fn shared_name() {}").lex();
+    let second = Lexer::new("shared_name()").lex();
+    assert_eq!(first[1].symbol(), second[0].symbol());
+}
+
+#[test]
+fn test_identifier_symbols_are_normalized() {
+    let tokens = Lexer::new("cafe\u{301}").lex();
+    assert_eq!(tokens[0].symbol(), Some(Symbol::intern("caf\u{e9}")));
+}
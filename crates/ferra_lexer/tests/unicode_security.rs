@@ -0,0 +1,219 @@
+use ferra_lexer::*;
+
+fn warnings(source: &str) -> Vec<LexWarning> {
+    let (_, errors, warnings) = Lexer::new(source).lex_with_diagnostics();
+    assert!(
+        errors.is_empty(),
+        "unexpected errors in {source:?}: {errors:?}"
+    );
+    warnings
+}
+
+fn warning_kinds(source: &str) -> Vec<LexWarningKind> {
+    warnings(source).into_iter().map(|w| w.kind).collect()
+}
+
+#[test]
+fn test_trojan_source_comment_is_rejected() {
+    // The override makes `if is_admin` render as part of the comment.
+    let source = "let is_admin = false; /*\u{202E} } \u{2066}if is_admin\u{2069} \u{2066} begin admins only */";
+    let (tokens, errors) = Lexer::new(source).lex_with_errors();
+    let codes: Vec<&str> = errors.iter().map(|e| e.code()).collect();
+    assert_eq!(codes, vec!["E018"; 4]);
+    assert!(errors[0]
+        .message
+        .contains("U+202E (RIGHT-TO-LEFT OVERRIDE)"));
+    assert!(errors[0].message.contains("in a comment"));
+    assert_eq!(
+        errors[0].span.start.offset,
+        source.find('\u{202E}').unwrap()
+    );
+
+    // The code around the comment still lexes.
+    let kinds: Vec<&TokenKind> = tokens
+        .iter()
+        .filter(|t| t.kind != TokenKind::Error)
+        .map(|t| &t.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &TokenKind::Let,
+            &TokenKind::Identifier,
+            &TokenKind::Equal,
+            &TokenKind::False,
+            &TokenKind::Semicolon,
+            &TokenKind::Eof,
+        ]
+    );
+}
+
+#[test]
+fn test_bidi_in_string_rejects_the_literal() {
+    let source = "let access = \"user\u{202E} \u{2066}// Check if admin\u{2069} \u{2066}\";";
+    let (tokens, errors) = Lexer::new(source).lex_with_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::BidiControl);
+    assert!(errors[0].message.contains("in a literal"));
+
+    let error = tokens.iter().find(|t| t.kind == TokenKind::Error).unwrap();
+    assert_eq!(error.lexeme, source[13..source.len() - 1]);
+    assert_eq!(tokens[tokens.len() - 2].kind, TokenKind::Semicolon);
+}
+
+#[test]
+fn test_escaped_bidi_is_allowed() {
+    let tokens = Lexer::new(r#"let s = "\u{202E}";"#).lex();
+    assert_eq!(tokens[3].kind, TokenKind::StringLiteral);
+    assert_eq!(
+        tokens[3].literal,
+        Some(LiteralValue::String("\u{202E}".to_string()))
+    );
+}
+
+#[test]
+fn test_other_right_to_left_text_is_allowed() {
+    assert!(warnings("// שלום עולם\nlet s = \"مرحبا\";").is_empty());
+}
+
+#[test]
+fn test_non_nfc_identifier_warns() {
+    // `e` followed by a combining acute accent, rather than `é`
+    let source = "let cafe\u{301} = 1; let caf\u{e9} = 2;";
+    let (tokens, _, warnings) = Lexer::new(source).lex_with_diagnostics();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, LexWarningKind::NonNormalizedIdentifier);
+    assert_eq!(warnings[0].code(), "W001");
+    assert_eq!(
+        warnings[0].message,
+        "Identifier `cafe\\u{301}` is not in Unicode normalization form C; it is read as `caf\u{e9}`."
+    );
+    assert_eq!(warnings[0].span, tokens[1].span);
+
+    // Both spellings name the same identifier.
+    assert_eq!(tokens[1].lexeme, tokens[6].lexeme);
+}
+
+#[test]
+fn test_mixed_script_identifier_warns() {
+    // Cyrillic `а` in an otherwise Latin name
+    let warnings = warnings("let p\u{430}ypal = 1;");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, LexWarningKind::MixedScriptIdentifier);
+    assert_eq!(warnings[0].code(), "W003");
+    assert_eq!(
+        warnings[0].message,
+        "Identifier `p\u{430}ypal` mixes Latin and Cyrillic characters."
+    );
+    assert_eq!(warnings[0].span.start.column, 5);
+}
+
+#[test]
+fn test_single_script_identifiers_do_not_warn() {
+    let sources = [
+        "let größe = 1;",
+        "let имя = 1;",
+        "let λ = 1;",
+        "let 名前 = 1;",
+        "let 변수_1 = 1;",
+        "let α2 = 1;",
+    ];
+    for source in sources {
+        assert!(warning_kinds(source).is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn test_east_asian_script_combinations_do_not_warn() {
+    let sources = [
+        "let ひらがなカタカナ漢字 = 1;",
+        "let データ = 1;",
+        "let user名前 = 1;",
+        "let 한국어漢字 = 1;",
+    ];
+    for source in sources {
+        assert!(warning_kinds(source).is_empty(), "{source}");
+    }
+}
+
+#[test]
+fn test_confusable_identifiers_warn() {
+    // Cyrillic `а`, `р` and `у` look like their Latin counterparts
+    let source = "let pay = 1;\nlet \u{440}\u{430}\u{443} = 2;";
+    let warnings = warnings(source);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, LexWarningKind::ConfusableIdentifier);
+    assert_eq!(warnings[0].code(), "W002");
+    assert_eq!(
+        warnings[0].message,
+        "Identifier `\u{440}\u{430}\u{443}` looks like `pay` from 1:5."
+    );
+    assert_eq!(warnings[0].span.start.line, 2);
+}
+
+#[test]
+fn test_mixed_confusable_identifier_reports_both() {
+    let kinds = warning_kinds("let scope = 1; let sc\u{43E}pe = 2;");
+    assert_eq!(
+        kinds,
+        vec![
+            LexWarningKind::MixedScriptIdentifier,
+            LexWarningKind::ConfusableIdentifier,
+        ]
+    );
+}
+
+#[test]
+fn test_each_spelling_warns_once() {
+    let kinds = warning_kinds("let p\u{430}y = 1; p\u{430}y + p\u{430}y");
+    assert_eq!(kinds, vec![LexWarningKind::MixedScriptIdentifier]);
+}
+
+#[test]
+fn test_raw_identifiers_lifetimes_and_interpolations_are_checked() {
+    assert_eq!(
+        warning_kinds("let r#\u{430}b = 1;"),
+        vec![LexWarningKind::MixedScriptIdentifier]
+    );
+    assert_eq!(
+        warning_kinds("fn f<'\u{430}b>() {}"),
+        vec![LexWarningKind::MixedScriptIdentifier]
+    );
+
+    let source = "let pay = 1; let s = f\"{\u{440}\u{430}\u{443}}\";";
+    let warnings = warnings(source);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, LexWarningKind::ConfusableIdentifier);
+    assert_eq!(
+        &source[warnings[0].span.start.offset..warnings[0].span.end.offset],
+        "\u{440}\u{430}\u{443}"
+    );
+}
+
+#[test]
+fn test_ascii_source_has_no_warnings() {
+    let source = "fn main() {\n    let Il1 = 0; let O0 = Il1; let rn = m;\n}\n";
+    assert!(warnings(source).is_empty());
+}
+
+#[test]
+fn test_warning_codes_are_stable_and_unique() {
+    for (i, kind) in LexWarningKind::ALL.iter().enumerate() {
+        assert_eq!(kind.code(), format!("W{:03}", i + 1));
+        assert!(!kind.description().is_empty());
+        assert!(!kind.help().is_empty());
+    }
+}
+
+#[test]
+fn test_warning_display() {
+    let warnings = warnings("\n  let \u{430}x = 1;");
+    assert_eq!(
+        warnings[0].to_string(),
+        "warning[W003]: Identifier `\u{430}x` mixes Latin and Cyrillic characters. at 2:7"
+    );
+    assert_eq!(
+        warnings[0].help.as_deref(),
+        Some("spell the identifier in a single script")
+    );
+}
@@ -41,7 +41,7 @@ fn byte_literals() {
     assert_eq!(tokens[1].lexeme, "b\"foo\"");
     assert_eq!(
         tokens[1].literal,
-        Some(LiteralValue::String("foo".to_string()))
+        Some(LiteralValue::ByteString(b"foo".to_vec()))
     );
     assert_eq!(tokens[2].kind, TokenKind::Error); // empty
     assert_eq!(tokens[3].kind, TokenKind::Error); // multi-char
@@ -49,6 +49,20 @@ fn byte_literals() {
     assert_eq!(tokens[5].kind, TokenKind::Error); // unterminated
 }
 
+#[test]
+fn byte_literal_escapes() {
+    let tokens = Lexer::new(r#"b'\n' b'\x7f' b"a\tb\x00\"" b'\xZZ' b'é'"#).lex();
+    assert_eq!(tokens[0].literal, Some(LiteralValue::Byte(b'\n')));
+    assert_eq!(tokens[1].literal, Some(LiteralValue::Byte(0x7f)));
+    assert_eq!(tokens[2].kind, TokenKind::ByteLiteral);
+    assert_eq!(
+        tokens[2].literal,
+        Some(LiteralValue::ByteString(b"a\tb\x00\"".to_vec()))
+    );
+    assert_eq!(tokens[3].kind, TokenKind::Error); // malformed \x escape
+    assert_eq!(tokens[4].kind, TokenKind::Error); // non-ASCII
+}
+
 #[test]
 fn block_comment() {
     let tokens = Lexer::new("let /* comment */ x").lex();
@@ -105,10 +119,10 @@ fn blank_and_comment_only_lines_indentation() {
         "TOKENS: {:?}",
         tokens
             .iter()
-            .map(|t| (t.kind.clone(), t.lexeme.clone()))
+            .map(|t| (t.kind, t.lexeme.clone()))
             .collect::<Vec<_>>()
     );
-    let kinds: Vec<_> = tokens.iter().map(|t| t.kind.clone()).collect();
+    let kinds: Vec<_> = tokens.iter().map(|t| t.kind).collect();
     // Updated to match the new correct behavior where 'b' is properly preserved
     assert_eq!(
         kinds,
@@ -234,7 +248,7 @@ fn test_simple_dedent_case() {
         "All tokens: {:?}",
         tokens
             .iter()
-            .map(|t| (t.kind.clone(), t.lexeme.clone()))
+            .map(|t| (t.kind, t.lexeme.clone()))
             .collect::<Vec<_>>()
     );
 
@@ -263,7 +277,7 @@ fn test_minimal_indent_case() {
         "Minimal tokens: {:?}",
         tokens
             .iter()
-            .map(|t| (t.kind.clone(), t.lexeme.clone()))
+            .map(|t| (t.kind, t.lexeme.clone()))
             .collect::<Vec<_>>()
     );
 
@@ -1,6 +1,11 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use ferra_lexer::{Lexer, TokenKind};
-use ferra_parser::{ast::Arena, token::VecTokenStream, ProgramParser, TokenType};
+use ferra_lexer::{Lexer, LexerBackend, LexerConfig, TokenKind};
+use ferra_parser::{
+    ast::Arena,
+    token::{Symbol, VecTokenStream},
+    PrattParser, ProgramParser, TokenType,
+};
+use std::collections::HashMap;
 
 /// Convert TokenKind from lexer to TokenType for parser
 fn convert_token_kind(kind: TokenKind) -> TokenType {
@@ -23,11 +28,12 @@ fn convert_token_kind(kind: TokenKind) -> TokenType {
         TokenKind::Continue => TokenType::Continue,
         TokenKind::Pub => TokenType::Pub,
         TokenKind::Unsafe => TokenType::Unsafe,
-        TokenKind::Identifier => TokenType::Identifier("dummy".to_string()),
+        TokenKind::Identifier => TokenType::Identifier("dummy".into()),
         TokenKind::IntegerLiteral => TokenType::IntegerLiteral(42),
         TokenKind::FloatLiteral => TokenType::FloatLiteral(3.15),
         TokenKind::StringLiteral => TokenType::StringLiteral("dummy".to_string()),
-        TokenKind::CharacterLiteral => TokenType::StringLiteral("dummy".to_string()),
+        TokenKind::CharacterLiteral => TokenType::CharLiteral('d'),
+        TokenKind::ByteLiteral => TokenType::ByteLiteral(b'd'),
         TokenKind::BooleanLiteral => TokenType::BooleanLiteral(true),
         TokenKind::Plus => TokenType::Plus,
         TokenKind::Minus => TokenType::Minus,
@@ -62,7 +68,7 @@ fn convert_token_kind(kind: TokenKind) -> TokenType {
         TokenKind::DotDot => TokenType::DotDot,
         TokenKind::DotDotEqual => TokenType::DotDotEqual,
         TokenKind::PathSep => TokenType::DoubleColon,
-        TokenKind::Underscore => TokenType::Identifier("_".to_string()),
+        TokenKind::Underscore => TokenType::Identifier("_".into()),
         TokenKind::Eof => TokenType::Eof,
         _ => TokenType::Eof,
     }
@@ -183,6 +189,70 @@ fn bench_expression_parsing(c: &mut Criterion) {
     group.finish();
 }
 
+/// Tokens for `n` nested groupings around a negated literal: `(-(-(...1)))`
+fn deep_expression_tokens(depth: usize) -> Vec<TokenType> {
+    let mut tokens = Vec::with_capacity(depth * 3 + 2);
+    for _ in 0..depth {
+        tokens.extend([TokenType::LeftParen, TokenType::Minus]);
+    }
+    tokens.push(TokenType::IntegerLiteral(1));
+    tokens.extend(std::iter::repeat_n(TokenType::RightParen, depth));
+    tokens.push(TokenType::Eof);
+    tokens
+}
+
+/// Tokens for a left-associative chain of `n` additions over call arguments:
+/// `f(x0, x1) + f(x1, x2) + ...`
+fn wide_expression_tokens(width: usize) -> Vec<TokenType> {
+    let mut tokens = Vec::with_capacity(width * 7 + 1);
+    for i in 0..width {
+        if i > 0 {
+            tokens.push(TokenType::Plus);
+        }
+        tokens.extend([
+            TokenType::Identifier("f".into()),
+            TokenType::LeftParen,
+            TokenType::Identifier(format!("x{i}").into()),
+            TokenType::Comma,
+            TokenType::IntegerLiteral(i as i64),
+            TokenType::RightParen,
+        ]);
+    }
+    tokens.push(TokenType::Eof);
+    tokens
+}
+
+/// Builds the token stream for one expression shape at a given size
+type ShapeGenerator = fn(usize) -> Vec<TokenType>;
+
+/// Benchmark how expression parsing scales with nesting depth and width
+fn bench_expression_shapes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expression_shapes");
+    group.warm_up_time(std::time::Duration::from_millis(200));
+    group.measurement_time(std::time::Duration::from_secs(1));
+    group.sample_size(20);
+
+    let shapes: [(&str, ShapeGenerator); 2] = [
+        ("deep", deep_expression_tokens),
+        ("wide", wide_expression_tokens),
+    ];
+    for (shape, generate) in shapes {
+        for size in [25, 50, 100] {
+            let tokens = generate(size);
+            group.bench_with_input(BenchmarkId::new(shape, size), &tokens, |b, tokens| {
+                b.iter(|| {
+                    let arena = Arena::new();
+                    let stream = VecTokenStream::from_token_types(tokens.clone());
+                    let mut parser = PrattParser::new(&arena, stream);
+                    let _ = black_box(parser.parse_expression(0).is_ok());
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
 /// Benchmark parsing larger programs to test scalability
 fn bench_large_programs(c: &mut Criterion) {
     let mut group = c.benchmark_group("large_programs");
@@ -659,18 +729,114 @@ fn bench_recovery_strategy_overhead(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark name lookups keyed by interned symbols against owned strings
+fn bench_identifier_interning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("identifier_interning");
+
+    let mut source = String::new();
+    for i in 0..200 {
+        source.push_str(&format!(
+            "fn handler_{i}(request, response) {{ let status_{i} = request + response; }}\n"
+        ));
+    }
+    let symbols: Vec<Symbol> = Lexer::new(&source)
+        .lex()
+        .iter()
+        .filter_map(|token| token.symbol())
+        .collect();
+    let strings: Vec<String> = symbols.iter().map(|name| name.to_string()).collect();
+
+    group.bench_function("string_keys", |b| {
+        b.iter(|| {
+            let mut counts: HashMap<String, usize> = HashMap::new();
+            for name in &strings {
+                *counts.entry(name.clone()).or_default() += 1;
+            }
+            black_box(counts)
+        })
+    });
+
+    group.bench_function("symbol_keys", |b| {
+        b.iter(|| {
+            let mut counts: HashMap<Symbol, usize> = HashMap::new();
+            for name in &symbols {
+                *counts.entry(*name).or_default() += 1;
+            }
+            black_box(counts)
+        })
+    });
+
+    group.bench_function("intern", |b| {
+        b.iter(|| {
+            for name in &strings {
+                black_box(Symbol::intern(name));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmark lexing a large input into owned and borrowed tokens
+fn bench_token_representation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("token_representation");
+
+    let mut source = String::new();
+    for i in 0..500 {
+        source.push_str(&format!(
+            "fn handler_{i}(request, response) {{ let message = \"request {i} handled\"; log(message, response + {i}); }}\n"
+        ));
+    }
+
+    group.bench_function("owned", |b| b.iter(|| black_box(Lexer::new(&source).lex())));
+    group.bench_function("borrowed", |b| {
+        b.iter(|| black_box(Lexer::new(&source).lex_borrowed()))
+    });
+
+    group.finish();
+}
+
+/// Benchmark the hand-written and DFA lexer backends on a large input
+fn bench_lexer_backends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lexer_backends");
+
+    let mut source = String::new();
+    for i in 0..500 {
+        source.push_str(&format!(
+            "fn handler_{i}(request, response) {{\n    let status = request.code + {i} * 2;\n    if status >= 400 && !response.sent {{ log(\"failed\", status); }}\n    return status;\n}}\n"
+        ));
+    }
+
+    for backend in [LexerBackend::HandWritten, LexerBackend::Dfa] {
+        let config = LexerConfig::new().with_backend(backend);
+        let name = format!("{backend:?}");
+        group.bench_with_input(BenchmarkId::new("owned", &name), &source, |b, source| {
+            b.iter(|| black_box(Lexer::with_config(source, config).lex()))
+        });
+        group.bench_with_input(BenchmarkId::new("borrowed", &name), &source, |b, source| {
+            b.iter(|| black_box(Lexer::with_config(source, config).lex_borrowed()))
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_parser_creation,
     bench_small_programs,
     bench_expression_parsing,
+    bench_expression_shapes,
     bench_large_programs,
     memory_leak_detection,
     real_world_scenarios,
     bench_error_recovery_overhead,
     bench_error_density_impact,
     bench_error_recovery_scalability,
-    bench_recovery_strategy_overhead
+    bench_recovery_strategy_overhead,
+    bench_identifier_interning,
+    bench_token_representation,
+    bench_lexer_backends
 );
 
 criterion_main!(benches);
@@ -28,12 +28,40 @@ impl Arena {
         self.bump.alloc_slice_clone(slice)
     }
 
-    /// Allocate a vector in the arena
-    pub fn alloc_vec<T>(&self, vec: Vec<T>) -> &[T]
+    /// Move the elements of a vector into an arena slice
+    pub fn alloc_vec<T>(&self, vec: Vec<T>) -> &[T] {
+        self.bump.alloc_slice_fill_iter(vec)
+    }
+
+    /// Allocate a copy of `node` with `edit` applied to it
+    ///
+    /// Arena nodes are shared, so passes that rewrite the AST edit copies and
+    /// point the parent at the result.
+    pub fn alloc_edited<T, E>(
+        &self,
+        node: &T,
+        edit: impl FnOnce(&mut T) -> Result<(), E>,
+    ) -> Result<&T, E>
+    where
+        T: Clone,
+    {
+        let mut node = node.clone();
+        edit(&mut node)?;
+        Ok(self.alloc(node))
+    }
+
+    /// Allocate a copy of `nodes` with `edit` applied to each element
+    pub fn alloc_slice_edited<T, E>(
+        &self,
+        nodes: &[T],
+        mut edit: impl FnMut(&mut T) -> Result<(), E>,
+    ) -> Result<&[T], E>
     where
         T: Clone,
     {
-        self.bump.alloc_slice_clone(&vec)
+        let mut nodes = nodes.to_vec();
+        nodes.iter_mut().try_for_each(&mut edit)?;
+        Ok(self.alloc_vec(nodes))
     }
 
     /// Get the number of bytes allocated in this arena
@@ -5,8 +5,10 @@
 
 pub mod arena;
 pub mod nodes;
+pub mod printer;
 pub mod visitor;
 
 pub use arena::*;
 pub use nodes::*;
+pub use printer::*;
 pub use visitor::*;
@@ -1,158 +1,179 @@
 //! AST node type definitions
 //!
 //! Defines the structure of AST nodes representing parsed Ferra code.
-//! Detailed implementation will be done during development phase.
+//! Nodes live in an [`Arena`] for the lifetime `'a`: child nodes are `&'a`
+//! references and lists are arena slices, so building a parent never copies
+//! the subtrees beneath it.
 
-use crate::token::{Span, Token};
+use super::Arena;
+use crate::token::{NumericSuffix, Span, Symbol, Token, WideInteger};
 
 /// Top-level compilation unit (represents a complete source file)
 #[derive(Debug, Clone)]
-pub struct CompilationUnit {
-    pub items: Vec<Item>,
+pub struct CompilationUnit<'a> {
+    pub attributes: &'a [Attribute<'a>], // Inner attributes: #![allow(dead_code)]
+    pub items: &'a [Item<'a>],
     pub span: Span,
 }
 
 /// Top-level items (declarations)
 #[derive(Debug, Clone)]
-pub enum Item {
-    FunctionDecl(FunctionDecl),
-    VariableDecl(VariableDecl),
-    DataClassDecl(DataClassDecl),
-    ExternBlock(ExternBlock),
+pub enum Item<'a> {
+    FunctionDecl(FunctionDecl<'a>),
+    VariableDecl(VariableDecl<'a>),
+    DataClassDecl(DataClassDecl<'a>),
+    ExternBlock(ExternBlock<'a>),
+    MacroDefinition(MacroDefinition<'a>),
+    Macro(MacroItem<'a>), // json! { ... } or getters!(Point);
 }
 
 /// Function declaration
 #[derive(Debug, Clone)]
-pub struct FunctionDecl {
-    pub name: String,
-    pub generics: Option<GenericParams>,
-    pub parameters: Vec<Parameter>,
-    pub return_type: Option<Type>,
-    pub body: Option<Block>,
+pub struct FunctionDecl<'a> {
+    pub name: Symbol,
+    pub generics: Option<GenericParams<'a>>,
+    pub parameters: &'a [Parameter<'a>],
+    pub return_type: Option<&'a Type<'a>>,
+    pub body: Option<&'a Block<'a>>,
     pub is_async: bool,
     pub is_extern: bool,
     pub abi: Option<String>,
     pub modifiers: Modifiers,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Function parameter
 #[derive(Debug, Clone)]
-pub struct Parameter {
-    pub name: String,
-    pub param_type: Type,
-    pub attributes: Vec<Attribute>,
+pub struct Parameter<'a> {
+    pub name: Symbol,
+    pub param_type: &'a Type<'a>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Variable declaration
 #[derive(Debug, Clone)]
-pub struct VariableDecl {
-    pub name: String,
-    pub var_type: Option<Type>,
-    pub initializer: Option<Expression>,
+pub struct VariableDecl<'a> {
+    pub name: Symbol,
+    pub var_type: Option<&'a Type<'a>>,
+    pub initializer: Option<&'a Expression<'a>>,
     pub is_mutable: bool,
     pub modifiers: Modifiers,
-    pub attributes: Vec<Attribute>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Data class declaration
 #[derive(Debug, Clone)]
-pub struct DataClassDecl {
-    pub name: String,
-    pub generics: Option<GenericParams>,
-    pub fields: Vec<Field>,
-    pub attributes: Vec<Attribute>,
+pub struct DataClassDecl<'a> {
+    pub name: Symbol,
+    pub generics: Option<GenericParams<'a>>,
+    pub fields: &'a [Field<'a>],
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Data class field
 #[derive(Debug, Clone)]
-pub struct Field {
-    pub name: String,
-    pub field_type: Type,
-    pub attributes: Vec<Attribute>,
+pub struct Field<'a> {
+    pub name: Symbol,
+    pub field_type: &'a Type<'a>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// External block for FFI
 #[derive(Debug, Clone)]
-pub struct ExternBlock {
+pub struct ExternBlock<'a> {
     pub abi: String,
-    pub items: Vec<ExternItem>,
+    pub items: &'a [ExternItem<'a>],
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// External item (function or variable)
 #[derive(Debug, Clone)]
-pub enum ExternItem {
-    Function(ExternFunction),
-    Variable(ExternVariable),
+pub enum ExternItem<'a> {
+    Function(ExternFunction<'a>),
+    Variable(ExternVariable<'a>),
 }
 
 /// External function declaration
 #[derive(Debug, Clone)]
-pub struct ExternFunction {
-    pub name: String,
-    pub parameters: Vec<Parameter>,
-    pub return_type: Option<Type>,
+pub struct ExternFunction<'a> {
+    pub name: Symbol,
+    pub parameters: &'a [Parameter<'a>],
+    pub return_type: Option<&'a Type<'a>>,
+    pub attributes: &'a [Attribute<'a>], // #[link_name = "..."]
     pub span: Span,
 }
 
 /// External variable declaration
 #[derive(Debug, Clone)]
-pub struct ExternVariable {
-    pub name: String,
-    pub var_type: Type,
+pub struct ExternVariable<'a> {
+    pub name: Symbol,
+    pub var_type: &'a Type<'a>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Statement types
 #[derive(Debug, Clone)]
-pub enum Statement {
-    Expression(Expression),
-    VariableDecl(VariableDecl),
-    If(IfStatement),
-    While(WhileStatement),
-    For(ForStatement),
-    Return(ReturnStatement),
+pub enum Statement<'a> {
+    Expression(&'a Expression<'a>),
+    VariableDecl(VariableDecl<'a>),
+    If(IfStatement<'a>),
+    While(WhileStatement<'a>),
+    For(ForStatement<'a>),
+    Return(ReturnStatement<'a>),
     Break(BreakStatement),
     Continue(ContinueStatement),
-    Block(Block),
+    Block(Block<'a>),
+    Attributed(AttributedStatement<'a>), // #[allow(unused)] on a single statement
+}
+
+/// Statement annotated with outer attributes
+///
+/// Variable declarations keep their attributes on `VariableDecl` itself;
+/// every other statement kind is wrapped in this node.
+#[derive(Debug, Clone)]
+pub struct AttributedStatement<'a> {
+    pub attributes: &'a [Attribute<'a>],
+    pub statement: &'a Statement<'a>,
+    pub span: Span,
 }
 
 /// If statement
 #[derive(Debug, Clone)]
-pub struct IfStatement {
-    pub condition: Expression,
-    pub then_block: Block,
-    pub else_block: Option<Block>,
+pub struct IfStatement<'a> {
+    pub condition: &'a Expression<'a>,
+    pub then_block: &'a Block<'a>,
+    pub else_block: Option<&'a Block<'a>>,
     pub span: Span,
 }
 
 /// While loop
 #[derive(Debug, Clone)]
-pub struct WhileStatement {
-    pub condition: Expression,
-    pub body: Block,
+pub struct WhileStatement<'a> {
+    pub condition: &'a Expression<'a>,
+    pub body: &'a Block<'a>,
     pub span: Span,
 }
 
 /// For loop
 #[derive(Debug, Clone)]
-pub struct ForStatement {
-    pub variable: String,
-    pub iterable: Expression,
-    pub body: Block,
+pub struct ForStatement<'a> {
+    pub variable: Symbol,
+    pub iterable: &'a Expression<'a>,
+    pub body: &'a Block<'a>,
     pub span: Span,
 }
 
 /// Return statement
 #[derive(Debug, Clone)]
-pub struct ReturnStatement {
-    pub value: Option<Expression>,
+pub struct ReturnStatement<'a> {
+    pub value: Option<&'a Expression<'a>>,
     pub span: Span,
 }
 
@@ -170,8 +191,8 @@ pub struct ContinueStatement {
 
 /// Block of statements
 #[derive(Debug, Clone)]
-pub struct Block {
-    pub statements: Vec<Statement>,
+pub struct Block<'a> {
+    pub statements: &'a [Statement<'a>],
     pub is_braced: bool, // true for {}, false for indented
     pub span: Span,
     // Phase 2.4 enhancements
@@ -179,13 +200,13 @@ pub struct Block {
     pub is_unsafe: bool,
     pub is_async: bool,
     pub is_try: bool,
-    pub label: Option<String>,
+    pub label: Option<Symbol>,
 }
 
-impl Default for Block {
+impl Default for Block<'_> {
     fn default() -> Self {
         Self {
-            statements: Vec::new(),
+            statements: &[],
             is_braced: true,
             span: Span::dummy(),
             scope_depth: 0,
@@ -199,23 +220,26 @@ impl Default for Block {
 
 /// Expression types
 #[derive(Debug, Clone)]
-pub enum Expression {
+pub enum Expression<'a> {
     Literal(Literal),
-    Identifier(String),
-    QualifiedIdentifier(QualifiedIdentifier),
-    Binary(BinaryExpression),
-    Unary(UnaryExpression),
-    Call(CallExpression),
-    MemberAccess(MemberAccessExpression),
-    Index(IndexExpression),
-    Await(AwaitExpression),
-    Array(ArrayLiteral),
-    Tuple(TupleLiteral),
-    If(IfExpression),
-    Match(MatchExpression),
-    Grouped(Box<Expression>),
-    Block(BlockExpression), // Phase 2.4 addition
-    Macro(MacroInvocation), // Phase 2.8.4: Macro invocations
+    Identifier(Symbol),
+    Path(Path<'a>), // Qualified and generic paths (std::mem::swap, parse::<Int>)
+    Binary(BinaryExpression<'a>),
+    Unary(UnaryExpression<'a>),
+    Call(CallExpression<'a>),
+    MemberAccess(MemberAccessExpression<'a>),
+    Index(IndexExpression<'a>),
+    Await(AwaitExpression<'a>),
+    Array(ArrayLiteral<'a>),
+    Tuple(TupleLiteral<'a>),
+    If(IfExpression<'a>),
+    Match(MatchExpression<'a>),
+    Grouped(&'a Expression<'a>),
+    Block(BlockExpression<'a>),             // Phase 2.4 addition
+    Macro(MacroInvocation),                 // Phase 2.8.4: Macro invocations
+    BuiltinMacro(BuiltinMacro<'a>),         // println!, format!, vec!, ... after expansion
+    DataClassLiteral(DataClassLiteral<'a>), // Point { x: 1, y: 2 }
+    Interpolated(InterpolatedString<'a>),   // f"Hello, {name}!"
 }
 
 /// Literal values
@@ -225,21 +249,156 @@ pub enum Literal {
     Integer(i64),
     Float(f64),
     Boolean(bool),
+    Char(char),
+    Byte(u8),
+    ByteString(Vec<u8>),
+    WideInteger(WideInteger),
+    /// Suffixed integer; the value is the magnitude, negation stays a unary `-`
+    TypedInteger {
+        value: u128,
+        suffix: NumericSuffix,
+    },
+    TypedFloat {
+        value: f64,
+        suffix: NumericSuffix,
+    },
+}
+
+/// Path shared by types, expressions, patterns and data class literals
+/// (e.g., `http::Client`, `std::collections::Map<K, V>`, `parse::<Int>`)
+#[derive(Debug, Clone)]
+pub struct Path<'a> {
+    pub segments: &'a [PathSegment<'a>],
+    pub span: Span,
+}
+
+/// A single `::`-separated path segment with its generic arguments
+#[derive(Debug, Clone)]
+pub struct PathSegment<'a> {
+    pub name: Symbol,
+    pub generic_args: &'a [Type<'a>],
+    pub span: Span,
+}
+
+impl<'a> Path<'a> {
+    /// Create a single-segment path without generic arguments
+    pub fn from_ident(arena: &'a Arena, name: impl Into<Symbol>, span: Span) -> Self {
+        Self {
+            segments: arena.alloc_slice(&[PathSegment {
+                name: name.into(),
+                generic_args: &[],
+                span: span.clone(),
+            }]),
+            span,
+        }
+    }
+
+    /// Segment names joined with `::`, ignoring generic arguments
+    pub fn name(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.name.as_str())
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    /// Returns the identifier if this is a plain single-segment path
+    pub fn as_ident(&self) -> Option<Symbol> {
+        match self.segments {
+            [segment] if segment.generic_args.is_empty() => Some(segment.name),
+            _ => None,
+        }
+    }
+
+    /// Generic arguments of the final segment: `[K, V]` for `Map<K, V>`
+    pub fn generic_args(&self) -> &'a [Type<'a>] {
+        self.segments
+            .last()
+            .map_or(&[], |segment| segment.generic_args)
+    }
+
+    /// Check whether any segment carries generic arguments
+    pub fn has_generic_args(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| !segment.generic_args.is_empty())
+    }
+}
+
+impl PartialEq<&str> for Path<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.name() == *other
+    }
 }
 
-/// Qualified identifier (e.g., module.function)
+impl PartialEq<str> for Path<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.name() == other
+    }
+}
+
+/// Interpolated string literal (e.g., `f"Hello, {name}!"`)
 #[derive(Debug, Clone)]
-pub struct QualifiedIdentifier {
-    pub parts: Vec<String>,
+pub struct InterpolatedString<'a> {
+    pub parts: &'a [InterpolatedPart<'a>],
+    pub span: Span,
+}
+
+impl<'a> InterpolatedString<'a> {
+    /// Copy with `edit` applied to each embedded expression, in source order
+    pub fn edit_expressions<E>(
+        &self,
+        arena: &'a Arena,
+        mut edit: impl FnMut(&mut Expression<'a>) -> Result<(), E>,
+    ) -> Result<Self, E> {
+        let parts = arena.alloc_slice_edited(self.parts, |part| match part {
+            InterpolatedPart::Expression { expression, .. } => {
+                *expression = arena.alloc_edited(*expression, &mut edit)?;
+                Ok(())
+            }
+            InterpolatedPart::Text(_) => Ok(()),
+        })?;
+        Ok(Self {
+            parts,
+            span: self.span.clone(),
+        })
+    }
+}
+
+/// Text or embedded expression of an interpolated string
+#[derive(Debug, Clone)]
+pub enum InterpolatedPart<'a> {
+    Text(String),
+    /// The span covers the source between the braces
+    Expression {
+        expression: &'a Expression<'a>,
+        span: Span,
+    },
+}
+
+/// Data class literal (e.g., `Point { x: 1, y: 2 }`, `geo::Point { x, y }`)
+#[derive(Debug, Clone)]
+pub struct DataClassLiteral<'a> {
+    pub path: Path<'a>,
+    pub fields: &'a [FieldInit<'a>],
+    pub span: Span,
+}
+
+/// Field initializer in a data class literal; shorthand `{ x }` stores `x` as the value
+#[derive(Debug, Clone)]
+pub struct FieldInit<'a> {
+    pub name: Symbol,
+    pub value: &'a Expression<'a>,
+    pub is_shorthand: bool,
     pub span: Span,
 }
 
 /// Binary expression
 #[derive(Debug, Clone)]
-pub struct BinaryExpression {
-    pub left: Box<Expression>,
+pub struct BinaryExpression<'a> {
+    pub left: &'a Expression<'a>,
     pub operator: BinaryOperator,
-    pub right: Box<Expression>,
+    pub right: &'a Expression<'a>,
     pub span: Span,
 }
 
@@ -269,9 +428,9 @@ pub enum BinaryOperator {
 
 /// Unary expression
 #[derive(Debug, Clone)]
-pub struct UnaryExpression {
+pub struct UnaryExpression<'a> {
     pub operator: UnaryOperator,
-    pub operand: Box<Expression>,
+    pub operand: &'a Expression<'a>,
     pub span: Span,
 }
 
@@ -286,149 +445,149 @@ pub enum UnaryOperator {
 
 /// Function call expression
 #[derive(Debug, Clone)]
-pub struct CallExpression {
-    pub callee: Box<Expression>,
-    pub arguments: Vec<Expression>,
+pub struct CallExpression<'a> {
+    pub callee: &'a Expression<'a>,
+    pub arguments: &'a [Expression<'a>],
     pub span: Span,
 }
 
 /// Member access expression
 #[derive(Debug, Clone)]
-pub struct MemberAccessExpression {
-    pub object: Box<Expression>,
-    pub member: String,
+pub struct MemberAccessExpression<'a> {
+    pub object: &'a Expression<'a>,
+    pub member: Symbol,
     pub span: Span,
 }
 
 /// If expression
 #[derive(Debug, Clone)]
-pub struct IfExpression {
-    pub condition: Box<Expression>,
-    pub then_expr: Box<Expression>,
-    pub else_expr: Option<Box<Expression>>,
+pub struct IfExpression<'a> {
+    pub condition: &'a Expression<'a>,
+    pub then_expr: &'a Expression<'a>,
+    pub else_expr: Option<&'a Expression<'a>>,
     pub span: Span,
 }
 
 /// Match expression
 #[derive(Debug, Clone)]
-pub struct MatchExpression {
-    pub scrutinee: Box<Expression>,
-    pub arms: Vec<MatchArm>,
+pub struct MatchExpression<'a> {
+    pub scrutinee: &'a Expression<'a>,
+    pub arms: &'a [MatchArm<'a>],
     pub span: Span,
 }
 
 /// Match arm
 #[derive(Debug, Clone)]
-pub struct MatchArm {
-    pub pattern: Pattern,
-    pub expression: Expression,
+pub struct MatchArm<'a> {
+    pub pattern: &'a Pattern<'a>,
+    pub expression: &'a Expression<'a>,
+    pub attributes: &'a [Attribute<'a>], // #[cold] on a single arm
     pub span: Span,
 }
 
 /// Pattern types for match expressions
 #[derive(Debug, Clone)]
-pub enum Pattern {
+pub enum Pattern<'a> {
     Literal(Literal),
-    Identifier(String),
+    Identifier(Symbol),
     Wildcard,
-    DataClass(DataClassPattern),
-    Range(RangePattern),     // Phase 2.8.3: Range patterns (1..=10)
-    Slice(SlicePattern),     // Phase 2.8.3: Slice patterns ([head, tail @ ..])
-    Or(OrPattern),           // Phase 2.8.3: Or patterns (Some(x) | None)
-    Guard(GuardPattern),     // Phase 2.8.3: Guard patterns (x if x > 0)
-    Binding(BindingPattern), // Phase 2.8.3: Binding patterns (name @ pattern)
+    DataClass(DataClassPattern<'a>),
+    Range(RangePattern<'a>),     // Phase 2.8.3: Range patterns (1..=10)
+    Slice(SlicePattern<'a>),     // Phase 2.8.3: Slice patterns ([head, tail @ ..])
+    Or(OrPattern<'a>),           // Phase 2.8.3: Or patterns (Some(x) | None)
+    Guard(GuardPattern<'a>),     // Phase 2.8.3: Guard patterns (x if x > 0)
+    Binding(BindingPattern<'a>), // Phase 2.8.3: Binding patterns (name @ pattern)
+    Path(Path<'a>),              // Qualified constant patterns (Color::Red)
+    Macro(MacroInvocation),      // Pattern-position macro invocation (pair!(x))
 }
 
 /// Data class pattern
 #[derive(Debug, Clone)]
-pub struct DataClassPattern {
-    pub name: String,
-    pub fields: Vec<FieldPattern>,
+pub struct DataClassPattern<'a> {
+    pub name: Path<'a>,
+    pub fields: &'a [FieldPattern<'a>],
     pub has_rest: bool,
     pub span: Span,
 }
 
 /// Field pattern in data class destructuring
 #[derive(Debug, Clone)]
-pub struct FieldPattern {
-    pub name: String,
-    pub pattern: Option<Pattern>,
+pub struct FieldPattern<'a> {
+    pub name: Symbol,
+    pub pattern: Option<&'a Pattern<'a>>,
     pub span: Span,
 }
 
 /// Range pattern for numeric ranges
 #[derive(Debug, Clone)]
-pub struct RangePattern {
-    pub start: Option<Box<Pattern>>, // None for open ranges like ..=5
-    pub end: Option<Box<Pattern>>,   // None for open ranges like 5..
-    pub inclusive: bool,             // true for ..=, false for ..
+pub struct RangePattern<'a> {
+    pub start: Option<&'a Pattern<'a>>, // None for open ranges like ..=5
+    pub end: Option<&'a Pattern<'a>>,   // None for open ranges like 5..
+    pub inclusive: bool,                // true for ..=, false for ..
     pub span: Span,
 }
 
 /// Slice pattern for array destructuring
 #[derive(Debug, Clone)]
-pub struct SlicePattern {
-    pub prefix: Vec<Pattern>, // Patterns before the rest element
-    pub rest: Option<String>, // Variable name for rest element (tail @ ..)
-    pub suffix: Vec<Pattern>, // Patterns after the rest element
+pub struct SlicePattern<'a> {
+    pub prefix: &'a [Pattern<'a>], // Patterns before the rest element
+    pub rest: Option<Symbol>,      // Variable name for rest element (tail @ ..)
+    pub suffix: &'a [Pattern<'a>], // Patterns after the rest element
     pub span: Span,
 }
 
 /// Or pattern for matching multiple patterns
 #[derive(Debug, Clone)]
-pub struct OrPattern {
-    pub patterns: Vec<Pattern>, // List of alternative patterns
+pub struct OrPattern<'a> {
+    pub patterns: &'a [Pattern<'a>], // List of alternative patterns
     pub span: Span,
 }
 
 /// Guard pattern with conditional expression
 #[derive(Debug, Clone)]
-pub struct GuardPattern {
-    pub pattern: Box<Pattern>, // Base pattern to match
-    pub guard: Expression,     // Guard condition
+pub struct GuardPattern<'a> {
+    pub pattern: &'a Pattern<'a>,  // Base pattern to match
+    pub guard: &'a Expression<'a>, // Guard condition
     pub span: Span,
 }
 
 /// Binding pattern for named pattern matching
 #[derive(Debug, Clone)]
-pub struct BindingPattern {
-    pub name: String,          // Variable name to bind to
-    pub pattern: Box<Pattern>, // Pattern to match
+pub struct BindingPattern<'a> {
+    pub name: Symbol,             // Variable name to bind to
+    pub pattern: &'a Pattern<'a>, // Pattern to match
     pub span: Span,
 }
 
 /// Type expressions
+///
+/// A bare name is `Type::Identifier`; every other named type, qualified or
+/// generic (`http::Client`, `Vec<T>`, `Outer<T>::Inner`), is a `Type::Path`
+/// with its arguments on the segments that take them.
 #[derive(Debug, Clone)]
-pub enum Type {
-    Identifier(String),
-    Generic(GenericType),
-    Tuple(Vec<Type>),
-    Array(Box<Type>),
-    Function(FunctionType),
-    Pointer(PointerType),
-}
-
-/// Generic type with type parameters (e.g., Vec<T>, HashMap<K, V>)
-#[derive(Debug, Clone)]
-pub struct GenericType {
-    pub base: String,
-    pub args: Vec<Type>,
-    pub span: Span,
+pub enum Type<'a> {
+    Identifier(Symbol),
+    Path(Path<'a>), // Qualified or generic types (http::Client, Map<K, V>)
+    Tuple(&'a [Type<'a>]),
+    Array(&'a Type<'a>),
+    Function(FunctionType<'a>),
+    Pointer(PointerType<'a>),
+    Macro(MacroInvocation), // Type-position macro invocation (Pair!(Int))
 }
 
 /// Function type
 #[derive(Debug, Clone)]
-pub struct FunctionType {
-    pub parameters: Vec<Type>,
-    pub return_type: Box<Type>,
+pub struct FunctionType<'a> {
+    pub parameters: &'a [Type<'a>],
+    pub return_type: &'a Type<'a>,
     pub is_extern: bool,
     pub abi: Option<String>,
 }
 
 /// Pointer type
 #[derive(Debug, Clone)]
-pub struct PointerType {
-    pub target: Box<Type>,
+pub struct PointerType<'a> {
+    pub target: &'a Type<'a>,
     pub is_mutable: bool,
 }
 
@@ -439,57 +598,164 @@ pub struct Modifiers {
     pub is_unsafe: bool,
 }
 
-/// Attribute for declarations and expressions
+/// Attribute for declarations and expressions: `#[name(args)]`, `#[name = value]`
+/// or inner `#![name(args)]`
 #[derive(Debug, Clone)]
-pub struct Attribute {
-    pub name: String,
-    pub arguments: Vec<String>,
+pub struct Attribute<'a> {
+    pub name: AttributePath<'a>,
+    pub arguments: &'a [AttributeArgument<'a>],
+    pub value: Option<&'a Expression<'a>>, // #[link_name = "puts"]
+    pub is_inner: bool,
     pub span: Span,
 }
 
+impl<'a> Attribute<'a> {
+    /// The contents of a `#[name = "value"]` string value
+    pub fn value_str(&self) -> Option<&str> {
+        match &self.value {
+            Some(Expression::Literal(Literal::String(value))) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Dotted attribute path (e.g., `inline`, `ai.assume`, `cfg.test`)
+#[derive(Debug, Clone)]
+pub struct AttributePath<'a> {
+    pub segments: &'a [Symbol],
+    pub span: Span,
+}
+
+impl<'a> AttributePath<'a> {
+    /// Segment names joined with `.`
+    pub fn name(&self) -> String {
+        self.segments
+            .iter()
+            .map(|segment| segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+impl PartialEq<&str> for AttributePath<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.name() == *other
+    }
+}
+
+impl PartialEq<str> for AttributePath<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.name() == other
+    }
+}
+
+/// Attribute argument
+#[derive(Debug, Clone)]
+pub enum AttributeArgument<'a> {
+    /// Positional expression: `Debug`, `"docs"`, `42`
+    Expression {
+        value: &'a Expression<'a>,
+        span: Span,
+    },
+    /// Key/value pair: `nll = "noalias"`, `target_env = "vulkan1.2"`
+    KeyValue {
+        key: Symbol,
+        value: &'a Expression<'a>,
+        span: Span,
+    },
+    /// Nested argument list: `not(feature = "std")`, `any(unix, windows)`
+    Nested {
+        name: Symbol,
+        arguments: &'a [AttributeArgument<'a>],
+        span: Span,
+    },
+}
+
+impl<'a> AttributeArgument<'a> {
+    /// Get the span of this argument
+    pub fn span(&self) -> Span {
+        match self {
+            AttributeArgument::Expression { span, .. }
+            | AttributeArgument::KeyValue { span, .. }
+            | AttributeArgument::Nested { span, .. } => span.clone(),
+        }
+    }
+
+    /// The identifier of a positional argument such as `Debug`
+    pub fn as_ident(&self) -> Option<&str> {
+        match self {
+            AttributeArgument::Expression {
+                value: Expression::Identifier(name),
+                ..
+            } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The contents of a positional string literal argument such as `"docs"`
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AttributeArgument::Expression {
+                value: Expression::Literal(Literal::String(value)),
+                ..
+            } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The key and value of a `key = value` argument
+    pub fn as_key_value(&self) -> Option<(&str, &'a Expression<'a>)> {
+        match self {
+            AttributeArgument::KeyValue { key, value, .. } => Some((key, *value)),
+            _ => None,
+        }
+    }
+}
+
 /// Index expression (arr[index])
 #[derive(Debug, Clone)]
-pub struct IndexExpression {
-    pub object: Box<Expression>,
-    pub index: Box<Expression>,
+pub struct IndexExpression<'a> {
+    pub object: &'a Expression<'a>,
+    pub index: &'a Expression<'a>,
     pub span: Span,
 }
 
 /// Await expression (expr.await)
 #[derive(Debug, Clone)]
-pub struct AwaitExpression {
-    pub expression: Box<Expression>,
+pub struct AwaitExpression<'a> {
+    pub expression: &'a Expression<'a>,
     pub span: Span,
 }
 
 /// Array literal ([1, 2, 3])
 #[derive(Debug, Clone)]
-pub struct ArrayLiteral {
-    pub elements: Vec<Expression>,
+pub struct ArrayLiteral<'a> {
+    pub elements: &'a [Expression<'a>],
     pub span: Span,
 }
 
 /// Tuple literal ((1, 2, 3))
 #[derive(Debug, Clone)]
-pub struct TupleLiteral {
-    pub elements: Vec<Expression>,
+pub struct TupleLiteral<'a> {
+    pub elements: &'a [Expression<'a>],
     pub span: Span,
 }
 
 /// Block expression (Phase 2.4)
 #[derive(Debug, Clone)]
-pub struct BlockExpression {
-    pub block: Block,
-    pub value: Option<Box<Expression>>,
+pub struct BlockExpression<'a> {
+    pub block: &'a Block<'a>,
+    pub value: Option<&'a Expression<'a>>,
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Generic type parameter
 #[derive(Debug, Clone)]
-pub struct GenericParam {
-    pub name: String,
-    pub bounds: Vec<TypeBound>,
-    pub default: Option<Type>,
+pub struct GenericParam<'a> {
+    pub name: Symbol,
+    pub bounds: &'a [TypeBound],
+    pub default: Option<&'a Type<'a>>,
     pub is_lifetime: bool,
     pub span: Span,
 }
@@ -497,41 +763,216 @@ pub struct GenericParam {
 /// Type bound for generic constraints (T: Clone + Debug)
 #[derive(Debug, Clone)]
 pub struct TypeBound {
-    pub trait_name: String,
+    pub trait_name: Symbol,
     pub span: Span,
 }
 
 /// Where clause for complex generic constraints
 #[derive(Debug, Clone)]
-pub struct WhereClause {
-    pub constraints: Vec<WhereConstraint>,
+pub struct WhereClause<'a> {
+    pub constraints: &'a [WhereConstraint<'a>],
     pub span: Span,
 }
 
 /// Individual constraint in where clause
 #[derive(Debug, Clone)]
-pub struct WhereConstraint {
-    pub type_name: String,
-    pub bounds: Vec<TypeBound>,
+pub struct WhereConstraint<'a> {
+    pub type_name: Symbol,
+    pub bounds: &'a [TypeBound],
     pub span: Span,
 }
 
 /// Generic parameters collection
 #[derive(Debug, Clone)]
-pub struct GenericParams {
-    pub params: Vec<GenericParam>,
-    pub where_clause: Option<WhereClause>,
+pub struct GenericParams<'a> {
+    pub params: &'a [GenericParam<'a>],
+    pub where_clause: Option<WhereClause<'a>>,
     pub span: Span,
 }
 
 /// Macro invocation expression
 #[derive(Debug, Clone)]
 pub struct MacroInvocation {
-    pub name: String,
+    pub name: Symbol,
     pub arguments: Vec<TokenTree>,
     pub span: Span,
 }
 
+/// Compiler-known macro whose arguments have been parsed into expressions
+#[derive(Debug, Clone)]
+pub struct BuiltinMacro<'a> {
+    pub name: Symbol,
+    pub kind: BuiltinMacroKind<'a>,
+    pub span: Span,
+}
+
+/// The built-in macros and their parsed arguments
+#[derive(Debug, Clone)]
+pub enum BuiltinMacroKind<'a> {
+    /// `print!`, `println!` and `eprintln!`
+    Print {
+        newline: bool,
+        stderr: bool,
+        format: FormatArgs<'a>,
+    },
+    /// `format!("...", args)`
+    Format(FormatArgs<'a>),
+    /// `vec![a, b, c]`
+    Vec(&'a [Expression<'a>]),
+    /// `vec![value; count]`
+    VecRepeat {
+        value: &'a Expression<'a>,
+        count: &'a Expression<'a>,
+    },
+    /// `assert!(condition)` with an optional formatted message
+    Assert {
+        condition: &'a Expression<'a>,
+        message: Option<FormatArgs<'a>>,
+    },
+    /// `assert_eq!(left, right)` with an optional formatted message
+    AssertEq {
+        left: &'a Expression<'a>,
+        right: &'a Expression<'a>,
+        message: Option<FormatArgs<'a>>,
+    },
+}
+
+impl<'a> BuiltinMacro<'a> {
+    /// Copy with `edit` applied to every argument expression, in source order
+    pub fn edit_arguments<E>(
+        &self,
+        arena: &'a Arena,
+        mut edit: impl FnMut(&mut Expression<'a>) -> Result<(), E>,
+    ) -> Result<Self, E> {
+        let edit_format = |format: &FormatArgs<'a>,
+                           edit: &mut dyn FnMut(&mut Expression<'a>) -> Result<(), E>|
+         -> Result<FormatArgs<'a>, E> {
+            let arguments = arena.alloc_slice_edited(format.arguments, |argument| {
+                argument.value = arena.alloc_edited(argument.value, &mut *edit)?;
+                Ok(())
+            })?;
+            Ok(FormatArgs {
+                arguments,
+                ..format.clone()
+            })
+        };
+
+        let kind = match &self.kind {
+            BuiltinMacroKind::Print {
+                newline,
+                stderr,
+                format,
+            } => BuiltinMacroKind::Print {
+                newline: *newline,
+                stderr: *stderr,
+                format: edit_format(format, &mut edit)?,
+            },
+            BuiltinMacroKind::Format(format) => {
+                BuiltinMacroKind::Format(edit_format(format, &mut edit)?)
+            }
+            BuiltinMacroKind::Vec(elements) => {
+                BuiltinMacroKind::Vec(arena.alloc_slice_edited(elements, &mut edit)?)
+            }
+            BuiltinMacroKind::VecRepeat { value, count } => BuiltinMacroKind::VecRepeat {
+                value: arena.alloc_edited(*value, &mut edit)?,
+                count: arena.alloc_edited(*count, &mut edit)?,
+            },
+            BuiltinMacroKind::Assert { condition, message } => BuiltinMacroKind::Assert {
+                condition: arena.alloc_edited(*condition, &mut edit)?,
+                message: match message {
+                    Some(format) => Some(edit_format(format, &mut edit)?),
+                    None => None,
+                },
+            },
+            BuiltinMacroKind::AssertEq {
+                left,
+                right,
+                message,
+            } => BuiltinMacroKind::AssertEq {
+                left: arena.alloc_edited(*left, &mut edit)?,
+                right: arena.alloc_edited(*right, &mut edit)?,
+                message: match message {
+                    Some(format) => Some(edit_format(format, &mut edit)?),
+                    None => None,
+                },
+            },
+        };
+        Ok(Self {
+            kind,
+            ..self.clone()
+        })
+    }
+}
+
+/// A validated format string and the arguments it refers to
+#[derive(Debug, Clone)]
+pub struct FormatArgs<'a> {
+    pub template: FormatString,
+    pub arguments: &'a [FormatArgument<'a>],
+    pub span: Span,
+}
+
+/// Format string literal split into text and placeholders
+#[derive(Debug, Clone)]
+pub struct FormatString {
+    pub pieces: Vec<FormatPiece>,
+    pub span: Span,
+}
+
+/// Piece of a format string
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatPiece {
+    /// Literal text with `{{` and `}}` already unescaped
+    Text(String),
+    Placeholder(FormatPlaceholder),
+}
+
+/// `{}`, `{0}`, `{name}` or `{name:spec}` inside a format string
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatPlaceholder {
+    pub argument: FormatArgumentRef,
+    pub spec: Option<String>,
+    /// Span of the placeholder inside the string literal
+    pub span: Span,
+}
+
+/// Argument a placeholder refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatArgumentRef {
+    /// Positional argument; implicit `{}` placeholders are numbered in order
+    Index(usize),
+    /// Named argument passed as `name = value`
+    Named(Symbol),
+    /// Variable in scope captured by name when no such named argument exists
+    Captured(Symbol),
+}
+
+/// Argument following the format string
+#[derive(Debug, Clone)]
+pub struct FormatArgument<'a> {
+    pub name: Option<Symbol>,
+    pub value: &'a Expression<'a>,
+    pub span: Span,
+}
+
+impl MacroInvocation {
+    /// Delimiter the arguments were written with
+    pub fn delimiter(&self) -> Option<GroupDelimiter> {
+        match &self.arguments[..] {
+            [TokenTree::Group(group)] => Some(group.delimiter),
+            _ => None,
+        }
+    }
+}
+
+/// Macro invocation in item position, expanded into zero or more items
+#[derive(Debug, Clone)]
+pub struct MacroItem<'a> {
+    pub invocation: MacroInvocation,
+    pub attributes: &'a [Attribute<'a>],
+    pub span: Span,
+}
+
 /// Token tree for macro arguments
 #[derive(Debug, Clone)]
 pub enum TokenTree {
@@ -548,30 +989,115 @@ pub struct TokenGroup {
 }
 
 /// Delimiters for token groups
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GroupDelimiter {
     Parentheses, // ()
     Brackets,    // []
     Braces,      // {}
 }
 
-/// Macro definition (basic framework)
+/// Declarative macro definition: `macro name { (matcher) => { transcriber }; ... }`
 #[derive(Debug, Clone)]
-pub struct MacroDefinition {
-    pub name: String,
-    pub rules: Vec<MacroRule>,
+pub struct MacroDefinition<'a> {
+    pub name: Symbol,
+    pub rules: &'a [MacroRule<'a>],
+    pub attributes: &'a [Attribute<'a>],
     pub span: Span,
 }
 
 /// Macro rule for pattern matching
 #[derive(Debug, Clone)]
-pub struct MacroRule {
-    pub pattern: Vec<TokenTree>,
-    pub replacement: Vec<TokenTree>,
+pub struct MacroRule<'a> {
+    pub pattern: Vec<TokenTree>,     // Raw matcher tokens
+    pub replacement: Vec<TokenTree>, // Raw transcriber tokens
+    pub matcher: &'a [MacroMatcher<'a>],
+    pub transcriber: &'a [MacroTranscriber<'a>],
     pub span: Span,
 }
 
-impl Statement {
+/// Matcher side of a macro rule
+#[derive(Debug, Clone)]
+pub enum MacroMatcher<'a> {
+    /// Token matched literally
+    Token(Token),
+    /// Delimited group matched with the same delimiter
+    Group {
+        delimiter: GroupDelimiter,
+        matchers: &'a [MacroMatcher<'a>],
+        span: Span,
+    },
+    /// Metavariable binding: `$x:expr`
+    Fragment {
+        name: Symbol,
+        kind: FragmentKind,
+        span: Span,
+    },
+    /// Repetition: `$($x:expr),*`
+    Repetition {
+        matchers: &'a [MacroMatcher<'a>],
+        separator: Option<Token>,
+        kind: RepetitionKind,
+        span: Span,
+    },
+}
+
+/// Transcriber side of a macro rule
+#[derive(Debug, Clone)]
+pub enum MacroTranscriber<'a> {
+    /// Token emitted as-is
+    Token(Token),
+    /// Delimited group emitted with the same delimiter
+    Group {
+        delimiter: GroupDelimiter,
+        transcribers: &'a [MacroTranscriber<'a>],
+        span: Span,
+    },
+    /// Metavariable substitution: `$x`
+    Variable { name: Symbol, span: Span },
+    /// Repetition: `$($x),*`
+    Repetition {
+        transcribers: &'a [MacroTranscriber<'a>],
+        separator: Option<Token>,
+        kind: RepetitionKind,
+        span: Span,
+    },
+}
+
+/// Fragment specifier of a metavariable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentKind {
+    Expr,  // expression
+    Ident, // identifier
+    Ty,    // type
+    Pat,   // pattern
+    Block, // braced block
+    Tt,    // single token tree
+}
+
+impl FragmentKind {
+    /// Look up a fragment specifier by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "expr" => Some(FragmentKind::Expr),
+            "ident" => Some(FragmentKind::Ident),
+            "ty" => Some(FragmentKind::Ty),
+            "pat" => Some(FragmentKind::Pat),
+            "block" => Some(FragmentKind::Block),
+            "tt" => Some(FragmentKind::Tt),
+            _ => None,
+        }
+    }
+}
+
+/// Repetition operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepetitionKind {
+    ZeroOrMore, // *
+    OneOrMore,  // +
+    ZeroOrOne,  // ?
+}
+
+impl<'a> Statement<'a> {
     /// Get the span of this statement
     pub fn span(&self) -> Span {
         match self {
@@ -584,17 +1110,52 @@ impl Statement {
             Statement::Break(break_stmt) => break_stmt.span.clone(),
             Statement::Continue(continue_stmt) => continue_stmt.span.clone(),
             Statement::Block(block) => block.span.clone(),
+            Statement::Attributed(attributed) => attributed.span.clone(),
+        }
+    }
+
+    /// Attach outer attributes, wrapping the statement when any are present
+    pub fn with_attributes(
+        self,
+        arena: &'a Arena,
+        attributes: &'a [Attribute<'a>],
+    ) -> Statement<'a> {
+        match attributes.first() {
+            Some(first) => {
+                // Literal and identifier statements carry dummy spans
+                let statement_span = self.span();
+                let span = if statement_span.end > first.span.end {
+                    first.span.clone().combine(statement_span)
+                } else {
+                    first.span.clone()
+                };
+                Statement::Attributed(AttributedStatement {
+                    attributes,
+                    statement: arena.alloc(self),
+                    span,
+                })
+            }
+            None => self,
+        }
+    }
+
+    /// Outer attributes attached to this statement
+    pub fn attributes(&self) -> &'a [Attribute<'a>] {
+        match self {
+            Statement::VariableDecl(var_decl) => var_decl.attributes,
+            Statement::Attributed(attributed) => attributed.attributes,
+            _ => &[],
         }
     }
 }
 
-impl Expression {
+impl<'a> Expression<'a> {
     /// Get the span of this expression
     pub fn span(&self) -> Span {
         match self {
             Expression::Literal(_) => Span::dummy(), // Literals would have their own spans
             Expression::Identifier(_) => Span::dummy(),
-            Expression::QualifiedIdentifier(qi) => qi.span.clone(),
+            Expression::Path(path) => path.span.clone(),
             Expression::Binary(binary) => binary.span.clone(),
             Expression::Unary(unary) => unary.span.clone(),
             Expression::Call(call) => call.span.clone(),
@@ -608,11 +1169,14 @@ impl Expression {
             Expression::Grouped(_) => Span::dummy(),
             Expression::Block(block_expr) => block_expr.span.clone(),
             Expression::Macro(macro_invocation) => macro_invocation.span.clone(),
+            Expression::BuiltinMacro(builtin) => builtin.span.clone(),
+            Expression::DataClassLiteral(literal) => literal.span.clone(),
+            Expression::Interpolated(interpolated) => interpolated.span.clone(),
         }
     }
 }
 
-impl Pattern {
+impl<'a> Pattern<'a> {
     /// Get the span for this pattern
     pub fn span(&self) -> Span {
         match self {
@@ -620,7 +1184,13 @@ impl Pattern {
                 Literal::String(_)
                 | Literal::Integer(_)
                 | Literal::Float(_)
-                | Literal::Boolean(_) => {
+                | Literal::Boolean(_)
+                | Literal::Char(_)
+                | Literal::Byte(_)
+                | Literal::ByteString(_)
+                | Literal::WideInteger(_)
+                | Literal::TypedInteger { .. }
+                | Literal::TypedFloat { .. } => {
                     // For literals, we'd need to track spans better - for now return dummy
                     Span::dummy()
                 }
@@ -633,6 +1203,8 @@ impl Pattern {
             Pattern::Or(o) => o.span.clone(),
             Pattern::Guard(g) => g.span.clone(),
             Pattern::Binding(b) => b.span.clone(),
+            Pattern::Path(p) => p.span.clone(),
+            Pattern::Macro(m) => m.span.clone(),
         }
     }
 }
@@ -0,0 +1,1216 @@
+//! Pretty-printer that renders AST nodes back into Ferra source
+//!
+//! Output is normalized rather than a copy of the original layout: blocks are
+//! always braced and indented by four spaces, and parentheses are added only
+//! where precedence requires them. Names that would lex as keywords are
+//! re-emitted as raw identifiers (`r#match`), so printed code parses back to
+//! the same tree.
+
+use super::*;
+use crate::{
+    pratt::precedence::infix_binding_power,
+    token::{Token, TokenType},
+};
+use std::fmt::Write;
+
+const INDENT: &str = "    ";
+
+/// Render a name, adding the `r#` prefix when it would otherwise be a keyword
+pub fn print_identifier(name: &str) -> String {
+    if ferra_lexer::keyword_kind(name).is_some() || ferra_lexer::is_reserved_keyword(name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Render a compilation unit, one item per paragraph
+pub fn print_compilation_unit(unit: &CompilationUnit) -> String {
+    let mut printer = Printer::default();
+    printer.compilation_unit(unit);
+    printer.out
+}
+
+/// Render a single item
+pub fn print_item(item: &Item) -> String {
+    let mut printer = Printer::default();
+    printer.item(item);
+    printer.out
+}
+
+/// Render a single statement
+pub fn print_statement(statement: &Statement) -> String {
+    let mut printer = Printer::default();
+    printer.statement(statement);
+    printer.out
+}
+
+/// Render an expression on one line, except for any blocks it contains
+pub fn print_expression(expr: &Expression) -> String {
+    let mut printer = Printer::default();
+    printer.expression(expr);
+    printer.out
+}
+
+/// Render a pattern
+pub fn print_pattern(pattern: &Pattern) -> String {
+    let mut printer = Printer::default();
+    printer.pattern(pattern);
+    printer.out
+}
+
+/// Render a type
+pub fn print_type(ty: &Type) -> String {
+    let mut printer = Printer::default();
+    printer.ty(ty);
+    printer.out
+}
+
+#[derive(Default)]
+struct Printer {
+    out: String,
+    indent: usize,
+}
+
+impl Printer {
+    fn push(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    fn ident(&mut self, name: &str) {
+        self.out.push_str(&print_identifier(name));
+    }
+
+    /// Start a new line at the current indentation
+    fn newline(&mut self) {
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str(INDENT);
+        }
+    }
+
+    fn comma_separated<T>(&mut self, nodes: &[T], mut print: impl FnMut(&mut Self, &T)) {
+        for (i, node) in nodes.iter().enumerate() {
+            if i > 0 {
+                self.push(", ");
+            }
+            print(self, node);
+        }
+    }
+
+    // Items
+
+    fn compilation_unit(&mut self, unit: &CompilationUnit) {
+        for attribute in unit.attributes {
+            self.attribute(attribute);
+            self.newline();
+        }
+        for (i, item) in unit.items.iter().enumerate() {
+            if i > 0 || !unit.attributes.is_empty() {
+                self.newline();
+            }
+            self.item(item);
+            self.newline();
+        }
+    }
+
+    fn attributes(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            self.attribute(attribute);
+            self.newline();
+        }
+    }
+
+    fn attribute(&mut self, attribute: &Attribute) {
+        self.push(if attribute.is_inner { "#![" } else { "#[" });
+        for (i, segment) in attribute.name.segments.iter().enumerate() {
+            if i > 0 {
+                self.push("::");
+            }
+            self.ident(segment);
+        }
+        if !attribute.arguments.is_empty() {
+            self.push("(");
+            self.comma_separated(attribute.arguments, Self::attribute_argument);
+            self.push(")");
+        }
+        if let Some(value) = &attribute.value {
+            self.push(" = ");
+            self.expression(value);
+        }
+        self.push("]");
+    }
+
+    fn attribute_argument(&mut self, argument: &AttributeArgument) {
+        match argument {
+            AttributeArgument::Expression { value, .. } => self.expression(value),
+            AttributeArgument::KeyValue { key, value, .. } => {
+                self.ident(key);
+                self.push(" = ");
+                self.expression(value);
+            }
+            AttributeArgument::Nested {
+                name, arguments, ..
+            } => {
+                self.ident(name);
+                self.push("(");
+                self.comma_separated(arguments, Self::attribute_argument);
+                self.push(")");
+            }
+        }
+    }
+
+    fn modifiers(&mut self, modifiers: &Modifiers) {
+        if modifiers.is_public {
+            self.push("pub ");
+        }
+        if modifiers.is_unsafe {
+            self.push("unsafe ");
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        match item {
+            Item::FunctionDecl(function) => self.function(function),
+            Item::VariableDecl(variable) => self.variable(variable),
+            Item::DataClassDecl(data_class) => {
+                self.attributes(data_class.attributes);
+                self.push("data ");
+                self.ident(&data_class.name);
+                self.generics(data_class.generics.as_ref());
+                self.push(" {");
+                self.indent += 1;
+                for field in data_class.fields {
+                    self.newline();
+                    self.attributes_inline(field.attributes);
+                    self.ident(&field.name);
+                    self.push(": ");
+                    self.ty(field.field_type);
+                    self.push(",");
+                }
+                self.indent -= 1;
+                if !data_class.fields.is_empty() {
+                    self.newline();
+                }
+                self.push("}");
+            }
+            Item::ExternBlock(extern_block) => {
+                self.attributes(extern_block.attributes);
+                self.push("extern ");
+                self.string_literal(&extern_block.abi);
+                self.push(" {");
+                self.indent += 1;
+                for item in extern_block.items {
+                    self.newline();
+                    self.extern_item(item);
+                }
+                self.indent -= 1;
+                if !extern_block.items.is_empty() {
+                    self.newline();
+                }
+                self.push("}");
+            }
+            Item::MacroDefinition(macro_def) => {
+                self.attributes(macro_def.attributes);
+                self.push("macro ");
+                self.ident(&macro_def.name);
+                self.push(" {");
+                self.indent += 1;
+                for (i, rule) in macro_def.rules.iter().enumerate() {
+                    self.newline();
+                    self.token_trees(&rule.pattern);
+                    self.push(" => ");
+                    self.token_trees(&rule.replacement);
+                    if i + 1 < macro_def.rules.len() {
+                        self.push(";");
+                    }
+                }
+                self.indent -= 1;
+                self.newline();
+                self.push("}");
+            }
+            Item::Macro(macro_item) => {
+                self.attributes(macro_item.attributes);
+                self.macro_invocation(&macro_item.invocation);
+                if macro_item.invocation.delimiter() != Some(GroupDelimiter::Braces) {
+                    self.push(";");
+                }
+            }
+        }
+    }
+
+    /// Attributes on fields and parameters stay on the same line
+    fn attributes_inline(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            self.attribute(attribute);
+            self.push(" ");
+        }
+    }
+
+    fn function(&mut self, function: &FunctionDecl) {
+        self.attributes(function.attributes);
+        self.modifiers(&function.modifiers);
+        if function.is_async {
+            self.push("async ");
+        }
+        if function.is_extern {
+            self.push("extern ");
+            if let Some(abi) = &function.abi {
+                self.string_literal(abi);
+                self.push(" ");
+            }
+        }
+        self.push("fn ");
+        self.ident(&function.name);
+        self.generics(function.generics.as_ref());
+        self.parameters(function.parameters);
+        if let Some(return_type) = &function.return_type {
+            self.push(" -> ");
+            self.ty(return_type);
+        }
+        self.where_clause(function.generics.as_ref());
+        match &function.body {
+            Some(body) => {
+                self.push(" ");
+                self.block(body);
+            }
+            None => self.push(";"),
+        }
+    }
+
+    fn parameters(&mut self, parameters: &[Parameter]) {
+        self.push("(");
+        self.comma_separated(parameters, |printer, parameter| {
+            printer.attributes_inline(parameter.attributes);
+            printer.ident(&parameter.name);
+            printer.push(": ");
+            printer.ty(parameter.param_type);
+        });
+        self.push(")");
+    }
+
+    fn variable(&mut self, variable: &VariableDecl) {
+        self.attributes(variable.attributes);
+        self.modifiers(&variable.modifiers);
+        self.push(if variable.is_mutable { "var " } else { "let " });
+        self.ident(&variable.name);
+        if let Some(var_type) = &variable.var_type {
+            self.push(": ");
+            self.ty(var_type);
+        }
+        if let Some(initializer) = &variable.initializer {
+            self.push(" = ");
+            self.expression(initializer);
+        }
+        self.push(";");
+    }
+
+    fn extern_item(&mut self, item: &ExternItem) {
+        match item {
+            ExternItem::Function(function) => {
+                self.attributes(function.attributes);
+                self.push("fn ");
+                self.ident(&function.name);
+                self.parameters(function.parameters);
+                if let Some(return_type) = &function.return_type {
+                    self.push(" -> ");
+                    self.ty(return_type);
+                }
+                self.push(";");
+            }
+            ExternItem::Variable(variable) => {
+                self.attributes(variable.attributes);
+                self.push("static ");
+                self.ident(&variable.name);
+                self.push(": ");
+                self.ty(variable.var_type);
+                self.push(";");
+            }
+        }
+    }
+
+    fn generics(&mut self, generics: Option<&GenericParams>) {
+        let Some(generics) = generics.filter(|generics| !generics.params.is_empty()) else {
+            return;
+        };
+        self.push("<");
+        self.comma_separated(generics.params, |printer, param| {
+            // Lifetime names keep their leading apostrophe
+            if param.is_lifetime {
+                printer.push(&param.name);
+            } else {
+                printer.ident(&param.name);
+            }
+            printer.bounds(param.bounds);
+            if let Some(default) = &param.default {
+                printer.push(" = ");
+                printer.ty(default);
+            }
+        });
+        self.push(">");
+    }
+
+    fn bounds(&mut self, bounds: &[TypeBound]) {
+        for (i, bound) in bounds.iter().enumerate() {
+            self.push(if i == 0 { ": " } else { " + " });
+            self.ident(&bound.trait_name);
+        }
+    }
+
+    fn where_clause(&mut self, generics: Option<&GenericParams>) {
+        let Some(where_clause) = generics.and_then(|generics| generics.where_clause.as_ref())
+        else {
+            return;
+        };
+        self.push(" where ");
+        self.comma_separated(where_clause.constraints, |printer, constraint| {
+            printer.ident(&constraint.type_name);
+            printer.bounds(constraint.bounds);
+        });
+    }
+
+    // Types and patterns
+
+    fn ty(&mut self, ty: &Type) {
+        match ty {
+            Type::Identifier(name) => self.ident(name),
+            Type::Path(path) => self.path(path, false),
+            Type::Tuple(elements) => {
+                self.push("(");
+                self.comma_separated(elements, Self::ty);
+                self.push(")");
+            }
+            Type::Array(element) => {
+                self.push("[");
+                self.ty(element);
+                self.push("]");
+            }
+            Type::Function(function) => {
+                if function.is_extern {
+                    self.push("extern ");
+                    if let Some(abi) = &function.abi {
+                        self.string_literal(abi);
+                        self.push(" ");
+                    }
+                }
+                self.push("fn(");
+                self.comma_separated(function.parameters, Self::ty);
+                self.push(") -> ");
+                self.ty(function.return_type);
+            }
+            Type::Pointer(pointer) => {
+                self.push("*");
+                self.ty(pointer.target);
+            }
+            Type::Macro(invocation) => self.macro_invocation(invocation),
+        }
+    }
+
+    fn pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Literal(literal) => self.literal(literal),
+            Pattern::Identifier(name) => self.ident(name),
+            Pattern::Wildcard => self.push("_"),
+            Pattern::Path(path) => self.path(path, true),
+            Pattern::Macro(invocation) => self.macro_invocation(invocation),
+            Pattern::DataClass(data_class) => {
+                self.path(&data_class.name, true);
+                if data_class.fields.is_empty() && !data_class.has_rest {
+                    return self.push(" {}");
+                }
+                self.push(" { ");
+                self.comma_separated(data_class.fields, |printer, field| {
+                    printer.ident(&field.name);
+                    if let Some(pattern) = &field.pattern {
+                        printer.push(": ");
+                        printer.pattern(pattern);
+                    }
+                });
+                if data_class.has_rest {
+                    self.push(if data_class.fields.is_empty() {
+                        ".."
+                    } else {
+                        ", .."
+                    });
+                }
+                self.push(" }");
+            }
+            Pattern::Range(range) => {
+                if let Some(start) = &range.start {
+                    self.pattern(start);
+                }
+                self.push(if range.inclusive { "..=" } else { ".." });
+                if let Some(end) = &range.end {
+                    self.pattern(end);
+                }
+            }
+            Pattern::Slice(slice) => {
+                self.push("[");
+                let mut elements: Vec<String> = slice.prefix.iter().map(print_pattern).collect();
+                match &slice.rest {
+                    Some(name) => elements.push(format!("{} @ ..", print_identifier(name))),
+                    // An anonymous rest is only recorded by its position
+                    None if !slice.suffix.is_empty() => elements.push("..".to_string()),
+                    None => {}
+                }
+                elements.extend(slice.suffix.iter().map(print_pattern));
+                self.push(&elements.join(", "));
+                self.push("]");
+            }
+            Pattern::Or(or) => {
+                for (i, alternative) in or.patterns.iter().enumerate() {
+                    if i > 0 {
+                        self.push(" | ");
+                    }
+                    self.pattern(alternative);
+                }
+            }
+            Pattern::Guard(guard) => {
+                self.pattern(guard.pattern);
+                self.push(" if ");
+                self.expression(guard.guard);
+            }
+            Pattern::Binding(binding) => {
+                self.ident(&binding.name);
+                self.push(" @ ");
+                self.pattern(binding.pattern);
+            }
+        }
+    }
+
+    // Statements
+
+    fn block(&mut self, block: &Block) {
+        self.block_with_value(block, None);
+    }
+
+    fn block_with_value(&mut self, block: &Block, value: Option<&Expression>) {
+        if let Some(label) = &block.label {
+            if !label.starts_with('\'') {
+                self.push("'");
+            }
+            self.push(label);
+            self.push(": ");
+        }
+        if block.is_unsafe {
+            self.push("unsafe ");
+        }
+        if block.is_async {
+            self.push("async ");
+        }
+        if block.is_try {
+            self.push("try ");
+        }
+        if block.statements.is_empty() && value.is_none() {
+            self.push("{}");
+            return;
+        }
+        self.push("{");
+        self.indent += 1;
+        for statement in block.statements {
+            self.newline();
+            self.statement(statement);
+        }
+        if let Some(value) = value {
+            self.newline();
+            self.expression(value);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.push("}");
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Expression(expr) => {
+                self.expression(expr);
+                if !matches!(expr, Expression::Block(_)) {
+                    self.push(";");
+                }
+            }
+            Statement::VariableDecl(variable) => self.variable(variable),
+            Statement::If(if_stmt) => {
+                self.push("if ");
+                self.expression(if_stmt.condition);
+                self.push(" ");
+                self.block(if_stmt.then_block);
+                if let Some(else_block) = &if_stmt.else_block {
+                    self.push(" else ");
+                    self.block(else_block);
+                }
+            }
+            Statement::While(while_stmt) => {
+                self.push("while ");
+                self.expression(while_stmt.condition);
+                self.push(" ");
+                self.block(while_stmt.body);
+            }
+            Statement::For(for_stmt) => {
+                self.push("for ");
+                self.ident(&for_stmt.variable);
+                self.push(" in ");
+                self.expression(for_stmt.iterable);
+                self.push(" ");
+                self.block(for_stmt.body);
+            }
+            Statement::Return(return_stmt) => {
+                self.push("return");
+                if let Some(value) = &return_stmt.value {
+                    self.push(" ");
+                    self.expression(value);
+                }
+                self.push(";");
+            }
+            Statement::Break(_) => self.push("break;"),
+            Statement::Continue(_) => self.push("continue;"),
+            Statement::Block(block) => self.block(block),
+            Statement::Attributed(attributed) => {
+                self.attributes(attributed.attributes);
+                self.statement(attributed.statement);
+            }
+        }
+    }
+
+    // Expressions
+
+    fn expression(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Literal(literal) => self.literal(literal),
+            Expression::Identifier(name) => self.ident(name),
+            Expression::Path(path) => self.path(path, true),
+            Expression::Binary(binary) => self.binary(binary),
+            Expression::Unary(unary) => match unary.operator {
+                UnaryOperator::Try => {
+                    self.postfix_operand(unary.operand);
+                    self.push("?");
+                }
+                UnaryOperator::Not | UnaryOperator::Minus | UnaryOperator::Plus => {
+                    self.push(match unary.operator {
+                        UnaryOperator::Not => "!",
+                        UnaryOperator::Minus => "-",
+                        _ => "+",
+                    });
+                    if matches!(unary.operand, Expression::Binary(_)) {
+                        self.parenthesized(unary.operand);
+                    } else {
+                        self.expression(unary.operand);
+                    }
+                }
+            },
+            Expression::Call(call) => {
+                self.postfix_operand(call.callee);
+                self.push("(");
+                self.comma_separated(call.arguments, Self::expression);
+                self.push(")");
+            }
+            Expression::MemberAccess(member) => {
+                self.postfix_operand(member.object);
+                self.push(".");
+                self.ident(&member.member);
+            }
+            Expression::Index(index) => {
+                self.postfix_operand(index.object);
+                self.push("[");
+                self.expression(index.index);
+                self.push("]");
+            }
+            Expression::Await(await_expr) => {
+                self.postfix_operand(await_expr.expression);
+                self.push(".await");
+            }
+            Expression::Array(array) => {
+                self.push("[");
+                self.comma_separated(array.elements, Self::expression);
+                self.push("]");
+            }
+            Expression::Tuple(tuple) => {
+                self.push("(");
+                self.comma_separated(tuple.elements, Self::expression);
+                if tuple.elements.len() == 1 {
+                    self.push(",");
+                }
+                self.push(")");
+            }
+            Expression::If(if_expr) => {
+                self.push("if ");
+                self.expression(if_expr.condition);
+                self.push(" ");
+                self.braced(if_expr.then_expr);
+                if let Some(else_expr) = &if_expr.else_expr {
+                    self.push(" else ");
+                    match else_expr {
+                        Expression::If(_) => self.expression(else_expr),
+                        _ => self.braced(else_expr),
+                    }
+                }
+            }
+            Expression::Match(match_expr) => {
+                self.push("match ");
+                self.expression(match_expr.scrutinee);
+                self.push(" {");
+                self.indent += 1;
+                for arm in match_expr.arms {
+                    self.newline();
+                    self.attributes_inline(arm.attributes);
+                    self.pattern(arm.pattern);
+                    self.push(" => ");
+                    self.expression(arm.expression);
+                    self.push(",");
+                }
+                self.indent -= 1;
+                self.newline();
+                self.push("}");
+            }
+            Expression::Grouped(inner) => self.parenthesized(inner),
+            Expression::Block(block_expr) => {
+                self.attributes_inline(block_expr.attributes);
+                self.block_with_value(block_expr.block, block_expr.value);
+            }
+            Expression::Macro(invocation) => self.macro_invocation(invocation),
+            Expression::BuiltinMacro(builtin) => self.builtin_macro(builtin),
+            Expression::DataClassLiteral(literal) => {
+                self.path(&literal.path, true);
+                self.push(" { ");
+                self.comma_separated(literal.fields, |printer, field| {
+                    printer.ident(&field.name);
+                    if !field.is_shorthand {
+                        printer.push(": ");
+                        printer.expression(field.value);
+                    }
+                });
+                self.push(" }");
+            }
+            Expression::Interpolated(interpolated) => {
+                self.push("f\"");
+                for part in interpolated.parts {
+                    match part {
+                        InterpolatedPart::Text(text) => self.push(&escape_string(text, true)),
+                        InterpolatedPart::Expression { expression, .. } => {
+                            self.push("{");
+                            self.expression(expression);
+                            self.push("}");
+                        }
+                    }
+                }
+                self.push("\"");
+            }
+        }
+    }
+
+    fn parenthesized(&mut self, expr: &Expression) {
+        self.push("(");
+        self.expression(expr);
+        self.push(")");
+    }
+
+    /// Print a branch of an `if` expression inside braces
+    fn braced(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Block(_) => self.expression(expr),
+            _ => {
+                self.push("{ ");
+                self.expression(expr);
+                self.push(" }");
+            }
+        }
+    }
+
+    /// Operand of a call, member access, index, `?` or `.await`
+    fn postfix_operand(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Binary(_) | Expression::Unary(_) | Expression::If(_) => {
+                // `x?` is itself postfix and needs no parentheses
+                if let Expression::Unary(unary) = expr {
+                    if matches!(unary.operator, UnaryOperator::Try) {
+                        return self.expression(expr);
+                    }
+                }
+                self.parenthesized(expr)
+            }
+            _ => self.expression(expr),
+        }
+    }
+
+    fn binary(&mut self, binary: &BinaryExpression) {
+        let (power, right_assoc) = binary_power(&binary.operator);
+        let operand = |printer: &mut Self, expr: &Expression, is_right: bool| {
+            let needs_parens = match expr {
+                Expression::Binary(inner) => {
+                    let (inner_power, _) = binary_power(&inner.operator);
+                    inner_power < power || (inner_power == power && is_right != right_assoc)
+                }
+                _ => false,
+            };
+            if needs_parens {
+                printer.parenthesized(expr);
+            } else {
+                printer.expression(expr);
+            }
+        };
+        operand(self, binary.left, false);
+        self.push(" ");
+        self.push(binary_symbol(&binary.operator));
+        self.push(" ");
+        operand(self, binary.right, true);
+    }
+
+    fn path(&mut self, path: &Path, in_expression: bool) {
+        for (i, segment) in path.segments.iter().enumerate() {
+            if i > 0 {
+                self.push("::");
+            }
+            self.ident(&segment.name);
+            if !segment.generic_args.is_empty() {
+                // Expression paths take turbofish arguments: parse::<Int>
+                if in_expression {
+                    self.push("::");
+                }
+                self.push("<");
+                self.comma_separated(segment.generic_args, Self::ty);
+                self.push(">");
+            }
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::String(value) => self.string_literal(value),
+            Literal::Integer(value) => self.push(&value.to_string()),
+            Literal::Float(value) => self.push(&format!("{:?}", value)),
+            Literal::Boolean(value) => self.push(if *value { "true" } else { "false" }),
+            Literal::Char(value) => {
+                self.push("'");
+                self.push(&escape_char(*value));
+                self.push("'");
+            }
+            Literal::Byte(value) => {
+                self.push("b'");
+                self.push(&escape_byte(*value, b'\''));
+                self.push("'");
+            }
+            Literal::ByteString(bytes) => {
+                self.push("b\"");
+                for byte in bytes {
+                    self.push(&escape_byte(*byte, b'"'));
+                }
+                self.push("\"");
+            }
+            Literal::WideInteger(value) => {
+                self.push(match value.radix {
+                    16 => "0x",
+                    8 => "0o",
+                    2 => "0b",
+                    _ => "",
+                });
+                self.push(&value.digits);
+            }
+            Literal::TypedInteger { value, suffix } => {
+                self.push(&format!("{}{}", value, suffix.name()));
+            }
+            Literal::TypedFloat { value, suffix } => {
+                self.push(&format!("{:?}{}", value, suffix.name()));
+            }
+        }
+    }
+
+    fn string_literal(&mut self, value: &str) {
+        self.push("\"");
+        self.push(&escape_string(value, false));
+        self.push("\"");
+    }
+
+    fn builtin_macro(&mut self, builtin: &BuiltinMacro) {
+        self.ident(&builtin.name);
+        match &builtin.kind {
+            BuiltinMacroKind::Print { format, .. } | BuiltinMacroKind::Format(format) => {
+                self.push("!(");
+                self.format_args(format);
+                self.push(")");
+            }
+            BuiltinMacroKind::Vec(elements) => {
+                self.push("![");
+                self.comma_separated(elements, Self::expression);
+                self.push("]");
+            }
+            BuiltinMacroKind::VecRepeat { value, count } => {
+                self.push("![");
+                self.expression(value);
+                self.push("; ");
+                self.expression(count);
+                self.push("]");
+            }
+            BuiltinMacroKind::Assert { condition, message } => {
+                self.push("!(");
+                self.expression(condition);
+                if let Some(message) = message {
+                    self.push(", ");
+                    self.format_args(message);
+                }
+                self.push(")");
+            }
+            BuiltinMacroKind::AssertEq {
+                left,
+                right,
+                message,
+            } => {
+                self.push("!(");
+                self.expression(left);
+                self.push(", ");
+                self.expression(right);
+                if let Some(message) = message {
+                    self.push(", ");
+                    self.format_args(message);
+                }
+                self.push(")");
+            }
+        }
+    }
+
+    fn format_args(&mut self, format: &FormatArgs) {
+        self.push("\"");
+        for piece in &format.template.pieces {
+            match piece {
+                FormatPiece::Text(text) => self.push(&escape_string(text, true)),
+                FormatPiece::Placeholder(placeholder) => {
+                    self.push("{");
+                    match &placeholder.argument {
+                        FormatArgumentRef::Index(index) => self.push(&index.to_string()),
+                        FormatArgumentRef::Named(name) | FormatArgumentRef::Captured(name) => {
+                            self.push(name)
+                        }
+                    }
+                    if let Some(spec) = &placeholder.spec {
+                        self.push(":");
+                        self.push(spec);
+                    }
+                    self.push("}");
+                }
+            }
+        }
+        self.push("\"");
+        for argument in format.arguments {
+            self.push(", ");
+            if let Some(name) = &argument.name {
+                self.ident(name);
+                self.push(" = ");
+            }
+            self.expression(argument.value);
+        }
+    }
+
+    fn macro_invocation(&mut self, invocation: &MacroInvocation) {
+        self.ident(&invocation.name);
+        self.push("!");
+        if invocation.delimiter().is_none() {
+            self.push("(");
+            self.token_trees(&invocation.arguments);
+            self.push(")");
+        } else {
+            self.token_trees(&invocation.arguments);
+        }
+    }
+
+    // Token trees
+
+    fn token_trees(&mut self, trees: &[TokenTree]) {
+        let mut previous: Option<&TokenType> = None;
+        for tree in trees {
+            let current = match tree {
+                TokenTree::Token(token) => Some(&token.token_type),
+                TokenTree::Group(_) => None,
+            };
+            if needs_space(previous, tree) {
+                self.push(" ");
+            }
+            match tree {
+                TokenTree::Token(token) => self.token(token),
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter {
+                        GroupDelimiter::Parentheses => ("(", ")"),
+                        GroupDelimiter::Brackets => ("[", "]"),
+                        GroupDelimiter::Braces => ("{ ", " }"),
+                    };
+                    if group.tokens.is_empty() {
+                        self.push(open.trim_end());
+                        self.push(close.trim_start());
+                    } else {
+                        self.push(open);
+                        self.token_trees(&group.tokens);
+                        self.push(close);
+                    }
+                }
+            }
+            previous = current;
+        }
+    }
+
+    fn token(&mut self, token: &Token) {
+        let text = match &token.token_type {
+            TokenType::StringLiteral(value) => return self.string_literal(value),
+            TokenType::IntegerLiteral(value) => return self.literal(&Literal::Integer(*value)),
+            TokenType::FloatLiteral(value) => return self.literal(&Literal::Float(*value)),
+            TokenType::BooleanLiteral(value) => return self.literal(&Literal::Boolean(*value)),
+            TokenType::CharLiteral(value) => return self.literal(&Literal::Char(*value)),
+            TokenType::ByteLiteral(value) => return self.literal(&Literal::Byte(*value)),
+            TokenType::ByteStringLiteral(bytes) => {
+                return self.literal(&Literal::ByteString(bytes.clone()))
+            }
+            TokenType::WideIntegerLiteral(value) => {
+                return self.literal(&Literal::WideInteger(value.clone()))
+            }
+            TokenType::TypedIntegerLiteral(value, suffix) => {
+                return self.literal(&Literal::TypedInteger {
+                    value: *value,
+                    suffix: *suffix,
+                })
+            }
+            TokenType::TypedFloatLiteral(value, suffix) => {
+                return self.literal(&Literal::TypedFloat {
+                    value: *value,
+                    suffix: *suffix,
+                })
+            }
+            TokenType::InterpolatedStringLiteral(segments) => {
+                self.push("f\"");
+                for segment in segments {
+                    match segment {
+                        crate::token::StringSegment::Text(text) => {
+                            self.push(&escape_string(text, true))
+                        }
+                        crate::token::StringSegment::Expression(tokens, _) => {
+                            self.push("{");
+                            let trees: Vec<_> =
+                                tokens.iter().cloned().map(TokenTree::Token).collect();
+                            self.token_trees(&trees);
+                            self.push("}");
+                        }
+                    }
+                }
+                return self.push("\"");
+            }
+            TokenType::Identifier(name) => return self.ident(name),
+            other => token_symbol(other),
+        };
+        self.push(text);
+    }
+}
+
+/// Binding power of a binary operator and whether it is right associative
+fn binary_power(operator: &BinaryOperator) -> (u8, bool) {
+    let token_type = match operator {
+        BinaryOperator::Add => TokenType::Plus,
+        BinaryOperator::Sub => TokenType::Minus,
+        BinaryOperator::Mul => TokenType::Star,
+        BinaryOperator::Div => TokenType::Slash,
+        BinaryOperator::Mod => TokenType::Percent,
+        BinaryOperator::Equal => TokenType::EqualEqual,
+        BinaryOperator::NotEqual => TokenType::BangEqual,
+        BinaryOperator::Less => TokenType::Less,
+        BinaryOperator::LessEqual => TokenType::LessEqual,
+        BinaryOperator::Greater => TokenType::Greater,
+        BinaryOperator::GreaterEqual => TokenType::GreaterEqual,
+        BinaryOperator::And => TokenType::AmpAmp,
+        BinaryOperator::Or => TokenType::PipePipe,
+        BinaryOperator::NullCoalesce => TokenType::QuestionQuestion,
+        BinaryOperator::Assign => TokenType::Equal,
+        BinaryOperator::AddAssign => TokenType::PlusEqual,
+        BinaryOperator::SubAssign => TokenType::MinusEqual,
+        BinaryOperator::MulAssign => TokenType::StarEqual,
+        BinaryOperator::DivAssign => TokenType::SlashEqual,
+    };
+    match infix_binding_power(&token_type) {
+        Some(info) => (
+            info.binding_power,
+            matches!(
+                info.associativity,
+                crate::pratt::precedence::Associativity::Right
+            ),
+        ),
+        None => (0, false),
+    }
+}
+
+fn binary_symbol(operator: &BinaryOperator) -> &'static str {
+    match operator {
+        BinaryOperator::Add => "+",
+        BinaryOperator::Sub => "-",
+        BinaryOperator::Mul => "*",
+        BinaryOperator::Div => "/",
+        BinaryOperator::Mod => "%",
+        BinaryOperator::Equal => "==",
+        BinaryOperator::NotEqual => "!=",
+        BinaryOperator::Less => "<",
+        BinaryOperator::LessEqual => "<=",
+        BinaryOperator::Greater => ">",
+        BinaryOperator::GreaterEqual => ">=",
+        BinaryOperator::And => "&&",
+        BinaryOperator::Or => "||",
+        BinaryOperator::NullCoalesce => "??",
+        BinaryOperator::Assign => "=",
+        BinaryOperator::AddAssign => "+=",
+        BinaryOperator::SubAssign => "-=",
+        BinaryOperator::MulAssign => "*=",
+        BinaryOperator::DivAssign => "/=",
+    }
+}
+
+/// Source text of a keyword, operator or punctuation token
+fn token_symbol(token_type: &TokenType) -> &'static str {
+    match token_type {
+        TokenType::Let => "let",
+        TokenType::Var => "var",
+        TokenType::Fn => "fn",
+        TokenType::Async => "async",
+        TokenType::Data => "data",
+        TokenType::Match => "match",
+        TokenType::If => "if",
+        TokenType::Else => "else",
+        TokenType::While => "while",
+        TokenType::For => "for",
+        TokenType::In => "in",
+        TokenType::Return => "return",
+        TokenType::Break => "break",
+        TokenType::Continue => "continue",
+        TokenType::Extern => "extern",
+        TokenType::Static => "static",
+        TokenType::Pub => "pub",
+        TokenType::Unsafe => "unsafe",
+        TokenType::Where => "where",
+        TokenType::Macro => "macro",
+        TokenType::Plus => "+",
+        TokenType::Minus => "-",
+        TokenType::Star => "*",
+        TokenType::Slash => "/",
+        TokenType::Percent => "%",
+        TokenType::Equal => "=",
+        TokenType::EqualEqual => "==",
+        TokenType::BangEqual => "!=",
+        TokenType::Less => "<",
+        TokenType::LessEqual => "<=",
+        TokenType::Greater => ">",
+        TokenType::GreaterEqual => ">=",
+        TokenType::ShiftLeft => "<<",
+        TokenType::ShiftRight => ">>",
+        TokenType::ShiftLeftEqual => "<<=",
+        TokenType::ShiftRightEqual => ">>=",
+        TokenType::AmpAmp => "&&",
+        TokenType::PipePipe => "||",
+        TokenType::Bang => "!",
+        TokenType::Question => "?",
+        TokenType::QuestionQuestion => "??",
+        TokenType::Pipe => "|",
+        TokenType::PlusEqual => "+=",
+        TokenType::MinusEqual => "-=",
+        TokenType::StarEqual => "*=",
+        TokenType::SlashEqual => "/=",
+        TokenType::LeftParen => "(",
+        TokenType::RightParen => ")",
+        TokenType::LeftBracket => "[",
+        TokenType::RightBracket => "]",
+        TokenType::LeftBrace => "{",
+        TokenType::RightBrace => "}",
+        TokenType::Comma => ",",
+        TokenType::Dot => ".",
+        TokenType::DotDot => "..",
+        TokenType::DotDotEqual => "..=",
+        TokenType::Semicolon => ";",
+        TokenType::Colon => ":",
+        TokenType::DoubleColon => "::",
+        TokenType::Arrow => "->",
+        TokenType::FatArrow => "=>",
+        TokenType::Hash => "#",
+        TokenType::At => "@",
+        TokenType::Apostrophe => "'",
+        TokenType::Ampersand => "&",
+        TokenType::Dollar => "$",
+        TokenType::Newline => "\n",
+        TokenType::Indent | TokenType::Dedent | TokenType::Eof => "",
+        // Literals and identifiers are printed from their values
+        _ => "",
+    }
+}
+
+/// Whether a space separates `previous` from `tree` in printed token trees
+fn needs_space(previous: Option<&TokenType>, tree: &TokenTree) -> bool {
+    let Some(previous) = previous else {
+        return false;
+    };
+    if matches!(
+        previous,
+        TokenType::Dollar
+            | TokenType::Dot
+            | TokenType::DoubleColon
+            | TokenType::Hash
+            | TokenType::Bang
+            | TokenType::Apostrophe
+            | TokenType::Newline
+    ) {
+        return false;
+    }
+    match tree {
+        TokenTree::Token(token) => !matches!(
+            token.token_type,
+            TokenType::Comma
+                | TokenType::Semicolon
+                | TokenType::Colon
+                | TokenType::Dot
+                | TokenType::DoubleColon
+                | TokenType::Question
+                | TokenType::Bang
+        ),
+        // `name!(...)` and calls keep the group attached
+        TokenTree::Group(group) => !matches!(
+            (previous, group.delimiter),
+            (TokenType::Identifier(_), GroupDelimiter::Parentheses)
+                | (TokenType::Identifier(_), GroupDelimiter::Brackets)
+        ),
+    }
+}
+
+fn escape_string(value: &str, double_braces: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '{' | '}' if double_braces => {
+                out.push(c);
+                out.push(c);
+            }
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{{{:x}}}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_char(c: char) -> String {
+    match c {
+        '\n' => "\\n".to_string(),
+        '\t' => "\\t".to_string(),
+        '\r' => "\\r".to_string(),
+        '\0' => "\\0".to_string(),
+        '\\' => "\\\\".to_string(),
+        '\'' => "\\'".to_string(),
+        c if c.is_control() => format!("\\u{{{:x}}}", c as u32),
+        c => c.to_string(),
+    }
+}
+
+/// Escape a byte inside a byte or byte string literal closed by `quote`
+fn escape_byte(byte: u8, quote: u8) -> String {
+    match byte {
+        b'\n' => "\\n".to_string(),
+        b'\t' => "\\t".to_string(),
+        b'\r' => "\\r".to_string(),
+        b'\0' => "\\0".to_string(),
+        b'\\' => "\\\\".to_string(),
+        _ if byte == quote => format!("\\{}", byte as char),
+        0x20..=0x7e => (byte as char).to_string(),
+        _ => format!("\\x{:02x}", byte),
+    }
+}
@@ -3,80 +3,167 @@
 //! Supports parsing of Ferra attribute syntax:
 //! - #[derive(Debug, Clone)] - standard attributes with arguments
 //! - #[inline] - simple attributes without arguments
-//! - #[cfg(feature = "std")] - configuration attributes
+//! - #[cfg(feature = "std")] - key/value and nested arguments
+//! - #[ai.assume(nll = "noalias")] - dotted attribute paths
+//! - #[link_name = "puts"] - attribute-level values
+//! - #![allow(dead_code)] - inner attributes at the start of a file
 //! - @inline - alternative syntax (future support)
 
 use crate::{
-    ast::Attribute,
+    ast::{Arena, Attribute, AttributeArgument, AttributePath, Expression},
     error::{ParseError, ParseResult},
+    options::ParseContext,
+    pratt::PrattParser,
     token::{Span, TokenStream, TokenType},
 };
 
-/// Parse a list of attributes from token stream
-pub fn parse_attributes<T: TokenStream>(tokens: &mut T) -> ParseResult<Vec<Attribute>> {
-    let mut parser = AttributeParser::new(tokens);
+/// Parse a list of (outer) attributes from token stream
+pub fn parse_attributes<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Vec<Attribute<'a>>> {
+    let mut parser = AttributeParser::new(arena, tokens, ParseContext::default());
     parser.parse_attribute_list()
 }
 
+/// Parse outer attributes in the context of the parser they are part of
+pub(crate) fn parse_attributes_with_context<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+    context: ParseContext,
+) -> ParseResult<Vec<Attribute<'a>>> {
+    AttributeParser::new(arena, tokens, context).parse_attribute_list()
+}
+
+/// Parse the inner attributes `#![...]` that open a file
+pub fn parse_inner_attributes<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Vec<Attribute<'a>>> {
+    let mut parser = AttributeParser::new(arena, tokens, ParseContext::default());
+    parser.parse_inner_attribute_list()
+}
+
+/// Parse inner attributes in the context of the parser they are part of
+pub(crate) fn parse_inner_attributes_with_context<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+    context: ParseContext,
+) -> ParseResult<Vec<Attribute<'a>>> {
+    AttributeParser::new(arena, tokens, context).parse_inner_attribute_list()
+}
+
 /// Parse a single attribute from token stream
-pub fn parse_attribute<T: TokenStream>(tokens: &mut T) -> ParseResult<Attribute> {
-    let mut parser = AttributeParser::new(tokens);
+pub fn parse_attribute<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Attribute<'a>> {
+    let mut parser = AttributeParser::new(arena, tokens, ParseContext::default());
     parser.parse_attribute()
 }
 
 /// Comprehensive attribute parser for Phase 2.8.1
-struct AttributeParser<'a, T: TokenStream> {
-    tokens: &'a mut T,
+struct AttributeParser<'a, 't, T: TokenStream> {
+    arena: &'a Arena,
+    tokens: &'t mut T,
+    context: ParseContext,
 }
 
-impl<'a, T: TokenStream> AttributeParser<'a, T> {
-    fn new(tokens: &'a mut T) -> Self {
-        Self { tokens }
+impl<'a, 't, T: TokenStream> AttributeParser<'a, 't, T> {
+    fn new(arena: &'a Arena, tokens: &'t mut T, context: ParseContext) -> Self {
+        Self {
+            arena,
+            tokens,
+            context,
+        }
     }
 
-    /// Parse a list of consecutive attributes
-    fn parse_attribute_list(&mut self) -> ParseResult<Vec<Attribute>> {
+    /// Parse a list of consecutive attributes, each optionally followed by newlines
+    fn parse_attribute_list(&mut self) -> ParseResult<Vec<Attribute<'a>>> {
         let mut attributes = Vec::new();
 
         // Parse consecutive attributes
         while matches!(self.tokens.peek().token_type, TokenType::Hash) {
+            if self.at_inner_attribute() {
+                return Err(ParseError::syntax_error_with_suggestion(
+                    "inner attributes are only allowed at the start of a file",
+                    self.current_span(),
+                    "use an outer attribute `#[...]` to annotate the following item",
+                ));
+            }
             let attribute = self.parse_attribute()?;
             attributes.push(attribute);
+
+            while matches!(self.tokens.peek().token_type, TokenType::Newline) {
+                self.tokens.consume();
+            }
         }
 
         Ok(attributes)
     }
 
-    /// Parse a single attribute: #[name] or #[name(args)]
-    fn parse_attribute(&mut self) -> ParseResult<Attribute> {
-        let start_span = self.current_span();
+    /// Parse consecutive inner attributes, each optionally followed by newlines
+    fn parse_inner_attribute_list(&mut self) -> ParseResult<Vec<Attribute<'a>>> {
+        let mut attributes = Vec::new();
+
+        while self.at_inner_attribute() {
+            attributes.push(self.parse_attribute()?);
+
+            while matches!(self.tokens.peek().token_type, TokenType::Newline) {
+                self.tokens.consume();
+            }
+        }
 
+        Ok(attributes)
+    }
+
+    /// Check for `#!` introducing an inner attribute
+    fn at_inner_attribute(&self) -> bool {
+        matches!(self.tokens.peek().token_type, TokenType::Hash)
+            && matches!(
+                self.tokens.peek_ahead(1).map(|t| &t.token_type),
+                Some(TokenType::Bang)
+            )
+    }
+
+    /// Parse a single attribute: #[path], #[path(args)], #[path = value] or #![path(args)]
+    fn parse_attribute(&mut self) -> ParseResult<Attribute<'a>> {
         // Consume '#'
         let hash_token = self.tokens.consume();
         if !matches!(hash_token.token_type, TokenType::Hash) {
             return Err(ParseError::unexpected_token("'#'", &hash_token));
         }
 
+        // Inner attributes: #![...]
+        let is_inner = matches!(self.tokens.peek().token_type, TokenType::Bang);
+        if is_inner {
+            self.tokens.consume(); // consume '!'
+        }
+
         // Consume '['
         let open_bracket = self.tokens.consume();
         if !matches!(open_bracket.token_type, TokenType::LeftBracket) {
             return Err(ParseError::unexpected_token("'['", &open_bracket));
         }
 
-        // Parse attribute name
-        let name_token = self.tokens.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("attribute name", &name_token)),
-        };
+        let name = self.parse_attribute_path()?;
 
         // Parse optional arguments
         let arguments = if matches!(self.tokens.peek().token_type, TokenType::LeftParen) {
-            self.parse_attribute_arguments()?
+            self.parse_attribute_arguments()?.0
         } else {
-            Vec::new()
+            &[]
         };
 
+        // Parse optional attribute-level value: #[link_name = "puts"]
+        let value =
+            if arguments.is_empty() && matches!(self.tokens.peek().token_type, TokenType::Equal) {
+                self.tokens.consume(); // consume '='
+                Some(self.parse_expression()?)
+            } else {
+                None
+            };
+
         // Consume ']'
         let close_bracket = self.tokens.consume();
         if !matches!(close_bracket.token_type, TokenType::RightBracket) {
@@ -86,12 +173,44 @@ impl<'a, T: TokenStream> AttributeParser<'a, T> {
         Ok(Attribute {
             name,
             arguments,
-            span: start_span,
+            value,
+            is_inner,
+            span: hash_token.span.combine(close_bracket.span),
+        })
+    }
+
+    /// Parse a dotted attribute path: IDENTIFIER ("." IDENTIFIER)*
+    fn parse_attribute_path(&mut self) -> ParseResult<AttributePath<'a>> {
+        let mut segments = Vec::new();
+        let start_span = self.current_span();
+        let mut end_span = start_span.clone();
+
+        loop {
+            let name_token = self.tokens.consume();
+            match name_token.token_type {
+                TokenType::Identifier(name) => segments.push(name),
+                _ => return Err(ParseError::unexpected_token("attribute name", &name_token)),
+            }
+            end_span = end_span.combine(name_token.span);
+
+            if !matches!(self.tokens.peek().token_type, TokenType::Dot) {
+                break;
+            }
+            self.tokens.consume(); // consume '.'
+        }
+
+        Ok(AttributePath {
+            segments: self.arena.alloc_vec(segments),
+            span: start_span.combine(end_span),
         })
     }
 
-    /// Parse attribute arguments: (arg1, arg2, ...)
-    fn parse_attribute_arguments(&mut self) -> ParseResult<Vec<String>> {
+    /// Parse attribute arguments: (arg1, arg2, ...), returning them with the span
+    /// of the closing parenthesis
+    fn parse_attribute_arguments(&mut self) -> ParseResult<(&'a [AttributeArgument<'a>], Span)> {
+        // Nested lists recurse: #[cfg(not(not(...)))]
+        let _nesting = self.context.enter_nesting(&self.tokens.peek().span)?;
+
         // Consume '('
         let open_paren = self.tokens.consume();
         if !matches!(open_paren.token_type, TokenType::LeftParen) {
@@ -100,24 +219,14 @@ impl<'a, T: TokenStream> AttributeParser<'a, T> {
 
         let mut arguments = Vec::new();
 
-        // Handle empty argument list
-        if matches!(self.tokens.peek().token_type, TokenType::RightParen) {
-            self.tokens.consume(); // consume ')'
-            return Ok(arguments);
-        }
-
         // Parse argument list
-        loop {
+        while !matches!(self.tokens.peek().token_type, TokenType::RightParen) {
             let arg = self.parse_attribute_argument()?;
             arguments.push(arg);
 
             match self.tokens.peek().token_type {
                 TokenType::Comma => {
-                    self.tokens.consume(); // consume ','
-                                           // Allow trailing comma
-                    if matches!(self.tokens.peek().token_type, TokenType::RightParen) {
-                        break;
-                    }
+                    self.tokens.consume(); // consume ',' (trailing comma allowed)
                 }
                 TokenType::RightParen => break,
                 _ => {
@@ -135,101 +244,55 @@ impl<'a, T: TokenStream> AttributeParser<'a, T> {
             return Err(ParseError::unexpected_token("')'", &close_paren));
         }
 
-        Ok(arguments)
+        Ok((self.arena.alloc_vec(arguments), close_paren.span))
     }
 
-    /// Parse a single attribute argument (identifier, string, or simple expression)
-    fn parse_attribute_argument(&mut self) -> ParseResult<String> {
-        let mut result = String::new();
-        let mut paren_depth = 0;
-        let mut bracket_depth = 0;
-
-        // Parse complex expressions by collecting tokens until we hit a delimiter
-        loop {
-            let token = self.tokens.peek();
-
-            match &token.token_type {
-                TokenType::Comma if paren_depth == 0 && bracket_depth == 0 => break,
-                TokenType::RightParen if paren_depth == 0 => break,
-                TokenType::RightBracket if bracket_depth == 0 => break,
-                TokenType::Eof => break,
-
-                TokenType::LeftParen => {
-                    paren_depth += 1;
-                    result.push('(');
-                    self.tokens.consume();
-                }
-                TokenType::RightParen => {
-                    paren_depth -= 1;
-                    result.push(')');
-                    self.tokens.consume();
-                }
-                TokenType::LeftBracket => {
-                    bracket_depth += 1;
-                    result.push('[');
-                    self.tokens.consume();
-                }
-                TokenType::RightBracket => {
-                    bracket_depth -= 1;
-                    result.push(']');
-                    self.tokens.consume();
-                }
-
-                TokenType::Identifier(name) => {
-                    if !result.is_empty() {
-                        result.push(' ');
-                    }
-                    result.push_str(name);
-                    self.tokens.consume();
+    /// Parse a single attribute argument: `key = expr`, `name(args)` or an expression
+    fn parse_attribute_argument(&mut self) -> ParseResult<AttributeArgument<'a>> {
+        let start = self.tokens.peek().clone();
+
+        if let TokenType::Identifier(name) = &start.token_type {
+            match self.tokens.peek_ahead(1).map(|t| &t.token_type) {
+                Some(TokenType::Equal) => {
+                    let key = *name;
+                    self.tokens.consume(); // consume key
+                    self.tokens.consume(); // consume '='
+                    let value = self.parse_expression()?;
+                    return Ok(AttributeArgument::KeyValue {
+                        key,
+                        span: expression_span(start.span, value),
+                        value,
+                    });
                 }
-                TokenType::StringLiteral(s) => {
-                    if !result.is_empty() {
-                        result.push(' ');
-                    }
-                    result.push_str(&format!("\"{}\"", s));
-                    self.tokens.consume();
-                }
-                TokenType::IntegerLiteral(i) => {
-                    if !result.is_empty() {
-                        result.push(' ');
-                    }
-                    result.push_str(&i.to_string());
-                    self.tokens.consume();
-                }
-                TokenType::BooleanLiteral(b) => {
-                    if !result.is_empty() {
-                        result.push(' ');
-                    }
-                    result.push_str(&b.to_string());
-                    self.tokens.consume();
-                }
-                TokenType::Equal => {
-                    result.push_str(" = ");
-                    self.tokens.consume();
-                }
-                TokenType::Comma => {
-                    result.push_str(", ");
-                    self.tokens.consume();
-                }
-                _ => {
-                    // For any other token, just add it as a string representation
-                    if !result.is_empty() {
-                        result.push(' ');
-                    }
-                    result.push_str(&format!("{:?}", token.token_type));
-                    self.tokens.consume();
+                Some(TokenType::LeftParen) => {
+                    let name = *name;
+                    self.tokens.consume(); // consume name
+                    let (arguments, end_span) = self.parse_attribute_arguments()?;
+                    return Ok(AttributeArgument::Nested {
+                        name,
+                        arguments,
+                        span: start.span.combine(end_span),
+                    });
                 }
+                _ => {}
             }
         }
 
-        if result.is_empty() {
-            Err(ParseError::unexpected_token(
-                "attribute argument",
-                self.tokens.peek(),
-            ))
-        } else {
-            Ok(result.trim().to_string())
-        }
+        let value = self.parse_expression()?;
+        Ok(AttributeArgument::Expression {
+            span: expression_span(start.span, value),
+            value,
+        })
+    }
+
+    /// Parse an argument expression with the Pratt parser
+    ///
+    /// The stream is passed as a trait object: expressions may contain attributes
+    /// again (`#[cfg(x)] { ... }`), and a generic stream would nest `&mut` forever.
+    fn parse_expression(&mut self) -> ParseResult<&'a Expression<'a>> {
+        let tokens: &mut dyn TokenStream = &mut *self.tokens;
+        let mut parser = PrattParser::new(self.arena, tokens).with_context(self.context.clone());
+        parser.parse_expression(0)
     }
 
     /// Get current token span
@@ -238,10 +301,21 @@ impl<'a, T: TokenStream> AttributeParser<'a, T> {
     }
 }
 
+/// Span of an argument starting at `start`; literals and identifiers carry no
+/// span of their own, so fall back to the first token
+fn expression_span(start: Span, value: &Expression) -> Span {
+    let value_span = value.span();
+    if value_span.end > start.end {
+        start.combine(value_span)
+    } else {
+        start
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::token::VecTokenStream;
+    use crate::{ast::Literal, token::VecTokenStream};
 
     fn create_token_stream(token_types: Vec<TokenType>) -> VecTokenStream {
         VecTokenStream::from_token_types(token_types)
@@ -250,14 +324,15 @@ mod tests {
     #[test]
     fn test_simple_attribute() {
         // #[inline]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("inline".to_string()),
+            TokenType::Identifier("inline".into()),
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "inline");
         assert_eq!(result.arguments.len(), 0);
     }
@@ -265,93 +340,98 @@ mod tests {
     #[test]
     fn test_attribute_with_single_argument() {
         // #[cfg(test)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("cfg".to_string()),
+            TokenType::Identifier("cfg".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "cfg");
         assert_eq!(result.arguments.len(), 1);
-        assert_eq!(result.arguments[0], "test");
+        assert_eq!(result.arguments[0].as_ident(), Some("test"));
     }
 
     #[test]
     fn test_attribute_with_multiple_arguments() {
         // #[derive(Debug, Clone)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::Comma,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "derive");
         assert_eq!(result.arguments.len(), 2);
-        assert_eq!(result.arguments[0], "Debug");
-        assert_eq!(result.arguments[1], "Clone");
+        assert_eq!(result.arguments[0].as_ident(), Some("Debug"));
+        assert_eq!(result.arguments[1].as_ident(), Some("Clone"));
     }
 
     #[test]
     fn test_attribute_with_trailing_comma() {
         // #[derive(Debug, Clone,)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::Comma,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::Comma,
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "derive");
         assert_eq!(result.arguments.len(), 2);
-        assert_eq!(result.arguments[0], "Debug");
-        assert_eq!(result.arguments[1], "Clone");
+        assert_eq!(result.arguments[0].as_ident(), Some("Debug"));
+        assert_eq!(result.arguments[1].as_ident(), Some("Clone"));
     }
 
     #[test]
     fn test_attribute_with_string_argument() {
         // #[doc("This is documentation")]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("doc".to_string()),
+            TokenType::Identifier("doc".into()),
             TokenType::LeftParen,
             TokenType::StringLiteral("This is documentation".to_string()),
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "doc");
         assert_eq!(result.arguments.len(), 1);
-        assert_eq!(result.arguments[0], "\"This is documentation\"");
+        assert_eq!(result.arguments[0].as_str(), Some("This is documentation"));
     }
 
     #[test]
     fn test_attribute_with_mixed_arguments() {
         // #[test_attr("string", 42, true)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("test_attr".to_string()),
+            TokenType::Identifier("test_attr".into()),
             TokenType::LeftParen,
             TokenType::StringLiteral("string".to_string()),
             TokenType::Comma,
@@ -362,71 +442,88 @@ mod tests {
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "test_attr");
         assert_eq!(result.arguments.len(), 3);
-        assert_eq!(result.arguments[0], "\"string\"");
-        assert_eq!(result.arguments[1], "42");
-        assert_eq!(result.arguments[2], "true");
+        assert_eq!(result.arguments[0].as_str(), Some("string"));
+        assert!(matches!(
+            result.arguments[1],
+            AttributeArgument::Expression {
+                value: Expression::Literal(Literal::Integer(42)),
+                ..
+            }
+        ));
+        assert!(matches!(
+            result.arguments[2],
+            AttributeArgument::Expression {
+                value: Expression::Literal(Literal::Boolean(true)),
+                ..
+            }
+        ));
     }
 
     #[test]
     fn test_multiple_attributes() {
         // #[inline] #[derive(Debug)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("inline".to_string()),
+            TokenType::Identifier("inline".into()),
             TokenType::RightBracket,
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attributes(&mut tokens).unwrap();
+        let result = parse_attributes(&arena, &mut tokens).unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].name, "inline");
         assert_eq!(result[0].arguments.len(), 0);
         assert_eq!(result[1].name, "derive");
         assert_eq!(result[1].arguments.len(), 1);
-        assert_eq!(result[1].arguments[0], "Debug");
+        assert_eq!(result[1].arguments[0].as_ident(), Some("Debug"));
     }
 
     #[test]
     fn test_empty_attribute_list() {
         // No attributes, just some other token
-        let mut tokens = create_token_stream(vec![TokenType::Identifier("something".to_string())]);
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![TokenType::Identifier("something".into())]);
 
-        let result = parse_attributes(&mut tokens).unwrap();
+        let result = parse_attributes(&arena, &mut tokens).unwrap();
         assert_eq!(result.len(), 0);
     }
 
     #[test]
     fn test_attribute_parsing_errors() {
         // Missing opening bracket: #identifier
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
-            TokenType::Identifier("inline".to_string()),
+            TokenType::Identifier("inline".into()),
         ]);
 
-        let result = parse_attribute(&mut tokens);
+        let result = parse_attribute(&arena, &mut tokens);
         assert!(result.is_err());
 
         // Missing closing bracket: #[inline
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("inline".to_string()),
+            TokenType::Identifier("inline".into()),
         ]);
 
-        let result = parse_attribute(&mut tokens);
+        let result = parse_attribute(&arena, &mut tokens);
         assert!(result.is_err());
 
         // Invalid attribute name: #[123]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
@@ -434,35 +531,158 @@ mod tests {
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens);
+        let result = parse_attribute(&arena, &mut tokens);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_complex_derive_attribute() {
         // #[derive(Debug, Clone, PartialEq, Eq)]
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::Comma,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::Comma,
-            TokenType::Identifier("PartialEq".to_string()),
+            TokenType::Identifier("PartialEq".into()),
             TokenType::Comma,
-            TokenType::Identifier("Eq".to_string()),
+            TokenType::Identifier("Eq".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_attribute(&mut tokens).unwrap();
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
         assert_eq!(result.name, "derive");
         assert_eq!(result.arguments.len(), 4);
-        assert_eq!(result.arguments[0], "Debug");
-        assert_eq!(result.arguments[1], "Clone");
-        assert_eq!(result.arguments[2], "PartialEq");
-        assert_eq!(result.arguments[3], "Eq");
+        assert_eq!(result.arguments[0].as_ident(), Some("Debug"));
+        assert_eq!(result.arguments[1].as_ident(), Some("Clone"));
+        assert_eq!(result.arguments[2].as_ident(), Some("PartialEq"));
+        assert_eq!(result.arguments[3].as_ident(), Some("Eq"));
+    }
+
+    #[test]
+    fn test_dotted_path_with_key_value_argument() {
+        // #[ai.assume(nll = "noalias")]
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            TokenType::Identifier("ai".into()),
+            TokenType::Dot,
+            TokenType::Identifier("assume".into()),
+            TokenType::LeftParen,
+            TokenType::Identifier("nll".into()),
+            TokenType::Equal,
+            TokenType::StringLiteral("noalias".to_string()),
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ]);
+
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
+        assert_eq!(result.name, "ai.assume");
+        assert_eq!(result.name.segments, vec!["ai", "assume"]);
+        assert!(!result.is_inner);
+
+        let (key, value) = result.arguments[0].as_key_value().unwrap();
+        assert_eq!(key, "nll");
+        assert!(matches!(value, Expression::Literal(Literal::String(s)) if s == "noalias"));
+    }
+
+    #[test]
+    fn test_nested_attribute_arguments() {
+        // #[cfg(not(feature = "std"))]
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            TokenType::Identifier("cfg".into()),
+            TokenType::LeftParen,
+            TokenType::Identifier("not".into()),
+            TokenType::LeftParen,
+            TokenType::Identifier("feature".into()),
+            TokenType::Equal,
+            TokenType::StringLiteral("std".to_string()),
+            TokenType::RightParen,
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ]);
+
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
+        match &result.arguments[0] {
+            AttributeArgument::Nested {
+                name, arguments, ..
+            } => {
+                assert_eq!(name, "not");
+                assert_eq!(arguments[0].as_key_value().unwrap().0, "feature");
+            }
+            other => panic!("Expected nested argument, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_inner_attributes() {
+        // #![allow(dead_code)] followed by an outer attribute
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![
+            TokenType::Hash,
+            TokenType::Bang,
+            TokenType::LeftBracket,
+            TokenType::Identifier("allow".into()),
+            TokenType::LeftParen,
+            TokenType::Identifier("dead_code".into()),
+            TokenType::RightParen,
+            TokenType::RightBracket,
+            TokenType::Newline,
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            TokenType::Identifier("inline".into()),
+            TokenType::RightBracket,
+        ]);
+
+        let inner = parse_inner_attributes(&arena, &mut tokens).unwrap();
+        assert_eq!(inner.len(), 1);
+        assert!(inner[0].is_inner);
+        assert_eq!(inner[0].name, "allow");
+
+        let outer = parse_attributes(&arena, &mut tokens).unwrap();
+        assert_eq!(outer.len(), 1);
+        assert!(!outer[0].is_inner);
+    }
+
+    #[test]
+    fn test_inner_attribute_in_outer_position() {
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![
+            TokenType::Hash,
+            TokenType::Bang,
+            TokenType::LeftBracket,
+            TokenType::Identifier("allow".into()),
+            TokenType::RightBracket,
+        ]);
+
+        assert!(parse_attributes(&arena, &mut tokens).is_err());
+    }
+
+    #[test]
+    fn test_attribute_with_value() {
+        // #[link_name = "puts"]
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            TokenType::Identifier("link_name".into()),
+            TokenType::Equal,
+            TokenType::StringLiteral("puts".to_string()),
+            TokenType::RightBracket,
+        ]);
+
+        let result = parse_attribute(&arena, &mut tokens).unwrap();
+        assert_eq!(result.name, "link_name");
+        assert!(result.arguments.is_empty());
+        assert_eq!(result.value_str(), Some("puts"));
     }
 }
@@ -1,15 +1,15 @@
 //! Block parsing implementation for Phase 2.4
 //!
-//! Handles all block types: braced, indented, and advanced block features
+//! Handles all block types: braced, indented, and advanced block features.
+//! The statements inside a block are parsed by `StatementParser`, the same
+//! grammar used everywhere else.
 
 use crate::{
-    ast::{
-        Arena, Block, BreakStatement, ContinueStatement, Expression, ForStatement, IfStatement,
-        Literal, Modifiers, ReturnStatement, Statement, Type, VariableDecl, WhileStatement,
-    },
+    ast::{Arena, Block, Statement},
     error::{ParseError, ParseResult},
-    pratt::parser::PrattParser,
-    token::{Span, Token, TokenStream, TokenType},
+    options::{self, ParseContext, ParserOptions},
+    statement::StatementParser,
+    token::{Span, Symbol, Token, TokenStream, TokenType},
 };
 
 /// Block style enumeration for consistency
@@ -23,18 +23,22 @@ pub enum BlockStyle {
 #[derive(Debug, Clone)]
 pub struct ScopeInfo {
     pub depth: usize,
-    pub variables: Vec<String>,
+    pub variables: Vec<Symbol>,
     pub is_unsafe: bool,
     pub is_async: bool,
-    pub label: Option<String>,
+    pub label: Option<Symbol>,
 }
 
 /// Block parser with comprehensive scope and style management
+#[derive(Clone)]
 pub struct BlockParser<'arena> {
     arena: &'arena Arena,
     current_scope_depth: usize,
     _current_indentation: usize, // For future indentation tracking
     block_style: Option<BlockStyle>,
+    context: ParseContext,
+    /// Newlines skipped while `options.retain_trivia` is set
+    trivia: Vec<Span>,
 }
 
 impl<'arena> BlockParser<'arena> {
@@ -45,11 +49,52 @@ impl<'arena> BlockParser<'arena> {
             current_scope_depth: 0,
             _current_indentation: 0,
             block_style: None,
+            context: ParseContext::default(),
+            trivia: Vec::new(),
         }
     }
 
+    /// Parse with `options` instead of the defaults
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.context = ParseContext::new(options);
+        self
+    }
+
+    /// Share the options and nesting depth of the parser creating this one
+    pub(crate) fn with_context(mut self, context: ParseContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Spans of the newlines skipped so far, in source order, when parsing
+    /// with `retain_trivia`
+    pub fn trivia(&self) -> Vec<Span> {
+        options::source_order(self.trivia.clone())
+    }
+
+    /// The skipped newlines, for the parser that created this one
+    pub(crate) fn into_trivia(self) -> Vec<Span> {
+        self.trivia
+    }
+
+    /// Keep the newlines skipped by a parser this one created
+    pub(crate) fn absorb_trivia(&mut self, trivia: Vec<Span>) {
+        self.trivia.extend(trivia);
+    }
+
+    pub(crate) fn arena(&self) -> &'arena Arena {
+        self.arena
+    }
+
+    pub(crate) fn context(&self) -> ParseContext {
+        self.context.clone()
+    }
+
     /// Parse a block, automatically detecting style
-    pub fn parse_block<T: TokenStream>(&mut self, tokens: &mut T) -> ParseResult<&'arena Block> {
+    pub fn parse_block<T: TokenStream>(
+        &mut self,
+        tokens: &mut T,
+    ) -> ParseResult<&'arena Block<'arena>> {
         let current = tokens.peek();
 
         match current.token_type {
@@ -63,8 +108,9 @@ impl<'arena> BlockParser<'arena> {
     pub fn parse_braced_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-    ) -> ParseResult<&'arena Block> {
+    ) -> ParseResult<&'arena Block<'arena>> {
         let start_span = tokens.peek().span.clone();
+        let _nesting = self.context.enter_nesting(&start_span)?;
 
         // Consume opening brace
         self.expect_token(tokens, TokenType::LeftBrace)?;
@@ -81,10 +127,12 @@ impl<'arena> BlockParser<'arena> {
         let mut statements = Vec::new();
         self.current_scope_depth += 1;
 
-        // Parse statements until closing brace
+        // Parse statements until closing brace; newlines separate statements
+        self.skip_newlines(tokens);
         while !tokens.is_at_end() && !matches!(tokens.peek().token_type, TokenType::RightBrace) {
             let statement = self.parse_statement_in_block(tokens)?;
             statements.push(statement.clone());
+            self.skip_newlines(tokens);
         }
 
         let end_span = tokens.peek().span.clone();
@@ -93,7 +141,7 @@ impl<'arena> BlockParser<'arena> {
         self.current_scope_depth -= 1;
 
         let block = self.arena.alloc(Block {
-            statements,
+            statements: self.arena.alloc_vec(statements),
             is_braced: true,
             scope_depth: self.current_scope_depth,
             span: start_span.combine(end_span),
@@ -110,14 +158,19 @@ impl<'arena> BlockParser<'arena> {
     pub fn parse_indented_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-    ) -> ParseResult<&'arena Block> {
+    ) -> ParseResult<&'arena Block<'arena>> {
         let start_span = tokens.peek().span.clone();
+        let _nesting = self.context.enter_nesting(&start_span)?;
 
         // Consume colon
         self.expect_token(tokens, TokenType::Colon)?;
 
-        // Expect newline
+        // Expect newline, then the indented body
         self.expect_token(tokens, TokenType::Newline)?;
+        let indented = matches!(tokens.peek().token_type, TokenType::Indent);
+        if indented {
+            tokens.consume();
+        }
 
         // Set block style for consistency checking
         match self.block_style {
@@ -131,14 +184,18 @@ impl<'arena> BlockParser<'arena> {
         let mut statements = Vec::new();
         self.current_scope_depth += 1;
 
-        // Parse indented statements (simplified - just parse until we see dedent)
+        // Parse indented statements until the matching dedent
         while !tokens.is_at_end() {
+            self.skip_newlines(tokens);
+
             // Check if we've reached the end of the indented block
-            if matches!(
-                tokens.peek().token_type,
-                TokenType::Eof | TokenType::RightBrace
-            ) {
-                break;
+            match tokens.peek().token_type {
+                TokenType::Dedent if indented => {
+                    tokens.consume();
+                    break;
+                }
+                TokenType::Eof | TokenType::RightBrace | TokenType::Dedent => break,
+                _ => {}
             }
 
             let statement = self.parse_statement_in_block(tokens)?;
@@ -154,7 +211,7 @@ impl<'arena> BlockParser<'arena> {
         };
 
         let block = self.arena.alloc(Block {
-            statements,
+            statements: self.arena.alloc_vec(statements),
             is_braced: false,
             scope_depth: self.current_scope_depth,
             span: start_span.combine(end_span),
@@ -168,348 +225,27 @@ impl<'arena> BlockParser<'arena> {
     }
 
     /// Parse a statement within a block context
+    ///
+    /// The stream is passed as a trait object: statements contain blocks
+    /// again, and a generic stream would nest `&mut` forever.
     fn parse_statement_in_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Create a temporary token stream for the statement parser
-        // We'll parse one statement at a time
-        let token = tokens.peek();
-
-        match &token.token_type {
-            // Variable declarations
-            TokenType::Let | TokenType::Var => self.parse_variable_statement(tokens),
-            // Control flow
-            TokenType::If => self.parse_if_statement(tokens),
-            TokenType::While => self.parse_while_statement(tokens),
-            TokenType::For => self.parse_for_statement(tokens),
-            TokenType::Return => self.parse_return_statement(tokens),
-            TokenType::Break => self.parse_break_statement(tokens),
-            TokenType::Continue => self.parse_continue_statement(tokens),
-            // Block statements
-            TokenType::LeftBrace => {
-                let block = self.parse_braced_block(tokens)?;
-                Ok(self.arena.alloc(Statement::Block(block.clone())))
-            }
-            // Expression statements (fallback)
-            _ => self.parse_expression_statement(tokens),
-        }
-    }
-
-    /// Parse a variable declaration statement
-    fn parse_variable_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        let start_token = tokens.consume(); // let or var
-        let is_mutable = matches!(start_token.token_type, TokenType::Var);
-
-        let name_token = tokens.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("identifier", &name_token)),
-        };
-
-        // Optional type annotation
-        let var_type = if matches!(tokens.peek().token_type, TokenType::Colon) {
-            tokens.consume(); // consume ':'
-            Some(Type::Identifier("i32".to_string())) // Simplified for now
-        } else {
-            None
-        };
-
-        // Optional initializer
-        let initializer = if matches!(tokens.peek().token_type, TokenType::Equal) {
-            tokens.consume(); // consume '='
-            Some(self.parse_expression(tokens)?)
-        } else {
-            None
-        };
-
-        // Consume optional semicolon
-        if matches!(tokens.peek().token_type, TokenType::Semicolon) {
-            tokens.consume();
-        }
-
-        let var_decl = VariableDecl {
-            name,
-            var_type,
-            initializer: initializer.cloned(),
-            is_mutable,
-            modifiers: Modifiers {
-                is_public: false,
-                is_unsafe: false,
-            },
-            attributes: Vec::new(),
-            span: start_token.span,
-        };
-
-        Ok(self.arena.alloc(Statement::VariableDecl(var_decl)))
-    }
-
-    #[allow(dead_code)]
-    fn parse_expression_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        let expr = self.parse_expression(tokens)?;
-
-        // Consume optional semicolon
-        if matches!(tokens.peek().token_type, TokenType::Semicolon) {
-            tokens.consume();
-        }
-
-        Ok(self.arena.alloc(Statement::Expression(expr.clone())))
-    }
-
-    /// Parse a complex expression using the PrattParser
-    fn parse_expression<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Expression> {
-        // Collect tokens into a vector for PrattParser consumption
-        let mut collected_tokens = Vec::new();
-        let mut paren_depth = 0;
-        let mut bracket_depth = 0;
-        let mut brace_depth = 0;
-
-        // Collect tokens until we find a statement terminator
-        loop {
-            let token = tokens.peek();
-            match token.token_type {
-                TokenType::LeftParen => paren_depth += 1,
-                TokenType::RightParen => {
-                    if paren_depth == 0 {
-                        break;
-                    }
-                    paren_depth -= 1;
-                }
-                TokenType::LeftBracket => bracket_depth += 1,
-                TokenType::RightBracket => {
-                    if bracket_depth == 0 {
-                        break;
-                    }
-                    bracket_depth -= 1;
-                }
-                TokenType::LeftBrace => brace_depth += 1,
-                TokenType::RightBrace => {
-                    if brace_depth == 0 {
-                        break;
-                    }
-                    brace_depth -= 1;
-                }
-                TokenType::Semicolon | TokenType::Newline => {
-                    if paren_depth == 0 && bracket_depth == 0 && brace_depth == 0 {
-                        break;
-                    }
-                }
-                // Statement keywords should terminate expression parsing
-                TokenType::Let
-                | TokenType::Var
-                | TokenType::If
-                | TokenType::While
-                | TokenType::For
-                | TokenType::Return
-                | TokenType::Break
-                | TokenType::Continue => {
-                    if paren_depth == 0 && bracket_depth == 0 && brace_depth == 0 {
-                        break;
-                    }
-                }
-                TokenType::Eof => break,
-                _ => {}
-            }
-
-            collected_tokens.push(tokens.consume());
-        }
-
-        // Add EOF token
-        collected_tokens.push(Token {
-            token_type: TokenType::Eof,
-            span: tokens.peek().span.clone(),
-        });
-
-        // Create a VecTokenStream and parse with PrattParser
-        let token_stream = crate::token::VecTokenStream::new(collected_tokens);
-        let mut pratt_parser = PrattParser::new(self.arena, token_stream);
-        pratt_parser.parse_expression(0)
-    }
-
-    /// Parse other statement types (simplified implementations)
-    fn parse_if_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Consume 'if' token
-        let if_token = self.expect_token(tokens, TokenType::If)?;
-
-        // Parse condition
-        let condition = self.parse_simple_expression(tokens)?;
-
-        // Parse then block
-        let then_block = self.parse_braced_block(tokens)?;
-
-        // Check for optional else block
-        let else_block = if tokens.peek().token_type == TokenType::Else {
-            tokens.consume(); // consume 'else'
-            Some(self.parse_braced_block(tokens)?.clone())
-        } else {
-            None
-        };
-
-        Ok(self.arena.alloc(Statement::If(IfStatement {
-            condition: condition.clone(),
-            then_block: then_block.clone(),
-            else_block,
-            span: if_token.span,
-        })))
-    }
-
-    fn parse_while_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Consume 'while' token
-        let while_token = self.expect_token(tokens, TokenType::While)?;
-
-        // Parse condition
-        let condition = self.parse_simple_expression(tokens)?;
-
-        // Parse body block
-        let body = self.parse_braced_block(tokens)?;
-
-        Ok(self.arena.alloc(Statement::While(WhileStatement {
-            condition: condition.clone(),
-            body: body.clone(),
-            span: while_token.span,
-        })))
-    }
-
-    fn parse_for_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Consume 'for' token
-        let for_token = self.expect_token(tokens, TokenType::For)?;
-
-        // Parse pattern/variable name
-        let variable = match tokens.peek().token_type {
-            TokenType::Identifier(ref name) => {
-                let name = name.clone();
-                tokens.consume();
-                name
-            }
-            _ => return Err(ParseError::unexpected_token("identifier", tokens.peek())),
-        };
-
-        // Consume 'in' keyword
-        self.expect_token(tokens, TokenType::In)?;
-
-        // Parse iterable expression
-        let iterable = self.parse_simple_expression(tokens)?;
-
-        // Parse body block
-        let body = self.parse_braced_block(tokens)?;
-
-        Ok(self.arena.alloc(Statement::For(ForStatement {
-            variable,
-            iterable: iterable.clone(),
-            body: body.clone(),
-            span: for_token.span,
-        })))
-    }
-
-    /// Parse a simple expression (identifier, literal, etc.)
-    fn parse_simple_expression<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Expression> {
-        let token = tokens.consume();
-
-        match token.token_type {
-            TokenType::BooleanLiteral(b) => {
-                Ok(self.arena.alloc(Expression::Literal(Literal::Boolean(b))))
-            }
-            TokenType::IntegerLiteral(i) => {
-                Ok(self.arena.alloc(Expression::Literal(Literal::Integer(i))))
-            }
-            TokenType::Identifier(name) => Ok(self.arena.alloc(Expression::Identifier(name))),
-            _ => Err(ParseError::unexpected_token("expression", &token)),
-        }
-    }
-
-    fn parse_return_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        tokens.consume(); // consume 'return'
-
-        // Optional return value
-        let value = if matches!(
-            tokens.peek().token_type,
-            TokenType::Semicolon | TokenType::Eof | TokenType::RightBrace
-        ) {
-            None
-        } else {
-            // Use the full expression parser to handle complex expressions
-            Some(self.parse_expression(tokens)?)
-        };
-
-        // Consume optional semicolon
-        if matches!(tokens.peek().token_type, TokenType::Semicolon) {
-            tokens.consume();
-        }
-
-        let return_stmt = ReturnStatement {
-            value: value.cloned(),
-            span: tokens.peek().span.clone(),
-        };
-
-        Ok(self.arena.alloc(Statement::Return(return_stmt)))
-    }
-
-    fn parse_break_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Consume 'break' token
-        let break_token = self.expect_token(tokens, TokenType::Break)?;
-
-        // Consume optional semicolon
-        if matches!(tokens.peek().token_type, TokenType::Semicolon) {
-            tokens.consume();
-        }
-
-        // For now, keep it simple without label support to match existing AST
-        Ok(self.arena.alloc(Statement::Break(BreakStatement {
-            span: break_token.span,
-        })))
-    }
-
-    fn parse_continue_statement<T: TokenStream>(
-        &mut self,
-        tokens: &mut T,
-    ) -> ParseResult<&'arena Statement> {
-        // Consume 'continue' token
-        let continue_token = self.expect_token(tokens, TokenType::Continue)?;
-
-        // Consume optional semicolon
-        if matches!(tokens.peek().token_type, TokenType::Semicolon) {
-            tokens.consume();
-        }
-
-        // For now, keep it simple without label support to match existing AST
-        Ok(self.arena.alloc(Statement::Continue(ContinueStatement {
-            span: continue_token.span,
-        })))
+    ) -> ParseResult<&'arena Statement<'arena>> {
+        let tokens: &mut dyn TokenStream = tokens;
+        // Statements nested in this block share its scope depth and style
+        let mut statements = StatementParser::within_block(self.clone(), tokens);
+        let statement = statements.parse_statement();
+        *self = statements.into_block_parser();
+        statement
     }
 
     /// Parse a labeled block (for break/continue)
     pub fn parse_labeled_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-        label: String,
-    ) -> ParseResult<&'arena Block> {
+        label: Symbol,
+    ) -> ParseResult<&'arena Block<'arena>> {
         let block = self.parse_block(tokens)?.clone();
 
         // Create new block with label
@@ -531,7 +267,7 @@ impl<'arena> BlockParser<'arena> {
     pub fn parse_unsafe_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-    ) -> ParseResult<&'arena Block> {
+    ) -> ParseResult<&'arena Block<'arena>> {
         let start_span = tokens.peek().span.clone();
 
         // Consume 'unsafe' keyword
@@ -558,7 +294,7 @@ impl<'arena> BlockParser<'arena> {
     pub fn parse_async_block<T: TokenStream>(
         &mut self,
         tokens: &mut T,
-    ) -> ParseResult<&'arena Block> {
+    ) -> ParseResult<&'arena Block<'arena>> {
         let start_span = tokens.peek().span.clone();
 
         // Consume 'async' keyword
@@ -592,13 +328,23 @@ impl<'arena> BlockParser<'arena> {
                     Span::dummy(), // Would need actual span tracking
                 ));
             }
-            seen_vars.insert(var.clone());
+            seen_vars.insert(*var);
         }
 
         Ok(())
     }
 
     /// Expect a specific token type
+    /// Skip the newlines that terminate statements
+    fn skip_newlines<T: TokenStream>(&mut self, tokens: &mut T) {
+        while matches!(tokens.peek().token_type, TokenType::Newline) {
+            let newline = tokens.consume();
+            if self.context.options.retain_trivia {
+                self.trivia.push(newline.span);
+            }
+        }
+    }
+
     fn expect_token<T: TokenStream>(
         &mut self,
         tokens: &mut T,
@@ -619,19 +365,28 @@ impl<'arena> BlockParser<'arena> {
 }
 
 /// Convenience functions for block parsing
-pub fn parse_block<T: TokenStream>(arena: &Arena, tokens: &mut T) -> ParseResult<Block> {
+pub fn parse_block<'arena, T: TokenStream>(
+    arena: &'arena Arena,
+    tokens: &mut T,
+) -> ParseResult<Block<'arena>> {
     let mut parser = BlockParser::new(arena);
     let block_ref = parser.parse_block(tokens)?;
     Ok(block_ref.clone())
 }
 
-pub fn parse_braced_block<T: TokenStream>(arena: &Arena, tokens: &mut T) -> ParseResult<Block> {
+pub fn parse_braced_block<'arena, T: TokenStream>(
+    arena: &'arena Arena,
+    tokens: &mut T,
+) -> ParseResult<Block<'arena>> {
     let mut parser = BlockParser::new(arena);
     let block_ref = parser.parse_braced_block(tokens)?;
     Ok(block_ref.clone())
 }
 
-pub fn parse_indented_block<T: TokenStream>(arena: &Arena, tokens: &mut T) -> ParseResult<Block> {
+pub fn parse_indented_block<'arena, T: TokenStream>(
+    arena: &'arena Arena,
+    tokens: &mut T,
+) -> ParseResult<Block<'arena>> {
     let mut parser = BlockParser::new(arena);
     let block_ref = parser.parse_indented_block(tokens)?;
     Ok(block_ref.clone())
@@ -0,0 +1,13 @@
+//! Conditional compilation with `#[cfg(...)]`
+//!
+//! Evaluates cfg predicates against a caller-supplied configuration and strips
+//! disabled nodes from the AST before later stages run:
+//! - Flags: #[cfg(test)]
+//! - Key/value options: #[cfg(target = "wasm")], #[cfg(feature = "simd")]
+//! - Combinators: #[cfg(not(...))], #[cfg(any(...))], #[cfg(all(...))]
+
+pub mod predicate;
+pub mod strip;
+
+pub use predicate::*;
+pub use strip::*;
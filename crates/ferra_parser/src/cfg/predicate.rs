@@ -0,0 +1,300 @@
+//! Cfg predicates and the configuration they are evaluated against
+
+use crate::{
+    ast::{Attribute, AttributeArgument, Expression, Literal},
+    error::{ParseError, ParseResult},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Build configuration that `#[cfg(...)]` predicates are evaluated against
+///
+/// A target may be registered under several names (e.g. both `wasm` and
+/// `wasm32-wasi`) so that code can match on a family or an exact triple.
+#[derive(Debug, Clone, Default)]
+pub struct CfgConfig {
+    flags: HashSet<String>,
+    values: HashMap<String, HashSet<String>>,
+}
+
+impl CfgConfig {
+    /// Create an empty configuration in which every predicate is unset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable a bare flag such as `test` or `debug_assertions`
+    pub fn with_flag(mut self, name: impl Into<String>) -> Self {
+        self.flags.insert(name.into());
+        self
+    }
+
+    /// Set a `key = "value"` option; a key may hold several values
+    pub fn with_value(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values
+            .entry(key.into())
+            .or_default()
+            .insert(value.into());
+        self
+    }
+
+    /// Register a target name matched by `target = "..."`
+    pub fn with_target(self, target: impl Into<String>) -> Self {
+        self.with_value("target", target)
+    }
+
+    /// Enable a feature matched by `feature = "..."`
+    pub fn with_feature(self, feature: impl Into<String>) -> Self {
+        self.with_value("feature", feature)
+    }
+
+    /// Check whether a bare flag is enabled
+    pub fn is_set(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    /// Check whether `key = "value"` holds
+    pub fn has_value(&self, key: &str, value: &str) -> bool {
+        self.values
+            .get(key)
+            .is_some_and(|values| values.contains(value))
+    }
+}
+
+/// Parsed cfg predicate
+#[derive(Debug, Clone, PartialEq)]
+pub enum CfgPredicate {
+    /// Bare flag: `test`
+    Flag(String),
+    /// Key/value option: `target = "wasm"`
+    KeyValue { key: String, value: String },
+    /// Negation: `not(predicate)`
+    Not(Box<CfgPredicate>),
+    /// Disjunction: `any(a, b, ...)`, false when empty
+    Any(Vec<CfgPredicate>),
+    /// Conjunction: `all(a, b, ...)`, true when empty
+    All(Vec<CfgPredicate>),
+}
+
+impl CfgPredicate {
+    /// Parse the predicate of a `#[cfg(...)]` attribute; several top-level
+    /// predicates must all hold
+    pub fn from_attribute(attribute: &Attribute) -> ParseResult<Self> {
+        if attribute.value.is_some() || attribute.arguments.is_empty() {
+            return Err(ParseError::syntax_error_with_suggestion(
+                "`cfg` requires a predicate",
+                attribute.span.clone(),
+                "write `#[cfg(test)]`, `#[cfg(target = \"wasm\")]` or `#[cfg(not(...))]`",
+            ));
+        }
+
+        let mut predicates = attribute
+            .arguments
+            .iter()
+            .map(Self::from_argument)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        if predicates.len() == 1 {
+            Ok(predicates.remove(0))
+        } else {
+            Ok(CfgPredicate::All(predicates))
+        }
+    }
+
+    /// Parse a single predicate from an attribute argument
+    pub fn from_argument(argument: &AttributeArgument) -> ParseResult<Self> {
+        match argument {
+            AttributeArgument::Expression {
+                value: Expression::Identifier(name),
+                ..
+            } => Ok(CfgPredicate::Flag(name.to_string())),
+            AttributeArgument::Expression { span, .. } => {
+                Err(ParseError::syntax_error_with_suggestion(
+                    "expected a cfg predicate",
+                    span.clone(),
+                    "use a flag such as `test` or an option such as `feature = \"name\"`",
+                ))
+            }
+            AttributeArgument::KeyValue { key, value, span } => match value {
+                Expression::Literal(Literal::String(value)) => Ok(CfgPredicate::KeyValue {
+                    key: key.to_string(),
+                    value: value.clone(),
+                }),
+                _ => Err(ParseError::syntax_error_with_suggestion(
+                    "cfg option values must be string literals",
+                    span.clone(),
+                    &format!("quote the value: `{} = \"...\"`", key),
+                )),
+            },
+            AttributeArgument::Nested {
+                name,
+                arguments,
+                span,
+            } => {
+                let predicates = arguments
+                    .iter()
+                    .map(Self::from_argument)
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                match name.as_str() {
+                    "any" => Ok(CfgPredicate::Any(predicates)),
+                    "all" => Ok(CfgPredicate::All(predicates)),
+                    "not" => match <[CfgPredicate; 1]>::try_from(predicates) {
+                        Ok([predicate]) => Ok(CfgPredicate::Not(Box::new(predicate))),
+                        Err(_) => Err(ParseError::syntax_error(
+                            "`not` takes exactly one cfg predicate",
+                            span.clone(),
+                        )),
+                    },
+                    _ => Err(ParseError::syntax_error_with_suggestion(
+                        &format!("unknown cfg predicate `{}`", name),
+                        span.clone(),
+                        "combine predicates with `not(...)`, `any(...)` or `all(...)`",
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Evaluate this predicate against a configuration
+    pub fn evaluate(&self, config: &CfgConfig) -> bool {
+        match self {
+            CfgPredicate::Flag(name) => config.is_set(name),
+            CfgPredicate::KeyValue { key, value } => config.has_value(key, value),
+            CfgPredicate::Not(predicate) => !predicate.evaluate(config),
+            CfgPredicate::Any(predicates) => predicates.iter().any(|p| p.evaluate(config)),
+            CfgPredicate::All(predicates) => predicates.iter().all(|p| p.evaluate(config)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ast::Arena, attribute::parse_attribute, token::TokenType, token::VecTokenStream};
+
+    fn parse_cfg(token_types: Vec<TokenType>) -> ParseResult<CfgPredicate> {
+        let arena = Arena::new();
+        let mut tokens = VecTokenStream::from_token_types(token_types);
+        CfgPredicate::from_attribute(&parse_attribute(&arena, &mut tokens).unwrap())
+    }
+
+    fn ident(name: &str) -> TokenType {
+        TokenType::Identifier(name.into())
+    }
+
+    #[test]
+    fn test_flag_and_key_value() {
+        // #[cfg(test, feature = "simd")]
+        let predicate = parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::LeftParen,
+            ident("test"),
+            TokenType::Comma,
+            ident("feature"),
+            TokenType::Equal,
+            TokenType::StringLiteral("simd".to_string()),
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ])
+        .unwrap();
+
+        let config = CfgConfig::new().with_flag("test");
+        assert!(!predicate.evaluate(&config));
+        assert!(predicate.evaluate(&config.with_feature("simd")));
+    }
+
+    #[test]
+    fn test_combinators() {
+        // #[cfg(any(target = "wasm", not(unix)))]
+        let predicate = parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::LeftParen,
+            ident("any"),
+            TokenType::LeftParen,
+            ident("target"),
+            TokenType::Equal,
+            TokenType::StringLiteral("wasm".to_string()),
+            TokenType::Comma,
+            ident("not"),
+            TokenType::LeftParen,
+            ident("unix"),
+            TokenType::RightParen,
+            TokenType::RightParen,
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ])
+        .unwrap();
+
+        assert!(predicate.evaluate(&CfgConfig::new()));
+        assert!(!predicate.evaluate(&CfgConfig::new().with_flag("unix")));
+        assert!(predicate.evaluate(&CfgConfig::new().with_flag("unix").with_target("wasm")));
+    }
+
+    #[test]
+    fn test_empty_any_and_all() {
+        assert!(!CfgPredicate::Any(Vec::new()).evaluate(&CfgConfig::new()));
+        assert!(CfgPredicate::All(Vec::new()).evaluate(&CfgConfig::new()));
+    }
+
+    #[test]
+    fn test_invalid_predicates() {
+        // #[cfg]
+        assert!(parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::RightBracket,
+        ])
+        .is_err());
+
+        // #[cfg(feature = 1)]
+        assert!(parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::LeftParen,
+            ident("feature"),
+            TokenType::Equal,
+            TokenType::IntegerLiteral(1),
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ])
+        .is_err());
+
+        // #[cfg(not(a, b))]
+        assert!(parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::LeftParen,
+            ident("not"),
+            TokenType::LeftParen,
+            ident("a"),
+            TokenType::Comma,
+            ident("b"),
+            TokenType::RightParen,
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ])
+        .is_err());
+
+        // #[cfg(maybe(a))]
+        assert!(parse_cfg(vec![
+            TokenType::Hash,
+            TokenType::LeftBracket,
+            ident("cfg"),
+            TokenType::LeftParen,
+            ident("maybe"),
+            TokenType::LeftParen,
+            ident("a"),
+            TokenType::RightParen,
+            TokenType::RightParen,
+            TokenType::RightBracket,
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! Cfg-stripping pass
+//!
+//! Removes every node whose `#[cfg(...)]` attributes evaluate to false and drops
+//! the evaluated `cfg` attributes from the nodes that are kept, so later stages
+//! never see conditional compilation.
+
+use super::predicate::{CfgConfig, CfgPredicate};
+use crate::{
+    ast::{
+        Arena, Attribute, Block, CompilationUnit, Expression, ExternItem, Item, MatchArm, Statement,
+    },
+    error::{ParseError, ParseResult},
+};
+
+/// Strip disabled nodes from a compilation unit, returning the configured copy
+pub fn strip_cfg<'a>(
+    arena: &'a Arena,
+    unit: &CompilationUnit<'a>,
+    config: &CfgConfig,
+) -> ParseResult<CompilationUnit<'a>> {
+    let mut unit = unit.clone();
+    CfgStripper::new(arena, config).strip_compilation_unit(&mut unit)?;
+    Ok(unit)
+}
+
+/// Evaluate the `#[cfg(...)]` attributes in a list against a configuration
+pub fn cfg_enabled(attributes: &[Attribute], config: &CfgConfig) -> ParseResult<bool> {
+    for attribute in attributes.iter().filter(|a| is_cfg(a)) {
+        if !CfgPredicate::from_attribute(attribute)?.evaluate(config) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn is_cfg(attribute: &Attribute) -> bool {
+    attribute.name == "cfg"
+}
+
+/// AST pass that removes nodes disabled by `#[cfg(...)]`
+///
+/// Arena nodes are shared, so every node on the path to a change is copied
+/// into the arena rather than edited in place.
+pub struct CfgStripper<'a, 'c> {
+    arena: &'a Arena,
+    config: &'c CfgConfig,
+}
+
+impl<'a, 'c> CfgStripper<'a, 'c> {
+    pub fn new(arena: &'a Arena, config: &'c CfgConfig) -> Self {
+        Self { arena, config }
+    }
+
+    /// Strip a compilation unit in place; a disabled `#![cfg(...)]` empties the file
+    pub fn strip_compilation_unit(&self, unit: &mut CompilationUnit<'a>) -> ParseResult<()> {
+        if !self.enabled(&mut unit.attributes)? {
+            unit.items = &[];
+            return Ok(());
+        }
+        self.retain_nodes(&mut unit.items, |item| self.strip_item(item))
+    }
+
+    /// Evaluate cfg attributes, removing them when the node is kept
+    fn enabled(&self, attributes: &mut &'a [Attribute<'a>]) -> ParseResult<bool> {
+        if !cfg_enabled(attributes, self.config)? {
+            return Ok(false);
+        }
+        if attributes.iter().any(is_cfg) {
+            let kept = attributes.iter().filter(|a| !is_cfg(a)).cloned().collect();
+            *attributes = self.arena.alloc_vec(kept);
+        }
+        Ok(true)
+    }
+
+    fn strip_item(&self, mut item: Item<'a>) -> ParseResult<Option<Item<'a>>> {
+        match &mut item {
+            Item::FunctionDecl(function) => {
+                if !self.enabled(&mut function.attributes)? {
+                    return Ok(None);
+                }
+                self.retain_nodes(&mut function.parameters, |mut parameter| {
+                    Ok(self
+                        .enabled(&mut parameter.attributes)?
+                        .then_some(parameter))
+                })?;
+                if let Some(body) = &mut function.body {
+                    self.strip_child_block(body)?;
+                }
+            }
+            Item::VariableDecl(variable) => {
+                if !self.enabled(&mut variable.attributes)? {
+                    return Ok(None);
+                }
+                if let Some(initializer) = &mut variable.initializer {
+                    self.strip_child(initializer)?;
+                }
+            }
+            Item::DataClassDecl(data_class) => {
+                if !self.enabled(&mut data_class.attributes)? {
+                    return Ok(None);
+                }
+                self.retain_nodes(&mut data_class.fields, |mut field| {
+                    Ok(self.enabled(&mut field.attributes)?.then_some(field))
+                })?;
+            }
+            Item::ExternBlock(extern_block) => {
+                if !self.enabled(&mut extern_block.attributes)? {
+                    return Ok(None);
+                }
+                self.retain_nodes(&mut extern_block.items, |item| self.strip_extern_item(item))?;
+            }
+            Item::MacroDefinition(macro_def) => {
+                if !self.enabled(&mut macro_def.attributes)? {
+                    return Ok(None);
+                }
+            }
+            Item::Macro(macro_item) => {
+                if !self.enabled(&mut macro_item.attributes)? {
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(item))
+    }
+
+    fn strip_extern_item(&self, mut item: ExternItem<'a>) -> ParseResult<Option<ExternItem<'a>>> {
+        match &mut item {
+            ExternItem::Function(function) => {
+                if !self.enabled(&mut function.attributes)? {
+                    return Ok(None);
+                }
+                self.retain_nodes(&mut function.parameters, |mut parameter| {
+                    Ok(self
+                        .enabled(&mut parameter.attributes)?
+                        .then_some(parameter))
+                })?;
+            }
+            ExternItem::Variable(variable) => {
+                if !self.enabled(&mut variable.attributes)? {
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(item))
+    }
+
+    fn strip_block(&self, block: &mut Block<'a>) -> ParseResult<()> {
+        self.retain_nodes(&mut block.statements, |statement| {
+            self.strip_statement(statement)
+        })
+    }
+
+    fn strip_statement(&self, statement: Statement<'a>) -> ParseResult<Option<Statement<'a>>> {
+        let statement = match statement {
+            Statement::Attributed(mut attributed) => {
+                if !self.enabled(&mut attributed.attributes)? {
+                    return Ok(None);
+                }
+                return match self.strip_statement(attributed.statement.clone())? {
+                    Some(inner) => Ok(Some(
+                        inner.with_attributes(self.arena, attributed.attributes),
+                    )),
+                    None => Ok(None),
+                };
+            }
+            Statement::VariableDecl(mut variable) => {
+                if !self.enabled(&mut variable.attributes)? {
+                    return Ok(None);
+                }
+                if let Some(initializer) = &mut variable.initializer {
+                    self.strip_child(initializer)?;
+                }
+                Statement::VariableDecl(variable)
+            }
+            // A disabled block expression in statement position removes the statement
+            Statement::Expression(Expression::Block(block_expr)) => {
+                let mut block_expr = block_expr.clone();
+                if !self.enabled(&mut block_expr.attributes)? {
+                    return Ok(None);
+                }
+                let mut expr = Expression::Block(block_expr);
+                self.strip_expression(&mut expr)?;
+                Statement::Expression(self.arena.alloc(expr))
+            }
+            Statement::Expression(mut expr) => {
+                self.strip_child(&mut expr)?;
+                Statement::Expression(expr)
+            }
+            Statement::If(mut if_stmt) => {
+                self.strip_child(&mut if_stmt.condition)?;
+                self.strip_child_block(&mut if_stmt.then_block)?;
+                if let Some(else_block) = &mut if_stmt.else_block {
+                    self.strip_child_block(else_block)?;
+                }
+                Statement::If(if_stmt)
+            }
+            Statement::While(mut while_stmt) => {
+                self.strip_child(&mut while_stmt.condition)?;
+                self.strip_child_block(&mut while_stmt.body)?;
+                Statement::While(while_stmt)
+            }
+            Statement::For(mut for_stmt) => {
+                self.strip_child(&mut for_stmt.iterable)?;
+                self.strip_child_block(&mut for_stmt.body)?;
+                Statement::For(for_stmt)
+            }
+            Statement::Return(mut return_stmt) => {
+                if let Some(value) = &mut return_stmt.value {
+                    self.strip_child(value)?;
+                }
+                Statement::Return(return_stmt)
+            }
+            Statement::Block(mut block) => {
+                self.strip_block(&mut block)?;
+                Statement::Block(block)
+            }
+            statement @ (Statement::Break(_) | Statement::Continue(_)) => statement,
+        };
+        Ok(Some(statement))
+    }
+
+    fn strip_expression(&self, expr: &mut Expression<'a>) -> ParseResult<()> {
+        match expr {
+            Expression::Block(block_expr) => {
+                if !self.enabled(&mut block_expr.attributes)? {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        "a block expression disabled by `#[cfg]` leaves no value",
+                        block_expr.span.clone(),
+                        "move the `#[cfg]` attribute to the enclosing statement",
+                    ));
+                }
+                self.strip_child_block(&mut block_expr.block)?;
+                if let Some(value) = &mut block_expr.value {
+                    self.strip_child(value)?;
+                }
+            }
+            Expression::Match(match_expr) => {
+                self.strip_child(&mut match_expr.scrutinee)?;
+                self.retain_nodes(&mut match_expr.arms, |arm| self.strip_match_arm(arm))?;
+            }
+            Expression::Binary(binary) => {
+                self.strip_child(&mut binary.left)?;
+                self.strip_child(&mut binary.right)?;
+            }
+            Expression::Unary(unary) => self.strip_child(&mut unary.operand)?,
+            Expression::Call(call) => {
+                self.strip_child(&mut call.callee)?;
+                self.strip_children(&mut call.arguments)?;
+            }
+            Expression::MemberAccess(member) => self.strip_child(&mut member.object)?,
+            Expression::Index(index) => {
+                self.strip_child(&mut index.object)?;
+                self.strip_child(&mut index.index)?;
+            }
+            Expression::Await(await_expr) => self.strip_child(&mut await_expr.expression)?,
+            Expression::Array(array) => self.strip_children(&mut array.elements)?,
+            Expression::Tuple(tuple) => self.strip_children(&mut tuple.elements)?,
+            Expression::If(if_expr) => {
+                self.strip_child(&mut if_expr.condition)?;
+                self.strip_child(&mut if_expr.then_expr)?;
+                if let Some(else_expr) = &mut if_expr.else_expr {
+                    self.strip_child(else_expr)?;
+                }
+            }
+            Expression::Grouped(inner) => self.strip_child(inner)?,
+            Expression::DataClassLiteral(literal) => {
+                literal.fields = self.arena.alloc_slice_edited(literal.fields, |field| {
+                    self.strip_child(&mut field.value)
+                })?;
+            }
+            Expression::BuiltinMacro(builtin) => {
+                *builtin = builtin
+                    .edit_arguments(self.arena, |argument| self.strip_expression(argument))?;
+            }
+            Expression::Interpolated(interpolated) => {
+                *interpolated = interpolated
+                    .edit_expressions(self.arena, |expression| self.strip_expression(expression))?;
+            }
+            Expression::Literal(_)
+            | Expression::Identifier(_)
+            | Expression::Path(_)
+            | Expression::Macro(_) => {}
+        }
+        Ok(())
+    }
+
+    fn strip_match_arm(&self, mut arm: MatchArm<'a>) -> ParseResult<Option<MatchArm<'a>>> {
+        if !self.enabled(&mut arm.attributes)? {
+            return Ok(None);
+        }
+        self.strip_child(&mut arm.expression)?;
+        Ok(Some(arm))
+    }
+
+    /// Point `expr` at a stripped copy of the expression
+    fn strip_child(&self, expr: &mut &'a Expression<'a>) -> ParseResult<()> {
+        *expr = self
+            .arena
+            .alloc_edited(*expr, |expr| self.strip_expression(expr))?;
+        Ok(())
+    }
+
+    /// Point `exprs` at stripped copies of the expressions
+    fn strip_children(&self, exprs: &mut &'a [Expression<'a>]) -> ParseResult<()> {
+        *exprs = self
+            .arena
+            .alloc_slice_edited(exprs, |expr| self.strip_expression(expr))?;
+        Ok(())
+    }
+
+    /// Point `block` at a stripped copy of the block
+    fn strip_child_block(&self, block: &mut &'a Block<'a>) -> ParseResult<()> {
+        *block = self
+            .arena
+            .alloc_edited(*block, |block| self.strip_block(block))?;
+        Ok(())
+    }
+
+    /// Rebuild `nodes` from the nodes `strip` keeps, stopping at the first error
+    fn retain_nodes<N: Clone>(
+        &self,
+        nodes: &mut &'a [N],
+        mut strip: impl FnMut(N) -> ParseResult<Option<N>>,
+    ) -> ParseResult<()> {
+        let mut kept = Vec::with_capacity(nodes.len());
+        for node in nodes.iter() {
+            if let Some(node) = strip(node.clone())? {
+                kept.push(node);
+            }
+        }
+        *nodes = self.arena.alloc_vec(kept);
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 //! Parse error types and positive-first error messaging
 
-use crate::token::{Span, Token};
+use crate::token::{LexDiagnostic, Span, Token, TokenType};
 use thiserror::Error;
 
 /// Error severity levels for better diagnostics
@@ -138,6 +138,25 @@ pub enum ParseError {
         error_code: Option<&'static str>,
     },
 
+    #[error("Literal out of range for `{ty}`: `{literal}`")]
+    LiteralOutOfRange {
+        literal: String,
+        ty: String,
+        span: Span,
+        suggestion: Option<String>,
+        severity: ErrorSeverity,
+        error_code: Option<&'static str>,
+    },
+
+    #[error("Nesting deeper than the limit of {limit} levels")]
+    NestingTooDeep {
+        limit: usize,
+        span: Span,
+        suggestion: Option<String>,
+        severity: ErrorSeverity,
+        error_code: Option<&'static str>,
+    },
+
     #[error("Recovery error: {message}")]
     RecoveryError {
         message: String,
@@ -151,6 +170,9 @@ pub enum ParseError {
 impl ParseError {
     /// Create an unexpected token error with positive-first messaging
     pub fn unexpected_token(expected: &str, found: &Token) -> Self {
+        if let TokenType::Error(diagnostic) = &found.token_type {
+            return Self::lex_error(diagnostic, found.span.clone());
+        }
         let found_str = format!("{:?}", found.token_type);
         Self::UnexpectedToken {
             expected: expected.to_string(),
@@ -168,6 +190,9 @@ impl ParseError {
         found: &Token,
         suggestion: &str,
     ) -> Self {
+        if let TokenType::Error(diagnostic) = &found.token_type {
+            return Self::lex_error(diagnostic, found.span.clone());
+        }
         let found_str = format!("{:?}", found.token_type);
         Self::UnexpectedToken {
             expected: expected.to_string(),
@@ -272,6 +297,31 @@ impl ParseError {
         }
     }
 
+    /// Create an out-of-range numeric literal error
+    pub fn literal_out_of_range(literal: &str, ty: &str, range: &str, span: Span) -> Self {
+        Self::LiteralOutOfRange {
+            literal: literal.to_string(),
+            ty: ty.to_string(),
+            span,
+            suggestion: Some(format!("the range of `{}` is {}", ty, range)),
+            severity: ErrorSeverity::Error,
+            error_code: Some("E100"),
+        }
+    }
+
+    /// Create an error for input nested past the parser's depth limit
+    pub fn nesting_too_deep(limit: usize, span: Span) -> Self {
+        Self::NestingTooDeep {
+            limit,
+            span,
+            suggestion: Some(
+                "move the inner expression into a `let` binding or a separate function".to_string(),
+            ),
+            severity: ErrorSeverity::Error,
+            error_code: Some("E101"),
+        }
+    }
+
     /// Create an unexpected EOF error
     pub fn unexpected_eof(expected: &str, span: Span) -> Self {
         Self::UnexpectedEof {
@@ -298,6 +348,8 @@ impl ParseError {
             Self::UnexpectedEof { span, .. } => span,
             Self::Internal { span, .. } => span,
             Self::SyntaxError { span, .. } => span,
+            Self::LiteralOutOfRange { span, .. } => span,
+            Self::NestingTooDeep { span, .. } => span,
             Self::RecoveryError { span, .. } => span,
         }
     }
@@ -318,6 +370,8 @@ impl ParseError {
             Self::UnexpectedEof { .. } => None,
             Self::Internal { .. } => None,
             Self::SyntaxError { suggestion, .. } => suggestion.as_deref(),
+            Self::LiteralOutOfRange { suggestion, .. } => suggestion.as_deref(),
+            Self::NestingTooDeep { suggestion, .. } => suggestion.as_deref(),
             Self::RecoveryError { .. } => None,
         }
     }
@@ -338,6 +392,8 @@ impl ParseError {
             Self::UnexpectedEof { severity, .. } => *severity,
             Self::Internal { severity, .. } => *severity,
             Self::SyntaxError { severity, .. } => *severity,
+            Self::LiteralOutOfRange { severity, .. } => *severity,
+            Self::NestingTooDeep { severity, .. } => *severity,
             Self::RecoveryError { severity, .. } => *severity,
         }
     }
@@ -358,10 +414,23 @@ impl ParseError {
             Self::UnexpectedEof { error_code, .. } => *error_code,
             Self::Internal { error_code, .. } => *error_code,
             Self::SyntaxError { error_code, .. } => *error_code,
+            Self::LiteralOutOfRange { error_code, .. } => *error_code,
+            Self::NestingTooDeep { error_code, .. } => *error_code,
             Self::RecoveryError { error_code, .. } => *error_code,
         }
     }
 
+    /// Report the lexer's diagnostic for input it could not turn into a token
+    pub fn lex_error(diagnostic: &LexDiagnostic, span: Span) -> Self {
+        Self::SyntaxError {
+            message: diagnostic.message.clone(),
+            span,
+            suggestion: diagnostic.help.clone(),
+            severity: ErrorSeverity::Error,
+            error_code: Some(diagnostic.code),
+        }
+    }
+
     /// Create a syntax error with custom message
     pub fn syntax_error(message: &str, span: Span) -> Self {
         Self::SyntaxError {
@@ -421,6 +490,8 @@ impl ParseError {
             Self::UnexpectedEof { severity: s, .. } => *s = severity,
             Self::Internal { severity: s, .. } => *s = severity,
             Self::SyntaxError { severity: s, .. } => *s = severity,
+            Self::LiteralOutOfRange { severity: s, .. } => *s = severity,
+            Self::NestingTooDeep { severity: s, .. } => *s = severity,
             Self::RecoveryError { severity: s, .. } => *s = severity,
         }
         self
@@ -442,6 +513,8 @@ impl ParseError {
             Self::UnexpectedEof { error_code, .. } => *error_code = Some(code),
             Self::Internal { error_code, .. } => *error_code = Some(code),
             Self::SyntaxError { error_code, .. } => *error_code = Some(code),
+            Self::LiteralOutOfRange { error_code, .. } => *error_code = Some(code),
+            Self::NestingTooDeep { error_code, .. } => *error_code = Some(code),
             Self::RecoveryError { error_code, .. } => *error_code = Some(code),
         }
         self
@@ -463,6 +536,18 @@ impl ParseError {
             output.push_str(&format!("  --> line {}:{}", span.line, span.column));
         }
 
+        for expansion in span.expansion_backtrace() {
+            output.push('\n');
+            output.push_str(&format!(
+                "  note: in expansion of `{}!` at line {}:{} (macro defined at line {}:{})",
+                expansion.macro_name,
+                expansion.call_site.line,
+                expansion.call_site.column,
+                expansion.def_site.line,
+                expansion.def_site.column
+            ));
+        }
+
         if let Some(suggestion) = self.suggestion() {
             output.push('\n');
             output.push_str(&format!("  help: {}", suggestion));
@@ -1,6 +1,7 @@
 //! Error recovery strategies for continuing parsing after errors
 
 use crate::error::ParseError;
+use crate::options::{ParserOptions, RecoveryStrategy, DEFAULT_MAX_ERRORS};
 use crate::token::{Token, TokenStream, TokenType};
 
 /// Tokens that can be used for synchronization during error recovery
@@ -37,6 +38,7 @@ impl SyncToken {
                     | TokenType::Fn
                     | TokenType::Data
                     | TokenType::Extern
+                    | TokenType::Macro
             ),
             SyncToken::BlockEnd => {
                 matches!(token.token_type, TokenType::RightBrace | TokenType::Dedent)
@@ -56,6 +58,13 @@ impl SyncToken {
                     | TokenType::FloatLiteral(_)
                     | TokenType::StringLiteral(_)
                     | TokenType::BooleanLiteral(_)
+                    | TokenType::CharLiteral(_)
+                    | TokenType::ByteLiteral(_)
+                    | TokenType::ByteStringLiteral(_)
+                    | TokenType::WideIntegerLiteral(_)
+                    | TokenType::TypedIntegerLiteral(..)
+                    | TokenType::TypedFloatLiteral(..)
+                    | TokenType::InterpolatedStringLiteral(_)
                     | TokenType::LeftParen
                     | TokenType::LeftBracket
                     | TokenType::Minus
@@ -253,7 +262,7 @@ impl Default for PartialRecovery {
     fn default() -> Self {
         Self {
             create_placeholders: true,
-            max_errors: 50,
+            max_errors: DEFAULT_MAX_ERRORS,
             attempt_expression_completion: true,
         }
     }
@@ -433,10 +442,21 @@ impl ErrorRecovery {
         )
     }
 
-    /// Check if we should attempt recovery or give up
+    /// Check if we should attempt recovery or give up under the default options
     pub fn should_continue_recovery<T: TokenStream>(tokens: &T, error_count: usize) -> bool {
-        // Continue if we haven't hit too many errors and aren't at EOF
-        error_count < 100 && !tokens.is_at_end()
+        Self::should_continue_recovery_with(tokens, error_count, &ParserOptions::default())
+    }
+
+    /// Check if we should attempt recovery or give up under `options`
+    pub fn should_continue_recovery_with<T: TokenStream>(
+        tokens: &T,
+        error_count: usize,
+        options: &ParserOptions,
+    ) -> bool {
+        // Continue if tolerant, we haven't hit too many errors and aren't at EOF
+        options.recovery == RecoveryStrategy::Tolerant
+            && error_count < options.max_errors
+            && !tokens.is_at_end()
     }
 
     /// Smart recovery that preserves context and guarantees forward progress
@@ -510,7 +530,7 @@ mod tests {
             TokenType::Plus, // Error token
             TokenType::Star, // Error token
             TokenType::Let,  // Sync token
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
         ];
         let mut stream = VecTokenStream::from_token_types(tokens);
 
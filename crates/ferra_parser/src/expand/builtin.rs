@@ -0,0 +1,575 @@
+//! Compiler-known built-in macros
+//!
+//! `print!`, `println!`, `eprintln!`, `format!`, `vec!`, `assert!` and
+//! `assert_eq!` have no `macro` definition. Their arguments are parsed into
+//! expressions, and format strings are checked against the arguments passed
+//! with them; format string diagnostics point inside the string literal.
+
+use super::{matcher::flatten_trees, transcriber::tree_span};
+use crate::{
+    ast::{
+        Arena, BuiltinMacro, BuiltinMacroKind, Expression, FormatArgs, FormatArgument,
+        FormatArgumentRef, FormatPiece, FormatPlaceholder, FormatString, MacroInvocation,
+        TokenTree,
+    },
+    error::{ParseError, ParseResult},
+    options::ParserOptions,
+    pratt::PrattParser,
+    token::{Span, Token, TokenStream, TokenType, VecTokenStream},
+};
+use std::collections::HashSet;
+
+/// Names of the compiler-known macros
+pub const BUILTIN_MACROS: &[&str] = &[
+    "print",
+    "println",
+    "eprintln",
+    "format",
+    "vec",
+    "assert",
+    "assert_eq",
+];
+
+/// Check whether `name` is a compiler-known macro
+pub fn is_builtin_macro(name: &str) -> bool {
+    BUILTIN_MACROS.contains(&name)
+}
+
+/// Parse the arguments of a built-in macro invocation
+pub fn expand_builtin<'a>(
+    arena: &'a Arena,
+    invocation: &MacroInvocation,
+) -> ParseResult<BuiltinMacro<'a>> {
+    expand_builtin_with_options(arena, invocation, ParserOptions::default())
+}
+
+/// Parse the arguments of a built-in macro invocation under the options of
+/// the parse it came from
+pub(crate) fn expand_builtin_with_options<'a>(
+    arena: &'a Arena,
+    invocation: &MacroInvocation,
+    options: ParserOptions,
+) -> ParseResult<BuiltinMacro<'a>> {
+    let span = invocation.span.clone();
+    let body = match &invocation.arguments[..] {
+        [TokenTree::Group(group)] => &group.tokens[..],
+        arguments => arguments,
+    };
+
+    let kind = match invocation.name.as_str() {
+        "print" | "println" | "eprintln" => BuiltinMacroKind::Print {
+            newline: invocation.name != "print",
+            stderr: invocation.name == "eprintln",
+            format: if body.is_empty() && invocation.name != "print" {
+                FormatArgs {
+                    template: FormatString {
+                        pieces: Vec::new(),
+                        span: span.clone(),
+                    },
+                    arguments: &[],
+                    span: span.clone(),
+                }
+            } else {
+                parse_format_args(arena, options, &split_arguments(body, &span)?, invocation)?
+            },
+        },
+        "format" => BuiltinMacroKind::Format(parse_format_args(
+            arena,
+            options,
+            &split_arguments(body, &span)?,
+            invocation,
+        )?),
+        "vec" => parse_vec(arena, options, body, &span)?,
+        "assert" => {
+            let arguments = split_arguments(body, &span)?;
+            let (condition, message) = arguments.split_first().ok_or_else(|| {
+                missing_arguments(invocation, "a condition", "assert!(condition)")
+            })?;
+            BuiltinMacroKind::Assert {
+                condition: parse_argument(arena, condition, options)?,
+                message: parse_message(arena, options, message, invocation)?,
+            }
+        }
+        "assert_eq" => {
+            let arguments = split_arguments(body, &span)?;
+            let [left, right, message @ ..] = &arguments[..] else {
+                return Err(missing_arguments(
+                    invocation,
+                    "two values to compare",
+                    "assert_eq!(left, right)",
+                ));
+            };
+            BuiltinMacroKind::AssertEq {
+                left: parse_argument(arena, left, options)?,
+                right: parse_argument(arena, right, options)?,
+                message: parse_message(arena, options, message, invocation)?,
+            }
+        }
+        name => {
+            return Err(ParseError::internal(
+                &format!("`{}!` is not a built-in macro", name),
+                span,
+            ))
+        }
+    };
+
+    Ok(BuiltinMacro {
+        name: invocation.name,
+        kind,
+        span,
+    })
+}
+
+fn missing_arguments(invocation: &MacroInvocation, expected: &str, usage: &str) -> ParseError {
+    ParseError::syntax_error_with_suggestion(
+        &format!("`{}!` requires {}", invocation.name, expected),
+        invocation.span.clone(),
+        &format!("use `{}`", usage),
+    )
+}
+
+/// One comma-separated argument with its span
+struct Argument<'t> {
+    trees: &'t [TokenTree],
+    span: Span,
+}
+
+/// Split invocation input at top-level commas, allowing a trailing comma
+fn split_arguments<'t>(trees: &'t [TokenTree], span: &Span) -> ParseResult<Vec<Argument<'t>>> {
+    let mut arguments = Vec::new();
+    if trees.is_empty() {
+        return Ok(arguments);
+    }
+
+    let mut start = 0;
+    for (index, tree) in trees.iter().enumerate() {
+        if let TokenTree::Token(token) = tree {
+            if token.token_type == TokenType::Comma {
+                if index == start {
+                    return Err(ParseError::expected_expression(token.span.clone()));
+                }
+                arguments.push(argument(&trees[start..index]));
+                start = index + 1;
+            }
+        }
+    }
+    if start < trees.len() {
+        arguments.push(argument(&trees[start..]));
+    } else if arguments.is_empty() {
+        return Err(ParseError::expected_expression(span.clone()));
+    }
+    Ok(arguments)
+}
+
+fn argument(trees: &[TokenTree]) -> Argument<'_> {
+    let span = trees
+        .iter()
+        .map(tree_span)
+        .reduce(|a, b| a.combine(b))
+        .unwrap_or_else(Span::dummy);
+    Argument { trees, span }
+}
+
+/// Parse one argument as a complete expression
+fn parse_argument<'a>(
+    arena: &'a Arena,
+    argument: &Argument,
+    options: ParserOptions,
+) -> ParseResult<&'a Expression<'a>> {
+    let mut tokens = flatten_trees(argument.trees);
+    tokens.push(Token::new(TokenType::Eof, argument.span.clone()));
+    let mut stream = VecTokenStream::new(tokens);
+    let expression = PrattParser::new(arena, &mut stream)
+        .with_options(options)
+        .parse_expression(0)?;
+
+    if !stream.is_at_end() {
+        return Err(ParseError::unexpected_token(
+            "`,` or end of macro arguments",
+            stream.peek(),
+        ));
+    }
+    Ok(expression)
+}
+
+fn parse_message<'a>(
+    arena: &'a Arena,
+    options: ParserOptions,
+    arguments: &[Argument],
+    invocation: &MacroInvocation,
+) -> ParseResult<Option<FormatArgs<'a>>> {
+    if arguments.is_empty() {
+        Ok(None)
+    } else {
+        parse_format_args(arena, options, arguments, invocation).map(Some)
+    }
+}
+
+/// Parse `vec![a, b, c]` or `vec![value; count]`
+fn parse_vec<'a>(
+    arena: &'a Arena,
+    options: ParserOptions,
+    body: &[TokenTree],
+    span: &Span,
+) -> ParseResult<BuiltinMacroKind<'a>> {
+    let semicolon = body.iter().position(
+        |tree| matches!(tree, TokenTree::Token(token) if token.token_type == TokenType::Semicolon),
+    );
+
+    match semicolon {
+        Some(index) => {
+            let (value, count) = (&body[..index], &body[index + 1..]);
+            if value.is_empty() || count.is_empty() {
+                return Err(ParseError::syntax_error_with_suggestion(
+                    "`vec!` repeat form needs a value and a count",
+                    span.clone(),
+                    "use `vec![value; count]`",
+                ));
+            }
+            Ok(BuiltinMacroKind::VecRepeat {
+                value: parse_argument(arena, &argument(value), options)?,
+                count: parse_argument(arena, &argument(count), options)?,
+            })
+        }
+        None => Ok(BuiltinMacroKind::Vec(
+            arena.alloc_vec(
+                split_arguments(body, span)?
+                    .iter()
+                    .map(|argument| parse_argument(arena, argument, options).cloned())
+                    .collect::<ParseResult<_>>()?,
+            ),
+        )),
+    }
+}
+
+/// Parse a format string literal followed by positional and `name = value`
+/// arguments, and check that every placeholder and argument lines up
+fn parse_format_args<'a>(
+    arena: &'a Arena,
+    options: ParserOptions,
+    arguments: &[Argument],
+    invocation: &MacroInvocation,
+) -> ParseResult<FormatArgs<'a>> {
+    let (template, rest) = arguments.split_first().ok_or_else(|| {
+        missing_arguments(
+            invocation,
+            "a format string",
+            &format!("{}!(\"...\")", invocation.name),
+        )
+    })?;
+
+    let template = match template.trees {
+        [TokenTree::Token(
+            token @ Token {
+                token_type: TokenType::StringLiteral(value),
+                ..
+            },
+        )] => parse_format_string_spelled(value, token.spelling.as_deref(), &token.span)?,
+        _ => {
+            return Err(ParseError::syntax_error_with_suggestion(
+                "format argument must be a string literal",
+                template.span.clone(),
+                "you might be missing a string literal to format with: `\"{}\", value`",
+            ))
+        }
+    };
+
+    let mut parsed = Vec::with_capacity(rest.len());
+    let mut names = HashSet::new();
+    for argument in rest {
+        if let [TokenTree::Token(Token {
+            token_type: TokenType::Identifier(name),
+            ..
+        }), TokenTree::Token(Token {
+            token_type: TokenType::Equal,
+            ..
+        }), value @ ..] = argument.trees
+        {
+            if !value.is_empty() {
+                if !names.insert(*name) {
+                    return Err(ParseError::syntax_error(
+                        &format!("duplicate argument named `{}`", name),
+                        argument.span.clone(),
+                    ));
+                }
+                parsed.push(FormatArgument {
+                    name: Some(*name),
+                    value: parse_argument(arena, &self::argument(value), options)?,
+                    span: argument.span.clone(),
+                });
+                continue;
+            }
+        }
+
+        if !names.is_empty() {
+            return Err(ParseError::syntax_error_with_suggestion(
+                "positional arguments cannot follow named arguments",
+                argument.span.clone(),
+                "move this argument before the named arguments",
+            ));
+        }
+        parsed.push(FormatArgument {
+            name: None,
+            value: parse_argument(arena, argument, options)?,
+            span: argument.span.clone(),
+        });
+    }
+
+    let template = resolve_placeholders(template, &parsed)?;
+    let span = arguments
+        .iter()
+        .map(|argument| argument.span.clone())
+        .reduce(|a, b| a.combine(b))
+        .unwrap_or_else(|| invocation.span.clone());
+
+    Ok(FormatArgs {
+        template,
+        arguments: arena.alloc_vec(parsed),
+        span,
+    })
+}
+
+/// Split a format string into text and placeholders
+///
+/// `literal_span` is the span of the string literal token, used to point
+/// diagnostics at the offending characters inside it.
+pub fn parse_format_string(value: &str, literal_span: &Span) -> ParseResult<FormatString> {
+    parse_format_string_spelled(value, None, literal_span)
+}
+
+/// Split a format string into text and placeholders, given the literal's
+/// source spelling when it differs from `"value"` (see [`Token::spelling`])
+pub fn parse_format_string_spelled(
+    value: &str,
+    spelling: Option<&str>,
+    literal_span: &Span,
+) -> ParseResult<FormatString> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut implicit = 0;
+    let mut chars = value.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if matches!(chars.peek(), Some((_, '{'))) => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                chars.next();
+                text.push('}');
+            }
+            '}' => {
+                return Err(ParseError::syntax_error_with_suggestion(
+                    "invalid format string: unmatched `}` found",
+                    inner_span(literal_span, spelling, value, start, start + 1),
+                    "if you intended to print `}`, you can escape it using `}}`",
+                ))
+            }
+            '{' => {
+                let Some(end) = value[start..].find('}').map(|offset| start + offset) else {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        "invalid format string: expected `}` but string was terminated",
+                        inner_span(literal_span, spelling, value, start, value.len()),
+                        "if you intended to print `{`, you can escape it using `{{`",
+                    ));
+                };
+                while chars.next_if(|(index, _)| *index < end).is_some() {}
+                chars.next();
+
+                let span = inner_span(literal_span, spelling, value, start, end + 1);
+                let contents = &value[start + 1..end];
+                let (name, spec) = match contents.split_once(':') {
+                    Some((name, spec)) => (name.trim(), Some(spec.to_string())),
+                    None => (contents.trim(), None),
+                };
+                let argument = parse_argument_ref(name, &mut implicit).ok_or_else(|| {
+                    ParseError::syntax_error_with_suggestion(
+                        &format!("invalid format string: invalid argument name `{}`", name),
+                        span.clone(),
+                        "use `{}`, a position such as `{0}` or a name such as `{value}`",
+                    )
+                })?;
+
+                if !text.is_empty() {
+                    pieces.push(FormatPiece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(FormatPiece::Placeholder(FormatPlaceholder {
+                    argument,
+                    spec,
+                    span,
+                }));
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if !text.is_empty() {
+        pieces.push(FormatPiece::Text(text));
+    }
+    Ok(FormatString {
+        pieces,
+        span: literal_span.clone(),
+    })
+}
+
+fn parse_argument_ref(name: &str, implicit: &mut usize) -> Option<FormatArgumentRef> {
+    if name.is_empty() {
+        *implicit += 1;
+        Some(FormatArgumentRef::Index(*implicit - 1))
+    } else if name.chars().all(|c| c.is_ascii_digit()) {
+        name.parse().ok().map(FormatArgumentRef::Index)
+    } else if name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+    {
+        Some(FormatArgumentRef::Named(name.into()))
+    } else {
+        None
+    }
+}
+
+/// Bind placeholders to arguments: names without a matching `name = value`
+/// argument capture the variable of that name, and every argument must be used
+fn resolve_placeholders(
+    mut template: FormatString,
+    arguments: &[FormatArgument],
+) -> ParseResult<FormatString> {
+    let positional = arguments.iter().filter(|a| a.name.is_none()).count();
+    let mut used = vec![false; arguments.len()];
+
+    for piece in &mut template.pieces {
+        let FormatPiece::Placeholder(placeholder) = piece else {
+            continue;
+        };
+        match &placeholder.argument {
+            FormatArgumentRef::Index(index) if *index < positional => used[*index] = true,
+            FormatArgumentRef::Index(index) => {
+                return Err(ParseError::syntax_error(
+                    &format!(
+                        "invalid reference to positional argument {} ({})",
+                        index,
+                        describe_count(positional)
+                    ),
+                    placeholder.span.clone(),
+                ))
+            }
+            FormatArgumentRef::Named(name) => {
+                match arguments
+                    .iter()
+                    .position(|a| a.name.as_deref() == Some(name.as_str()))
+                {
+                    Some(index) => used[index] = true,
+                    None => placeholder.argument = FormatArgumentRef::Captured(*name),
+                }
+            }
+            FormatArgumentRef::Captured(_) => {}
+        }
+    }
+
+    if let Some(index) = used.iter().position(|used| !used) {
+        let argument = &arguments[index];
+        let message = match &argument.name {
+            Some(name) => format!("named argument `{}` never used", name),
+            None => "argument never used".to_string(),
+        };
+        return Err(ParseError::syntax_error_with_suggestion(
+            &message,
+            argument.span.clone(),
+            "add a placeholder such as `{}` to the format string, or remove the argument",
+        ));
+    }
+
+    Ok(template)
+}
+
+fn describe_count(count: usize) -> String {
+    match count {
+        0 => "no arguments were given".to_string(),
+        1 => "there is 1 argument".to_string(),
+        count => format!("there are {} arguments", count),
+    }
+}
+
+/// Span of `value[start..end]` inside its string literal
+///
+/// Parser tokens carry the unescaped value, so offsets are mapped back through
+/// the literal's spelling when it has escapes or is a raw string. Multi-line
+/// strings have their indentation stripped, so the whole literal is used.
+fn inner_span(
+    literal_span: &Span,
+    spelling: Option<&str>,
+    value: &str,
+    start: usize,
+    end: usize,
+) -> Span {
+    let exact = literal_span.end.saturating_sub(literal_span.start) == value.len() + 2
+        && !value.contains('\n');
+    let source_offset = |index: usize| match spelling {
+        Some(spelling) => spelled_offset(spelling, value, index),
+        None if exact => Some(1 + index),
+        None => None,
+    };
+    let (Some(from), Some(to)) = (source_offset(start), source_offset(end)) else {
+        return literal_span.clone();
+    };
+
+    // Raw strings may span lines
+    let before = spelling.map_or(&value[..start], |spelling| &spelling[..from]);
+    let (line, column) = match before.rfind('\n') {
+        Some(newline) => (
+            literal_span.line + before.matches('\n').count(),
+            before[newline + 1..].chars().count() + 1,
+        ),
+        None if spelling.is_some() => (
+            literal_span.line,
+            literal_span.column + before.chars().count(),
+        ),
+        None => (
+            literal_span.line,
+            literal_span.column + 1 + before.chars().count(),
+        ),
+    };
+    let mut span = Span::new(
+        literal_span.start + from,
+        literal_span.start + to,
+        line,
+        column,
+    );
+    span.expansion = literal_span.expansion.clone();
+    span
+}
+
+/// Offset within `spelling` of byte `index` of the literal's value
+///
+/// Walks the spelling alongside the value, stepping over whole escape
+/// sequences; `None` for spellings whose characters do not map one to one.
+fn spelled_offset(spelling: &str, value: &str, index: usize) -> Option<usize> {
+    let (content, escapes) = if let Some(raw) = spelling.strip_prefix('r') {
+        (&raw[raw.find('"')? + 1..], false)
+    } else if spelling.starts_with("\"\"\"") {
+        return None;
+    } else {
+        (spelling.strip_prefix('"')?, true)
+    };
+    let mut offset = spelling.len() - content.len();
+    let mut source = content.chars();
+    for (position, _) in value.char_indices() {
+        if position >= index {
+            break;
+        }
+        let c = source.next()?;
+        offset += c.len_utf8();
+        if escapes && c == '\\' {
+            match source.next()? {
+                'u' => {
+                    // \u{...}
+                    let digits = source.as_str().find('}')? + 1;
+                    offset += 1 + digits;
+                    source = source.as_str()[digits..].chars();
+                }
+                escaped => offset += escaped.len_utf8(),
+            }
+        }
+    }
+    Some(offset)
+}
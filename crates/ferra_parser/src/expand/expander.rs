@@ -0,0 +1,651 @@
+//! Macro expansion pass
+//!
+//! Expands invocations of macros defined with `macro name { ... }` items and
+//! re-parses each expansion in the syntactic position of its invocation.
+//! Expanded output is expanded again until no user-defined invocations remain.
+//! Invocations of built-in macros that no `macro` item shadows become
+//! `Expression::BuiltinMacro` nodes with parsed, checked arguments.
+
+use super::{
+    builtin::{expand_builtin_with_options, is_builtin_macro},
+    matcher::{flatten_trees, match_rule_with_options},
+    transcriber::transcribe,
+};
+use crate::{
+    ast::{
+        Arena, Block, CompilationUnit, Expression, ExternItem, Item, MacroDefinition,
+        MacroInvocation, Parameter, Pattern, Statement, TokenTree, Type,
+    },
+    block::BlockParser,
+    error::{ParseError, ParseResult},
+    options::{Feature, ParseContext, ParserOptions},
+    pratt::PrattParser,
+    program::ProgramParser,
+    token::{ExpansionInfo, Symbol, Token, TokenStream, TokenType, VecTokenStream},
+    types::parse_type_with_context,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Default limit on nested expansions before reporting runaway recursion
+pub const DEFAULT_RECURSION_LIMIT: usize = 64;
+
+/// Syntactic position an invocation is expanded in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacroPosition {
+    Expression,
+    Statement,
+    Item,
+    Pattern,
+    Type,
+}
+
+impl MacroPosition {
+    fn describe(self) -> &'static str {
+        match self {
+            MacroPosition::Expression => "an expression",
+            MacroPosition::Statement => "statements",
+            MacroPosition::Item => "items",
+            MacroPosition::Pattern => "a pattern",
+            MacroPosition::Type => "a type",
+        }
+    }
+}
+
+/// Result of expanding one invocation
+#[derive(Debug, Clone)]
+pub enum Expansion<'a> {
+    Expression(Expression<'a>),
+    Statements(Vec<Statement<'a>>),
+    Items(Vec<Item<'a>>),
+    Pattern(Pattern<'a>),
+    Type(Type<'a>),
+}
+
+/// Expand every user-defined macro invocation in a compilation unit,
+/// returning the expanded copy
+pub fn expand_macros<'a>(
+    arena: &'a Arena,
+    unit: &CompilationUnit<'a>,
+) -> ParseResult<CompilationUnit<'a>> {
+    let mut unit = unit.clone();
+    MacroExpander::from_compilation_unit(arena, &unit).expand_compilation_unit(&mut unit)?;
+    Ok(unit)
+}
+
+/// Expands invocations of user-defined and built-in macros
+///
+/// Invocations of other macros it has no definition for are left untouched.
+/// Expansions are parsed into the expander's arena; since arena nodes are
+/// shared, nodes on the path to an expanded invocation are copied rather
+/// than edited in place.
+pub struct MacroExpander<'a> {
+    arena: &'a Arena,
+    macros: HashMap<Symbol, MacroDefinition<'a>>,
+    next_mark: u32,
+    depth: usize,
+    recursion_limit: usize,
+    options: ParserOptions,
+}
+
+impl<'a> MacroExpander<'a> {
+    /// Create an expander with no macros defined
+    pub fn new(arena: &'a Arena) -> Self {
+        Self {
+            arena,
+            macros: HashMap::new(),
+            next_mark: 0,
+            depth: 0,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            options: ParserOptions::default(),
+        }
+    }
+
+    /// Create an expander with the macros defined at the top level of a unit
+    pub fn from_compilation_unit(arena: &'a Arena, unit: &CompilationUnit<'a>) -> Self {
+        let mut expander = Self::new(arena);
+        for item in unit.items {
+            if let Item::MacroDefinition(macro_def) = item {
+                expander.define(macro_def.clone());
+            }
+        }
+        expander
+    }
+
+    /// Set how deeply expansions may nest
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
+    /// Parse invocation input and expansions with `options`, normally those
+    /// the compilation unit was parsed with
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Register a macro; a later definition replaces an earlier one
+    pub fn define(&mut self, macro_def: MacroDefinition<'a>) {
+        self.macros.insert(macro_def.name, macro_def);
+    }
+
+    /// Check whether a macro is defined
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.macros.contains_key(&Symbol::intern(name))
+    }
+
+    /// Expand a single invocation in the given position, including any
+    /// invocations its expansion produces
+    pub fn expand_invocation(
+        &mut self,
+        invocation: &MacroInvocation,
+        position: MacroPosition,
+    ) -> ParseResult<Expansion<'a>> {
+        let macro_def = self.macros.get(&invocation.name).ok_or_else(|| {
+            ParseError::syntax_error(
+                &format!("cannot find macro `{}` in this scope", invocation.name),
+                invocation.span.clone(),
+            )
+        })?;
+
+        if self.depth >= self.recursion_limit {
+            return Err(ParseError::syntax_error_with_suggestion(
+                &format!(
+                    "recursion limit of {} reached while expanding `{}!`",
+                    self.recursion_limit, invocation.name
+                ),
+                invocation.span.clone(),
+                "check the macro for an expansion that invokes itself unconditionally",
+            ));
+        }
+
+        let input = invocation_body(&invocation.arguments);
+        let (rule, bindings) = macro_def
+            .rules
+            .iter()
+            .find_map(|rule| {
+                match_rule_with_options(rule.matcher, input, self.options).map(|b| (rule, b))
+            })
+            .ok_or_else(|| {
+                ParseError::syntax_error_with_suggestion(
+                    &format!(
+                        "no rules of macro `{}` matched this invocation",
+                        invocation.name
+                    ),
+                    invocation.span.clone(),
+                    &format!(
+                        "check the arguments against the rules of `{}`",
+                        invocation.name
+                    ),
+                )
+            })?;
+
+        self.next_mark += 1;
+        let expansion = Arc::new(ExpansionInfo {
+            macro_name: invocation.name,
+            call_site: invocation.span.clone(),
+            def_site: macro_def.span.clone(),
+            mark: self.next_mark,
+        });
+        let trees = transcribe(rule.transcriber, &bindings, &expansion)?;
+        let mut result = reparse(self.arena, &trees, position, &expansion, self.options)?;
+
+        self.depth += 1;
+        let nested = self.expand_nested(&mut result);
+        self.depth -= 1;
+        nested?;
+
+        Ok(result)
+    }
+
+    /// Expand all user-defined and built-in invocations in a compilation unit in place
+    pub fn expand_compilation_unit(&mut self, unit: &mut CompilationUnit<'a>) -> ParseResult<()> {
+        unit.items = self.arena.alloc_vec(self.expand_items(unit.items)?);
+        Ok(())
+    }
+
+    fn expand_nested(&mut self, expansion: &mut Expansion<'a>) -> ParseResult<()> {
+        match expansion {
+            Expansion::Expression(expr) => self.expand_expression(expr),
+            Expansion::Statements(statements) => {
+                *statements = self.expand_statements(statements)?;
+                Ok(())
+            }
+            Expansion::Items(items) => {
+                *items = self.expand_items(items)?;
+                Ok(())
+            }
+            Expansion::Pattern(pattern) => self.expand_pattern(pattern),
+            Expansion::Type(ty) => self.expand_type(ty),
+        }
+    }
+
+    /// Expand items, splicing item-position expansions in place
+    fn expand_items(&mut self, items: &[Item<'a>]) -> ParseResult<Vec<Item<'a>>> {
+        let mut expanded = Vec::with_capacity(items.len());
+        for item in items {
+            let mut item = item.clone();
+            match &mut item {
+                Item::Macro(macro_item) if self.is_defined(&macro_item.invocation.name) => {
+                    match self.expand_invocation(&macro_item.invocation, MacroPosition::Item)? {
+                        Expansion::Items(spliced) => expanded.extend(spliced),
+                        _ => unreachable!("item position expands to items"),
+                    }
+                    continue;
+                }
+                Item::Macro(macro_item) if is_builtin_macro(&macro_item.invocation.name) => {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        &format!(
+                            "built-in macro `{}!` cannot be used in item position",
+                            macro_item.invocation.name
+                        ),
+                        macro_item.span.clone(),
+                        "move the invocation into a function body",
+                    ));
+                }
+                Item::FunctionDecl(function) => {
+                    self.expand_parameters(&mut function.parameters)?;
+                    if let Some(return_type) = &mut function.return_type {
+                        self.expand_child_type(return_type)?;
+                    }
+                    if let Some(body) = &mut function.body {
+                        self.expand_child_block(body)?;
+                    }
+                }
+                Item::VariableDecl(variable) => {
+                    if let Some(var_type) = &mut variable.var_type {
+                        self.expand_child_type(var_type)?;
+                    }
+                    if let Some(initializer) = &mut variable.initializer {
+                        self.expand_child(initializer)?;
+                    }
+                }
+                Item::DataClassDecl(data_class) => {
+                    let arena = self.arena;
+                    data_class.fields = arena.alloc_slice_edited(data_class.fields, |field| {
+                        self.expand_child_type(&mut field.field_type)
+                    })?;
+                }
+                Item::ExternBlock(extern_block) => {
+                    let arena = self.arena;
+                    extern_block.items =
+                        arena.alloc_slice_edited(extern_block.items, |item| match item {
+                            ExternItem::Function(function) => {
+                                self.expand_parameters(&mut function.parameters)?;
+                                match &mut function.return_type {
+                                    Some(return_type) => self.expand_child_type(return_type),
+                                    None => Ok(()),
+                                }
+                            }
+                            ExternItem::Variable(variable) => {
+                                self.expand_child_type(&mut variable.var_type)
+                            }
+                        })?;
+                }
+                Item::MacroDefinition(_) | Item::Macro(_) => {}
+            }
+            expanded.push(item);
+        }
+        Ok(expanded)
+    }
+
+    fn expand_block(&mut self, block: &mut Block<'a>) -> ParseResult<()> {
+        block.statements = self
+            .arena
+            .alloc_vec(self.expand_statements(block.statements)?);
+        Ok(())
+    }
+
+    /// Expand statements, splicing statement-position expansions in place
+    fn expand_statements(
+        &mut self,
+        statements: &[Statement<'a>],
+    ) -> ParseResult<Vec<Statement<'a>>> {
+        let mut expanded = Vec::with_capacity(statements.len());
+        for statement in statements {
+            match statement {
+                Statement::Expression(Expression::Macro(invocation))
+                    if self.is_defined(&invocation.name) =>
+                {
+                    match self.expand_invocation(invocation, MacroPosition::Statement)? {
+                        Expansion::Statements(spliced) => expanded.extend(spliced),
+                        _ => unreachable!("statement position expands to statements"),
+                    }
+                }
+                _ => {
+                    let mut statement = statement.clone();
+                    self.expand_statement(&mut statement)?;
+                    expanded.push(statement);
+                }
+            }
+        }
+        Ok(expanded)
+    }
+
+    fn expand_statement(&mut self, statement: &mut Statement<'a>) -> ParseResult<()> {
+        match statement {
+            Statement::Attributed(attributed) => {
+                let arena = self.arena;
+                attributed.statement = arena.alloc_edited(attributed.statement, |statement| {
+                    self.expand_statement(statement)
+                })?;
+                Ok(())
+            }
+            Statement::Expression(expr) => self.expand_child(expr),
+            Statement::VariableDecl(variable) => {
+                if let Some(var_type) = &mut variable.var_type {
+                    self.expand_child_type(var_type)?;
+                }
+                match &mut variable.initializer {
+                    Some(initializer) => self.expand_child(initializer),
+                    None => Ok(()),
+                }
+            }
+            Statement::If(if_stmt) => {
+                self.expand_child(&mut if_stmt.condition)?;
+                self.expand_child_block(&mut if_stmt.then_block)?;
+                match &mut if_stmt.else_block {
+                    Some(else_block) => self.expand_child_block(else_block),
+                    None => Ok(()),
+                }
+            }
+            Statement::While(while_stmt) => {
+                self.expand_child(&mut while_stmt.condition)?;
+                self.expand_child_block(&mut while_stmt.body)
+            }
+            Statement::For(for_stmt) => {
+                self.expand_child(&mut for_stmt.iterable)?;
+                self.expand_child_block(&mut for_stmt.body)
+            }
+            Statement::Return(return_stmt) => match &mut return_stmt.value {
+                Some(value) => self.expand_child(value),
+                None => Ok(()),
+            },
+            Statement::Block(block) => self.expand_block(block),
+            Statement::Break(_) | Statement::Continue(_) => Ok(()),
+        }
+    }
+
+    fn expand_expression(&mut self, expr: &mut Expression<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        match expr {
+            Expression::Macro(invocation) => {
+                if self.is_defined(&invocation.name) {
+                    match self.expand_invocation(invocation, MacroPosition::Expression)? {
+                        Expansion::Expression(expanded) => *expr = expanded,
+                        _ => unreachable!("expression position expands to an expression"),
+                    }
+                } else if is_builtin_macro(&invocation.name) {
+                    let builtin = expand_builtin_with_options(arena, invocation, self.options)?
+                        .edit_arguments(arena, |argument| self.expand_expression(argument))?;
+                    *expr = Expression::BuiltinMacro(builtin);
+                }
+            }
+            Expression::BuiltinMacro(builtin) => {
+                *builtin =
+                    builtin.edit_arguments(arena, |argument| self.expand_expression(argument))?;
+            }
+            Expression::Block(block_expr) => {
+                self.expand_child_block(&mut block_expr.block)?;
+                if let Some(value) = &mut block_expr.value {
+                    self.expand_child(value)?;
+                }
+            }
+            Expression::Match(match_expr) => {
+                self.expand_child(&mut match_expr.scrutinee)?;
+                match_expr.arms = arena.alloc_slice_edited(match_expr.arms, |arm| {
+                    self.expand_child_pattern(&mut arm.pattern)?;
+                    self.expand_child(&mut arm.expression)
+                })?;
+            }
+            Expression::Binary(binary) => {
+                self.expand_child(&mut binary.left)?;
+                self.expand_child(&mut binary.right)?;
+            }
+            Expression::Unary(unary) => self.expand_child(&mut unary.operand)?,
+            Expression::Call(call) => {
+                self.expand_child(&mut call.callee)?;
+                self.expand_children(&mut call.arguments)?;
+            }
+            Expression::MemberAccess(member) => self.expand_child(&mut member.object)?,
+            Expression::Index(index) => {
+                self.expand_child(&mut index.object)?;
+                self.expand_child(&mut index.index)?;
+            }
+            Expression::Await(await_expr) => self.expand_child(&mut await_expr.expression)?,
+            Expression::Array(array) => self.expand_children(&mut array.elements)?,
+            Expression::Tuple(tuple) => self.expand_children(&mut tuple.elements)?,
+            Expression::If(if_expr) => {
+                self.expand_child(&mut if_expr.condition)?;
+                self.expand_child(&mut if_expr.then_expr)?;
+                if let Some(else_expr) = &mut if_expr.else_expr {
+                    self.expand_child(else_expr)?;
+                }
+            }
+            Expression::Grouped(inner) => self.expand_child(inner)?,
+            Expression::DataClassLiteral(literal) => {
+                literal.fields = arena.alloc_slice_edited(literal.fields, |field| {
+                    self.expand_child(&mut field.value)
+                })?;
+            }
+            Expression::Interpolated(interpolated) => {
+                *interpolated = interpolated
+                    .edit_expressions(arena, |expression| self.expand_expression(expression))?;
+            }
+            Expression::Literal(_) | Expression::Identifier(_) | Expression::Path(_) => {}
+        }
+        Ok(())
+    }
+
+    fn expand_pattern(&mut self, pattern: &mut Pattern<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        match pattern {
+            Pattern::Macro(invocation) => {
+                if self.is_defined(&invocation.name) {
+                    match self.expand_invocation(invocation, MacroPosition::Pattern)? {
+                        Expansion::Pattern(expanded) => *pattern = expanded,
+                        _ => unreachable!("pattern position expands to a pattern"),
+                    }
+                }
+            }
+            Pattern::DataClass(data_class) => {
+                data_class.fields = arena.alloc_slice_edited(data_class.fields, |field| {
+                    match &mut field.pattern {
+                        Some(pattern) => self.expand_child_pattern(pattern),
+                        None => Ok(()),
+                    }
+                })?;
+            }
+            Pattern::Range(range) => {
+                if let Some(start) = &mut range.start {
+                    self.expand_child_pattern(start)?;
+                }
+                if let Some(end) = &mut range.end {
+                    self.expand_child_pattern(end)?;
+                }
+            }
+            Pattern::Slice(slice) => {
+                slice.prefix = arena
+                    .alloc_slice_edited(slice.prefix, |element| self.expand_pattern(element))?;
+                slice.suffix = arena
+                    .alloc_slice_edited(slice.suffix, |element| self.expand_pattern(element))?;
+            }
+            Pattern::Or(or) => {
+                or.patterns = arena.alloc_slice_edited(or.patterns, |alternative| {
+                    self.expand_pattern(alternative)
+                })?;
+            }
+            Pattern::Guard(guard) => {
+                self.expand_child_pattern(&mut guard.pattern)?;
+                self.expand_child(&mut guard.guard)?;
+            }
+            Pattern::Binding(binding) => self.expand_child_pattern(&mut binding.pattern)?,
+            Pattern::Literal(_) | Pattern::Identifier(_) | Pattern::Wildcard | Pattern::Path(_) => {
+            }
+        }
+        Ok(())
+    }
+
+    fn expand_type(&mut self, ty: &mut Type<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        match ty {
+            Type::Macro(invocation) => {
+                if self.is_defined(&invocation.name) {
+                    match self.expand_invocation(invocation, MacroPosition::Type)? {
+                        Expansion::Type(expanded) => *ty = expanded,
+                        _ => unreachable!("type position expands to a type"),
+                    }
+                }
+            }
+            Type::Path(path) => {
+                path.segments = arena.alloc_slice_edited(path.segments, |segment| {
+                    segment.generic_args = arena
+                        .alloc_slice_edited(segment.generic_args, |arg| self.expand_type(arg))?;
+                    Ok(())
+                })?;
+            }
+            Type::Tuple(elements) => {
+                *elements =
+                    arena.alloc_slice_edited(elements, |element| self.expand_type(element))?;
+            }
+            Type::Array(element) => self.expand_child_type(element)?,
+            Type::Function(function) => {
+                function.parameters = arena
+                    .alloc_slice_edited(function.parameters, |parameter| {
+                        self.expand_type(parameter)
+                    })?;
+                self.expand_child_type(&mut function.return_type)?;
+            }
+            Type::Pointer(pointer) => self.expand_child_type(&mut pointer.target)?,
+            Type::Identifier(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Point `parameters` at copies with their types expanded
+    fn expand_parameters(&mut self, parameters: &mut &'a [Parameter<'a>]) -> ParseResult<()> {
+        let arena = self.arena;
+        *parameters = arena.alloc_slice_edited(parameters, |parameter| {
+            self.expand_child_type(&mut parameter.param_type)
+        })?;
+        Ok(())
+    }
+
+    /// Point `pattern` at an expanded copy of the pattern
+    fn expand_child_pattern(&mut self, pattern: &mut &'a Pattern<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        *pattern = arena.alloc_edited(*pattern, |pattern| self.expand_pattern(pattern))?;
+        Ok(())
+    }
+
+    /// Point `ty` at an expanded copy of the type
+    fn expand_child_type(&mut self, ty: &mut &'a Type<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        *ty = arena.alloc_edited(*ty, |ty| self.expand_type(ty))?;
+        Ok(())
+    }
+
+    /// Point `expr` at an expanded copy of the expression
+    fn expand_child(&mut self, expr: &mut &'a Expression<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        *expr = arena.alloc_edited(*expr, |expr| self.expand_expression(expr))?;
+        Ok(())
+    }
+
+    /// Point `exprs` at expanded copies of the expressions
+    fn expand_children(&mut self, exprs: &mut &'a [Expression<'a>]) -> ParseResult<()> {
+        let arena = self.arena;
+        *exprs = arena.alloc_slice_edited(exprs, |expr| self.expand_expression(expr))?;
+        Ok(())
+    }
+
+    /// Point `block` at an expanded copy of the block
+    fn expand_child_block(&mut self, block: &mut &'a Block<'a>) -> ParseResult<()> {
+        let arena = self.arena;
+        *block = arena.alloc_edited(*block, |block| self.expand_block(block))?;
+        Ok(())
+    }
+}
+
+/// Token trees inside the invocation delimiters
+fn invocation_body(arguments: &[TokenTree]) -> &[TokenTree] {
+    match arguments {
+        [TokenTree::Group(group)] => &group.tokens,
+        _ => arguments,
+    }
+}
+
+/// Parse transcribed token trees in the position of the invocation
+fn reparse<'a>(
+    arena: &'a Arena,
+    trees: &[TokenTree],
+    position: MacroPosition,
+    expansion: &Arc<ExpansionInfo>,
+    options: ParserOptions,
+) -> ParseResult<Expansion<'a>> {
+    let end_span = expansion
+        .call_site
+        .clone()
+        .with_expansion(expansion.clone());
+    let mut tokens = flatten_trees(trees);
+    if position == MacroPosition::Statement {
+        tokens.insert(0, Token::new(TokenType::LeftBrace, end_span.clone()));
+        tokens.push(Token::new(TokenType::RightBrace, end_span.clone()));
+    }
+    tokens.push(Token::new(TokenType::Eof, end_span));
+    let mut stream = VecTokenStream::new(tokens);
+
+    let result = match position {
+        MacroPosition::Expression => Expansion::Expression(
+            PrattParser::new(arena, &mut stream)
+                .with_options(options)
+                .parse_expression(0)?
+                .clone(),
+        ),
+        MacroPosition::Statement => Expansion::Statements(
+            BlockParser::new(arena)
+                .with_options(options)
+                .parse_braced_block(&mut stream)?
+                .statements
+                .to_vec(),
+        ),
+        // Item parsing always runs to the end of the expansion
+        MacroPosition::Item => {
+            // Only defined macros reach expansion, so their output may define macros too
+            let unit = ProgramParser::new(arena, stream)
+                .with_options(options.with_feature(Feature::MacroDefinitions))
+                .parse_compilation_unit()
+                .map_err(|errors| errors.into_iter().next().unwrap())?;
+            return Ok(Expansion::Items(unit.items.to_vec()));
+        }
+        MacroPosition::Pattern => Expansion::Pattern(
+            PrattParser::new(arena, &mut stream)
+                .with_options(options)
+                .parse_pattern()?
+                .clone(),
+        ),
+        MacroPosition::Type => Expansion::Type(parse_type_with_context(
+            arena,
+            &mut stream,
+            ParseContext::new(options),
+        )?),
+    };
+
+    if !stream.is_at_end() {
+        let token = stream.peek();
+        return Err(ParseError::syntax_error_with_suggestion(
+            &format!(
+                "macro expansion ignores token {:?} and any following",
+                token.token_type
+            ),
+            token.span.clone(),
+            &format!(
+                "the expansion of `{}!` must form {}",
+                expansion.macro_name,
+                position.describe()
+            ),
+        ));
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,276 @@
+//! Matching macro invocations against rule matchers
+//!
+//! Fragments are matched by running the real parser over the remaining input,
+//! so `$x:expr` accepts exactly what an expression position would.
+
+use crate::{
+    ast::{Arena, FragmentKind, GroupDelimiter, MacroMatcher, RepetitionKind, TokenTree},
+    block::BlockParser,
+    options::{ParseContext, ParserOptions},
+    pratt::PrattParser,
+    token::{Symbol, Token, TokenStream, TokenType, VecTokenStream},
+    types::parse_type_with_context,
+};
+use std::collections::HashMap;
+
+/// Token trees captured by a matcher metavariable
+#[derive(Debug, Clone)]
+pub enum Binding {
+    /// A single fragment
+    Single {
+        kind: FragmentKind,
+        trees: Vec<TokenTree>,
+    },
+    /// One binding per iteration of an enclosing repetition
+    Repeated(Vec<Binding>),
+}
+
+/// Metavariable name to the tokens it captured
+pub type BindingMap = HashMap<Symbol, Binding>;
+
+/// Match invocation input against a rule, returning the captured fragments
+pub fn match_rule(matchers: &[MacroMatcher], input: &[TokenTree]) -> Option<BindingMap> {
+    match_rule_with_options(matchers, input, ParserOptions::default())
+}
+
+/// Match invocation input against a rule, parsing fragments under the
+/// options of the parse the invocation came from
+pub(crate) fn match_rule_with_options(
+    matchers: &[MacroMatcher],
+    input: &[TokenTree],
+    options: ParserOptions,
+) -> Option<BindingMap> {
+    let mut bindings = BindingMap::new();
+    let mut position = 0;
+    (match_sequence(matchers, input, &mut position, &mut bindings, options)
+        && position == input.len())
+    .then_some(bindings)
+}
+
+fn match_sequence(
+    matchers: &[MacroMatcher],
+    input: &[TokenTree],
+    position: &mut usize,
+    bindings: &mut BindingMap,
+    options: ParserOptions,
+) -> bool {
+    for matcher in matchers {
+        match matcher {
+            MacroMatcher::Token(expected) => match input.get(*position) {
+                Some(TokenTree::Token(token)) if token.token_type == expected.token_type => {
+                    *position += 1;
+                }
+                _ => return false,
+            },
+            MacroMatcher::Group {
+                delimiter,
+                matchers,
+                ..
+            } => match input.get(*position) {
+                Some(TokenTree::Group(group)) if group.delimiter == *delimiter => {
+                    let mut inner = 0;
+                    if !match_sequence(matchers, &group.tokens, &mut inner, bindings, options)
+                        || inner != group.tokens.len()
+                    {
+                        return false;
+                    }
+                    *position += 1;
+                }
+                _ => return false,
+            },
+            MacroMatcher::Fragment { name, kind, .. } => {
+                let Some(length) = match_fragment(*kind, &input[*position..], options) else {
+                    return false;
+                };
+                bindings.insert(
+                    *name,
+                    Binding::Single {
+                        kind: *kind,
+                        trees: input[*position..*position + length].to_vec(),
+                    },
+                );
+                *position += length;
+            }
+            MacroMatcher::Repetition {
+                matchers,
+                separator,
+                kind,
+                ..
+            } => {
+                let iterations = match_repetition(
+                    matchers,
+                    separator.as_ref(),
+                    *kind,
+                    input,
+                    position,
+                    options,
+                );
+                if *kind == RepetitionKind::OneOrMore && iterations.is_empty() {
+                    return false;
+                }
+                for name in fragment_names(matchers) {
+                    let repeated = iterations
+                        .iter()
+                        .filter_map(|iteration| iteration.get(&name).cloned())
+                        .collect();
+                    bindings.insert(name, Binding::Repeated(repeated));
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Greedily match as many iterations of a repetition as possible
+fn match_repetition(
+    matchers: &[MacroMatcher],
+    separator: Option<&Token>,
+    kind: RepetitionKind,
+    input: &[TokenTree],
+    position: &mut usize,
+    options: ParserOptions,
+) -> Vec<BindingMap> {
+    let mut iterations = Vec::new();
+
+    loop {
+        let mut next = *position;
+        if let (Some(separator), false) = (separator, iterations.is_empty()) {
+            match input.get(next) {
+                Some(TokenTree::Token(token)) if token.token_type == separator.token_type => {
+                    next += 1;
+                }
+                _ => break,
+            }
+        }
+
+        let start = next;
+        let mut iteration = BindingMap::new();
+        if !match_sequence(matchers, input, &mut next, &mut iteration, options) || next == start {
+            break;
+        }
+        iterations.push(iteration);
+        *position = next;
+
+        if kind == RepetitionKind::ZeroOrOne {
+            break;
+        }
+    }
+
+    iterations
+}
+
+/// Number of token trees a fragment of `kind` matches at the start of `input`
+fn match_fragment(
+    kind: FragmentKind,
+    input: &[TokenTree],
+    options: ParserOptions,
+) -> Option<usize> {
+    let first = input.first()?;
+    match kind {
+        FragmentKind::Tt => Some(1),
+        FragmentKind::Ident => matches!(
+            first,
+            TokenTree::Token(Token {
+                token_type: TokenType::Identifier(_),
+                ..
+            })
+        )
+        .then_some(1),
+        FragmentKind::Block => match first {
+            TokenTree::Group(group) if group.delimiter == GroupDelimiter::Braces => {
+                let mut stream = VecTokenStream::new(flatten_trees(&input[..1]));
+                let arena = Arena::new();
+                BlockParser::new(&arena)
+                    .with_options(options)
+                    .parse_braced_block(&mut stream)
+                    .ok()
+                    .map(|_| 1)
+            }
+            _ => None,
+        },
+        FragmentKind::Expr | FragmentKind::Ty | FragmentKind::Pat => {
+            let mut stream = VecTokenStream::new(flatten_trees(input));
+            let arena = Arena::new();
+            let parsed = match kind {
+                FragmentKind::Expr => PrattParser::new(&arena, &mut stream)
+                    .with_options(options)
+                    .parse_expression(0)
+                    .is_ok(),
+                FragmentKind::Pat => PrattParser::new(&arena, &mut stream)
+                    .with_options(options)
+                    .parse_pattern()
+                    .is_ok(),
+                _ => {
+                    parse_type_with_context(&arena, &mut stream, ParseContext::new(options)).is_ok()
+                }
+            };
+            if !parsed {
+                return None;
+            }
+            trees_consumed(input, stream.position())
+        }
+    }
+}
+
+/// Convert a count of flattened tokens back into whole token trees, failing
+/// when the parser stopped inside a group
+fn trees_consumed(input: &[TokenTree], tokens: usize) -> Option<usize> {
+    let mut consumed = 0;
+    for (count, tree) in input.iter().enumerate() {
+        if consumed == tokens {
+            return (count > 0).then_some(count);
+        }
+        consumed += flattened_len(tree);
+        if consumed > tokens {
+            return None;
+        }
+    }
+    (consumed == tokens && !input.is_empty()).then_some(input.len())
+}
+
+fn flattened_len(tree: &TokenTree) -> usize {
+    match tree {
+        TokenTree::Token(_) => 1,
+        TokenTree::Group(group) => 2 + group.tokens.iter().map(flattened_len).sum::<usize>(),
+    }
+}
+
+/// Flatten token trees back into a token sequence, re-creating delimiters
+pub fn flatten_trees(trees: &[TokenTree]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    flatten_into(trees, &mut tokens);
+    tokens
+}
+
+fn flatten_into(trees: &[TokenTree], tokens: &mut Vec<Token>) {
+    for tree in trees {
+        match tree {
+            TokenTree::Token(token) => tokens.push(token.clone()),
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter {
+                    GroupDelimiter::Parentheses => (TokenType::LeftParen, TokenType::RightParen),
+                    GroupDelimiter::Brackets => (TokenType::LeftBracket, TokenType::RightBracket),
+                    GroupDelimiter::Braces => (TokenType::LeftBrace, TokenType::RightBrace),
+                };
+                tokens.push(Token::new(open, group.span.clone()));
+                flatten_into(&group.tokens, tokens);
+                tokens.push(Token::new(close, group.span.clone()));
+            }
+        }
+    }
+}
+
+/// Names bound by fragments anywhere inside `matchers`
+fn fragment_names(matchers: &[MacroMatcher]) -> Vec<Symbol> {
+    let mut names = Vec::new();
+    for matcher in matchers {
+        match matcher {
+            MacroMatcher::Token(_) => {}
+            MacroMatcher::Fragment { name, .. } => names.push(*name),
+            MacroMatcher::Group { matchers, .. } | MacroMatcher::Repetition { matchers, .. } => {
+                names.extend(fragment_names(matchers))
+            }
+        }
+    }
+    names
+}
@@ -0,0 +1,18 @@
+//! Declarative macro expansion
+//!
+//! Matches invocations against the rules of `macro` items, transcribes the
+//! matched rule hygienically and re-parses the result where it was invoked:
+//! - Fragment matching with the real expression, type and pattern parsers
+//! - Renaming of macro-introduced bindings per expansion
+//! - Expansion backtraces recorded in spans
+//! - Built-in macros with checked format strings
+
+pub mod builtin;
+pub mod expander;
+pub mod matcher;
+pub mod transcriber;
+
+pub use builtin::*;
+pub use expander::*;
+pub use matcher::*;
+pub use transcriber::*;
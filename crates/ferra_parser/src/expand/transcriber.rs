@@ -0,0 +1,354 @@
+//! Transcribing macro rules and applying hygiene
+//!
+//! Tokens written in the macro body are marked with the expansion they came
+//! from; tokens substituted from the invocation keep their original spans.
+//! Bindings the macro body introduces with `let`, `var` or `for` are renamed
+//! per expansion so they can never capture names from the call site; field,
+//! method and path names that share a binding's name are left alone.
+
+use super::matcher::{Binding, BindingMap};
+use crate::{
+    ast::{FragmentKind, GroupDelimiter, MacroTranscriber, TokenGroup, TokenTree},
+    error::{ParseError, ParseResult},
+    token::{ExpansionInfo, Span, Symbol, Token, TokenType},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+/// Produce the token trees of a matched rule
+pub fn transcribe(
+    transcribers: &[MacroTranscriber],
+    bindings: &BindingMap,
+    expansion: &Arc<ExpansionInfo>,
+) -> ParseResult<Vec<TokenTree>> {
+    let mut output = Vec::new();
+    Transcriber {
+        bindings,
+        expansion,
+    }
+    .transcribe_into(transcribers, &mut Vec::new(), &mut output)?;
+    apply_hygiene(&mut output, expansion);
+    Ok(output)
+}
+
+struct Transcriber<'a> {
+    bindings: &'a BindingMap,
+    expansion: &'a Arc<ExpansionInfo>,
+}
+
+impl Transcriber<'_> {
+    fn transcribe_into(
+        &self,
+        transcribers: &[MacroTranscriber],
+        indices: &mut Vec<usize>,
+        output: &mut Vec<TokenTree>,
+    ) -> ParseResult<()> {
+        for transcriber in transcribers {
+            match transcriber {
+                MacroTranscriber::Token(token) => output.push(TokenTree::Token(self.mark(token))),
+                MacroTranscriber::Group {
+                    delimiter,
+                    transcribers,
+                    span,
+                } => {
+                    let mut tokens = Vec::new();
+                    self.transcribe_into(transcribers, indices, &mut tokens)?;
+                    output.push(TokenTree::Group(TokenGroup {
+                        delimiter: *delimiter,
+                        tokens,
+                        span: span.clone().with_expansion(self.expansion.clone()),
+                    }));
+                }
+                MacroTranscriber::Variable { name, span } => {
+                    match self.lookup(name, indices, span)? {
+                        Binding::Single { kind, trees } => substitute(*kind, trees, output),
+                        Binding::Repeated(_) => {
+                            return Err(ParseError::syntax_error(
+                                &format!("variable `${}` is still repeating at this depth", name),
+                                self.marked(span),
+                            ))
+                        }
+                    }
+                }
+                MacroTranscriber::Repetition {
+                    transcribers,
+                    separator,
+                    span,
+                    ..
+                } => {
+                    let count = self.repetition_count(transcribers, indices, span)?;
+                    for index in 0..count {
+                        if index > 0 {
+                            if let Some(separator) = separator {
+                                output.push(TokenTree::Token(self.mark(separator)));
+                            }
+                        }
+                        indices.push(index);
+                        let result = self.transcribe_into(transcribers, indices, output);
+                        indices.pop();
+                        result?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve a metavariable at the current repetition indices
+    fn lookup(&self, name: &Symbol, indices: &[usize], span: &Span) -> ParseResult<&Binding> {
+        let mut binding = self.bindings.get(name).ok_or_else(|| {
+            ParseError::syntax_error(
+                &format!("unknown macro variable `${}`", name),
+                self.marked(span),
+            )
+        })?;
+        for index in indices {
+            match binding {
+                Binding::Repeated(iterations) => {
+                    binding = iterations.get(*index).ok_or_else(|| {
+                        ParseError::syntax_error(
+                            &format!("variable `${}` has no value in this repetition", name),
+                            self.marked(span),
+                        )
+                    })?;
+                }
+                Binding::Single { .. } => break,
+            }
+        }
+        Ok(binding)
+    }
+
+    /// Number of iterations of a transcriber repetition, taken from the
+    /// metavariables that still repeat at this depth
+    fn repetition_count(
+        &self,
+        transcribers: &[MacroTranscriber],
+        indices: &[usize],
+        span: &Span,
+    ) -> ParseResult<usize> {
+        let mut count: Option<(usize, Symbol)> = None;
+        for name in variable_names(transcribers) {
+            if let Binding::Repeated(iterations) = self.lookup(&name, indices, span)? {
+                match count {
+                    Some((expected, first)) if expected != iterations.len() => {
+                        return Err(ParseError::syntax_error(
+                            &format!(
+                                "meta-variable `${}` repeats {} times, but `${}` repeats {} times",
+                                first,
+                                expected,
+                                name,
+                                iterations.len()
+                            ),
+                            self.marked(span),
+                        ))
+                    }
+                    Some(_) => {}
+                    None => count = Some((iterations.len(), name)),
+                }
+            }
+        }
+        count.map(|(count, _)| count).ok_or_else(|| {
+            ParseError::syntax_error(
+                "repetition contains no metavariables that repeat at this depth",
+                self.marked(span),
+            )
+        })
+    }
+
+    fn mark(&self, token: &Token) -> Token {
+        Token::new(token.token_type.clone(), self.marked(&token.span))
+    }
+
+    fn marked(&self, span: &Span) -> Span {
+        span.clone().with_expansion(self.expansion.clone())
+    }
+}
+
+/// Substitute a captured fragment; multi-token expressions are parenthesized
+/// so that `$x * 2` keeps the precedence of the argument
+fn substitute(kind: FragmentKind, trees: &[TokenTree], output: &mut Vec<TokenTree>) {
+    if kind == FragmentKind::Expr && trees.len() > 1 {
+        let span = trees
+            .iter()
+            .map(tree_span)
+            .reduce(|a, b| a.combine(b))
+            .unwrap_or_else(Span::dummy);
+        output.push(TokenTree::Group(TokenGroup {
+            delimiter: GroupDelimiter::Parentheses,
+            tokens: trees.to_vec(),
+            span,
+        }));
+    } else {
+        output.extend(trees.iter().cloned());
+    }
+}
+
+/// Span covered by a token tree
+pub(crate) fn tree_span(tree: &TokenTree) -> Span {
+    match tree {
+        TokenTree::Token(token) => token.span.clone(),
+        TokenTree::Group(group) => group.span.clone(),
+    }
+}
+
+/// Names used anywhere inside `transcribers`
+fn variable_names(transcribers: &[MacroTranscriber]) -> Vec<Symbol> {
+    let mut names = Vec::new();
+    for transcriber in transcribers {
+        match transcriber {
+            MacroTranscriber::Token(_) => {}
+            MacroTranscriber::Variable { name, .. } => names.push(*name),
+            MacroTranscriber::Group { transcribers, .. }
+            | MacroTranscriber::Repetition { transcribers, .. } => {
+                names.extend(variable_names(transcribers))
+            }
+        }
+    }
+    names
+}
+
+/// Name a macro-introduced binding is renamed to within one expansion
+///
+/// `#` cannot appear in source identifiers, so renamed bindings never collide
+/// with names written at the call site.
+///
+/// Renamed names are interned and so live for the rest of the program. Marks
+/// restart with each [`MacroExpander`](super::MacroExpander), so the names
+/// created are bounded by the binders of a macro times the expansions in one
+/// compilation unit, and re-expanding the same unit reuses them.
+pub fn hygienic_name(name: &str, mark: u32) -> String {
+    format!("{}#{}", name, mark)
+}
+
+/// Rename bindings introduced by the macro body within this expansion
+fn apply_hygiene(trees: &mut [TokenTree], expansion: &Arc<ExpansionInfo>) {
+    let mut binders = HashSet::new();
+    collect_binders(trees, expansion, &mut binders);
+    // Intern each renamed binder once, however often it is used
+    let renames: HashMap<Symbol, Symbol> = binders
+        .into_iter()
+        .map(|name| (name, hygienic_name(&name, expansion.mark).into()))
+        .collect();
+    if !renames.is_empty() {
+        rename_binders(trees, None, expansion, &renames);
+    }
+}
+
+fn from_this_expansion(token: &Token, expansion: &Arc<ExpansionInfo>) -> bool {
+    token
+        .span
+        .expansion
+        .as_ref()
+        .is_some_and(|info| Arc::ptr_eq(info, expansion))
+}
+
+fn collect_binders(
+    trees: &[TokenTree],
+    expansion: &Arc<ExpansionInfo>,
+    binders: &mut HashSet<Symbol>,
+) {
+    for (index, tree) in trees.iter().enumerate() {
+        match tree {
+            TokenTree::Token(token)
+                if from_this_expansion(token, expansion)
+                    && matches!(
+                        token.token_type,
+                        TokenType::Let | TokenType::Var | TokenType::For
+                    ) =>
+            {
+                match trees.get(index + 1) {
+                    Some(TokenTree::Token(binder)) => {
+                        if let TokenType::Identifier(name) = &binder.token_type {
+                            if from_this_expansion(binder, expansion) {
+                                binders.insert(*name);
+                            }
+                        }
+                    }
+                    // Destructuring: let (a, b) = ...
+                    Some(TokenTree::Group(group))
+                        if group.delimiter == GroupDelimiter::Parentheses =>
+                    {
+                        collect_pattern_binders(&group.tokens, expansion, binders);
+                    }
+                    _ => {}
+                }
+            }
+            TokenTree::Group(group) => collect_binders(&group.tokens, expansion, binders),
+            TokenTree::Token(_) => {}
+        }
+    }
+}
+
+fn collect_pattern_binders(
+    trees: &[TokenTree],
+    expansion: &Arc<ExpansionInfo>,
+    binders: &mut HashSet<Symbol>,
+) {
+    for tree in trees {
+        match tree {
+            TokenTree::Token(token) if from_this_expansion(token, expansion) => {
+                if let TokenType::Identifier(name) = &token.token_type {
+                    binders.insert(*name);
+                }
+            }
+            TokenTree::Group(group) => collect_pattern_binders(&group.tokens, expansion, binders),
+            TokenTree::Token(_) => {}
+        }
+    }
+}
+
+fn rename_binders(
+    trees: &mut [TokenTree],
+    delimiter: Option<GroupDelimiter>,
+    expansion: &Arc<ExpansionInfo>,
+    renames: &HashMap<Symbol, Symbol>,
+) {
+    for index in 0..trees.len() {
+        let names_binding = is_binding_position(trees, index, delimiter);
+        match &mut trees[index] {
+            TokenTree::Token(token) if names_binding && from_this_expansion(token, expansion) => {
+                if let TokenType::Identifier(name) = &mut token.token_type {
+                    if let Some(renamed) = renames.get(name) {
+                        *name = *renamed;
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                rename_binders(&mut group.tokens, Some(group.delimiter), expansion, renames)
+            }
+            TokenTree::Token(_) => {}
+        }
+    }
+}
+
+/// Whether the identifier at `index` binds or uses a variable, as opposed to
+/// naming a field, method, path segment or macro that happens to share its name
+fn is_binding_position(
+    trees: &[TokenTree],
+    index: usize,
+    delimiter: Option<GroupDelimiter>,
+) -> bool {
+    fn token_type(tree: Option<&TokenTree>) -> Option<&TokenType> {
+        match tree {
+            Some(TokenTree::Token(token)) => Some(&token.token_type),
+            _ => None,
+        }
+    }
+    let previous = index.checked_sub(1).and_then(|index| trees.get(index));
+    let previous = token_type(previous);
+    let next = token_type(trees.get(index + 1));
+
+    match (previous, next) {
+        // value.len, Type::len
+        (Some(TokenType::Dot | TokenType::DoubleColon), _) => false,
+        // len!(...)
+        (_, Some(TokenType::Bang)) => false,
+        // Point { len: ... }
+        (None | Some(TokenType::Comma), Some(TokenType::Colon)) => {
+            delimiter != Some(GroupDelimiter::Braces)
+        }
+        _ => true,
+    }
+}
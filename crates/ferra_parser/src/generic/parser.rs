@@ -7,36 +7,59 @@
 //! - Where clauses: `where T: Clone + Debug, U: Default`
 
 use crate::ast::{
-    GenericParam, GenericParams, GenericType, Type, TypeBound, WhereClause, WhereConstraint,
+    Arena, GenericParam, GenericParams, Path, Type, TypeBound, WhereClause, WhereConstraint,
 };
 use crate::error::{ParseError, ParseResult};
-use crate::token::{Span, Token, TokenStream, TokenType};
+use crate::options::ParseContext;
+use crate::token::{at_closing_angle, Span, Token, TokenStream, TokenType};
 
 /// Parse generic parameters `<T, U>` or `<T: Clone + Debug, U: Default>`
-pub fn parse_generic_params<T: TokenStream>(tokens: &mut T) -> ParseResult<Option<GenericParams>> {
-    let mut parser = GenericParser::new(tokens);
+pub fn parse_generic_params<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Option<GenericParams<'a>>> {
+    let mut parser = GenericParser::new(arena, tokens, ParseContext::default());
     parser.parse_generic_params()
 }
 
-/// Parse a generic type instantiation like `Vec<T>` or `HashMap<K, V>`
-pub fn parse_generic_type<T: TokenStream>(
+/// Parse generic parameters in the context of the parser they are part of
+pub(crate) fn parse_generic_params_with_context<'a, T: TokenStream>(
+    arena: &'a Arena,
     tokens: &mut T,
-    base_name: String,
-) -> ParseResult<GenericType> {
-    let mut parser = GenericParser::new(tokens);
-    parser.parse_generic_type(base_name)
+    context: ParseContext,
+) -> ParseResult<Option<GenericParams<'a>>> {
+    GenericParser::new(arena, tokens, context).parse_generic_params()
 }
 
-struct GenericParser<'a, T: TokenStream> {
-    tokens: &'a mut T,
+/// Parse a generic type instantiation like `Vec<T>` or `std::collections::Map<K, V>`
+/// whose base path has already been consumed
+///
+/// The arguments are attached to the final segment of `base`.
+pub fn parse_generic_type<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+    base: Path<'a>,
+) -> ParseResult<Path<'a>> {
+    let mut parser = GenericParser::new(arena, tokens, ParseContext::default());
+    parser.parse_generic_type(base)
 }
 
-impl<'a, T: TokenStream> GenericParser<'a, T> {
-    fn new(tokens: &'a mut T) -> Self {
-        Self { tokens }
+struct GenericParser<'a, 't, T: TokenStream> {
+    arena: &'a Arena,
+    tokens: &'t mut T,
+    context: ParseContext,
+}
+
+impl<'a, 't, T: TokenStream> GenericParser<'a, 't, T> {
+    fn new(arena: &'a Arena, tokens: &'t mut T, context: ParseContext) -> Self {
+        Self {
+            arena,
+            tokens,
+            context,
+        }
     }
 
-    fn parse_generic_params(&mut self) -> ParseResult<Option<GenericParams>> {
+    fn parse_generic_params(&mut self) -> ParseResult<Option<GenericParams<'a>>> {
         if !matches!(self.peek().token_type, TokenType::Less) {
             return Ok(None);
         }
@@ -45,10 +68,10 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         let mut params = Vec::new();
 
         // Handle empty generic parameters: <>
-        if matches!(self.peek().token_type, TokenType::Greater) {
+        if at_closing_angle(self.tokens) {
             let end_span = self.consume().span; // consume '>'
             return Ok(Some(GenericParams {
-                params,
+                params: &[],
                 where_clause: None,
                 span: start_span.combine(end_span),
             }));
@@ -62,14 +85,14 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
             self.consume(); // consume ','
 
             // Allow trailing comma
-            if matches!(self.peek().token_type, TokenType::Greater) {
+            if at_closing_angle(self.tokens) {
                 break;
             }
 
             params.push(self.parse_generic_param()?);
         }
 
-        if !matches!(self.peek().token_type, TokenType::Greater) {
+        if !at_closing_angle(self.tokens) {
             return Err(ParseError::unexpected_token(">", &self.peek()));
         }
 
@@ -89,13 +112,13 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         };
 
         Ok(Some(GenericParams {
-            params,
+            params: self.arena.alloc_vec(params),
             where_clause,
             span: final_span,
         }))
     }
 
-    fn parse_generic_param(&mut self) -> ParseResult<GenericParam> {
+    fn parse_generic_param(&mut self) -> ParseResult<GenericParam<'a>> {
         let token = self.peek();
         let start_span = token.span.clone();
 
@@ -105,12 +128,12 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
                 self.consume(); // consume "'"
 
                 if let TokenType::Identifier(name) = &self.peek().token_type {
-                    let name = name.clone();
+                    let name = *name;
                     let end_span = self.consume().span;
 
                     Ok(GenericParam {
-                        name: format!("'{}", name),
-                        bounds: Vec::new(),
+                        name: format!("'{}", name).into(),
+                        bounds: &[],
                         default: None,
                         is_lifetime: true,
                         span: start_span.combine(end_span),
@@ -121,9 +144,8 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
             }
             TokenType::Identifier(name) => {
                 // Type parameter: T, U, T: Clone + Debug
-                let name = name.clone();
                 let mut end_span = self.consume().span;
-                let mut bounds = Vec::new();
+                let mut bounds: &[TypeBound] = &[];
                 let mut default = None;
 
                 // Check for bounds: T: Clone + Debug
@@ -141,7 +163,7 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
                     self.consume(); // consume '='
                     let default_type = self.parse_type()?;
                     end_span = default_type.span();
-                    default = Some(default_type);
+                    default = Some(self.arena.alloc(default_type));
                 }
 
                 Ok(GenericParam {
@@ -156,7 +178,7 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         }
     }
 
-    fn parse_type_bounds(&mut self) -> ParseResult<Vec<TypeBound>> {
+    fn parse_type_bounds(&mut self) -> ParseResult<&'a [TypeBound]> {
         let mut bounds = Vec::new();
 
         // Parse first bound
@@ -168,12 +190,12 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
             bounds.push(self.parse_type_bound()?);
         }
 
-        Ok(bounds)
+        Ok(self.arena.alloc_vec(bounds))
     }
 
     fn parse_type_bound(&mut self) -> ParseResult<TypeBound> {
         if let TokenType::Identifier(trait_name) = &self.peek().token_type {
-            let trait_name = trait_name.clone();
+            let trait_name = *trait_name;
             let _span = self.consume().span;
 
             Ok(TypeBound {
@@ -185,7 +207,7 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         }
     }
 
-    fn parse_where_clause(&mut self) -> ParseResult<WhereClause> {
+    fn parse_where_clause(&mut self) -> ParseResult<WhereClause<'a>> {
         let start_span = self.consume().span; // consume 'where'
         let mut constraints = Vec::new();
 
@@ -211,14 +233,14 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         };
 
         Ok(WhereClause {
-            constraints,
+            constraints: self.arena.alloc_vec(constraints),
             span: start_span.combine(end_span),
         })
     }
 
-    fn parse_where_constraint(&mut self) -> ParseResult<WhereConstraint> {
+    fn parse_where_constraint(&mut self) -> ParseResult<WhereConstraint<'a>> {
         if let TokenType::Identifier(type_name) = &self.peek().token_type {
-            let type_name = type_name.clone();
+            let type_name = *type_name;
             let start_span = self.consume().span;
 
             if !matches!(self.peek().token_type, TokenType::Colon) {
@@ -244,72 +266,32 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
         }
     }
 
-    fn parse_generic_type(&mut self, base_name: String) -> ParseResult<GenericType> {
-        let start_span = self.current_span();
-
+    fn parse_generic_type(&mut self, base: Path<'a>) -> ParseResult<Path<'a>> {
         if !matches!(self.peek().token_type, TokenType::Less) {
             return Err(ParseError::unexpected_token("<", &self.peek()));
         }
 
-        self.consume(); // consume '<'
-        let mut args = Vec::new();
-
-        // Handle empty type arguments: HashMap<>
-        if matches!(self.peek().token_type, TokenType::Greater) {
-            let end_span = self.consume().span;
-            return Ok(GenericType {
-                base: base_name,
-                args,
-                span: start_span.combine(end_span),
-            });
-        }
-
-        // Parse first type argument
-        args.push(self.parse_type()?);
-
-        // Parse additional type arguments
-        while matches!(self.peek().token_type, TokenType::Comma) {
-            self.consume(); // consume ','
-
-            // Allow trailing comma
-            if matches!(self.peek().token_type, TokenType::Greater) {
-                break;
-            }
-
-            args.push(self.parse_type()?);
-        }
-
-        if !matches!(self.peek().token_type, TokenType::Greater) {
-            return Err(ParseError::unexpected_token(">", &self.peek()));
-        }
-
-        let end_span = self.consume().span;
-
-        Ok(GenericType {
-            base: base_name,
-            args,
-            span: start_span.combine(end_span),
+        let (args, args_span) = crate::types::parse_generic_args_with_context(
+            self.arena,
+            self.tokens,
+            self.context.clone(),
+        )?;
+        let mut segments = base.segments.to_vec();
+        let last = segments
+            .last_mut()
+            .expect("paths have at least one segment");
+        last.generic_args = args;
+        last.span = last.span.combine(args_span.clone());
+
+        Ok(Path {
+            segments: self.arena.alloc_vec(segments),
+            span: base.span.combine(args_span),
         })
     }
 
-    fn parse_type(&mut self) -> ParseResult<Type> {
-        // This is a simplified type parser for generic contexts
-        // It will delegate to the main type parser
-        match &self.peek().token_type {
-            TokenType::Identifier(name) => {
-                let name = name.clone();
-                let _span = self.consume().span;
-
-                // Check if this is a generic type instantiation
-                if matches!(self.peek().token_type, TokenType::Less) {
-                    let generic_type = self.parse_generic_type(name)?;
-                    Ok(Type::Generic(generic_type))
-                } else {
-                    Ok(Type::Identifier(name))
-                }
-            }
-            _ => Err(ParseError::unexpected_token("type", &self.peek())),
-        }
+    fn parse_type(&mut self) -> ParseResult<Type<'a>> {
+        // Delegate to the main type parser so defaults may use any type form
+        crate::types::parse_type_with_context(self.arena, self.tokens, self.context.clone())
     }
 
     fn is_where_clause_end(&self) -> bool {
@@ -326,22 +308,19 @@ impl<'a, T: TokenStream> GenericParser<'a, T> {
     fn consume(&mut self) -> Token {
         self.tokens.consume()
     }
-
-    fn current_span(&self) -> Span {
-        self.peek().span
-    }
 }
 
 // Add span method to Type enum
-impl Type {
+impl Type<'_> {
     pub fn span(&self) -> Span {
         match self {
             Type::Identifier(_) => Span::dummy(), // Would need actual span tracking
-            Type::Generic(gt) => gt.span.clone(),
+            Type::Path(path) => path.span.clone(),
             Type::Tuple(_) => Span::dummy(),
             Type::Array(_) => Span::dummy(),
             Type::Function(_) => Span::dummy(),
             Type::Pointer(_) => Span::dummy(),
+            Type::Macro(invocation) => invocation.span.clone(),
         }
     }
 }
@@ -358,13 +337,14 @@ mod tests {
 
     #[test]
     fn test_simple_generic_params() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -376,15 +356,16 @@ mod tests {
 
     #[test]
     fn test_multiple_generic_params() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Comma,
-            TokenType::Identifier("U".to_string()),
+            TokenType::Identifier("U".into()),
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -395,17 +376,18 @@ mod tests {
 
     #[test]
     fn test_lifetime_params() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
             TokenType::Apostrophe,
-            TokenType::Identifier("a".to_string()),
+            TokenType::Identifier("a".into()),
             TokenType::Comma,
             TokenType::Apostrophe,
-            TokenType::Identifier("b".to_string()),
+            TokenType::Identifier("b".into()),
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -418,17 +400,18 @@ mod tests {
 
     #[test]
     fn test_type_bounds() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Colon,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::Plus,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -440,19 +423,20 @@ mod tests {
 
     #[test]
     fn test_where_clause() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Greater,
             TokenType::Where,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Colon,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::Plus,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -466,17 +450,23 @@ mod tests {
 
     #[test]
     fn test_generic_type_instantiation() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_type(&mut tokens, "Vec".to_string()).unwrap();
-        assert_eq!(result.base, "Vec");
-        assert_eq!(result.args.len(), 1);
+        let result = parse_generic_type(
+            &arena,
+            &mut tokens,
+            Path::from_ident(&arena, "Vec", Span::dummy()),
+        )
+        .unwrap();
+        assert_eq!(result, "Vec");
+        assert_eq!(result.generic_args().len(), 1);
 
-        if let Type::Identifier(name) = &result.args[0] {
+        if let Type::Identifier(name) = &result.generic_args()[0] {
             assert_eq!(name, "i32");
         } else {
             panic!("Expected identifier type");
@@ -485,22 +475,28 @@ mod tests {
 
     #[test]
     fn test_nested_generic_types() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("Vec".to_string()),
+            TokenType::Identifier("Vec".into()),
             TokenType::Less,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::Greater,
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_type(&mut tokens, "Option".to_string()).unwrap();
-        assert_eq!(result.base, "Option");
-        assert_eq!(result.args.len(), 1);
+        let result = parse_generic_type(
+            &arena,
+            &mut tokens,
+            Path::from_ident(&arena, "Option", Span::dummy()),
+        )
+        .unwrap();
+        assert_eq!(result, "Option");
+        assert_eq!(result.generic_args().len(), 1);
 
-        if let Type::Generic(inner) = &result.args[0] {
-            assert_eq!(inner.base, "Vec");
-            assert_eq!(inner.args.len(), 1);
+        if let Type::Path(inner) = &result.generic_args()[0] {
+            assert_eq!(inner, "Vec");
+            assert_eq!(inner.generic_args().len(), 1);
         } else {
             panic!("Expected generic type");
         }
@@ -508,9 +504,10 @@ mod tests {
 
     #[test]
     fn test_empty_generic_params() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![TokenType::Less, TokenType::Greater]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -519,16 +516,17 @@ mod tests {
 
     #[test]
     fn test_trailing_comma_in_generics() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Comma,
-            TokenType::Identifier("U".to_string()),
+            TokenType::Identifier("U".into()),
             TokenType::Comma,
             TokenType::Greater,
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -537,23 +535,24 @@ mod tests {
 
     #[test]
     fn test_complex_where_clause() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Less,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Comma,
-            TokenType::Identifier("U".to_string()),
+            TokenType::Identifier("U".into()),
             TokenType::Greater,
             TokenType::Where,
-            TokenType::Identifier("T".to_string()),
+            TokenType::Identifier("T".into()),
             TokenType::Colon,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::Comma,
-            TokenType::Identifier("U".to_string()),
+            TokenType::Identifier("U".into()),
             TokenType::Colon,
-            TokenType::Identifier("Default".to_string()),
+            TokenType::Identifier("Default".into()),
         ]);
 
-        let result = parse_generic_params(&mut tokens).unwrap();
+        let result = parse_generic_params(&arena, &mut tokens).unwrap();
         assert!(result.is_some());
 
         let generics = result.unwrap();
@@ -23,7 +23,7 @@
 //! // Create token stream (normally from lexer)
 //! let tokens = VecTokenStream::from_token_types(vec![
 //!     TokenType::Fn,
-//!     TokenType::Identifier("test".to_string()),
+//!     TokenType::Identifier("test".into()),
 //!     TokenType::LeftParen,
 //!     TokenType::RightParen,
 //!     TokenType::LeftBrace,
@@ -36,12 +36,19 @@
 //! let ast = parser.parse_compilation_unit();
 //! ```
 
+// `ParseError` carries spans with macro expansion backtraces; errors are the
+// cold path, so their size is not worth boxing every variant for.
+#![allow(clippy::result_large_err)]
+
 pub mod ast;
 pub mod attribute; // Phase 2.8.1: Attribute parsing
 pub mod block;
+pub mod cfg; // Conditional compilation: #[cfg(...)] stripping
 pub mod error;
+pub mod expand; // Declarative macro expansion
 pub mod generic; // Phase 2.8.2: Generic type parameters
 pub mod macro_parser; // Phase 2.8.4: Macro system foundation
+pub mod options; // ParserOptions shared by every entry point
 pub mod pattern;
 pub mod pratt;
 pub mod program;
@@ -56,6 +63,8 @@ pub mod test_utils;
 // Re-export commonly used types
 pub use ast::{Arena, CompilationUnit, Expression, Item, Statement};
 pub use error::{ParseError, ParseResult};
+use options::ParseContext;
+pub use options::{Feature, FeatureSet, ParserOptions, RecoveryStrategy};
 pub use pratt::PrattParser;
 pub use program::ProgramParser;
 pub use statement::StatementParser;
@@ -65,39 +74,71 @@ pub use token::{TokenStream, TokenType};
 pub struct Parser<'arena, T: TokenStream> {
     arena: &'arena Arena,
     tokens: T,
+    context: ParseContext,
+    /// Newlines skipped while `options.retain_trivia` is set
+    trivia: Vec<token::Span>,
 }
 
 impl<'arena, T: TokenStream> Parser<'arena, T> {
     /// Create a new parser with the given token stream
     pub fn new(arena: &'arena Arena, tokens: T) -> Self {
-        Self { arena, tokens }
+        Self {
+            arena,
+            tokens,
+            context: ParseContext::default(),
+            trivia: Vec::new(),
+        }
+    }
+
+    /// Parse with `options` instead of the defaults
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.context = ParseContext::new(options);
+        self
+    }
+
+    /// Spans of the newlines skipped so far, in source order, when parsing
+    /// with `retain_trivia`
+    pub fn trivia(&self) -> Vec<token::Span> {
+        options::source_order(self.trivia.clone())
     }
 
     /// Parse a complete compilation unit
-    pub fn parse_compilation_unit(&mut self) -> Result<&'arena ast::CompilationUnit, ParseError> {
-        statement::parser::StatementParser::new(self.arena, &mut self.tokens)
-            .parse_compilation_unit()
+    pub fn parse_compilation_unit(
+        &mut self,
+    ) -> Result<&'arena ast::CompilationUnit<'arena>, ParseError> {
+        let mut parser = statement::parser::StatementParser::new(self.arena, &mut self.tokens)
+            .with_context(self.context.clone());
+        let unit = parser.parse_compilation_unit();
+        self.trivia.extend(parser.into_trivia());
+        unit
     }
 
     /// Parse a single expression
-    pub fn parse_expression(&mut self) -> Result<&'arena Expression, ParseError> {
-        let mut pratt_parser = pratt::parser::PrattParser::new(self.arena, &mut self.tokens);
-        pratt_parser.parse_expression(0)
+    pub fn parse_expression(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        let mut parser = pratt::parser::PrattParser::new(self.arena, &mut self.tokens)
+            .with_context(self.context.clone());
+        let expression = parser.parse_expression(0);
+        self.trivia.extend(parser.into_trivia());
+        expression
     }
 
     /// Parse a single statement
-    pub fn parse_statement(&mut self) -> Result<&'arena Statement, ParseError> {
-        statement::parser::StatementParser::new(self.arena, &mut self.tokens).parse_statement()
+    pub fn parse_statement(&mut self) -> Result<&'arena Statement<'arena>, ParseError> {
+        let mut parser = statement::parser::StatementParser::new(self.arena, &mut self.tokens)
+            .with_context(self.context.clone());
+        let statement = parser.parse_statement();
+        self.trivia.extend(parser.into_trivia());
+        statement
     }
 }
 
 /// Convenience function to parse a file from path
-pub fn parse_file<P: AsRef<std::path::Path>>(_path: P) -> ParseResult<CompilationUnit> {
+pub fn parse_file<P: AsRef<std::path::Path>>(_path: P) -> ParseResult<CompilationUnit<'static>> {
     todo!("Implementation will be done after lexer integration")
 }
 
 /// Convenience function to parse source code from string
-pub fn parse_source(_source: &str) -> ParseResult<CompilationUnit> {
+pub fn parse_source<'a>(_arena: &'a Arena, _source: &str) -> ParseResult<CompilationUnit<'a>> {
     todo!("Implementation will be done after lexer integration")
 }
 
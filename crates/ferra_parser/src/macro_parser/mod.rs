@@ -3,10 +3,12 @@
 //! This module provides basic macro parsing functionality including:
 //! - Macro invocation parsing (macro!())
 //! - Token tree parsing for macro arguments
-//! - Basic macro definition parsing framework
+//! - Macro definitions with matcher/transcriber rules (`macro name { ... }`)
 //!
 //! This is a foundation implementation for Phase 2.8.4
 
 pub mod parser;
+pub mod rules;
 
 pub use parser::*;
+pub use rules::*;
@@ -7,25 +7,48 @@ use crate::{
         Arena, GroupDelimiter, MacroDefinition, MacroInvocation, MacroRule, TokenGroup, TokenTree,
     },
     error::ParseError,
-    token::{TokenStream, TokenType},
+    macro_parser::rules::{parse_matchers, parse_transcribers, validate_rule},
+    options::{ParseContext, ParserOptions},
+    token::{Span, Symbol, TokenStream, TokenType},
 };
 
 /// Macro parser for handling macro invocations and definitions
 pub struct MacroParser<'arena, T: TokenStream> {
     arena: &'arena Arena,
     tokens: T,
+    context: ParseContext,
 }
 
 impl<'arena, T: TokenStream> MacroParser<'arena, T> {
     /// Create a new macro parser
     pub fn new(arena: &'arena Arena, tokens: T) -> Self {
-        Self { arena, tokens }
+        Self {
+            arena,
+            tokens,
+            context: ParseContext::default(),
+        }
+    }
+
+    /// Parse with `options` instead of the defaults
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.context = ParseContext::new(options);
+        self
+    }
+
+    /// Share the options and nesting depth of the parser creating this one
+    pub(crate) fn with_context(mut self, context: ParseContext) -> Self {
+        self.context = context;
+        self
     }
 
-    /// Parse a macro invocation like `println!("Hello, world!")`
+    /// Parse a macro invocation like `println!("Hello, world!")` after its
+    /// name has been consumed
+    ///
+    /// The span runs from `!` through the closing delimiter; callers extend
+    /// it back to the name they consumed.
     pub fn parse_macro_invocation(
         &mut self,
-        name: String,
+        name: Symbol,
     ) -> Result<&'arena MacroInvocation, ParseError> {
         // Expect '!' after macro name
         let bang_token = self.tokens.consume();
@@ -35,16 +58,51 @@ impl<'arena, T: TokenStream> MacroParser<'arena, T> {
 
         // Parse arguments as token tree
         let arguments = self.parse_token_tree_group()?;
+        let span = bang_token.span.combine(arguments.span.clone());
 
         Ok(self.arena.alloc(MacroInvocation {
             name,
             arguments: vec![TokenTree::Group(arguments)],
-            span: bang_token.span.clone(),
+            span,
         }))
     }
 
+    /// Parse `name!(...);`, `name![...];` or `name! { ... }` in item or
+    /// statement position
+    ///
+    /// The brace form ends at its closing brace; the other forms must be
+    /// terminated with `;`.
+    pub fn parse_macro_item_invocation(&mut self) -> Result<&'arena MacroInvocation, ParseError> {
+        let name_token = self.tokens.consume();
+        let name = match name_token.token_type {
+            TokenType::Identifier(name) => name,
+            _ => return Err(ParseError::unexpected_token("macro name", &name_token)),
+        };
+
+        let mut invocation = self.parse_macro_invocation(name)?.clone();
+        let mut span = name_token.span.combine(invocation.span.clone());
+
+        let semicolon = self.tokens.peek();
+        if matches!(semicolon.token_type, TokenType::Semicolon) {
+            span = span.combine(self.tokens.consume().span);
+        } else if invocation.delimiter() != Some(GroupDelimiter::Braces) {
+            return Err(ParseError::unexpected_token_with_suggestion(
+                "`;` after macro invocation",
+                semicolon,
+                &format!(
+                    "add `;` here, or invoke it with braces: `{}! {{ ... }}`",
+                    invocation.name
+                ),
+            ));
+        }
+
+        invocation.span = span;
+        Ok(self.arena.alloc(invocation))
+    }
+
     /// Parse a token tree group (parentheses, brackets, or braces)
     fn parse_token_tree_group(&mut self) -> Result<TokenGroup, ParseError> {
+        let _nesting = self.context.enter_nesting(&self.tokens.peek().span)?;
         let open_token = self.tokens.consume();
 
         let delimiter = match open_token.token_type {
@@ -79,6 +137,9 @@ impl<'arena, T: TokenStream> MacroParser<'arena, T> {
                 (_, TokenType::Eof) => {
                     return Err(ParseError::unexpected_token("closing delimiter", token));
                 }
+                (_, TokenType::Newline) => {
+                    self.tokens.consume();
+                }
                 _ => {
                     // Check if this is a nested group
                     if matches!(
@@ -96,88 +157,139 @@ impl<'arena, T: TokenStream> MacroParser<'arena, T> {
         }
     }
 
-    /// Parse a basic macro definition (framework only)
+    /// Parse a single token tree: a delimited group or one token
+    fn parse_token_tree(&mut self) -> Result<TokenTree, ParseError> {
+        if matches!(
+            self.tokens.peek().token_type,
+            TokenType::LeftParen | TokenType::LeftBracket | TokenType::LeftBrace
+        ) {
+            Ok(TokenTree::Group(self.parse_token_tree_group()?))
+        } else {
+            Ok(TokenTree::Token(self.tokens.consume()))
+        }
+    }
+
+    /// Parse the body of a macro definition: `{ (matcher) => { transcriber }; ... }`
+    ///
+    /// Each rule is parsed into matcher and transcriber trees and validated, so
+    /// unbound or mis-nested metavariables are reported at the definition site.
     pub fn parse_macro_definition(
         &mut self,
-        name: String,
-    ) -> Result<&'arena MacroDefinition, ParseError> {
-        // This is a basic framework implementation
-        // In a full implementation, this would parse macro rules with patterns and replacements
-
-        // For now, just parse a simple rule structure
-        let _open_brace = self.tokens.consume(); // consume '{'
-        if !matches!(_open_brace.token_type, TokenType::LeftBrace) {
-            return Err(ParseError::unexpected_token("{", &_open_brace));
+        name: Symbol,
+    ) -> Result<&'arena MacroDefinition<'arena>, ParseError> {
+        let open_brace = self.tokens.consume();
+        if !matches!(open_brace.token_type, TokenType::LeftBrace) {
+            return Err(ParseError::unexpected_token("{", &open_brace));
         }
 
         let mut rules = Vec::new();
 
-        // Parse basic rule (pattern => replacement)
         while !matches!(
             self.tokens.peek().token_type,
             TokenType::RightBrace | TokenType::Eof
         ) {
+            if matches!(self.tokens.peek().token_type, TokenType::Newline) {
+                self.tokens.consume();
+                continue;
+            }
+
             let rule = self.parse_macro_rule()?;
             rules.push(rule);
 
-            // Check for continuation
+            // Rules are separated by ';'
             if matches!(self.tokens.peek().token_type, TokenType::Semicolon) {
-                self.tokens.consume(); // consume ';'
+                self.tokens.consume();
             }
         }
 
-        let close_brace = self.tokens.consume(); // consume '}'
+        let close_brace = self.tokens.consume();
         if !matches!(close_brace.token_type, TokenType::RightBrace) {
             return Err(ParseError::unexpected_token("}", &close_brace));
         }
 
         Ok(self.arena.alloc(MacroDefinition {
             name,
-            rules,
-            span: close_brace.span.clone(),
+            rules: self.arena.alloc_vec(rules),
+            attributes: &[],
+            span: open_brace.span.combine(close_brace.span),
         }))
     }
 
-    /// Parse a single macro rule (basic framework)
-    fn parse_macro_rule(&mut self) -> Result<MacroRule, ParseError> {
+    /// Parse a single macro rule `matcher => transcriber`
+    ///
+    /// Either side may be a single delimited group, whose delimiters are not
+    /// part of the rule.
+    fn parse_macro_rule(&mut self) -> Result<MacroRule<'arena>, ParseError> {
         let mut pattern = Vec::new();
         let mut replacement = Vec::new();
 
         // Parse pattern until '=>'
         while !matches!(self.tokens.peek().token_type, TokenType::FatArrow) {
-            let token = self.tokens.consume();
-            if matches!(token.token_type, TokenType::Eof) {
-                return Err(ParseError::unexpected_token("=>", &token));
+            match self.tokens.peek().token_type {
+                TokenType::Eof => {
+                    return Err(ParseError::unexpected_token("=>", self.tokens.peek()))
+                }
+                TokenType::Newline => {
+                    self.tokens.consume();
+                }
+                _ => pattern.push(self.parse_token_tree()?),
             }
-            pattern.push(TokenTree::Token(token));
         }
 
-        // Consume '=>'
         let arrow_token = self.tokens.consume();
-        if !matches!(arrow_token.token_type, TokenType::FatArrow) {
-            return Err(ParseError::unexpected_token("=>", &arrow_token));
-        }
 
         // Parse replacement until ';' or '}'
         while !matches!(
             self.tokens.peek().token_type,
-            TokenType::Semicolon | TokenType::RightBrace
+            TokenType::Semicolon | TokenType::RightBrace | TokenType::Eof
         ) {
-            let token = self.tokens.consume();
-            if matches!(token.token_type, TokenType::Eof) {
-                break;
+            if matches!(self.tokens.peek().token_type, TokenType::Newline) {
+                self.tokens.consume();
+            } else {
+                replacement.push(self.parse_token_tree()?);
             }
-            replacement.push(TokenTree::Token(token));
         }
 
+        let matcher = parse_matchers(self.arena, rule_body(&pattern))?;
+        let transcriber = parse_transcribers(self.arena, rule_body(&replacement))?;
+        validate_rule(matcher, transcriber)?;
+
+        let span = pattern
+            .first()
+            .map(token_tree_span)
+            .unwrap_or_else(|| arrow_token.span.clone())
+            .combine(
+                replacement
+                    .last()
+                    .map(token_tree_span)
+                    .unwrap_or_else(|| arrow_token.span.clone()),
+            );
+
         Ok(MacroRule {
             pattern,
             replacement,
-            span: arrow_token.span.clone(),
+            matcher,
+            transcriber,
+            span,
         })
     }
 }
 
+/// Strip the delimiters of a rule side written as a single group
+fn rule_body(trees: &[TokenTree]) -> &[TokenTree] {
+    match trees {
+        [TokenTree::Group(group)] => &group.tokens,
+        _ => trees,
+    }
+}
+
+fn token_tree_span(tree: &TokenTree) -> Span {
+    match tree {
+        TokenTree::Token(token) => token.span.clone(),
+        TokenTree::Group(group) => group.span.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,7 +318,7 @@ mod tests {
         ]);
         let mut parser = MacroParser::new(&arena, tokens);
 
-        let result = parser.parse_macro_invocation("println".to_string());
+        let result = parser.parse_macro_invocation("println".into());
         assert!(result.is_ok());
 
         if let Ok(macro_invocation) = result {
@@ -229,7 +341,7 @@ mod tests {
         ]);
         let mut parser = MacroParser::new(&arena, tokens);
 
-        let result = parser.parse_macro_invocation("test".to_string());
+        let result = parser.parse_macro_invocation("test".into());
         assert!(result.is_ok());
 
         if let Ok(macro_invocation) = result {
@@ -248,9 +360,9 @@ mod tests {
         let arena = Arena::new();
         let tokens = create_token_stream(vec![
             TokenType::LeftBrace,
-            TokenType::Identifier("$x".to_string()),
+            TokenType::Identifier("$x".into()),
             TokenType::FatArrow,
-            TokenType::Identifier("$x".to_string()),
+            TokenType::Identifier("$x".into()),
             TokenType::Plus,
             TokenType::IntegerLiteral(1),
             TokenType::RightBrace,
@@ -258,7 +370,7 @@ mod tests {
         ]);
         let mut parser = MacroParser::new(&arena, tokens);
 
-        let result = parser.parse_macro_definition("increment".to_string());
+        let result = parser.parse_macro_definition("increment".into());
         assert!(result.is_ok());
 
         if let Ok(macro_def) = result {
@@ -279,7 +391,7 @@ mod tests {
             TokenType::Eof,
         ]);
         let mut parser = MacroParser::new(&arena, tokens);
-        let result = parser.parse_macro_invocation("println".to_string());
+        let result = parser.parse_macro_invocation("println".into());
         assert!(result.is_err());
 
         // Test missing bang
@@ -290,7 +402,7 @@ mod tests {
             TokenType::Eof,
         ]);
         let mut parser = MacroParser::new(&arena, tokens);
-        let result = parser.parse_macro_invocation("println".to_string());
+        let result = parser.parse_macro_invocation("println".into());
         assert!(result.is_err());
     }
 }
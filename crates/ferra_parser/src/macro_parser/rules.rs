@@ -0,0 +1,348 @@
+//! Macro rule matchers and transcribers
+//!
+//! Builds structured matcher/transcriber trees from the raw token trees of a
+//! macro rule and validates them when the macro is defined:
+//! - `$x:expr` fragments with `expr`, `ident`, `ty`, `pat`, `block` and `tt`
+//! - `$( ... ) sep? op` repetitions with `*`, `+` and `?`
+//! - Unbound, duplicate and mis-nested metavariables
+
+use crate::{
+    ast::{Arena, FragmentKind, MacroMatcher, MacroTranscriber, RepetitionKind, TokenTree},
+    error::{ParseError, ParseResult},
+    token::{Span, Symbol, Token, TokenType},
+};
+use std::collections::HashMap;
+
+/// Parse the matcher side of a rule into `arena`
+pub fn parse_matchers<'a>(
+    arena: &'a Arena,
+    trees: &[TokenTree],
+) -> ParseResult<&'a [MacroMatcher<'a>]> {
+    let mut matchers = Vec::new();
+    let mut index = 0;
+
+    while index < trees.len() {
+        match &trees[index] {
+            TokenTree::Group(group) => matchers.push(MacroMatcher::Group {
+                delimiter: group.delimiter,
+                matchers: parse_matchers(arena, &group.tokens)?,
+                span: group.span.clone(),
+            }),
+            TokenTree::Token(dollar) if dollar.token_type == TokenType::Dollar => {
+                match trees.get(index + 1) {
+                    Some(TokenTree::Token(Token {
+                        token_type: TokenType::Identifier(name),
+                        span,
+                        ..
+                    })) => {
+                        let (kind, end_span) = parse_fragment_specifier(trees, index + 2, name)?;
+                        matchers.push(MacroMatcher::Fragment {
+                            name: *name,
+                            kind,
+                            span: dollar.span.combine(span.clone()).combine(end_span),
+                        });
+                        index += 3;
+                    }
+                    Some(TokenTree::Group(group)) if is_parenthesized(trees, index + 1) => {
+                        let inner = parse_matchers(arena, &group.tokens)?;
+                        if inner.is_empty() {
+                            return Err(ParseError::syntax_error(
+                                "repetition matches an empty token tree",
+                                group.span.clone(),
+                            ));
+                        }
+                        let (separator, kind, end_span, consumed) =
+                            parse_repetition_suffix(trees, index + 2, &group.span)?;
+                        matchers.push(MacroMatcher::Repetition {
+                            matchers: inner,
+                            separator,
+                            kind,
+                            span: dollar.span.combine(end_span),
+                        });
+                        index += 1 + consumed;
+                    }
+                    _ => return Err(expected_after_dollar(dollar)),
+                }
+            }
+            TokenTree::Token(token) => matchers.push(MacroMatcher::Token(token.clone())),
+        }
+        index += 1;
+    }
+
+    Ok(arena.alloc_vec(matchers))
+}
+
+/// Parse the transcriber side of a rule into `arena`
+pub fn parse_transcribers<'a>(
+    arena: &'a Arena,
+    trees: &[TokenTree],
+) -> ParseResult<&'a [MacroTranscriber<'a>]> {
+    let mut transcribers = Vec::new();
+    let mut index = 0;
+
+    while index < trees.len() {
+        match &trees[index] {
+            TokenTree::Group(group) => transcribers.push(MacroTranscriber::Group {
+                delimiter: group.delimiter,
+                transcribers: parse_transcribers(arena, &group.tokens)?,
+                span: group.span.clone(),
+            }),
+            TokenTree::Token(dollar) if dollar.token_type == TokenType::Dollar => {
+                match trees.get(index + 1) {
+                    Some(TokenTree::Token(Token {
+                        token_type: TokenType::Identifier(name),
+                        span,
+                        ..
+                    })) => {
+                        transcribers.push(MacroTranscriber::Variable {
+                            name: *name,
+                            span: dollar.span.combine(span.clone()),
+                        });
+                        index += 1;
+                    }
+                    Some(TokenTree::Group(group)) if is_parenthesized(trees, index + 1) => {
+                        let inner = parse_transcribers(arena, &group.tokens)?;
+                        let (separator, kind, end_span, consumed) =
+                            parse_repetition_suffix(trees, index + 2, &group.span)?;
+                        transcribers.push(MacroTranscriber::Repetition {
+                            transcribers: inner,
+                            separator,
+                            kind,
+                            span: dollar.span.combine(end_span),
+                        });
+                        index += 1 + consumed;
+                    }
+                    _ => return Err(expected_after_dollar(dollar)),
+                }
+            }
+            TokenTree::Token(token) => {
+                transcribers.push(MacroTranscriber::Token(token.clone()));
+            }
+        }
+        index += 1;
+    }
+
+    Ok(arena.alloc_vec(transcribers))
+}
+
+/// Check that every transcriber metavariable is bound by the matcher and used
+/// at the repetition depth it was bound at
+pub fn validate_rule(
+    matchers: &[MacroMatcher],
+    transcribers: &[MacroTranscriber],
+) -> ParseResult<()> {
+    let mut bindings = HashMap::new();
+    collect_bindings(matchers, 0, &mut bindings)?;
+    check_transcribers(transcribers, 0, &bindings)
+}
+
+/// Metavariable name to the repetition depth it was bound at
+type Bindings = HashMap<Symbol, (usize, Span)>;
+
+fn collect_bindings(
+    matchers: &[MacroMatcher],
+    depth: usize,
+    bindings: &mut Bindings,
+) -> ParseResult<()> {
+    for matcher in matchers {
+        match matcher {
+            MacroMatcher::Token(_) => {}
+            MacroMatcher::Group { matchers, .. } => collect_bindings(matchers, depth, bindings)?,
+            MacroMatcher::Fragment { name, span, .. } => {
+                if bindings.insert(*name, (depth, span.clone())).is_some() {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        &format!("duplicate macro variable `${}`", name),
+                        span.clone(),
+                        "give each metavariable in a matcher a distinct name",
+                    ));
+                }
+            }
+            MacroMatcher::Repetition { matchers, .. } => {
+                collect_bindings(matchers, depth + 1, bindings)?
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_transcribers(
+    transcribers: &[MacroTranscriber],
+    depth: usize,
+    bindings: &Bindings,
+) -> ParseResult<()> {
+    for transcriber in transcribers {
+        match transcriber {
+            MacroTranscriber::Token(_) => {}
+            MacroTranscriber::Group { transcribers, .. } => {
+                check_transcribers(transcribers, depth, bindings)?
+            }
+            MacroTranscriber::Variable { name, span } => match bindings.get(name) {
+                None => {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        &format!("unknown macro variable `${}`", name),
+                        span.clone(),
+                        &format!("bind it in the matcher, e.g. `${}:expr`", name),
+                    ))
+                }
+                Some((bound_depth, _)) if *bound_depth > depth => {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        &format!(
+                            "variable `${}` is still repeating at this depth ({} of {})",
+                            name, depth, bound_depth
+                        ),
+                        span.clone(),
+                        &format!("wrap the use in a repetition: `$( ... ${} ... ),*`", name),
+                    ))
+                }
+                Some(_) => {}
+            },
+            MacroTranscriber::Repetition {
+                transcribers, span, ..
+            } => {
+                // Unbound variables are reported before the repetition itself
+                check_transcribers(transcribers, depth + 1, bindings)?;
+                if !repeats_at_depth(transcribers, depth + 1, bindings) {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        "repetition contains no metavariables that repeat at this depth",
+                        span.clone(),
+                        "use a metavariable bound inside a matcher repetition `$(...)*`",
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether a transcriber repetition at `depth` is driven by some variable
+/// bound at least that deep
+fn repeats_at_depth(transcribers: &[MacroTranscriber], depth: usize, bindings: &Bindings) -> bool {
+    transcribers.iter().any(|transcriber| match transcriber {
+        MacroTranscriber::Token(_) => false,
+        MacroTranscriber::Variable { name, .. } => bindings
+            .get(name)
+            .is_some_and(|(bound_depth, _)| *bound_depth >= depth),
+        MacroTranscriber::Group { transcribers, .. }
+        | MacroTranscriber::Repetition { transcribers, .. } => {
+            repeats_at_depth(transcribers, depth, bindings)
+        }
+    })
+}
+
+/// Parse `: kind` after a matcher metavariable name
+fn parse_fragment_specifier(
+    trees: &[TokenTree],
+    index: usize,
+    name: &str,
+) -> ParseResult<(FragmentKind, Span)> {
+    let colon = match trees.get(index) {
+        Some(TokenTree::Token(colon)) if colon.token_type == TokenType::Colon => colon,
+        other => {
+            return Err(ParseError::syntax_error_with_suggestion(
+                &format!("missing fragment specifier for `${}`", name),
+                tree_span(other),
+                &format!("add a fragment specifier, e.g. `${}:expr`", name),
+            ))
+        }
+    };
+
+    match trees.get(index + 1) {
+        Some(TokenTree::Token(Token {
+            token_type: TokenType::Identifier(kind),
+            span,
+            ..
+        })) => match FragmentKind::from_name(kind) {
+            Some(kind) => Ok((kind, span.clone())),
+            None => Err(ParseError::syntax_error_with_suggestion(
+                &format!("unknown fragment specifier `{}`", kind),
+                span.clone(),
+                "valid fragment specifiers are `expr`, `ident`, `ty`, `pat`, `block` and `tt`",
+            )),
+        },
+        other => Err(ParseError::syntax_error_with_suggestion(
+            &format!("missing fragment specifier for `${}`", name),
+            tree_span(other).combine(colon.span.clone()),
+            &format!("add a fragment specifier, e.g. `${}:expr`", name),
+        )),
+    }
+}
+
+/// Parse the optional separator and the operator after `$( ... )`, returning
+/// how many token trees they occupy
+fn parse_repetition_suffix(
+    trees: &[TokenTree],
+    index: usize,
+    group_span: &Span,
+) -> ParseResult<(Option<Token>, RepetitionKind, Span, usize)> {
+    if let Some((kind, span)) = repetition_operator(trees.get(index)) {
+        return Ok((None, kind, span, 1));
+    }
+
+    let separator = match trees.get(index) {
+        Some(TokenTree::Token(token)) if token.token_type != TokenType::Dollar => token,
+        other => return Err(expected_repetition_operator(tree_span(other), group_span)),
+    };
+
+    match repetition_operator(trees.get(index + 1)) {
+        Some((RepetitionKind::ZeroOrOne, span)) => Err(ParseError::syntax_error_with_suggestion(
+            "the `?` repetition operator does not take a separator",
+            separator.span.combine(span),
+            "remove the separator: `$( ... )?`",
+        )),
+        Some((kind, span)) => Ok((Some(separator.clone()), kind, span, 2)),
+        None => Err(expected_repetition_operator(
+            tree_span(trees.get(index + 1)),
+            group_span,
+        )),
+    }
+}
+
+fn repetition_operator(tree: Option<&TokenTree>) -> Option<(RepetitionKind, Span)> {
+    match tree {
+        Some(TokenTree::Token(token)) => {
+            let kind = match token.token_type {
+                TokenType::Star => RepetitionKind::ZeroOrMore,
+                TokenType::Plus => RepetitionKind::OneOrMore,
+                TokenType::Question => RepetitionKind::ZeroOrOne,
+                _ => return None,
+            };
+            Some((kind, token.span.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn is_parenthesized(trees: &[TokenTree], index: usize) -> bool {
+    matches!(
+        trees.get(index),
+        Some(TokenTree::Group(group)) if group.delimiter == crate::ast::GroupDelimiter::Parentheses
+    )
+}
+
+fn tree_span(tree: Option<&TokenTree>) -> Span {
+    match tree {
+        Some(TokenTree::Token(token)) => token.span.clone(),
+        Some(TokenTree::Group(group)) => group.span.clone(),
+        None => Span::dummy(),
+    }
+}
+
+fn expected_after_dollar(dollar: &Token) -> ParseError {
+    ParseError::syntax_error_with_suggestion(
+        "expected a metavariable or repetition after `$`",
+        dollar.span.clone(),
+        "write `$name` or `$( ... ),*`",
+    )
+}
+
+fn expected_repetition_operator(span: Span, group_span: &Span) -> ParseError {
+    ParseError::syntax_error_with_suggestion(
+        "expected repetition operator `*`, `+` or `?`",
+        if span == Span::dummy() {
+            group_span.clone()
+        } else {
+            span
+        },
+        "follow the repetition with an operator, e.g. `$( ... ),*`",
+    )
+}
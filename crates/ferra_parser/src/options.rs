@@ -0,0 +1,274 @@
+//! Options shared by every parser entry point
+//!
+//! `Parser`, `ProgramParser`, `StatementParser`, `PrattParser` and
+//! `BlockParser` all accept a `ParserOptions` through `with_options`, and pass
+//! it on to the parsers they create for nested syntax, so a compilation unit
+//! is parsed under the same limits whichever entry point it starts from.
+//! Those parsers also share a `ParseContext`, which counts how deeply they
+//! have recursed between them.
+
+use crate::{error::ParseError, token::Span};
+use std::{cell::Cell, rc::Rc};
+
+/// Default number of errors collected before parsing gives up
+pub const DEFAULT_MAX_ERRORS: usize = 50;
+
+/// Default limit on how deeply expressions, patterns, types, blocks and
+/// token trees may nest before parsing fails with `E101`
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 128;
+
+/// What a parser does after a syntax error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecoveryStrategy {
+    /// Stop at the first error
+    Strict,
+    /// Resynchronise at the next declaration and keep collecting errors
+    #[default]
+    Tolerant,
+}
+
+/// Syntax beyond the v0.1 grammar, behind a feature gate
+///
+/// Gates for syntax the parser already shipped are enabled by default and
+/// can be switched off; new experimental syntax starts disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// Declarative `macro name { (matcher) => { transcriber } }` items
+    /// (SYNTAX_GRAMMAR §6 is still open; enabled by default)
+    MacroDefinitions,
+}
+
+impl Feature {
+    /// Every gated feature
+    pub const ALL: [Feature; 1] = [Feature::MacroDefinitions];
+
+    /// Features enabled unless switched off
+    pub const DEFAULT: [Feature; 1] = [Feature::MacroDefinitions];
+
+    /// Human-readable name of the gated syntax
+    pub fn description(&self) -> &'static str {
+        match self {
+            Feature::MacroDefinitions => "`macro` definitions",
+        }
+    }
+
+    fn bit(&self) -> u32 {
+        1 << (*self as u32)
+    }
+}
+
+/// A set of enabled features
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureSet(u32);
+
+impl Default for FeatureSet {
+    fn default() -> Self {
+        Feature::DEFAULT
+            .iter()
+            .fold(Self::none(), |set, feature| set.with(*feature))
+    }
+}
+
+impl FeatureSet {
+    /// No gated syntax at all
+    pub fn none() -> Self {
+        Self(0)
+    }
+
+    /// Every gated feature
+    pub fn all() -> Self {
+        Feature::ALL
+            .iter()
+            .fold(Self::none(), |set, feature| set.with(*feature))
+    }
+
+    /// This set with `feature` enabled
+    pub fn with(self, feature: Feature) -> Self {
+        Self(self.0 | feature.bit())
+    }
+
+    /// This set with `feature` disabled
+    pub fn without(self, feature: Feature) -> Self {
+        Self(self.0 & !feature.bit())
+    }
+
+    /// Whether `feature` is enabled
+    pub fn contains(&self, feature: Feature) -> bool {
+        self.0 & feature.bit() != 0
+    }
+}
+
+/// `trivia` in source order, each span once, as parsers report it
+pub(crate) fn source_order(mut trivia: Vec<Span>) -> Vec<Span> {
+    trivia.sort_by_key(|span| span.start);
+    trivia.dedup_by_key(|span| span.start);
+    trivia
+}
+
+/// Configuration for a parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// Errors collected before parsing gives up
+    pub max_errors: usize,
+    pub recovery: RecoveryStrategy,
+    /// Gated syntax accepted by the parser
+    pub features: FeatureSet,
+    /// How deeply expressions, patterns, types, blocks and token trees may
+    /// nest before parsing fails with `E101`
+    pub max_nesting_depth: usize,
+    /// Record the newlines the parser skips, returned by its `trivia()`
+    pub retain_trivia: bool,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            max_errors: DEFAULT_MAX_ERRORS,
+            recovery: RecoveryStrategy::Tolerant,
+            features: FeatureSet::default(),
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            retain_trivia: false,
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Default options: tolerant recovery, 50 errors, default features
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options that stop at the first error
+    pub fn strict() -> Self {
+        Self::default().with_recovery(RecoveryStrategy::Strict)
+    }
+
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    pub fn with_recovery(mut self, recovery: RecoveryStrategy) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    pub fn with_feature(mut self, feature: Feature) -> Self {
+        self.features = self.features.with(feature);
+        self
+    }
+
+    pub fn without_feature(mut self, feature: Feature) -> Self {
+        self.features = self.features.without(feature);
+        self
+    }
+
+    pub fn with_features(mut self, features: FeatureSet) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    pub fn with_retain_trivia(mut self, retain_trivia: bool) -> Self {
+        self.retain_trivia = retain_trivia;
+        self
+    }
+
+    /// Whether `feature` is enabled
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        self.features.contains(feature)
+    }
+
+    /// Fail with `E102` unless `feature` is enabled
+    pub(crate) fn require(&self, feature: Feature, span: &Span) -> Result<(), ParseError> {
+        if self.is_enabled(feature) {
+            return Ok(());
+        }
+        Err(ParseError::syntax_error_with_suggestion(
+            &format!("{} are disabled", feature.description()),
+            span.clone(),
+            &format!("enable `Feature::{:?}` in `ParserOptions`", feature),
+        )
+        .with_error_code("E102"))
+    }
+}
+
+/// Options of one parse and the nesting depth shared by its parsers
+///
+/// A parser hands a clone of its context to each parser it creates for
+/// nested syntax, so recursion through expressions, blocks, types and token
+/// trees counts against a single limit. `with_options` starts a new context.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ParseContext {
+    pub options: ParserOptions,
+    depth: Rc<Cell<usize>>,
+}
+
+impl ParseContext {
+    pub fn new(options: ParserOptions) -> Self {
+        Self {
+            options,
+            depth: Rc::default(),
+        }
+    }
+
+    /// Enter one level of nested syntax starting at `span`, failing once
+    /// `max_nesting_depth` levels are open
+    ///
+    /// The level is left when the returned guard is dropped, so recursive
+    /// parse functions hold the guard for the duration of the call.
+    pub fn enter_nesting(&self, span: &Span) -> Result<NestingGuard, ParseError> {
+        let depth = self.depth.get();
+        let max_depth = self.options.max_nesting_depth;
+        if depth >= max_depth {
+            return Err(ParseError::nesting_too_deep(max_depth, span.clone()));
+        }
+        self.depth.set(depth + 1);
+        Ok(NestingGuard {
+            depth: self.depth.clone(),
+        })
+    }
+}
+
+/// One level of nesting entered with [`ParseContext::enter_nesting`]
+pub(crate) struct NestingGuard {
+    depth: Rc<Cell<usize>>,
+}
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        self.depth.set(self.depth.get() - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nesting_guard() {
+        let context = ParseContext::new(ParserOptions::new().with_max_nesting_depth(2));
+        let nested = context.clone();
+        let span = Span::dummy();
+
+        let outer = context.enter_nesting(&span).unwrap();
+        {
+            // Clones share the depth
+            let _inner = nested.enter_nesting(&span).unwrap();
+            let error = context.enter_nesting(&span).err().unwrap();
+            assert_eq!(error.error_code(), Some("E101"));
+        }
+        // Dropping a guard leaves its level
+        let _inner = nested.enter_nesting(&span).unwrap();
+        drop(outer);
+
+        // A new context starts from zero
+        let fresh = ParseContext::new(context.options);
+        let _first = fresh.enter_nesting(&span).unwrap();
+        let _second = fresh.enter_nesting(&span).unwrap();
+    }
+}
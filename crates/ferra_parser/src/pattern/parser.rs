@@ -2,29 +2,48 @@
 //!
 //! Implementation will be completed during development phase
 
-use crate::{ast::Pattern, error::ParseResult, token::TokenStream};
+use crate::{
+    ast::{Arena, Pattern},
+    error::ParseResult,
+    token::TokenStream,
+};
 
 /// Parse a pattern
-pub fn parse_pattern<T: TokenStream>(_tokens: &mut T) -> ParseResult<Pattern> {
+pub fn parse_pattern<'a, T: TokenStream>(
+    _arena: &'a Arena,
+    _tokens: &mut T,
+) -> ParseResult<Pattern<'a>> {
     todo!("Implementation will be done during development phase")
 }
 
 /// Parse a literal pattern
-pub fn parse_literal_pattern<T: TokenStream>(_tokens: &mut T) -> ParseResult<Pattern> {
+pub fn parse_literal_pattern<'a, T: TokenStream>(
+    _arena: &'a Arena,
+    _tokens: &mut T,
+) -> ParseResult<Pattern<'a>> {
     todo!("Implementation will be done during development phase")
 }
 
 /// Parse an identifier pattern
-pub fn parse_identifier_pattern<T: TokenStream>(_tokens: &mut T) -> ParseResult<Pattern> {
+pub fn parse_identifier_pattern<'a, T: TokenStream>(
+    _arena: &'a Arena,
+    _tokens: &mut T,
+) -> ParseResult<Pattern<'a>> {
     todo!("Implementation will be done during development phase")
 }
 
 /// Parse a wildcard pattern (_)
-pub fn parse_wildcard_pattern<T: TokenStream>(_tokens: &mut T) -> ParseResult<Pattern> {
+pub fn parse_wildcard_pattern<'a, T: TokenStream>(
+    _arena: &'a Arena,
+    _tokens: &mut T,
+) -> ParseResult<Pattern<'a>> {
     todo!("Implementation will be done during development phase")
 }
 
 /// Parse a data class pattern
-pub fn parse_data_class_pattern<T: TokenStream>(_tokens: &mut T) -> ParseResult<Pattern> {
+pub fn parse_data_class_pattern<'a, T: TokenStream>(
+    _arena: &'a Arena,
+    _tokens: &mut T,
+) -> ParseResult<Pattern<'a>> {
     todo!("Implementation will be done during development phase")
 }
@@ -20,7 +20,10 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
         Self { arena, tokens }
     }
 
-    pub fn handle(&mut self, token_type: &TokenType) -> Result<&'arena Expression, ParseError> {
+    pub fn handle(
+        &mut self,
+        token_type: &TokenType,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         match token_type {
             // Literals
             TokenType::IntegerLiteral(value) => Ok(self
@@ -35,11 +38,33 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
             TokenType::BooleanLiteral(value) => Ok(self
                 .arena
                 .alloc(Expression::Literal(Literal::Boolean(*value)))),
+            TokenType::CharLiteral(value) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::Char(*value))))
+            }
+            TokenType::ByteLiteral(value) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::Byte(*value))))
+            }
+            TokenType::ByteStringLiteral(value) => Ok(self
+                .arena
+                .alloc(Expression::Literal(Literal::ByteString(value.clone())))),
+            TokenType::WideIntegerLiteral(value) => Ok(self
+                .arena
+                .alloc(Expression::Literal(Literal::WideInteger(value.clone())))),
+            TokenType::TypedIntegerLiteral(value, suffix) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::TypedInteger {
+                    value: *value,
+                    suffix: *suffix,
+                })))
+            }
+            TokenType::TypedFloatLiteral(value, suffix) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::TypedFloat {
+                    value: *value,
+                    suffix: *suffix,
+                })))
+            }
 
             // Identifiers
-            TokenType::Identifier(name) => {
-                Ok(self.arena.alloc(Expression::Identifier(name.clone())))
-            }
+            TokenType::Identifier(name) => Ok(self.arena.alloc(Expression::Identifier(*name))),
 
             // Grouped expressions
             TokenType::LeftParen => self.parse_grouped_or_tuple_expression(),
@@ -59,7 +84,9 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
         }
     }
 
-    fn parse_grouped_or_tuple_expression(&mut self) -> Result<&'arena Expression, ParseError> {
+    fn parse_grouped_or_tuple_expression(
+        &mut self,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         // For now, just handle simple cases. TODO: Implement proper recursive parsing
         // Expect the inner expression to be consumed already
         let token = self.tokens.consume();
@@ -71,7 +98,7 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
         Ok(self.arena.alloc(Expression::Literal(Literal::Integer(0))))
     }
 
-    fn parse_array_literal(&mut self) -> Result<&'arena Expression, ParseError> {
+    fn parse_array_literal(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
         // For now, just consume until closing bracket
         loop {
             let token = self.tokens.consume();
@@ -86,7 +113,7 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
         use crate::ast::ArrayLiteral;
         let token = self.tokens.peek();
         Ok(self.arena.alloc(Expression::Array(ArrayLiteral {
-            elements: vec![],
+            elements: &[],
             span: token.span.clone(),
         })))
     }
@@ -94,7 +121,7 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
     fn parse_unary_expression(
         &mut self,
         operator: UnaryOperator,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         // For now, just parse a simple operand (no recursive parsing)
         let token = self.tokens.consume();
         let operand = match &token.token_type {
@@ -102,14 +129,14 @@ impl<'arena, T: TokenStream> NudHandler<'arena, T> {
             TokenType::StringLiteral(value) => Expression::Literal(Literal::String(value.clone())),
             TokenType::FloatLiteral(value) => Expression::Literal(Literal::Float(*value)),
             TokenType::BooleanLiteral(value) => Expression::Literal(Literal::Boolean(*value)),
-            TokenType::Identifier(name) => Expression::Identifier(name.clone()),
+            TokenType::Identifier(name) => Expression::Identifier(*name),
             _ => return Err(ParseError::unexpected_token("expression", &token)),
         };
 
         use crate::ast::UnaryExpression;
         Ok(self.arena.alloc(Expression::Unary(UnaryExpression {
             operator,
-            operand: Box::new(operand),
+            operand: self.arena.alloc(operand),
             span: token.span.clone(),
         })))
     }
@@ -128,9 +155,9 @@ impl<'arena, T: TokenStream> LedHandler<'arena, T> {
 
     pub fn handle(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         token_type: &TokenType,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         match token_type {
             // Binary operators
             TokenType::Plus => self.parse_binary_expression(left, BinaryOperator::Add, token_type),
@@ -181,10 +208,10 @@ impl<'arena, T: TokenStream> LedHandler<'arena, T> {
 
     fn parse_binary_expression(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         operator: BinaryOperator,
         _token_type: &TokenType,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         // For now, just parse a simple right operand (no recursive parsing)
         let token = self.tokens.consume();
         let right = match &token.token_type {
@@ -192,15 +219,15 @@ impl<'arena, T: TokenStream> LedHandler<'arena, T> {
             TokenType::StringLiteral(value) => Expression::Literal(Literal::String(value.clone())),
             TokenType::FloatLiteral(value) => Expression::Literal(Literal::Float(*value)),
             TokenType::BooleanLiteral(value) => Expression::Literal(Literal::Boolean(*value)),
-            TokenType::Identifier(name) => Expression::Identifier(name.clone()),
+            TokenType::Identifier(name) => Expression::Identifier(*name),
             _ => return Err(ParseError::unexpected_token("expression", &token)),
         };
 
         use crate::ast::BinaryExpression;
         Ok(self.arena.alloc(Expression::Binary(BinaryExpression {
-            left: Box::new(left.clone()),
+            left,
             operator,
-            right: Box::new(right),
+            right: self.arena.alloc(right),
             span: token.span.clone(),
         })))
     }
@@ -5,26 +5,89 @@
 
 use crate::{
     ast::{
-        Arena, BinaryExpression, BinaryOperator, Expression, Literal, UnaryExpression,
-        UnaryOperator,
+        Arena, Attribute, BinaryExpression, BinaryOperator, BlockExpression, DataClassLiteral,
+        Expression, FieldInit, InterpolatedPart, InterpolatedString, Literal, MacroInvocation,
+        MatchArm, MatchExpression, Path, PathSegment, UnaryExpression, UnaryOperator,
     },
     error::ParseError,
+    options::{self, ParseContext, ParserOptions},
     pratt::precedence::{
         can_continue_expression, infix_binding_power, Associativity, BindingPower,
     },
-    token::{Span, Token, TokenStream, TokenType},
+    token::{
+        NumericSuffix, Span, StringSegment, Symbol, Token, TokenStream, TokenType, VecTokenStream,
+    },
 };
 
+/// Range-check a non-negated suffixed integer literal
+fn typed_integer_literal(
+    value: u128,
+    suffix: NumericSuffix,
+    span: &Span,
+) -> Result<Literal, ParseError> {
+    if value > suffix.max_value() {
+        return Err(ParseError::literal_out_of_range(
+            &format!("{}{}", value, suffix.name()),
+            suffix.name(),
+            &suffix.range(),
+            span.clone(),
+        ));
+    }
+    Ok(Literal::TypedInteger { value, suffix })
+}
+
 /// The main Pratt parser for expressions
 pub struct PrattParser<'arena, T: TokenStream> {
     arena: &'arena Arena,
     tokens: T,
+    /// Cleared while parsing `if`/`while` conditions where `Name {` opens a block
+    allow_data_class_literals: bool,
+    context: ParseContext,
+    /// Newlines skipped while `options.retain_trivia` is set
+    trivia: Vec<Span>,
 }
 
 impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Create a new Pratt parser
     pub fn new(arena: &'arena Arena, tokens: T) -> Self {
-        Self { arena, tokens }
+        Self {
+            arena,
+            tokens,
+            allow_data_class_literals: true,
+            context: ParseContext::default(),
+            trivia: Vec::new(),
+        }
+    }
+
+    /// Parse with `options` instead of the defaults
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.context = ParseContext::new(options);
+        self
+    }
+
+    /// Share the options and nesting depth of the parser creating this one
+    pub(crate) fn with_context(mut self, context: ParseContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Spans of the newlines skipped so far, in source order, when parsing
+    /// with `retain_trivia`
+    pub fn trivia(&self) -> Vec<Span> {
+        options::source_order(self.trivia.clone())
+    }
+
+    /// The skipped newlines, for the parser that created this one
+    pub(crate) fn into_trivia(self) -> Vec<Span> {
+        self.trivia
+    }
+
+    /// Parse a condition expression in which `Name { ... }` is not a data class literal
+    pub fn parse_condition(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        let previous = std::mem::replace(&mut self.allow_data_class_literals, false);
+        let result = self.parse_expression(0);
+        self.allow_data_class_literals = previous;
+        result
     }
 
     /// Parse an expression with the given minimum binding power
@@ -32,7 +95,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     pub fn parse_expression(
         &mut self,
         min_bp: BindingPower,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        let _nesting = self.context.enter_nesting(&self.tokens.peek().span)?;
+
         // Parse the primary expression (NUD)
         let mut left = self.parse_primary()?;
 
@@ -72,7 +137,14 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     }
 
     /// Parse primary expressions (literals, identifiers, etc.)
-    fn parse_primary(&mut self) -> Result<&'arena Expression, ParseError> {
+    fn parse_primary(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        // Block expressions hand the opening brace to the block parser
+        match self.tokens.peek().token_type {
+            TokenType::Hash => return self.parse_attributed_expression(),
+            TokenType::LeftBrace => return self.parse_block_expression(Vec::new()),
+            _ => {}
+        }
+
         let token = self.tokens.consume();
 
         match &token.token_type {
@@ -89,21 +161,48 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
             TokenType::BooleanLiteral(b) => {
                 Ok(self.arena.alloc(Expression::Literal(Literal::Boolean(*b))))
             }
+            TokenType::CharLiteral(c) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::Char(*c))))
+            }
+            TokenType::ByteLiteral(b) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::Byte(*b))))
+            }
+            TokenType::ByteStringLiteral(bytes) => Ok(self
+                .arena
+                .alloc(Expression::Literal(Literal::ByteString(bytes.clone())))),
+            TokenType::WideIntegerLiteral(value) => Ok(self
+                .arena
+                .alloc(Expression::Literal(Literal::WideInteger(value.clone())))),
+            TokenType::TypedIntegerLiteral(value, suffix) => {
+                let literal = typed_integer_literal(*value, *suffix, &token.span)?;
+                Ok(self.arena.alloc(Expression::Literal(literal)))
+            }
+            TokenType::TypedFloatLiteral(value, suffix) => {
+                Ok(self.arena.alloc(Expression::Literal(Literal::TypedFloat {
+                    value: *value,
+                    suffix: *suffix,
+                })))
+            }
+            TokenType::InterpolatedStringLiteral(segments) => {
+                self.parse_interpolated_string(segments, &token.span)
+            }
 
             // Identifier expressions
             TokenType::Identifier(name) => {
                 // Check for macro invocation: identifier!
                 if let TokenType::Bang = self.tokens.peek().token_type {
-                    // Parse as macro invocation
-                    let mut macro_parser =
-                        crate::macro_parser::MacroParser::new(self.arena, &mut self.tokens);
-                    let macro_invocation = macro_parser.parse_macro_invocation(name.clone())?;
-                    Ok(self
-                        .arena
-                        .alloc(Expression::Macro(macro_invocation.clone())))
+                    let invocation = self.parse_macro_after_name(*name, &token.span)?;
+                    Ok(self.arena.alloc(Expression::Macro(invocation)))
+                } else if self.at_path_continuation() {
+                    // Qualified or generic path: module::function, parse::<Int>, Vec<T>::new
+                    let path = self.parse_expression_path(*name, token.span.clone())?;
+                    self.finish_path_expression(path)
+                } else if self.at_data_class_literal() {
+                    let path = Path::from_ident(self.arena, *name, token.span.clone());
+                    self.parse_data_class_literal(path)
                 } else {
-                    // Simple identifier (qualified identifiers handled as postfix dot operations)
-                    Ok(self.arena.alloc(Expression::Identifier(name.clone())))
+                    // Simple identifier (member access handled as postfix dot operations)
+                    Ok(self.arena.alloc(Expression::Identifier(*name)))
                 }
             }
 
@@ -115,41 +214,154 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                     _ => return Err(ParseError::unexpected_token("unary operator", &token)),
                 };
 
+                if matches!(operator, UnaryOperator::Minus) {
+                    if let Some(negated) = self.parse_negated_integer_literal(&token)? {
+                        return Ok(negated);
+                    }
+                }
+
                 // Use a fixed precedence for unary operators
                 let operand = self.parse_expression(100)?; // High precedence for unary
 
                 Ok(self.arena.alloc(Expression::Unary(UnaryExpression {
                     operator,
-                    operand: Box::new(operand.clone()),
+                    operand,
                     span: token.span.combine(operand.span()),
                 })))
             }
 
             // Grouped expressions
             TokenType::LeftParen => {
-                let expr = self.parse_expression(0)?;
+                let previous = std::mem::replace(&mut self.allow_data_class_literals, true);
+                let expr = self.parse_expression(0);
+                self.allow_data_class_literals = previous;
+                let expr = expr?;
                 let close_token = self.tokens.consume();
                 if !matches!(close_token.token_type, TokenType::RightParen) {
                     return Err(ParseError::unexpected_token(")", &close_token));
                 }
-                Ok(self
-                    .arena
-                    .alloc(Expression::Grouped(Box::new(expr.clone()))))
+                Ok(self.arena.alloc(Expression::Grouped(expr)))
             }
 
             // Array literals
             TokenType::LeftBracket => self.parse_array_literal(),
 
+            // Match expressions
+            TokenType::Match => self.parse_match_expression(token.span.clone()),
+
             _ => Err(ParseError::unexpected_token("expression", &token)),
         }
     }
 
+    /// Parse each embedded expression of an interpolated string on its own,
+    /// requiring it to span everything between its braces
+    fn parse_interpolated_string(
+        &mut self,
+        segments: &[StringSegment],
+        span: &Span,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        let mut parts = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match segment {
+                StringSegment::Text(text) => parts.push(InterpolatedPart::Text(text.clone())),
+                StringSegment::Expression(tokens, expr_span) => {
+                    let end = Span::new(
+                        expr_span.end,
+                        expr_span.end,
+                        expr_span.line,
+                        expr_span.column + (expr_span.end - expr_span.start),
+                    );
+                    let mut tokens = tokens.clone();
+                    tokens.push(Token::new(TokenType::Eof, end));
+
+                    let mut parser = PrattParser::new(self.arena, VecTokenStream::new(tokens))
+                        .with_context(self.context.clone());
+                    let expression = parser.parse_expression(0)?;
+                    let trailing = parser.tokens.peek();
+                    if !trailing.is_eof() {
+                        return Err(ParseError::unexpected_token_with_suggestion(
+                            "`}` after interpolated expression",
+                            trailing,
+                            "interpolate a single expression per `{...}`",
+                        ));
+                    }
+                    self.trivia.extend(parser.into_trivia());
+                    parts.push(InterpolatedPart::Expression {
+                        expression,
+                        span: expr_span.clone(),
+                    });
+                }
+            }
+        }
+        Ok(self
+            .arena
+            .alloc(Expression::Interpolated(InterpolatedString {
+                parts: self.arena.alloc_vec(parts),
+                span: span.clone(),
+            })))
+    }
+
+    /// Negate a suffixed or wide integer literal as a whole, so the range
+    /// check sees `-128i8` and `-9223372036854775808` rather than their
+    /// out-of-range magnitudes. Returns `None` when a postfix operator
+    /// binds to the literal first (`-1i8.abs()`).
+    fn parse_negated_integer_literal(
+        &mut self,
+        minus: &Token,
+    ) -> Result<Option<&'arena Expression<'arena>>, ParseError> {
+        if matches!(
+            self.tokens.peek_ahead(1).map(|token| &token.token_type),
+            Some(
+                TokenType::Dot
+                    | TokenType::LeftParen
+                    | TokenType::LeftBracket
+                    | TokenType::Question
+            )
+        ) {
+            return Ok(None);
+        }
+
+        let (value, suffix) = match &self.tokens.peek().token_type {
+            TokenType::TypedIntegerLiteral(value, suffix) => (*value, *suffix),
+            // The only wide literal whose negation fits `i64`
+            TokenType::WideIntegerLiteral(wide) if wide.to_u128() == Some(1 << 63) => {
+                self.tokens.consume();
+                return Ok(Some(
+                    self.arena
+                        .alloc(Expression::Literal(Literal::Integer(i64::MIN))),
+                ));
+            }
+            _ => return Ok(None),
+        };
+
+        let literal = self.tokens.consume();
+        let span = minus.span.combine(literal.span.clone());
+        if !suffix.is_signed() || value > suffix.min_magnitude() {
+            return Err(ParseError::literal_out_of_range(
+                &format!("-{}{}", value, suffix.name()),
+                suffix.name(),
+                &suffix.range(),
+                span,
+            ));
+        }
+
+        Ok(Some(
+            self.arena.alloc(Expression::Unary(UnaryExpression {
+                operator: UnaryOperator::Minus,
+                operand: self
+                    .arena
+                    .alloc(Expression::Literal(Literal::TypedInteger { value, suffix })),
+                span,
+            })),
+        ))
+    }
+
     #[inline]
     fn handle_led(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         match &token.token_type {
             // Binary operators - batch the most common ones first
             TokenType::Plus => self.parse_binary_expression(left, BinaryOperator::Add, token),
@@ -194,10 +406,10 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     #[inline]
     fn parse_binary_expression(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         operator: BinaryOperator,
         token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         // Get the precedence for this operator (should exist since we matched it)
         let op_info = infix_binding_power(&token.token_type)
             .ok_or_else(|| ParseError::unexpected_token("binary operator", token))?;
@@ -212,26 +424,369 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
         let right = self.parse_expression(right_bp)?;
 
         Ok(self.arena.alloc(Expression::Binary(BinaryExpression {
-            left: Box::new(left.clone()),
+            left,
             operator,
-            right: Box::new(right.clone()),
+            right,
             span: token.span.clone(),
         })))
     }
 
-    /// Parse qualified identifiers like module.function or simple identifiers
-    #[allow(dead_code)]
-    fn parse_qualified_identifier(
+    /// Check whether the tokens after an identifier continue a path:
+    /// `::segment`, turbofish `::<T>` or generic arguments followed by `::`
+    fn at_path_continuation(&self) -> bool {
+        match self.tokens.peek().token_type {
+            TokenType::DoubleColon => matches!(
+                self.tokens.peek_ahead(1).map(|t| &t.token_type),
+                Some(TokenType::Identifier(_) | TokenType::Less)
+            ),
+            TokenType::Less => self.at_generic_args(),
+            _ => false,
+        }
+    }
+
+    /// Resolve a `<` in expression position as generic arguments or less-than.
+    ///
+    /// The bracketed tokens must all be valid in a type, and the matching `>`
+    /// must be directly followed by `::`, which cannot start an expression.
+    /// Anything else, such as `a < b`, `a < b && c > d` or `f(a < b, c > (d))`,
+    /// is parsed as a comparison; generic calls are written with a turbofish,
+    /// `parse::<Int>(s)`.
+    fn at_generic_args(&self) -> bool {
+        let mut depth = 0usize;
+        let mut offset = 0;
+
+        while let Some(token) = self.tokens.peek_ahead(offset) {
+            match token.token_type {
+                TokenType::Less => depth += 1,
+                TokenType::Greater | TokenType::ShiftRight => {
+                    let closed = if matches!(token.token_type, TokenType::ShiftRight) {
+                        2
+                    } else {
+                        1
+                    };
+                    if closed > depth {
+                        return false;
+                    }
+                    depth -= closed;
+                    if depth == 0 {
+                        return matches!(
+                            self.tokens.peek_ahead(offset + 1).map(|t| &t.token_type),
+                            Some(TokenType::DoubleColon)
+                        );
+                    }
+                }
+                TokenType::Identifier(_)
+                | TokenType::DoubleColon
+                | TokenType::Comma
+                | TokenType::LeftParen
+                | TokenType::RightParen
+                | TokenType::LeftBracket
+                | TokenType::RightBracket
+                | TokenType::Star
+                | TokenType::Fn
+                | TokenType::Arrow => {}
+                _ => return false,
+            }
+            offset += 1;
+        }
+
+        false
+    }
+
+    /// Parse the remaining segments of a path whose first identifier was consumed
+    fn parse_expression_path(
+        &mut self,
+        first: Symbol,
+        first_span: Span,
+    ) -> Result<Path<'arena>, ParseError> {
+        let mut segments = vec![PathSegment {
+            name: first,
+            generic_args: &[],
+            span: first_span,
+        }];
+
+        loop {
+            let next = self.tokens.peek_ahead(1).map(|t| t.token_type.clone());
+            match (&self.tokens.peek().token_type, next) {
+                // Turbofish: segment::<T>
+                (TokenType::DoubleColon, Some(TokenType::Less)) => {
+                    self.tokens.consume(); // consume '::'
+                    self.attach_generic_args(&mut segments)?;
+                }
+                (TokenType::DoubleColon, Some(TokenType::Identifier(name))) => {
+                    self.tokens.consume(); // consume '::'
+                    let segment_token = self.tokens.consume();
+                    segments.push(PathSegment {
+                        name,
+                        generic_args: &[],
+                        span: segment_token.span,
+                    });
+                }
+                // Generic arguments without turbofish: Vec<T>::new()
+                (TokenType::Less, _) if self.at_generic_args() => {
+                    self.attach_generic_args(&mut segments)?;
+                }
+                _ => break,
+            }
+        }
+
+        let span = segments[0]
+            .span
+            .combine(segments[segments.len() - 1].span.clone());
+        Ok(Path {
+            segments: self.arena.alloc_vec(segments),
+            span,
+        })
+    }
+
+    /// Parse `<...>` and attach the arguments to the last path segment
+    fn attach_generic_args(
+        &mut self,
+        segments: &mut [PathSegment<'arena>],
+    ) -> Result<(), ParseError> {
+        let less_token = self.tokens.peek().clone();
+        let segment = segments
+            .last_mut()
+            .expect("paths have at least one segment");
+        if !segment.generic_args.is_empty() {
+            return Err(ParseError::unexpected_token("'::' or '('", &less_token));
+        }
+
+        let (args, args_span) = crate::types::parse_generic_args_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
+        segment.generic_args = args;
+        segment.span = segment.span.combine(args_span);
+        Ok(())
+    }
+
+    /// Build the expression for a parsed path
+    fn finish_path_expression(
+        &mut self,
+        path: Path<'arena>,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        if self.at_data_class_literal() {
+            return self.parse_data_class_literal(path);
+        }
+
+        match path.as_ident() {
+            Some(name) => Ok(self.arena.alloc(Expression::Identifier(name))),
+            None => Ok(self.arena.alloc(Expression::Path(path))),
+        }
+    }
+
+    /// Check for the start of a data class literal body: `{}`, `{ x: ...`, `{ x, ...` or `{ x }`
+    fn at_data_class_literal(&self) -> bool {
+        if !self.allow_data_class_literals
+            || !matches!(self.tokens.peek().token_type, TokenType::LeftBrace)
+        {
+            return false;
+        }
+
+        match self.tokens.peek_ahead(1).map(|t| &t.token_type) {
+            Some(TokenType::RightBrace) => true,
+            Some(TokenType::Identifier(_)) => matches!(
+                self.tokens.peek_ahead(2).map(|t| &t.token_type),
+                Some(TokenType::Colon | TokenType::Comma | TokenType::RightBrace)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Parse data class literals like Point { x: 1, y } or geo::Point { x: 0, y: 0 }
+    fn parse_data_class_literal(
+        &mut self,
+        path: Path<'arena>,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        self.tokens.consume(); // consume '{'
+
+        // Field values are delimited by the braces, so literals are allowed again
+        let previous = std::mem::replace(&mut self.allow_data_class_literals, true);
+        let fields = self.parse_field_inits();
+        self.allow_data_class_literals = previous;
+        let (fields, close_token) = fields?;
+
+        let span = path.span.combine(close_token.span);
+        Ok(self
+            .arena
+            .alloc(Expression::DataClassLiteral(DataClassLiteral {
+                path,
+                fields: self.arena.alloc_vec(fields),
+                span,
+            })))
+    }
+
+    /// Parse field initializers up to and including the closing brace
+    fn parse_field_inits(&mut self) -> Result<(Vec<FieldInit<'arena>>, Token), ParseError> {
+        let mut fields = Vec::new();
+
+        loop {
+            if let TokenType::RightBrace = self.tokens.peek().token_type {
+                return Ok((fields, self.tokens.consume()));
+            }
+
+            let name_token = self.tokens.consume();
+            let name = match &name_token.token_type {
+                TokenType::Identifier(name) => *name,
+                _ => return Err(ParseError::unexpected_token("field name", &name_token)),
+            };
+
+            if let TokenType::Colon = self.tokens.peek().token_type {
+                self.tokens.consume(); // consume ':'
+                let value = self.parse_expression(0)?;
+                fields.push(FieldInit {
+                    name,
+                    value,
+                    is_shorthand: false,
+                    span: name_token.span.combine(value.span()),
+                });
+            } else {
+                // Shorthand field: Point { x } is Point { x: x }
+                fields.push(FieldInit {
+                    value: self.arena.alloc(Expression::Identifier(name)),
+                    name,
+                    is_shorthand: true,
+                    span: name_token.span.clone(),
+                });
+            }
+
+            let next_token = self.tokens.consume();
+            match next_token.token_type {
+                TokenType::Comma => {}
+                TokenType::RightBrace => return Ok((fields, next_token)),
+                _ => return Err(ParseError::unexpected_token("',' or '}'", &next_token)),
+            }
+        }
+    }
+
+    /// Parse an expression preceded by outer attributes: `#[cfg(test)] { ... }`
+    fn parse_attributed_expression(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        let attributes = crate::attribute::parse_attributes_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
+
+        if !matches!(self.tokens.peek().token_type, TokenType::LeftBrace) {
+            return Err(ParseError::syntax_error_with_suggestion(
+                "attributes are not allowed on this expression",
+                self.tokens.peek().span.clone(),
+                "attributes can be applied to block expressions `#[attr] { ... }`",
+            ));
+        }
+
+        self.parse_block_expression(attributes)
+    }
+
+    /// Parse a braced block expression `{ statements... }`
+    fn parse_block_expression(
+        &mut self,
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        let mut block_parser =
+            crate::block::parser::BlockParser::new(self.arena).with_context(self.context.clone());
+        let block = block_parser.parse_braced_block(&mut self.tokens);
+        self.trivia.extend(block_parser.into_trivia());
+        let block = block?;
+
+        let span = match attributes.first() {
+            Some(first) => first.span.clone().combine(block.span.clone()),
+            None => block.span.clone(),
+        };
+
+        Ok(self.arena.alloc(Expression::Block(BlockExpression {
+            block,
+            value: None,
+            attributes: self.arena.alloc_vec(attributes),
+            span,
+        })))
+    }
+
+    /// Parse a match expression after the `match` keyword:
+    /// `match value { #[cold] pattern => expr, ... }`
+    fn parse_match_expression(
         &mut self,
-        first_part: String,
-        _start_span: Span,
-    ) -> Result<&'arena Expression, ParseError> {
-        // Just return a simple identifier - dots are handled as postfix operators
-        Ok(self.arena.alloc(Expression::Identifier(first_part)))
+        start_span: Span,
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
+        let scrutinee = self.parse_condition()?;
+
+        let open_brace = self.tokens.consume();
+        if !matches!(open_brace.token_type, TokenType::LeftBrace) {
+            return Err(ParseError::unexpected_token("'{'", &open_brace));
+        }
+
+        let mut arms = Vec::new();
+        self.skip_newlines();
+
+        while !matches!(
+            self.tokens.peek().token_type,
+            TokenType::RightBrace | TokenType::Eof
+        ) {
+            arms.push(self.parse_match_arm()?);
+
+            if matches!(self.tokens.peek().token_type, TokenType::Comma) {
+                self.tokens.consume(); // consume ','
+            }
+            self.skip_newlines();
+        }
+
+        let close_brace = self.tokens.consume();
+        if !matches!(close_brace.token_type, TokenType::RightBrace) {
+            return Err(ParseError::unexpected_token("'}'", &close_brace));
+        }
+
+        Ok(self.arena.alloc(Expression::Match(MatchExpression {
+            scrutinee,
+            arms: self.arena.alloc_vec(arms),
+            span: start_span.combine(close_brace.span),
+        })))
+    }
+
+    /// Parse a single match arm with its outer attributes
+    fn parse_match_arm(&mut self) -> Result<MatchArm<'arena>, ParseError> {
+        let start_span = self.tokens.peek().span.clone();
+        let attributes = crate::attribute::parse_attributes_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
+
+        let pattern = self.parse_pattern()?;
+
+        let arrow = self.tokens.consume();
+        if !matches!(arrow.token_type, TokenType::FatArrow) {
+            return Err(ParseError::unexpected_token("'=>'", &arrow));
+        }
+
+        let expression = self.parse_expression(0)?;
+        let expression_span = expression.span();
+        let span = if expression_span.end > arrow.span.end {
+            start_span.combine(expression_span)
+        } else {
+            start_span.combine(arrow.span)
+        };
+
+        Ok(MatchArm {
+            pattern,
+            expression,
+            attributes: self.arena.alloc_vec(attributes),
+            span,
+        })
+    }
+
+    fn skip_newlines(&mut self) {
+        while matches!(self.tokens.peek().token_type, TokenType::Newline) {
+            let newline = self.tokens.consume();
+            if self.context.options.retain_trivia {
+                self.trivia.push(newline.span);
+            }
+        }
     }
 
     /// Parse array literals like [1, 2, 3]
-    fn parse_array_literal(&mut self) -> Result<&'arena Expression, ParseError> {
+    fn parse_array_literal(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
         let start_span = self.tokens.peek().span.clone();
         let mut elements = Vec::new();
 
@@ -240,7 +795,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
             let end_token = self.tokens.consume();
             use crate::ast::ArrayLiteral;
             return Ok(self.arena.alloc(Expression::Array(ArrayLiteral {
-                elements,
+                elements: &[],
                 span: Span::new(
                     start_span.start,
                     end_token.span.end,
@@ -263,7 +818,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                         let end_token = self.tokens.consume();
                         use crate::ast::ArrayLiteral;
                         return Ok(self.arena.alloc(Expression::Array(ArrayLiteral {
-                            elements,
+                            elements: self.arena.alloc_vec(elements),
                             span: Span::new(
                                 start_span.start,
                                 end_token.span.end,
@@ -277,7 +832,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 TokenType::RightBracket => {
                     use crate::ast::ArrayLiteral;
                     return Ok(self.arena.alloc(Expression::Array(ArrayLiteral {
-                        elements,
+                        elements: self.arena.alloc_vec(elements),
                         span: Span::new(
                             start_span.start,
                             token.span.end,
@@ -294,9 +849,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse member access like obj.member
     fn parse_member_access(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         _token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         let member_token = self.tokens.consume();
         match member_token.token_type {
             TokenType::Identifier(member_name) => {
@@ -304,7 +859,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 Ok(self
                     .arena
                     .alloc(Expression::MemberAccess(MemberAccessExpression {
-                        object: Box::new(left.clone()),
+                        object: left,
                         member: member_name,
                         span: member_token.span.clone(),
                     })))
@@ -316,9 +871,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse function calls like func(arg1, arg2)
     fn parse_function_call(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         _token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         let mut arguments = Vec::new();
 
         // Check for empty argument list
@@ -326,8 +881,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
             self.tokens.consume();
             use crate::ast::CallExpression;
             return Ok(self.arena.alloc(Expression::Call(CallExpression {
-                callee: Box::new(left.clone()),
-                arguments,
+                callee: left,
+                arguments: &[],
                 span: _token.span.clone(),
             })));
         }
@@ -354,8 +909,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
 
         use crate::ast::CallExpression;
         Ok(self.arena.alloc(Expression::Call(CallExpression {
-            callee: Box::new(left.clone()),
-            arguments,
+            callee: left,
+            arguments: self.arena.alloc_vec(arguments),
             span: _token.span.clone(),
         })))
     }
@@ -363,9 +918,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse index expressions like arr[index]
     fn parse_index_expression(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         _token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         let index = self.parse_expression(0)?;
 
         let close_token = self.tokens.consume();
@@ -375,8 +930,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
 
         use crate::ast::IndexExpression;
         Ok(self.arena.alloc(Expression::Index(IndexExpression {
-            object: Box::new(left.clone()),
-            index: Box::new(index.clone()),
+            object: left,
+            index,
             span: _token.span.clone(),
         })))
     }
@@ -384,19 +939,19 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse try expressions like expr?
     fn parse_try_expression(
         &mut self,
-        left: &'arena Expression,
+        left: &'arena Expression<'arena>,
         token: &Token,
-    ) -> Result<&'arena Expression, ParseError> {
+    ) -> Result<&'arena Expression<'arena>, ParseError> {
         use crate::ast::UnaryExpression;
         Ok(self.arena.alloc(Expression::Unary(UnaryExpression {
             operator: crate::ast::UnaryOperator::Try,
-            operand: Box::new(left.clone()),
+            operand: left,
             span: token.span.clone(),
         })))
     }
 
     /// Parse patterns for match expressions
-    pub fn parse_pattern(&mut self) -> Result<&'arena crate::ast::Pattern, ParseError> {
+    pub fn parse_pattern(&mut self) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         self.parse_pattern_with_precedence(0)
     }
 
@@ -404,42 +959,51 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     fn parse_pattern_with_precedence(
         &mut self,
         min_precedence: u8,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
-        let mut left = self.parse_primary_pattern()?;
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
+        let left = self.parse_primary_pattern()?;
+        if min_precedence > 10 || !matches!(self.tokens.peek().token_type, TokenType::Pipe) {
+            // Or patterns have low precedence
+            return Ok(left);
+        }
 
-        // Handle Or patterns with precedence
+        // Collect every alternative before building a single or pattern
+        let mut patterns = match left {
+            crate::ast::Pattern::Or(or_pattern) => or_pattern.patterns.to_vec(),
+            _ => vec![left.clone()],
+        };
+        let mut span = left.span();
         while let TokenType::Pipe = self.tokens.peek().token_type {
-            if min_precedence > 10 {
-                // Or patterns have low precedence
-                break;
-            }
-
             self.tokens.consume(); // consume '|'
             let right = self.parse_pattern_with_precedence(11)?;
-
-            // Convert to or pattern
-            let patterns = match left {
-                crate::ast::Pattern::Or(or_pattern) => {
-                    let mut patterns = or_pattern.patterns.clone();
-                    patterns.push(right.clone());
-                    patterns
-                }
-                _ => vec![left.clone(), right.clone()],
-            };
-
-            left = self
-                .arena
-                .alloc(crate::ast::Pattern::Or(crate::ast::OrPattern {
-                    patterns,
-                    span: left.span().combine(right.span()),
-                }));
+            span = span.combine(right.span());
+            patterns.push(right.clone());
         }
 
-        Ok(left)
+        Ok(self
+            .arena
+            .alloc(crate::ast::Pattern::Or(crate::ast::OrPattern {
+                patterns: self.arena.alloc_vec(patterns),
+                span,
+            })))
+    }
+
+    /// Parse `!(...)` after a macro name, spanning the whole invocation
+    fn parse_macro_after_name(
+        &mut self,
+        name: Symbol,
+        name_span: &Span,
+    ) -> Result<MacroInvocation, ParseError> {
+        let mut invocation = crate::macro_parser::MacroParser::new(self.arena, &mut self.tokens)
+            .with_context(self.context.clone())
+            .parse_macro_invocation(name)?
+            .clone();
+        invocation.span = name_span.combine(invocation.span);
+        Ok(invocation)
     }
 
     /// Parse primary patterns (not including or patterns)
-    fn parse_primary_pattern(&mut self) -> Result<&'arena crate::ast::Pattern, ParseError> {
+    fn parse_primary_pattern(&mut self) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
+        let _nesting = self.context.enter_nesting(&self.tokens.peek().span)?;
         let token = self.tokens.consume();
 
         match &token.token_type {
@@ -468,6 +1032,48 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                     .alloc(crate::ast::Pattern::Literal(Literal::Boolean(*b)));
                 self.check_for_guard_or_binding(pattern)
             }
+            TokenType::CharLiteral(c) => {
+                let pattern = self
+                    .arena
+                    .alloc(crate::ast::Pattern::Literal(Literal::Char(*c)));
+                self.check_for_guard_or_binding(pattern)
+            }
+            TokenType::ByteLiteral(b) => {
+                let pattern = self
+                    .arena
+                    .alloc(crate::ast::Pattern::Literal(Literal::Byte(*b)));
+                self.check_for_guard_or_binding(pattern)
+            }
+            TokenType::ByteStringLiteral(bytes) => {
+                let pattern = self
+                    .arena
+                    .alloc(crate::ast::Pattern::Literal(Literal::ByteString(
+                        bytes.clone(),
+                    )));
+                self.check_for_guard_or_binding(pattern)
+            }
+            TokenType::WideIntegerLiteral(value) => {
+                let pattern = self
+                    .arena
+                    .alloc(crate::ast::Pattern::Literal(Literal::WideInteger(
+                        value.clone(),
+                    )));
+                self.check_for_guard_or_binding(pattern)
+            }
+            TokenType::TypedIntegerLiteral(value, suffix) => {
+                let literal = typed_integer_literal(*value, *suffix, &token.span)?;
+                let pattern = self.arena.alloc(crate::ast::Pattern::Literal(literal));
+                self.check_for_guard_or_binding(pattern)
+            }
+            TokenType::TypedFloatLiteral(value, suffix) => {
+                let pattern = self
+                    .arena
+                    .alloc(crate::ast::Pattern::Literal(Literal::TypedFloat {
+                        value: *value,
+                        suffix: *suffix,
+                    }));
+                self.check_for_guard_or_binding(pattern)
+            }
 
             // Identifier patterns
             TokenType::Identifier(name) => {
@@ -475,18 +1081,31 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 if name == "_" {
                     let pattern = self.arena.alloc(crate::ast::Pattern::Wildcard);
                     self.check_for_guard_or_binding(pattern)
+                } else if let TokenType::Bang = self.tokens.peek().token_type {
+                    // Macro invocation in pattern position: pair!(x)
+                    let invocation = self.parse_macro_after_name(*name, &token.span)?;
+                    let pattern = self.arena.alloc(crate::ast::Pattern::Macro(invocation));
+                    self.check_for_guard_or_binding(pattern)
+                } else if let TokenType::DoubleColon = self.tokens.peek().token_type {
+                    // Qualified pattern: Color::Red or shapes::Circle { radius }
+                    let path = self.parse_expression_path(*name, token.span.clone())?;
+                    let pattern = if let TokenType::LeftBrace = self.tokens.peek().token_type {
+                        self.parse_data_class_pattern(path)?
+                    } else {
+                        self.arena.alloc(crate::ast::Pattern::Path(path))
+                    };
+                    self.check_for_guard_or_binding(pattern)
                 } else if let TokenType::LeftBrace = self.tokens.peek().token_type {
                     // Data class pattern
-                    let pattern = self.parse_data_class_pattern(name.clone())?;
+                    let path = Path::from_ident(self.arena, *name, token.span.clone());
+                    let pattern = self.parse_data_class_pattern(path)?;
                     self.check_for_guard_or_binding(pattern)
                 } else if let TokenType::At = self.tokens.peek().token_type {
                     // Binding pattern: name @ pattern
-                    self.parse_binding_pattern(name.clone())
+                    self.parse_binding_pattern(*name)
                 } else {
                     // Simple identifier pattern
-                    let pattern = self
-                        .arena
-                        .alloc(crate::ast::Pattern::Identifier(name.clone()));
+                    let pattern = self.arena.alloc(crate::ast::Pattern::Identifier(*name));
                     self.check_for_guard_or_binding(pattern)
                 }
             }
@@ -507,8 +1126,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     fn check_for_range_or_guard_or_binding(
         &mut self,
         value: i64,
-        pattern: &'arena crate::ast::Pattern,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+        pattern: &'arena crate::ast::Pattern<'arena>,
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         match self.tokens.peek().token_type {
             TokenType::DotDot | TokenType::DotDotEqual => {
                 self.parse_range_pattern_from_start(value)
@@ -520,8 +1139,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Check for guard expressions: pattern if condition
     fn check_for_guard_or_binding(
         &mut self,
-        pattern: &'arena crate::ast::Pattern,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+        pattern: &'arena crate::ast::Pattern<'arena>,
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         match self.tokens.peek().token_type {
             TokenType::If => self.parse_guard_pattern(pattern),
             _ => Ok(pattern),
@@ -532,7 +1151,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     fn parse_range_pattern_from_start(
         &mut self,
         start_value: i64,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         let range_token = self.tokens.consume(); // consume .. or ..=
         let inclusive = matches!(range_token.token_type, TokenType::DotDotEqual);
 
@@ -542,7 +1161,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
 
         // Parse end pattern if present
         let end_pattern = if matches!(self.tokens.peek().token_type, TokenType::IntegerLiteral(_)) {
-            Some(Box::new(self.parse_primary_pattern()?.clone()))
+            Some(self.parse_primary_pattern()?)
         } else {
             None
         };
@@ -550,7 +1169,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
         let pattern = self
             .arena
             .alloc(crate::ast::Pattern::Range(crate::ast::RangePattern {
-                start: Some(Box::new(start_pattern.clone())),
+                start: Some(start_pattern),
                 end: end_pattern,
                 inclusive,
                 span: range_token.span.clone(),
@@ -563,12 +1182,12 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     fn parse_range_pattern_from_operator(
         &mut self,
         range_token: &Token,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         let inclusive = matches!(range_token.token_type, TokenType::DotDotEqual);
 
         // Parse end pattern if present
         let end_pattern = if matches!(self.tokens.peek().token_type, TokenType::IntegerLiteral(_)) {
-            Some(Box::new(self.parse_primary_pattern()?.clone()))
+            Some(self.parse_primary_pattern()?)
         } else {
             None
         };
@@ -586,7 +1205,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     }
 
     /// Parse slice patterns like [head, tail @ ..]
-    fn parse_slice_pattern(&mut self) -> Result<&'arena crate::ast::Pattern, ParseError> {
+    fn parse_slice_pattern(&mut self) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         use crate::ast::SlicePattern;
 
         let mut prefix = Vec::new();
@@ -598,9 +1217,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
         if let TokenType::RightBracket = self.tokens.peek().token_type {
             let close_token = self.tokens.consume();
             return Ok(self.arena.alloc(crate::ast::Pattern::Slice(SlicePattern {
-                prefix,
+                prefix: &[],
                 rest,
-                suffix,
+                suffix: &[],
                 span: close_token.span.clone(),
             })));
         }
@@ -612,7 +1231,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 self.tokens.consume(); // consume '..'
                 found_rest = true;
             } else if let TokenType::Identifier(name) = &self.tokens.peek().token_type {
-                let name = name.clone();
+                let name = *name;
                 if let Some(at_token) = self.tokens.peek_ahead(1) {
                     if matches!(at_token.token_type, TokenType::At) {
                         if let Some(dot_token) = self.tokens.peek_ahead(2) {
@@ -655,9 +1274,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                     if let TokenType::RightBracket = self.tokens.peek().token_type {
                         let close_token = self.tokens.consume();
                         return Ok(self.arena.alloc(crate::ast::Pattern::Slice(SlicePattern {
-                            prefix,
+                            prefix: self.arena.alloc_vec(prefix),
                             rest,
-                            suffix,
+                            suffix: self.arena.alloc_vec(suffix),
                             span: close_token.span.clone(),
                         })));
                     }
@@ -665,9 +1284,9 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 }
                 TokenType::RightBracket => {
                     return Ok(self.arena.alloc(crate::ast::Pattern::Slice(SlicePattern {
-                        prefix,
+                        prefix: self.arena.alloc_vec(prefix),
                         rest,
-                        suffix,
+                        suffix: self.arena.alloc_vec(suffix),
                         span: next_token.span.clone(),
                     })));
                 }
@@ -679,8 +1298,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse guard patterns like x if x > 0
     fn parse_guard_pattern(
         &mut self,
-        pattern: &'arena crate::ast::Pattern,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+        pattern: &'arena crate::ast::Pattern<'arena>,
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         self.tokens.consume(); // consume 'if'
 
         let guard_expr = self.parse_expression(0)?;
@@ -688,8 +1307,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
         Ok(self
             .arena
             .alloc(crate::ast::Pattern::Guard(crate::ast::GuardPattern {
-                pattern: Box::new(pattern.clone()),
-                guard: guard_expr.clone(),
+                pattern,
+                guard: guard_expr,
                 span: pattern.span().combine(guard_expr.span()),
             })))
     }
@@ -697,8 +1316,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse binding patterns like name @ pattern
     fn parse_binding_pattern(
         &mut self,
-        name: String,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+        name: Symbol,
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         self.tokens.consume(); // consume '@'
 
         let pattern = self.parse_primary_pattern()?;
@@ -707,7 +1326,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
             .arena
             .alloc(crate::ast::Pattern::Binding(crate::ast::BindingPattern {
                 name,
-                pattern: Box::new(pattern.clone()),
+                pattern,
                 span: pattern.span(), // TODO: Better span calculation
             })))
     }
@@ -715,8 +1334,8 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
     /// Parse data class patterns like Person { name, age }
     fn parse_data_class_pattern(
         &mut self,
-        name: String,
-    ) -> Result<&'arena crate::ast::Pattern, ParseError> {
+        name: Path<'arena>,
+    ) -> Result<&'arena crate::ast::Pattern<'arena>, ParseError> {
         use crate::ast::{DataClassPattern, FieldPattern};
 
         // Consume the opening brace
@@ -735,7 +1354,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                 .arena
                 .alloc(crate::ast::Pattern::DataClass(DataClassPattern {
                     name,
-                    fields,
+                    fields: &[],
                     has_rest,
                     span: close_token.span.clone(),
                 })));
@@ -752,7 +1371,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                         let pattern = self.parse_pattern()?;
                         fields.push(FieldPattern {
                             name: field_name,
-                            pattern: Some(pattern.clone()),
+                            pattern: Some(pattern),
                             span: token.span.clone(),
                         });
                     } else {
@@ -777,7 +1396,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                         return Ok(self.arena.alloc(crate::ast::Pattern::DataClass(
                             DataClassPattern {
                                 name,
-                                fields,
+                                fields: self.arena.alloc_vec(fields),
                                 has_rest,
                                 span: close_token.span.clone(),
                             },
@@ -789,7 +1408,7 @@ impl<'arena, T: TokenStream> PrattParser<'arena, T> {
                     return Ok(self.arena.alloc(crate::ast::Pattern::DataClass(
                         DataClassPattern {
                             name,
-                            fields,
+                            fields: self.arena.alloc_vec(fields),
                             has_rest,
                             span: next_token.span.clone(),
                         },
@@ -888,7 +1507,7 @@ mod tests {
             if let Expression::Binary(binary_expr) = expr {
                 assert!(matches!(binary_expr.operator, BinaryOperator::Add));
                 // Right side should be another binary expression (2 * 3)
-                if let Expression::Binary(right_expr) = binary_expr.right.as_ref() {
+                if let Expression::Binary(right_expr) = binary_expr.right {
                     assert!(matches!(right_expr.operator, BinaryOperator::Mul));
                 } else {
                     panic!("Expected right side to be binary expression");
@@ -903,9 +1522,9 @@ mod tests {
     fn test_parser_state_management() {
         let arena = create_test_arena();
         let tokens = create_token_stream(vec![
-            TokenType::Identifier("a".to_string()),
-            TokenType::Identifier("b".to_string()),
-            TokenType::Identifier("c".to_string()),
+            TokenType::Identifier("a".into()),
+            TokenType::Identifier("b".into()),
+            TokenType::Identifier("c".into()),
         ]);
         let mut parser = PrattParser::new(&arena, tokens);
 
@@ -34,6 +34,13 @@ pub fn prefix_binding_power(token_type: &TokenType) -> Option<BindingPower> {
         | TokenType::FloatLiteral(_)
         | TokenType::StringLiteral(_)
         | TokenType::BooleanLiteral(_)
+        | TokenType::CharLiteral(_)
+        | TokenType::ByteLiteral(_)
+        | TokenType::ByteStringLiteral(_)
+        | TokenType::WideIntegerLiteral(_)
+        | TokenType::TypedIntegerLiteral(..)
+        | TokenType::TypedFloatLiteral(..)
+        | TokenType::InterpolatedStringLiteral(_)
         | TokenType::Identifier(_)
         | TokenType::LeftParen => Some(160),
 
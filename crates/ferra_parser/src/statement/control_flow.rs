@@ -1,42 +1,59 @@
 //! Control flow statement parsing (if, while, for, return, break, continue)
 //!
-//! Implementation will be completed during development phase
+//! Entry points into the statement grammar in `StatementParser`
 
 use crate::{
     ast::{
-        BreakStatement, ContinueStatement, ForStatement, IfStatement, ReturnStatement,
+        Arena, BreakStatement, ContinueStatement, ForStatement, IfStatement, ReturnStatement,
         WhileStatement,
     },
     error::ParseResult,
+    statement::StatementParser,
     token::TokenStream,
 };
 
 /// Parse if statements
-pub fn parse_if_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<IfStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_if_statement<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<IfStatement<'a>> {
+    StatementParser::new(arena, tokens).parse_if_statement()
 }
 
 /// Parse while statements
-pub fn parse_while_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<WhileStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_while_statement<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<WhileStatement<'a>> {
+    StatementParser::new(arena, tokens).parse_while_statement()
 }
 
 /// Parse for statements
-pub fn parse_for_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<ForStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_for_statement<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<ForStatement<'a>> {
+    StatementParser::new(arena, tokens).parse_for_statement()
 }
 
 /// Parse return statements
-pub fn parse_return_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<ReturnStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_return_statement<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<ReturnStatement<'a>> {
+    StatementParser::new(arena, tokens).parse_return_statement()
 }
 
 /// Parse break statements
-pub fn parse_break_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<BreakStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_break_statement<T: TokenStream>(tokens: &mut T) -> ParseResult<BreakStatement> {
+    // Nothing is allocated for a bare `break`
+    let arena = Arena::new();
+    StatementParser::new(&arena, tokens).parse_break_statement()
 }
 
 /// Parse continue statements
-pub fn parse_continue_statement<T: TokenStream>(_tokens: &mut T) -> ParseResult<ContinueStatement> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_continue_statement<T: TokenStream>(tokens: &mut T) -> ParseResult<ContinueStatement> {
+    // Nothing is allocated for a bare `continue`
+    let arena = Arena::new();
+    StatementParser::new(&arena, tokens).parse_continue_statement()
 }
@@ -1,29 +1,42 @@
 //! Declaration statement parsing (let, var, fn, data, extern)
 //!
-//! Implementation will be completed during development phase
+//! Entry points into the item grammar in `StatementParser`
 
 use crate::{
-    ast::{DataClassDecl, ExternBlock, FunctionDecl, VariableDecl},
+    ast::{Arena, DataClassDecl, ExternBlock, FunctionDecl, Modifiers, VariableDecl},
     error::ParseResult,
+    statement::StatementParser,
     token::TokenStream,
 };
 
 /// Parse variable declarations (let/var)
-pub fn parse_variable_declaration<T: TokenStream>(_tokens: &mut T) -> ParseResult<VariableDecl> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_variable_declaration<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<VariableDecl<'a>> {
+    StatementParser::new(arena, tokens).parse_variable_declaration()
 }
 
 /// Parse function declarations
-pub fn parse_function_declaration<T: TokenStream>(_tokens: &mut T) -> ParseResult<FunctionDecl> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_function_declaration<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<FunctionDecl<'a>> {
+    StatementParser::new(arena, tokens).parse_function_declaration(Modifiers::default())
 }
 
 /// Parse data class declarations
-pub fn parse_data_class_declaration<T: TokenStream>(_tokens: &mut T) -> ParseResult<DataClassDecl> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_data_class_declaration<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<DataClassDecl<'a>> {
+    StatementParser::new(arena, tokens).parse_data_class_declaration()
 }
 
 /// Parse extern blocks
-pub fn parse_extern_block<T: TokenStream>(_tokens: &mut T) -> ParseResult<ExternBlock> {
-    todo!("Implementation will be done during development phase")
+pub fn parse_extern_block<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<ExternBlock<'a>> {
+    StatementParser::new(arena, tokens).parse_extern_block(Vec::new())
 }
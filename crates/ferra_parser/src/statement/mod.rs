@@ -1,6 +1,7 @@
 //! Statement parsing functionality
 //!
-//! Implementation will be completed during development phase
+//! `StatementParser` is the one grammar for items and statements; the free
+//! functions are entry points into it
 
 pub mod control_flow;
 pub mod declaration;
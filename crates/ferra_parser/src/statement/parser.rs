@@ -1,11 +1,24 @@
+//! The single grammar for items and statements
+//!
+//! `ProgramParser` parses top-level items through `parse_item`, and
+//! `BlockParser` parses the statements of every braced or indented block
+//! through `parse_statement`, so a construct is accepted in the same form
+//! wherever it appears. Expressions always go to the Pratt parser and blocks
+//! to the block parser.
+
 use crate::{
     ast::{
         Arena, Attribute, Block, BreakStatement, CompilationUnit, ContinueStatement, DataClassDecl,
-        ExternBlock, ExternFunction, ExternItem, ExternVariable, Field, ForStatement, FunctionDecl,
-        IfStatement, Item, Modifiers, Parameter, ReturnStatement, Statement, Type, VariableDecl,
-        WhileStatement,
+        Expression, ExternBlock, ExternFunction, ExternItem, ExternVariable, Field, ForStatement,
+        FunctionDecl, GenericParams, IfStatement, Item, MacroDefinition, MacroItem, Modifiers,
+        Parameter, ReturnStatement, Statement, Type, TypeBound, VariableDecl, WhereClause,
+        WhereConstraint, WhileStatement,
     },
+    block::BlockParser,
     error::ParseError,
+    macro_parser::MacroParser,
+    options::{self, Feature, ParseContext, ParserOptions},
+    pratt::PrattParser,
     token::{Span, Token, TokenStream, TokenType},
 };
 
@@ -13,112 +26,179 @@ use crate::{
 pub struct StatementParser<'arena, T: TokenStream> {
     arena: &'arena Arena,
     tokens: T,
+    context: ParseContext,
+    /// Parses the blocks of this parser's statements, carrying scope depth
+    /// and block style across nested blocks
+    blocks: BlockParser<'arena>,
+    /// Newlines skipped while `options.retain_trivia` is set
+    trivia: Vec<Span>,
 }
 
 impl<'arena, T: TokenStream> StatementParser<'arena, T> {
     pub fn new(arena: &'arena Arena, tokens: T) -> Self {
-        Self { arena, tokens }
+        Self {
+            arena,
+            tokens,
+            context: ParseContext::default(),
+            blocks: BlockParser::new(arena),
+            trivia: Vec::new(),
+        }
+    }
+
+    /// Parse with `options` instead of the defaults
+    pub fn with_options(self, options: ParserOptions) -> Self {
+        self.with_context(ParseContext::new(options))
+    }
+
+    /// Share the options and nesting depth of the parser creating this one
+    pub(crate) fn with_context(mut self, context: ParseContext) -> Self {
+        self.blocks = self.blocks.with_context(context.clone());
+        self.context = context;
+        self
+    }
+
+    /// Parse the statements of `blocks`' current block
+    pub(crate) fn within_block(blocks: BlockParser<'arena>, tokens: T) -> Self {
+        Self {
+            arena: blocks.arena(),
+            tokens,
+            context: blocks.context(),
+            blocks,
+            trivia: Vec::new(),
+        }
+    }
+
+    /// The block parser, with the scope and style state of nested blocks
+    pub(crate) fn into_block_parser(mut self) -> BlockParser<'arena> {
+        self.blocks.absorb_trivia(self.trivia);
+        self.blocks
+    }
+
+    /// Spans of the newlines skipped so far, in source order, when parsing
+    /// with `retain_trivia`
+    pub fn trivia(&self) -> Vec<Span> {
+        let mut trivia = self.trivia.clone();
+        trivia.extend(self.blocks.trivia());
+        options::source_order(trivia)
+    }
+
+    /// The skipped newlines, for the parser that created this one
+    pub(crate) fn into_trivia(mut self) -> Vec<Span> {
+        self.trivia.extend(self.blocks.into_trivia());
+        self.trivia
     }
 
     /// Parse a complete compilation unit
-    pub fn parse_compilation_unit(&mut self) -> Result<&'arena CompilationUnit, ParseError> {
-        let mut items = Vec::new();
+    pub fn parse_compilation_unit(
+        &mut self,
+    ) -> Result<&'arena CompilationUnit<'arena>, ParseError> {
         let start_span = self.current_span();
+        let attributes = self.parse_inner_attributes()?;
 
-        // Skip any leading whitespace/newlines
-        self.skip_newlines();
-
+        let mut items = Vec::new();
         while !self.is_at_end() {
             let item = self.parse_item()?;
             items.push(item.clone());
             self.skip_newlines();
         }
 
-        let end_span = self.previous_span();
-        let span = Span::new(
-            start_span.start,
-            end_span.end,
-            start_span.line,
-            start_span.column,
-        );
+        Ok(self.finish_compilation_unit(start_span, attributes, items))
+    }
 
-        Ok(self.arena.alloc(CompilationUnit { items, span }))
+    /// Inner attributes of a compilation unit: #![allow(dead_code)]
+    pub(crate) fn parse_inner_attributes(&mut self) -> Result<Vec<Attribute<'arena>>, ParseError> {
+        self.skip_newlines();
+        let attributes = crate::attribute::parser::parse_inner_attributes_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
+        self.skip_newlines();
+        Ok(attributes)
+    }
+
+    /// Allocate the compilation unit that started at `start_span`
+    pub(crate) fn finish_compilation_unit(
+        &self,
+        start_span: Span,
+        attributes: Vec<Attribute<'arena>>,
+        items: Vec<Item<'arena>>,
+    ) -> &'arena CompilationUnit<'arena> {
+        self.arena.alloc(CompilationUnit {
+            attributes: self.arena.alloc_vec(attributes),
+            items: self.arena.alloc_vec(items),
+            span: start_span.combine(self.current_span()),
+        })
     }
 
     /// Parse a single statement
-    pub fn parse_statement(&mut self) -> Result<&'arena Statement, ParseError> {
+    pub fn parse_statement(&mut self) -> Result<&'arena Statement<'arena>, ParseError> {
         // Check for attributes first
         let attributes = self.parse_attributes()?;
 
-        let token = self.peek();
+        // Variable declarations carry their own attributes
+        if matches!(self.peek().token_type, TokenType::Let | TokenType::Var) {
+            let var_decl = self.parse_variable_declaration_with_modifiers_and_attributes(
+                Modifiers {
+                    is_public: false,
+                    is_unsafe: false,
+                },
+                attributes,
+            )?;
+            return Ok(self.arena.alloc(Statement::VariableDecl(var_decl)));
+        }
 
-        match &token.token_type {
-            // Variable declarations
-            TokenType::Let | TokenType::Var => {
-                let var_decl = self.parse_variable_declaration_with_modifiers_and_attributes(
-                    Modifiers {
-                        is_public: false,
-                        is_unsafe: false,
-                    },
-                    attributes,
-                )?;
-                Ok(self.arena.alloc(Statement::VariableDecl(var_decl.clone())))
-            }
+        let statement = self.parse_unattributed_statement()?;
 
+        Ok(self
+            .arena
+            .alloc(statement.with_attributes(self.arena, self.arena.alloc_vec(attributes))))
+    }
+
+    /// Parse the statement at the current token, after its attributes
+    ///
+    /// Every nested block passes through this match, so the arms map their
+    /// results instead of using `?` to keep the frame small in debug builds.
+    fn parse_unattributed_statement(&mut self) -> Result<Statement<'arena>, ParseError> {
+        match self.tokens.peek().token_type {
             // Control flow
-            TokenType::If => {
-                let if_stmt = self.parse_if_statement()?;
-                Ok(self.arena.alloc(Statement::If(if_stmt.clone())))
-            }
-            TokenType::While => {
-                let while_stmt = self.parse_while_statement()?;
-                Ok(self.arena.alloc(Statement::While(while_stmt.clone())))
-            }
-            TokenType::For => {
-                let for_stmt = self.parse_for_statement()?;
-                Ok(self.arena.alloc(Statement::For(for_stmt.clone())))
-            }
-            TokenType::Return => {
-                let return_stmt = self.parse_return_statement()?;
-                Ok(self.arena.alloc(Statement::Return(return_stmt.clone())))
-            }
-            TokenType::Break => {
-                let break_stmt = self.parse_break_statement()?;
-                Ok(self.arena.alloc(Statement::Break(break_stmt.clone())))
-            }
-            TokenType::Continue => {
-                let continue_stmt = self.parse_continue_statement()?;
-                Ok(self.arena.alloc(Statement::Continue(continue_stmt.clone())))
-            }
+            TokenType::If => self.parse_if_statement().map(Statement::If),
+            TokenType::While => self.parse_while_statement().map(Statement::While),
+            TokenType::For => self.parse_for_statement().map(Statement::For),
+            TokenType::Return => self.parse_return_statement().map(Statement::Return),
+            TokenType::Break => self.parse_break_statement().map(Statement::Break),
+            TokenType::Continue => self.parse_continue_statement().map(Statement::Continue),
 
             // Block statements
-            TokenType::LeftBrace => {
-                let block = self.parse_block()?;
-                Ok(self.arena.alloc(Statement::Block(block.clone())))
+            TokenType::LeftBrace => self
+                .parse_block()
+                .map(|block| Statement::Block(block.clone())),
+
+            // Brace-delimited macro statements end at their closing brace
+            TokenType::Identifier(_) if self.at_braced_macro_invocation() => {
+                MacroParser::new(self.arena, &mut self.tokens)
+                    .with_context(self.context.clone())
+                    .parse_macro_item_invocation()
+                    .map(|invocation| {
+                        Statement::Expression(
+                            self.arena.alloc(Expression::Macro(invocation.clone())),
+                        )
+                    })
             }
 
             // Expression statements (fallback)
-            _ => {
-                // If we have attributes but no statement that can use them, that's an error
-                if !attributes.is_empty() {
-                    return Err(ParseError::unexpected_token(
-                        "statement that supports attributes",
-                        &token,
-                    ));
-                }
-
-                let expr = self.parse_expression()?;
-                // Consume optional semicolon
-                if matches!(self.peek().token_type, TokenType::Semicolon) {
-                    self.consume();
-                }
-                Ok(self.arena.alloc(Statement::Expression(expr.clone())))
-            }
+            _ => self.parse_expression_statement(),
         }
     }
 
+    fn parse_expression_statement(&mut self) -> Result<Statement<'arena>, ParseError> {
+        let expr = self.parse_expression()?;
+        self.skip_semicolon();
+        Ok(Statement::Expression(expr))
+    }
+
     /// Parse a top-level item (function, data class, extern block, etc.)
-    pub fn parse_item(&mut self) -> Result<&'arena Item, ParseError> {
+    pub fn parse_item(&mut self) -> Result<&'arena Item<'arena>, ParseError> {
         // Parse optional attributes first
         let attributes = self.parse_attributes()?;
 
@@ -130,23 +210,49 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             TokenType::Fn | TokenType::Async => {
                 let func_decl =
                     self.parse_function_declaration_with_attributes(modifiers, attributes)?;
-                Ok(self.arena.alloc(Item::FunctionDecl(func_decl.clone())))
+                Ok(self.arena.alloc(Item::FunctionDecl(func_decl)))
             }
             TokenType::Let | TokenType::Var => {
                 let var_decl = self.parse_variable_declaration_with_modifiers_and_attributes(
                     modifiers, attributes,
                 )?;
-                Ok(self.arena.alloc(Item::VariableDecl(var_decl.clone())))
+                Ok(self.arena.alloc(Item::VariableDecl(var_decl)))
             }
             TokenType::Data => {
                 let data_decl = self.parse_data_class_declaration_with_attributes(attributes)?;
-                Ok(self.arena.alloc(Item::DataClassDecl(data_decl.clone())))
+                Ok(self.arena.alloc(Item::DataClassDecl(data_decl)))
             }
             TokenType::Extern => {
-                let extern_block = self.parse_extern_block()?;
-                Ok(self.arena.alloc(Item::ExternBlock(extern_block.clone())))
+                let extern_block = self.parse_extern_block(attributes)?;
+                Ok(self.arena.alloc(Item::ExternBlock(extern_block)))
+            }
+            TokenType::Macro => {
+                let macro_def = self.parse_macro_definition(attributes)?;
+                Ok(self.arena.alloc(Item::MacroDefinition(macro_def)))
+            }
+            TokenType::Identifier(_) if self.at_macro_invocation() => {
+                if modifiers.is_public || modifiers.is_unsafe {
+                    return Err(ParseError::syntax_error_with_suggestion(
+                        "macro invocations cannot have modifiers",
+                        token.span,
+                        "remove the `pub` or `unsafe` before the invocation",
+                    ));
+                }
+                let invocation = MacroParser::new(self.arena, &mut self.tokens)
+                    .with_context(self.context.clone())
+                    .parse_macro_item_invocation()?
+                    .clone();
+                let span = invocation.span.clone();
+                Ok(self.arena.alloc(Item::Macro(MacroItem {
+                    invocation,
+                    attributes: self.arena.alloc_vec(attributes),
+                    span,
+                })))
             }
-            _ => Err(ParseError::unexpected_token("item declaration", &token)),
+            _ => Err(ParseError::unexpected_token(
+                "function, data class, extern block, variable declaration, or other top-level declaration",
+                &token,
+            )),
         }
     }
 
@@ -167,35 +273,63 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         self.peek().span.clone()
     }
 
-    fn previous_span(&self) -> Span {
-        // For now, return current span - in real implementation we'd track previous
-        self.peek().span.clone()
+    /// Consume `expected`, or fail naming it
+    fn expect(&mut self, expected: TokenType, description: &str) -> Result<Token, ParseError> {
+        let token = self.consume();
+        if token.token_type != expected {
+            return Err(ParseError::unexpected_token(description, &token));
+        }
+        Ok(token)
     }
 
-    fn skip_newlines(&mut self) {
-        while matches!(self.peek().token_type, TokenType::Newline) {
-            self.consume();
+    /// Consume an identifier, or fail naming what it would have been
+    fn expect_identifier(
+        &mut self,
+        description: &str,
+    ) -> Result<(crate::token::Symbol, Span), ParseError> {
+        let token = self.consume();
+        match token.token_type {
+            TokenType::Identifier(name) => Ok((name, token.span)),
+            _ => Err(ParseError::unexpected_token(description, &token)),
         }
     }
 
-    fn parse_expression(&mut self) -> Result<&'arena crate::ast::Expression, ParseError> {
-        let token = self.consume();
+    fn skip_semicolon(&mut self) {
+        if matches!(self.peek().token_type, TokenType::Semicolon) {
+            self.consume();
+        }
+    }
 
-        match token.token_type {
-            TokenType::BooleanLiteral(b) => Ok(self.arena.alloc(crate::ast::Expression::Literal(
-                crate::ast::Literal::Boolean(b),
-            ))),
-            TokenType::IntegerLiteral(i) => Ok(self.arena.alloc(crate::ast::Expression::Literal(
-                crate::ast::Literal::Integer(i),
-            ))),
-            TokenType::Identifier(name) => {
-                Ok(self.arena.alloc(crate::ast::Expression::Identifier(name)))
+    fn skip_newlines(&mut self) {
+        while matches!(self.peek().token_type, TokenType::Newline) {
+            let newline = self.consume();
+            if self.context.options.retain_trivia {
+                self.trivia.push(newline.span);
             }
-            _ => Err(ParseError::unexpected_token("expression", &token)),
         }
     }
 
-    // Placeholder implementations - we'll implement these in the next steps
+    fn parse_expression(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        let mut parser =
+            PrattParser::new(self.arena, &mut self.tokens).with_context(self.context.clone());
+        let expression = parser.parse_expression(0);
+        self.trivia.extend(parser.into_trivia());
+        expression
+    }
+
+    /// Parse an `if`/`while`/`for` head, where `Name {` opens the body
+    fn parse_condition(&mut self) -> Result<&'arena Expression<'arena>, ParseError> {
+        let mut parser =
+            PrattParser::new(self.arena, &mut self.tokens).with_context(self.context.clone());
+        let condition = parser.parse_condition();
+        self.trivia.extend(parser.into_trivia());
+        condition
+    }
+
+    fn parse_block(&mut self) -> Result<&'arena Block<'arena>, ParseError> {
+        self.blocks.parse_block(&mut self.tokens)
+    }
+
     fn parse_modifiers(&mut self) -> Result<Modifiers, ParseError> {
         let mut is_public = false;
         let mut is_unsafe = false;
@@ -214,40 +348,32 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         })
     }
 
-    #[allow(dead_code)]
-    fn parse_variable_declaration(&mut self) -> Result<VariableDecl, ParseError> {
-        self.parse_variable_declaration_with_modifiers(Modifiers {
-            is_public: false,
-            is_unsafe: false,
-        })
-    }
-
-    #[allow(dead_code)]
-    fn parse_variable_declaration_with_modifiers(
+    pub(crate) fn parse_variable_declaration(
         &mut self,
-        modifiers: Modifiers,
-    ) -> Result<VariableDecl, ParseError> {
-        self.parse_variable_declaration_with_modifiers_and_attributes(modifiers, Vec::new())
+    ) -> Result<VariableDecl<'arena>, ParseError> {
+        self.parse_variable_declaration_with_modifiers_and_attributes(
+            Modifiers {
+                is_public: false,
+                is_unsafe: false,
+            },
+            Vec::new(),
+        )
     }
 
     fn parse_variable_declaration_with_modifiers_and_attributes(
         &mut self,
         modifiers: Modifiers,
-        attributes: Vec<Attribute>,
-    ) -> Result<VariableDecl, ParseError> {
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<VariableDecl<'arena>, ParseError> {
         let start_token = self.consume(); // let or var
         let is_mutable = matches!(start_token.token_type, TokenType::Var);
 
-        let name_token = self.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("variable name", &name_token)),
-        };
+        let (name, _) = self.expect_identifier("variable name")?;
 
         // Optional type annotation
         let var_type = if matches!(self.peek().token_type, TokenType::Colon) {
             self.consume(); // consume ':'
-            Some(self.parse_type()?)
+            Some(self.arena.alloc(self.parse_type()?))
         } else {
             None
         };
@@ -255,15 +381,12 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         // Optional initializer
         let initializer = if matches!(self.peek().token_type, TokenType::Equal) {
             self.consume(); // consume '='
-            Some(self.parse_expression()?.clone())
+            Some(self.parse_expression()?)
         } else {
             None
         };
 
-        // Consume optional semicolon
-        if matches!(self.peek().token_type, TokenType::Semicolon) {
-            self.consume();
-        }
+        self.skip_semicolon();
 
         Ok(VariableDecl {
             name,
@@ -271,24 +394,25 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             initializer,
             is_mutable,
             modifiers,
-            attributes,
+            attributes: self.arena.alloc_vec(attributes),
             span: start_token.span,
         })
     }
 
-    #[allow(dead_code)]
-    fn parse_function_declaration(
+    pub(crate) fn parse_function_declaration(
         &mut self,
         modifiers: Modifiers,
-    ) -> Result<FunctionDecl, ParseError> {
+    ) -> Result<FunctionDecl<'arena>, ParseError> {
         self.parse_function_declaration_with_attributes(modifiers, Vec::new())
     }
 
     fn parse_function_declaration_with_attributes(
         &mut self,
         modifiers: Modifiers,
-        attributes: Vec<Attribute>,
-    ) -> Result<FunctionDecl, ParseError> {
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<FunctionDecl<'arena>, ParseError> {
+        let start_span = self.current_span();
+
         // Check for async keyword first
         let is_async = if matches!(self.peek().token_type, TokenType::Async) {
             self.consume(); // consume 'async'
@@ -297,85 +421,66 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             false
         };
 
-        // Now consume 'fn'
-        if !matches!(self.peek().token_type, TokenType::Fn) {
-            return Err(ParseError::unexpected_token("fn", &self.peek()));
+        if is_async {
+            self.expect(TokenType::Fn, "'fn' after 'async'")?;
+        } else {
+            self.expect(TokenType::Fn, "'fn'")?;
         }
-        self.consume(); // consume 'fn'
 
-        let name = if let TokenType::Identifier(name) = &self.peek().token_type {
-            name.clone()
-        } else {
-            return Err(ParseError::unexpected_token("function name", &self.peek()));
-        };
-        let name_span = self.consume().span;
+        let (name, _) = self.expect_identifier("function name")?;
 
         // Parse generic parameters if present
-        let generics = crate::generic::parser::parse_generic_params(&mut self.tokens)?;
-
-        if !matches!(self.peek().token_type, TokenType::LeftParen) {
-            return Err(ParseError::unexpected_token("(", &self.peek()));
-        }
+        let generics = crate::generic::parser::parse_generic_params_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
 
         let parameters = self.parse_parameter_list()?;
 
         let return_type = if matches!(self.peek().token_type, TokenType::Arrow) {
             self.consume(); // consume '->'
-            Some(self.parse_type()?)
+            Some(self.arena.alloc(self.parse_type()?))
         } else {
             None
         };
 
-        // Handle where clause that might come after return type
-        let final_generics = if matches!(self.peek().token_type, TokenType::Where) {
-            // Parse where clause
-            let where_clause = self.parse_where_clause()?;
-            let where_clause_span = where_clause.span.clone();
+        let generics = self.parse_optional_where_clause(generics)?;
 
-            if let Some(mut gen) = generics {
-                gen.where_clause = Some(where_clause);
-                Some(gen)
-            } else {
-                // Create new generics with just the where clause
-                Some(crate::ast::GenericParams {
-                    params: Vec::new(),
-                    where_clause: Some(where_clause),
-                    span: where_clause_span,
-                })
+        // A body, `;`, or nothing for a declaration that ends the line. Each
+        // body picks its own block style.
+        let previous = std::mem::replace(
+            &mut self.blocks,
+            BlockParser::new(self.arena).with_context(self.context.clone()),
+        );
+        self.trivia.extend(previous.into_trivia());
+        let body = match self.peek().token_type {
+            TokenType::LeftBrace | TokenType::Colon => Some(self.parse_block()?),
+            TokenType::Semicolon => {
+                self.consume(); // consume ';'
+                None
             }
-        } else {
-            generics
-        };
-
-        let body = if matches!(self.peek().token_type, TokenType::LeftBrace) {
-            Some(self.parse_block()?)
-        } else if matches!(self.peek().token_type, TokenType::Semicolon) {
-            self.consume(); // consume ';'
-            None
-        } else {
-            return Err(ParseError::unexpected_token("{ or ;", &self.peek()));
+            TokenType::Newline | TokenType::Eof | TokenType::RightBrace => None,
+            _ => return Err(ParseError::unexpected_token("{ or ;", &self.peek())),
         };
 
         Ok(FunctionDecl {
             name,
-            generics: final_generics,
-            parameters,
+            generics,
+            parameters: self.arena.alloc_vec(parameters),
             return_type,
             body,
             is_async,
             is_extern: false,
             abi: None,
             modifiers,
-            attributes,
-            span: name_span,
+            attributes: self.arena.alloc_vec(attributes),
+            span: start_span,
         })
     }
 
-    fn parse_parameter_list(&mut self) -> Result<Vec<Parameter>, ParseError> {
-        let open_paren = self.consume();
-        if !matches!(open_paren.token_type, TokenType::LeftParen) {
-            return Err(ParseError::unexpected_token("'('", &open_paren));
-        }
+    fn parse_parameter_list(&mut self) -> Result<Vec<Parameter<'arena>>, ParseError> {
+        self.expect(TokenType::LeftParen, "'('")?;
 
         let mut parameters = Vec::new();
 
@@ -388,7 +493,8 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             let param = self.parse_parameter()?;
             parameters.push(param);
 
-            match self.consume().token_type {
+            let separator = self.consume();
+            match separator.token_type {
                 TokenType::Comma => {
                     if matches!(self.peek().token_type, TokenType::RightParen) {
                         self.consume();
@@ -396,75 +502,70 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
                     }
                 }
                 TokenType::RightParen => break,
-                _ => return Err(ParseError::unexpected_token("',' or ')'", &self.peek())),
+                _ => return Err(ParseError::unexpected_token("',' or ')'", &separator)),
             }
         }
 
         Ok(parameters)
     }
 
-    fn parse_parameter(&mut self) -> Result<Parameter, ParseError> {
+    fn parse_parameter(&mut self) -> Result<Parameter<'arena>, ParseError> {
         // Parse optional attributes
         let attributes = self.parse_attributes()?;
 
-        let name_token = self.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("parameter name", &name_token)),
-        };
+        let (name, span) = self.expect_identifier("parameter name")?;
 
-        let colon_token = self.consume();
-        if !matches!(colon_token.token_type, TokenType::Colon) {
-            return Err(ParseError::unexpected_token("':'", &colon_token));
-        }
-
-        let param_type = self.parse_type()?;
+        // Without an annotation the type is left for inference to fill in
+        let param_type = if matches!(self.peek().token_type, TokenType::Colon) {
+            self.consume(); // consume ':'
+            self.parse_type()?
+        } else {
+            Type::Identifier("_".into())
+        };
 
         Ok(Parameter {
             name,
-            param_type,
-            attributes,
-            span: name_token.span,
+            param_type: self.arena.alloc(param_type),
+            attributes: self.arena.alloc_vec(attributes),
+            span,
         })
     }
 
-    fn parse_attributes(&mut self) -> Result<Vec<Attribute>, ParseError> {
-        crate::attribute::parse_attributes(&mut self.tokens)
+    fn parse_attributes(&mut self) -> Result<Vec<Attribute<'arena>>, ParseError> {
+        crate::attribute::parse_attributes_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )
     }
 
-    fn parse_type(&mut self) -> Result<Type, ParseError> {
-        crate::types::parse_type(&mut self.tokens)
+    fn parse_type(&mut self) -> Result<Type<'arena>, ParseError> {
+        crate::types::parse_type_with_context(self.arena, &mut self.tokens, self.context.clone())
     }
 
-    #[allow(dead_code)]
-    fn parse_data_class_declaration(&mut self) -> Result<DataClassDecl, ParseError> {
+    pub(crate) fn parse_data_class_declaration(
+        &mut self,
+    ) -> Result<DataClassDecl<'arena>, ParseError> {
         self.parse_data_class_declaration_with_attributes(Vec::new())
     }
 
     fn parse_data_class_declaration_with_attributes(
         &mut self,
-        attributes: Vec<Attribute>,
-    ) -> Result<DataClassDecl, ParseError> {
-        self.consume(); // consume 'data'
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<DataClassDecl<'arena>, ParseError> {
+        let start_span = self.expect(TokenType::Data, "'data'")?.span;
 
-        let name = if let TokenType::Identifier(name) = &self.peek().token_type {
-            name.clone()
-        } else {
-            return Err(ParseError::unexpected_token(
-                "data class name",
-                &self.peek(),
-            ));
-        };
-        let name_span = self.consume().span;
+        let (name, _) = self.expect_identifier("data class name")?;
 
         // Parse generic parameters if present
-        let generics = crate::generic::parser::parse_generic_params(&mut self.tokens)?;
-
-        if !matches!(self.peek().token_type, TokenType::LeftBrace) {
-            return Err(ParseError::unexpected_token("'{'", &self.peek()));
-        }
-
-        self.consume(); // consume '{'
+        let generics = crate::generic::parser::parse_generic_params_with_context(
+            self.arena,
+            &mut self.tokens,
+            self.context.clone(),
+        )?;
+        let generics = self.parse_optional_where_clause(generics)?;
+
+        self.expect(TokenType::LeftBrace, "'{'")?;
         let mut fields = Vec::new();
 
         while !matches!(self.peek().token_type, TokenType::RightBrace) && !self.is_at_end() {
@@ -475,62 +576,89 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             }
         }
 
-        if !matches!(self.peek().token_type, TokenType::RightBrace) {
-            return Err(ParseError::unexpected_token("'}'", &self.peek()));
-        }
-        self.consume(); // consume '}'
+        self.expect(TokenType::RightBrace, "'}'")?;
 
         Ok(DataClassDecl {
             name,
             generics,
-            fields,
-            attributes,
-            span: name_span,
+            fields: self.arena.alloc_vec(fields),
+            attributes: self.arena.alloc_vec(attributes),
+            span: start_span,
         })
     }
 
-    fn parse_field(&mut self) -> Result<Field, ParseError> {
+    fn parse_field(&mut self) -> Result<Field<'arena>, ParseError> {
         // Parse attributes before field declaration
         let attributes = self.parse_attributes()?;
 
-        let name_token = self.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("field name", &name_token)),
-        };
-
-        let colon_token = self.consume();
-        if !matches!(colon_token.token_type, TokenType::Colon) {
-            return Err(ParseError::unexpected_token("':'", &colon_token));
-        }
+        // Fields may be marked `pub`; visibility is not part of the AST yet
+        let _modifiers = self.parse_modifiers()?;
 
+        let (name, span) = self.expect_identifier("field name")?;
+        self.expect(TokenType::Colon, "':'")?;
         let field_type = self.parse_type()?;
 
         Ok(Field {
             name,
-            field_type,
-            attributes,
-            span: name_token.span,
+            field_type: self.arena.alloc(field_type),
+            attributes: self.arena.alloc_vec(attributes),
+            span,
         })
     }
 
-    fn parse_extern_block(&mut self) -> Result<ExternBlock, ParseError> {
-        let extern_token = self.consume();
-        if !matches!(extern_token.token_type, TokenType::Extern) {
-            return Err(ParseError::unexpected_token("'extern'", &extern_token));
-        }
+    fn parse_macro_definition(
+        &mut self,
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<MacroDefinition<'arena>, ParseError> {
+        let macro_token = self.expect(TokenType::Macro, "'macro'")?;
+        self.context
+            .options
+            .require(Feature::MacroDefinitions, &macro_token.span)?;
+
+        let (name, _) = self.expect_identifier("macro name")?;
+
+        let mut macro_def = MacroParser::new(self.arena, &mut self.tokens)
+            .with_context(self.context.clone())
+            .parse_macro_definition(name)?
+            .clone();
+        macro_def.attributes = self.arena.alloc_vec(attributes);
+        macro_def.span = macro_token.span.combine(macro_def.span);
+        Ok(macro_def)
+    }
+
+    /// Check for `name!` at the current position
+    fn at_macro_invocation(&self) -> bool {
+        matches!(
+            self.tokens.peek_ahead(1).map(|token| &token.token_type),
+            Some(TokenType::Bang)
+        )
+    }
+
+    /// Check for `name! {` at the current position
+    fn at_braced_macro_invocation(&self) -> bool {
+        self.at_macro_invocation()
+            && matches!(
+                self.tokens.peek_ahead(2).map(|token| &token.token_type),
+                Some(TokenType::LeftBrace)
+            )
+    }
 
-        // ABI string
-        let abi_token = self.consume();
-        let abi = match abi_token.token_type {
-            TokenType::StringLiteral(abi) => abi,
-            _ => return Err(ParseError::unexpected_token("ABI string", &abi_token)),
+    pub(crate) fn parse_extern_block(
+        &mut self,
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<ExternBlock<'arena>, ParseError> {
+        let extern_token = self.expect(TokenType::Extern, "'extern'")?;
+
+        // The ABI defaults to C
+        let abi = match self.peek().token_type {
+            TokenType::StringLiteral(abi) => {
+                self.consume();
+                abi
+            }
+            _ => "C".to_string(),
         };
 
-        let open_brace = self.consume();
-        if !matches!(open_brace.token_type, TokenType::LeftBrace) {
-            return Err(ParseError::unexpected_token("'{'", &open_brace));
-        }
+        self.expect(TokenType::LeftBrace, "'{'")?;
 
         let mut items = Vec::new();
         while !matches!(
@@ -541,111 +669,96 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             items.push(item);
         }
 
-        let close_brace = self.consume();
-        if !matches!(close_brace.token_type, TokenType::RightBrace) {
-            return Err(ParseError::unexpected_token("'}'", &close_brace));
-        }
+        self.expect(TokenType::RightBrace, "'}'")?;
 
         Ok(ExternBlock {
             abi,
-            items,
+            items: self.arena.alloc_vec(items),
+            attributes: self.arena.alloc_vec(attributes),
             span: extern_token.span,
         })
     }
 
-    fn parse_extern_item(&mut self) -> Result<ExternItem, ParseError> {
+    fn parse_extern_item(&mut self) -> Result<ExternItem<'arena>, ParseError> {
+        // Parse optional attributes: #[link_name = "..."]
+        let attributes = self.parse_attributes()?;
+
         let token = self.peek();
         match &token.token_type {
             TokenType::Fn => {
-                let func = self.parse_extern_function()?;
+                let func = self.parse_extern_function(attributes)?;
                 Ok(ExternItem::Function(func))
             }
             TokenType::Static => {
-                let var = self.parse_extern_variable()?;
+                let var = self.parse_extern_variable(attributes)?;
                 Ok(ExternItem::Variable(var))
             }
-            _ => Err(ParseError::unexpected_token("'fn' or 'static'", &token)),
+            _ => Err(ParseError::unexpected_token(
+                "extern function or variable",
+                &token,
+            )),
         }
     }
 
-    fn parse_extern_function(&mut self) -> Result<ExternFunction, ParseError> {
-        let fn_token = self.consume();
-        if !matches!(fn_token.token_type, TokenType::Fn) {
-            return Err(ParseError::unexpected_token("'fn'", &fn_token));
-        }
-
-        let name_token = self.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("function name", &name_token)),
-        };
+    fn parse_extern_function(
+        &mut self,
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<ExternFunction<'arena>, ParseError> {
+        let fn_token = self.expect(TokenType::Fn, "'fn'")?;
+        let (name, _) = self.expect_identifier("function name")?;
 
         let parameters = self.parse_parameter_list()?;
 
         let return_type = if matches!(self.peek().token_type, TokenType::Arrow) {
             self.consume(); // consume '->'
-            Some(self.parse_type()?)
+            Some(self.arena.alloc(self.parse_type()?))
         } else {
             None
         };
 
-        // Expect semicolon
-        let semicolon = self.consume();
-        if !matches!(semicolon.token_type, TokenType::Semicolon) {
-            return Err(ParseError::unexpected_token("';'", &semicolon));
-        }
+        self.expect(TokenType::Semicolon, "';'")?;
 
         Ok(ExternFunction {
             name,
-            parameters,
+            parameters: self.arena.alloc_vec(parameters),
             return_type,
+            attributes: self.arena.alloc_vec(attributes),
             span: fn_token.span,
         })
     }
 
-    fn parse_extern_variable(&mut self) -> Result<ExternVariable, ParseError> {
-        let static_token = self.consume();
-        if !matches!(static_token.token_type, TokenType::Static) {
-            return Err(ParseError::unexpected_token("'static'", &static_token));
-        }
-
-        let name_token = self.consume();
-        let name = match name_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("variable name", &name_token)),
-        };
-
-        let colon_token = self.consume();
-        if !matches!(colon_token.token_type, TokenType::Colon) {
-            return Err(ParseError::unexpected_token("':'", &colon_token));
-        }
-
+    fn parse_extern_variable(
+        &mut self,
+        attributes: Vec<Attribute<'arena>>,
+    ) -> Result<ExternVariable<'arena>, ParseError> {
+        let static_token = self.expect(TokenType::Static, "'static'")?;
+        let (name, _) = self.expect_identifier("variable name")?;
+        self.expect(TokenType::Colon, "':'")?;
         let var_type = self.parse_type()?;
-
-        let semicolon = self.consume();
-        if !matches!(semicolon.token_type, TokenType::Semicolon) {
-            return Err(ParseError::unexpected_token("';'", &semicolon));
-        }
+        self.expect(TokenType::Semicolon, "';'")?;
 
         Ok(ExternVariable {
             name,
-            var_type,
+            var_type: self.arena.alloc(var_type),
+            attributes: self.arena.alloc_vec(attributes),
             span: static_token.span,
         })
     }
 
     // Control flow statement parsers
-    fn parse_if_statement(&mut self) -> Result<IfStatement, ParseError> {
-        let if_token = self.consume();
-        if !matches!(if_token.token_type, TokenType::If) {
-            return Err(ParseError::unexpected_token("'if'", &if_token));
-        }
+    pub(crate) fn parse_if_statement(&mut self) -> Result<IfStatement<'arena>, ParseError> {
+        let if_token = self.expect(TokenType::If, "'if'")?;
 
-        let condition = self.parse_expression()?.clone();
+        let condition = self.parse_condition()?;
         let then_block = self.parse_block()?;
 
         let else_block = if matches!(self.peek().token_type, TokenType::Else) {
-            Some(self.parse_block()?)
+            self.consume(); // consume 'else'
+            if matches!(self.peek().token_type, TokenType::If) {
+                Some(self.parse_else_if(then_block.scope_depth)?)
+            } else {
+                Some(self.parse_block()?)
+            }
         } else {
             None
         };
@@ -658,13 +771,26 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         })
     }
 
-    fn parse_while_statement(&mut self) -> Result<WhileStatement, ParseError> {
-        let while_token = self.consume();
-        if !matches!(while_token.token_type, TokenType::While) {
-            return Err(ParseError::unexpected_token("'while'", &while_token));
-        }
+    /// Parse `else if ...` as an else block holding the nested `if`
+    fn parse_else_if(&mut self, scope_depth: usize) -> Result<&'arena Block<'arena>, ParseError> {
+        let nested = self.parse_if_statement()?;
+        let span = nested.span.clone().combine(nested.then_block.span.clone());
+        Ok(self.arena.alloc(Block {
+            statements: self.arena.alloc_vec(vec![Statement::If(nested)]),
+            is_braced: true,
+            scope_depth,
+            span,
+            is_unsafe: false,
+            is_async: false,
+            is_try: false,
+            label: None,
+        }))
+    }
+
+    pub(crate) fn parse_while_statement(&mut self) -> Result<WhileStatement<'arena>, ParseError> {
+        let while_token = self.expect(TokenType::While, "'while'")?;
 
-        let condition = self.parse_expression()?.clone();
+        let condition = self.parse_condition()?;
         let body = self.parse_block()?;
 
         Ok(WhileStatement {
@@ -674,24 +800,12 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         })
     }
 
-    fn parse_for_statement(&mut self) -> Result<ForStatement, ParseError> {
-        let for_token = self.consume();
-        if !matches!(for_token.token_type, TokenType::For) {
-            return Err(ParseError::unexpected_token("'for'", &for_token));
-        }
-
-        let var_token = self.consume();
-        let variable = match var_token.token_type {
-            TokenType::Identifier(name) => name,
-            _ => return Err(ParseError::unexpected_token("variable name", &var_token)),
-        };
+    pub(crate) fn parse_for_statement(&mut self) -> Result<ForStatement<'arena>, ParseError> {
+        let for_token = self.expect(TokenType::For, "'for'")?;
+        let (variable, _) = self.expect_identifier("variable name")?;
+        self.expect(TokenType::In, "'in'")?;
 
-        let in_token = self.consume();
-        if !matches!(in_token.token_type, TokenType::In) {
-            return Err(ParseError::unexpected_token("'in'", &in_token));
-        }
-
-        let iterable = self.parse_expression()?.clone();
+        let iterable = self.parse_condition()?;
         let body = self.parse_block()?;
 
         Ok(ForStatement {
@@ -702,20 +816,18 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         })
     }
 
-    fn parse_return_statement(&mut self) -> Result<ReturnStatement, ParseError> {
-        let return_token = self.consume();
-        if !matches!(return_token.token_type, TokenType::Return) {
-            return Err(ParseError::unexpected_token("'return'", &return_token));
-        }
+    pub(crate) fn parse_return_statement(&mut self) -> Result<ReturnStatement<'arena>, ParseError> {
+        let return_token = self.expect(TokenType::Return, "'return'")?;
 
         let value = if matches!(
             self.peek().token_type,
-            TokenType::Semicolon | TokenType::Newline | TokenType::Eof
+            TokenType::Semicolon | TokenType::Newline | TokenType::Eof | TokenType::RightBrace
         ) {
             None
         } else {
-            Some(self.parse_expression()?.clone())
+            Some(self.parse_expression()?)
         };
+        self.skip_semicolon();
 
         Ok(ReturnStatement {
             value,
@@ -723,67 +835,49 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         })
     }
 
-    fn parse_break_statement(&mut self) -> Result<BreakStatement, ParseError> {
-        let break_token = self.consume();
-        if !matches!(break_token.token_type, TokenType::Break) {
-            return Err(ParseError::unexpected_token("'break'", &break_token));
-        }
+    pub(crate) fn parse_break_statement(&mut self) -> Result<BreakStatement, ParseError> {
+        let break_token = self.expect(TokenType::Break, "'break'")?;
+        self.skip_semicolon();
 
         Ok(BreakStatement {
             span: break_token.span,
         })
     }
 
-    fn parse_continue_statement(&mut self) -> Result<ContinueStatement, ParseError> {
-        let continue_token = self.consume();
-        if !matches!(continue_token.token_type, TokenType::Continue) {
-            return Err(ParseError::unexpected_token("'continue'", &continue_token));
-        }
+    pub(crate) fn parse_continue_statement(&mut self) -> Result<ContinueStatement, ParseError> {
+        let continue_token = self.expect(TokenType::Continue, "'continue'")?;
+        self.skip_semicolon();
 
         Ok(ContinueStatement {
             span: continue_token.span,
         })
     }
 
-    fn parse_block(&mut self) -> Result<Block, ParseError> {
-        let open_brace = self.consume();
-        if !matches!(open_brace.token_type, TokenType::LeftBrace) {
-            return Err(ParseError::unexpected_token("'{'", &open_brace));
-        }
-
-        let mut statements = Vec::new();
-        self.skip_newlines();
-
-        while !matches!(
-            self.peek().token_type,
-            TokenType::RightBrace | TokenType::Eof
-        ) {
-            let stmt = self.parse_statement()?;
-            statements.push(stmt.clone());
-            self.skip_newlines();
-        }
-
-        let close_brace = self.consume();
-        if !matches!(close_brace.token_type, TokenType::RightBrace) {
-            return Err(ParseError::unexpected_token("'}'", &close_brace));
-        }
-
-        let start_span = open_brace.span;
-        let end_span = close_brace.span;
-
-        Ok(Block {
-            statements,
-            is_braced: true,
-            span: start_span.combine(end_span),
-            scope_depth: 0,
-            is_unsafe: false,
-            is_async: false,
-            is_try: false,
-            label: None,
-        })
-    }
-
-    fn parse_where_clause(&mut self) -> Result<crate::ast::WhereClause, ParseError> {
+    /// Attach a `where` clause following an item's signature to its generics
+    fn parse_optional_where_clause(
+        &mut self,
+        generics: Option<GenericParams<'arena>>,
+    ) -> Result<Option<GenericParams<'arena>>, ParseError> {
+        if !matches!(self.peek().token_type, TokenType::Where) {
+            return Ok(generics);
+        }
+
+        let where_clause = self.parse_where_clause()?;
+        Ok(Some(match generics {
+            Some(generics) => GenericParams {
+                where_clause: Some(where_clause),
+                ..generics
+            },
+            // Create new generics with just the where clause
+            None => GenericParams {
+                params: &[],
+                span: where_clause.span.clone(),
+                where_clause: Some(where_clause),
+            },
+        }))
+    }
+
+    fn parse_where_clause(&mut self) -> Result<WhereClause<'arena>, ParseError> {
         let start_span = self.consume().span; // consume 'where'
         let mut constraints = Vec::new();
 
@@ -808,41 +902,31 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
             start_span.clone()
         };
 
-        Ok(crate::ast::WhereClause {
-            constraints,
+        Ok(WhereClause {
+            constraints: self.arena.alloc_vec(constraints),
             span: start_span.combine(end_span),
         })
     }
 
-    fn parse_where_constraint(&mut self) -> Result<crate::ast::WhereConstraint, ParseError> {
-        if let TokenType::Identifier(type_name) = &self.peek().token_type {
-            let type_name = type_name.clone();
-            let start_span = self.consume().span;
-
-            if !matches!(self.peek().token_type, TokenType::Colon) {
-                return Err(ParseError::unexpected_token(":", &self.peek()));
-            }
+    fn parse_where_constraint(&mut self) -> Result<WhereConstraint<'arena>, ParseError> {
+        let (type_name, start_span) = self.expect_identifier("type name")?;
+        self.expect(TokenType::Colon, ":")?;
+        let bounds = self.parse_type_bounds()?;
 
-            self.consume(); // consume ':'
-            let bounds = self.parse_type_bounds()?;
-
-            let end_span = if let Some(last_bound) = bounds.last() {
-                last_bound.span.clone()
-            } else {
-                start_span.clone()
-            };
-
-            Ok(crate::ast::WhereConstraint {
-                type_name,
-                bounds,
-                span: start_span.combine(end_span),
-            })
+        let end_span = if let Some(last_bound) = bounds.last() {
+            last_bound.span.clone()
         } else {
-            Err(ParseError::unexpected_token("type name", &self.peek()))
-        }
+            start_span.clone()
+        };
+
+        Ok(WhereConstraint {
+            type_name,
+            bounds: self.arena.alloc_vec(bounds),
+            span: start_span.combine(end_span),
+        })
     }
 
-    fn parse_type_bounds(&mut self) -> Result<Vec<crate::ast::TypeBound>, ParseError> {
+    fn parse_type_bounds(&mut self) -> Result<Vec<TypeBound>, ParseError> {
         let mut bounds = Vec::new();
 
         // Parse first bound
@@ -857,15 +941,9 @@ impl<'arena, T: TokenStream> StatementParser<'arena, T> {
         Ok(bounds)
     }
 
-    fn parse_type_bound(&mut self) -> Result<crate::ast::TypeBound, ParseError> {
-        if let TokenType::Identifier(trait_name) = &self.peek().token_type {
-            let trait_name = trait_name.clone();
-            let span = self.consume().span;
-
-            Ok(crate::ast::TypeBound { trait_name, span })
-        } else {
-            Err(ParseError::unexpected_token("trait name", &self.peek()))
-        }
+    fn parse_type_bound(&mut self) -> Result<TypeBound, ParseError> {
+        let (trait_name, span) = self.expect_identifier("trait name")?;
+        Ok(TypeBound { trait_name, span })
     }
 
     fn is_where_clause_end(&self) -> bool {
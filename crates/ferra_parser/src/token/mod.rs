@@ -4,8 +4,10 @@
 //! abstracting over token types and providing a stream-like interface
 //! for consuming tokens during parsing.
 
+pub mod newline;
 pub mod stream;
 pub mod types;
 
+pub use newline::*;
 pub use stream::*;
 pub use types::*;
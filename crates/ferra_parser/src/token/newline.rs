@@ -0,0 +1,315 @@
+//! Newline filtering between the lexer and the parser
+//!
+//! The lexer emits a `Newline` for every line break and `Indent`/`Dedent`
+//! whenever the indentation changes. Following SYNTAX_GRAMMAR §1.1 rule 3,
+//! most of those do not end a statement:
+//!
+//! - inside `(...)` and `[...]` every line break is insignificant
+//! - inside `{...}` indentation is insignificant; a line break still ends a
+//!   statement unless the line is incomplete
+//! - a line ending in an opening delimiter, `,`, `.`, `::`, an operator,
+//!   `->`, `=>` or a keyword such as `let` continues on the next line
+//! - a line starting with `.` (method chains) or a binary operator such as
+//!   `+` or `&&` continues the previous line
+//!
+//! Suppressed `Indent` tokens take their matching `Dedent` with them, so the
+//! parser only sees the indentation of real indented blocks; lexer errors
+//! about indentation are dropped wherever indentation is. Runs of blank lines
+//! collapse into a single `Newline`, and no `Newline` is kept at the start of
+//! the input, after `;` or before a closing delimiter.
+
+use super::{Token, TokenType};
+
+/// Drop the `Newline`, `Indent` and `Dedent` tokens that do not end a
+/// statement or delimit an indented block
+pub fn filter_newlines(tokens: Vec<Token>) -> Vec<Token> {
+    let mut filter = NewlineFilter::default();
+    let mut output = Vec::with_capacity(tokens.len());
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        if !is_layout(&token.token_type) {
+            filter.track_delimiter(&token.token_type);
+            output.push(token);
+            continue;
+        }
+
+        // Gather the whole run of layout tokens up to the next real token
+        let mut run = vec![token];
+        while let Some(next) = tokens.next_if(|next| is_layout(&next.token_type)) {
+            run.push(next);
+        }
+        let next = tokens.peek().map(|token| &token.token_type);
+        let previous = output.last().map(|token: &Token| token.token_type.clone());
+        filter.filter_run(run, previous.as_ref(), next, &mut output);
+    }
+
+    output
+}
+
+/// Whether a line ending in this token continues on the next line
+pub fn continues_after(token_type: &TokenType) -> bool {
+    matches!(
+        token_type,
+        TokenType::LeftParen
+            | TokenType::LeftBracket
+            | TokenType::LeftBrace
+            | TokenType::Comma
+            | TokenType::Dot
+            | TokenType::DoubleColon
+            | TokenType::Arrow
+            | TokenType::FatArrow
+            | TokenType::Plus
+            | TokenType::Minus
+            | TokenType::Star
+            | TokenType::Slash
+            | TokenType::Percent
+            | TokenType::EqualEqual
+            | TokenType::BangEqual
+            | TokenType::Less
+            | TokenType::LessEqual
+            | TokenType::GreaterEqual
+            | TokenType::AmpAmp
+            | TokenType::PipePipe
+            | TokenType::QuestionQuestion
+            | TokenType::Ampersand
+            | TokenType::Pipe
+            | TokenType::Bang
+            | TokenType::Equal
+            | TokenType::PlusEqual
+            | TokenType::MinusEqual
+            | TokenType::StarEqual
+            | TokenType::SlashEqual
+            | TokenType::Let
+            | TokenType::Var
+            | TokenType::Fn
+            | TokenType::Async
+            | TokenType::Data
+            | TokenType::Match
+    )
+}
+
+/// Whether a line starting with this token continues the previous line
+///
+/// Operators that can also start an expression (`-`, `*`, `&`, `|`, `..`)
+/// are left out; `+` is kept since SYNTAX_GRAMMAR §1.1 uses it this way.
+pub fn continues_before(token_type: &TokenType) -> bool {
+    matches!(
+        token_type,
+        TokenType::Dot
+            | TokenType::Question
+            | TokenType::Arrow
+            | TokenType::FatArrow
+            | TokenType::Plus
+            | TokenType::Slash
+            | TokenType::Percent
+            | TokenType::EqualEqual
+            | TokenType::BangEqual
+            | TokenType::Less
+            | TokenType::Greater
+            | TokenType::LessEqual
+            | TokenType::GreaterEqual
+            | TokenType::AmpAmp
+            | TokenType::PipePipe
+            | TokenType::QuestionQuestion
+            | TokenType::Equal
+            | TokenType::PlusEqual
+            | TokenType::MinusEqual
+            | TokenType::StarEqual
+            | TokenType::SlashEqual
+    )
+}
+
+fn is_layout(token_type: &TokenType) -> bool {
+    match token_type {
+        TokenType::Newline | TokenType::Indent | TokenType::Dedent => true,
+        TokenType::Error(diagnostic) => diagnostic.layout,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Delimiter {
+    Paren,
+    Bracket,
+    Brace,
+}
+
+#[derive(Debug, Default)]
+struct NewlineFilter {
+    /// Open delimiters, innermost last
+    delimiters: Vec<Delimiter>,
+    /// One entry per open `Indent`: whether it was passed to the parser
+    indents: Vec<bool>,
+}
+
+impl NewlineFilter {
+    fn track_delimiter(&mut self, token_type: &TokenType) {
+        match token_type {
+            TokenType::LeftParen => self.delimiters.push(Delimiter::Paren),
+            TokenType::LeftBracket => self.delimiters.push(Delimiter::Bracket),
+            TokenType::LeftBrace => self.delimiters.push(Delimiter::Brace),
+            TokenType::RightParen | TokenType::RightBracket | TokenType::RightBrace => {
+                self.delimiters.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Filter one run of layout tokens between `previous` and `next`
+    fn filter_run(
+        &mut self,
+        run: Vec<Token>,
+        previous: Option<&TokenType>,
+        next: Option<&TokenType>,
+        output: &mut Vec<Token>,
+    ) {
+        let in_group = matches!(
+            self.delimiters.last(),
+            Some(Delimiter::Paren | Delimiter::Bracket)
+        );
+        let continued = in_group
+            || previous.is_none_or(|previous| {
+                continues_after(previous) || matches!(previous, TokenType::Semicolon)
+            })
+            || next.is_none_or(|next| {
+                continues_before(next)
+                    || matches!(
+                        next,
+                        TokenType::RightParen
+                            | TokenType::RightBracket
+                            | TokenType::RightBrace
+                            | TokenType::Eof
+                    )
+            });
+        // Indentation only delimits blocks outside of any brackets
+        let indentation_counts = self.delimiters.is_empty() && !continued;
+
+        let mut newline = None;
+        let mut layout = Vec::new();
+        for token in run {
+            match token.token_type {
+                TokenType::Newline => {
+                    newline.get_or_insert(token);
+                }
+                TokenType::Indent => {
+                    self.indents.push(indentation_counts);
+                    if indentation_counts {
+                        layout.push(token);
+                    }
+                }
+                TokenType::Dedent => {
+                    if self.indents.pop().unwrap_or(false) {
+                        layout.push(token);
+                    }
+                }
+                // Indentation errors only matter where indentation does
+                TokenType::Error(_) => {
+                    if indentation_counts {
+                        layout.push(token);
+                    }
+                }
+                _ => unreachable!("layout runs only hold layout tokens"),
+            }
+        }
+
+        // A closed indented block ends the line even if it looked incomplete
+        if !continued || !layout.is_empty() {
+            output.extend(newline);
+        }
+        output.extend(layout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(token_types: Vec<TokenType>) -> Vec<TokenType> {
+        filter_newlines(token_types.into_iter().map(Token::dummy).collect())
+            .into_iter()
+            .map(|token| token.token_type)
+            .collect()
+    }
+
+    fn ident(name: &str) -> TokenType {
+        TokenType::Identifier(name.into())
+    }
+
+    #[test]
+    fn test_newline_inside_parens_is_dropped() {
+        let tokens = filter(vec![
+            ident("f"),
+            TokenType::LeftParen,
+            TokenType::Newline,
+            TokenType::Indent,
+            ident("a"),
+            TokenType::Comma,
+            TokenType::Newline,
+            ident("b"),
+            TokenType::Newline,
+            TokenType::Dedent,
+            TokenType::RightParen,
+            TokenType::Newline,
+            TokenType::Eof,
+        ]);
+        assert_eq!(
+            tokens,
+            vec![
+                ident("f"),
+                TokenType::LeftParen,
+                ident("a"),
+                TokenType::Comma,
+                ident("b"),
+                TokenType::RightParen,
+                TokenType::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_statement_newline_is_kept() {
+        let tokens = filter(vec![
+            ident("a"),
+            TokenType::Newline,
+            TokenType::Newline,
+            ident("b"),
+            TokenType::Eof,
+        ]);
+        assert_eq!(
+            tokens,
+            vec![ident("a"), TokenType::Newline, ident("b"), TokenType::Eof]
+        );
+    }
+
+    #[test]
+    fn test_indented_block_is_kept() {
+        let tokens = filter(vec![
+            TokenType::If,
+            ident("x"),
+            TokenType::Colon,
+            TokenType::Newline,
+            TokenType::Indent,
+            ident("y"),
+            TokenType::Newline,
+            TokenType::Dedent,
+            ident("z"),
+            TokenType::Eof,
+        ]);
+        assert_eq!(
+            tokens,
+            vec![
+                TokenType::If,
+                ident("x"),
+                TokenType::Colon,
+                TokenType::Newline,
+                TokenType::Indent,
+                ident("y"),
+                TokenType::Newline,
+                TokenType::Dedent,
+                ident("z"),
+                TokenType::Eof,
+            ]
+        );
+    }
+}
@@ -21,6 +21,24 @@ pub trait TokenStream {
 
     /// Get the current position in the stream
     fn position(&self) -> usize;
+
+    /// Replace the current token with `first`, one character wide, followed
+    /// by `rest` covering the remaining characters
+    fn split_current(&mut self, first: TokenType, rest: TokenType);
+}
+
+/// Whether the current token closes a generic list, splitting a `>` off the
+/// front of `>>`, `>>=` and `>=` so `Vec<Vec<T>>` closes both lists
+pub fn at_closing_angle<T: TokenStream + ?Sized>(tokens: &mut T) -> bool {
+    let rest = match tokens.peek().token_type {
+        TokenType::Greater => return true,
+        TokenType::ShiftRight => TokenType::Greater,
+        TokenType::ShiftRightEqual => TokenType::GreaterEqual,
+        TokenType::GreaterEqual => TokenType::Equal,
+        _ => return false,
+    };
+    tokens.split_current(TokenType::Greater, rest);
+    true
 }
 
 /// A simple vector-based token stream for testing and development
@@ -74,6 +92,22 @@ impl TokenStream for VecTokenStream {
     fn position(&self) -> usize {
         self.current
     }
+
+    fn split_current(&mut self, first: TokenType, rest: TokenType) {
+        if self.is_at_end() {
+            return;
+        }
+        let token = &mut self.tokens[self.current];
+        let mut rest_span = token.span.clone();
+        if rest_span.end > rest_span.start {
+            rest_span.start += 1;
+            rest_span.column += 1;
+            token.span.end = rest_span.start;
+        }
+        token.token_type = first;
+        self.tokens
+            .insert(self.current + 1, Token::new(rest, rest_span));
+    }
 }
 
 impl Default for VecTokenStream {
@@ -82,7 +116,7 @@ impl Default for VecTokenStream {
     }
 }
 
-impl<T: TokenStream> TokenStream for &mut T {
+impl<T: TokenStream + ?Sized> TokenStream for &mut T {
     fn peek(&self) -> &Token {
         (**self).peek()
     }
@@ -102,17 +136,22 @@ impl<T: TokenStream> TokenStream for &mut T {
     fn position(&self) -> usize {
         (**self).position()
     }
+
+    fn split_current(&mut self, first: TokenType, rest: TokenType) {
+        (**self).split_current(first, rest)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::token::Span;
 
     #[test]
     fn test_vec_token_stream_basic() {
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
         ];
@@ -125,10 +164,7 @@ mod tests {
         assert_eq!(token.token_type, TokenType::Let);
         assert_eq!(stream.position(), 1);
 
-        assert_eq!(
-            stream.peek().token_type,
-            TokenType::Identifier("x".to_string())
-        );
+        assert_eq!(stream.peek().token_type, TokenType::Identifier("x".into()));
     }
 
     #[test]
@@ -149,7 +185,7 @@ mod tests {
     fn test_peek_ahead() {
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
         ];
         let stream = VecTokenStream::from_token_types(tokens);
@@ -157,10 +193,31 @@ mod tests {
         assert_eq!(stream.peek_ahead(0).unwrap().token_type, TokenType::Let);
         assert_eq!(
             stream.peek_ahead(1).unwrap().token_type,
-            TokenType::Identifier("x".to_string())
+            TokenType::Identifier("x".into())
         );
         assert_eq!(stream.peek_ahead(2).unwrap().token_type, TokenType::Equal);
         assert_eq!(stream.peek_ahead(3).unwrap().token_type, TokenType::Eof);
         assert!(stream.peek_ahead(4).is_none());
     }
+
+    #[test]
+    fn test_closing_angle_splits_shift() {
+        let tokens = vec![Token::new(
+            TokenType::ShiftRightEqual,
+            Span::new(4, 7, 1, 5),
+        )];
+        let mut stream = VecTokenStream::new(tokens);
+
+        assert!(at_closing_angle(&mut stream));
+        let close = stream.consume();
+        assert_eq!(close.token_type, TokenType::Greater);
+        assert_eq!((close.span.start, close.span.end), (4, 5));
+        let rest = stream.consume();
+        assert_eq!(rest.token_type, TokenType::GreaterEqual);
+        assert_eq!(
+            (rest.span.start, rest.span.end, rest.span.column),
+            (5, 7, 6)
+        );
+        assert!(stream.is_at_end());
+    }
 }
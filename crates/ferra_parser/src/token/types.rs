@@ -3,6 +3,8 @@
 //! These types will eventually interface with the lexer output.
 //! For now, they provide a mock interface for development.
 
+use std::sync::Arc;
+
 /// Source location information for tokens
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Span {
@@ -10,6 +12,24 @@ pub struct Span {
     pub end: usize,
     pub line: usize,
     pub column: usize,
+    /// Set when the span was produced by a macro expansion
+    pub expansion: Option<Arc<ExpansionInfo>>,
+}
+
+/// Record of the macro expansion a span came from
+///
+/// The call site may itself come from an expansion, so following
+/// `call_site.expansion` yields the full expansion backtrace.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExpansionInfo {
+    /// Name of the expanded macro
+    pub macro_name: Symbol,
+    /// Span of the invocation that was expanded
+    pub call_site: Span,
+    /// Span of the macro definition
+    pub def_site: Span,
+    /// Hygiene mark unique to this expansion
+    pub mark: u32,
 }
 
 impl Span {
@@ -19,6 +39,7 @@ impl Span {
             end,
             line,
             column,
+            expansion: None,
         }
     }
 
@@ -33,10 +54,50 @@ impl Span {
             end: self.end.max(other.end),
             line: self.line,     // Use the start line
             column: self.column, // Use the start column
+            expansion: self.expansion.clone().or(other.expansion),
+        }
+    }
+
+    /// Mark this span as produced by a macro expansion
+    pub fn with_expansion(mut self, expansion: Arc<ExpansionInfo>) -> Self {
+        self.expansion = Some(expansion);
+        self
+    }
+
+    /// Check whether this span was produced by a macro expansion
+    pub fn from_expansion(&self) -> bool {
+        self.expansion.is_some()
+    }
+
+    /// Expansions this span came through, innermost first
+    pub fn expansion_backtrace(&self) -> Vec<&ExpansionInfo> {
+        let mut backtrace = Vec::new();
+        let mut current = self.expansion.as_deref();
+        while let Some(info) = current {
+            backtrace.push(info);
+            current = info.call_site.expansion.as_deref();
         }
+        backtrace
     }
 }
 
+/// Numeric type named by a literal suffix (`255u8`, `1.5f32`), shared with the lexer
+pub use ferra_lexer::NumericSuffix;
+
+/// Interned identifier produced by the lexer
+pub use ferra_lexer::Symbol;
+
+/// Integer literal too wide for `i64`, kept as its digits and radix
+pub use ferra_lexer::WideInteger;
+
+/// Piece of an interpolated string token
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringSegment {
+    Text(String),
+    /// Tokens of an embedded expression and the source span between its braces
+    Expression(Vec<Token>, Span),
+}
+
 /// Token types as they would come from the lexer
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenType {
@@ -45,9 +106,16 @@ pub enum TokenType {
     IntegerLiteral(i64),
     FloatLiteral(f64),
     BooleanLiteral(bool),
+    CharLiteral(char),
+    ByteLiteral(u8),
+    ByteStringLiteral(Vec<u8>),
+    WideIntegerLiteral(WideInteger), // Integers that do not fit i64
+    TypedIntegerLiteral(u128, NumericSuffix), // 255u8, 1_000i64
+    TypedFloatLiteral(f64, NumericSuffix), // 1.5f32
+    InterpolatedStringLiteral(Vec<StringSegment>), // f"Hello, {name}!"
 
     // Identifiers
-    Identifier(String),
+    Identifier(Symbol),
 
     // Keywords
     Let,
@@ -69,6 +137,7 @@ pub enum TokenType {
     Pub,
     Unsafe,
     Where, // for generic where clauses
+    Macro, // for declarative macro items
 
     // Operators
     Plus,
@@ -83,8 +152,12 @@ pub enum TokenType {
     LessEqual,
     Greater,
     GreaterEqual,
-    AmpAmp,   // && (also `and` keyword)
-    PipePipe, // || (also `or` keyword)
+    ShiftLeft,       // <<
+    ShiftRight,      // >> (also two closing `>` of nested generics)
+    ShiftLeftEqual,  // <<=
+    ShiftRightEqual, // >>=
+    AmpAmp,          // && (also `and` keyword)
+    PipePipe,        // || (also `or` keyword)
     Bang,
     Question,
     QuestionQuestion, // ??
@@ -116,26 +189,55 @@ pub enum TokenType {
     At,          // @ (for alternative attribute syntax like @inline and binding patterns)
     Apostrophe,  // ' (for lifetimes like 'a, 'static)
     Ampersand,   // & (for references)
+    Dollar,      // $ (for macro metavariables like $x:expr)
 
     // Special tokens for indentation
     Indent,
     Dedent,
     Newline,
 
+    /// Placeholder for input the lexer rejected, carrying its diagnostic
+    Error(LexDiagnostic),
+
     // End of file
     Eof,
 }
 
+/// Diagnostic the lexer reported where a token should have been
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexDiagnostic {
+    pub message: String,
+    /// The lexer's error code, e.g. `E011`
+    pub code: &'static str,
+    pub help: Option<String>,
+    /// About indentation, which only matters where it delimits blocks
+    pub layout: bool,
+}
+
 /// A token with its type and location information
 #[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     pub token_type: TokenType,
     pub span: Span,
+    /// Source spelling of a string literal whose value differs from it, e.g.
+    /// because of escapes, so positions inside the value can be mapped back
+    /// to the source
+    pub spelling: Option<Arc<str>>,
 }
 
 impl Token {
     pub fn new(token_type: TokenType, span: Span) -> Self {
-        Self { token_type, span }
+        Self {
+            token_type,
+            span,
+            spelling: None,
+        }
+    }
+
+    /// Record the source spelling of a string literal
+    pub fn with_spelling(mut self, spelling: &str) -> Self {
+        self.spelling = Some(spelling.into());
+        self
     }
 
     pub fn dummy(token_type: TokenType) -> Self {
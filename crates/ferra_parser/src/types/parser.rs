@@ -3,54 +3,123 @@
 //! Comprehensive type parsing for Ferra language Phase 2.7
 
 use crate::{
-    ast::{FunctionType, PointerType, Type},
+    ast::{Arena, FunctionType, Path, PathSegment, PointerType, Type},
     error::{ParseError, ParseResult},
-    token::{TokenStream, TokenType},
+    macro_parser::MacroParser,
+    options::ParseContext,
+    token::{at_closing_angle, Span, TokenStream, TokenType},
 };
 
 /// Parse a type expression with full Phase 2.7 support
-pub fn parse_type<T: TokenStream>(tokens: &mut T) -> ParseResult<Type> {
-    let mut parser = TypeParser::new(tokens);
+pub fn parse_type<'a, T: TokenStream>(arena: &'a Arena, tokens: &mut T) -> ParseResult<Type<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
     parser.parse_type()
 }
 
 /// Parse a simple type (identifier)
-pub fn parse_simple_type<T: TokenStream>(tokens: &mut T) -> ParseResult<Type> {
-    let mut parser = TypeParser::new(tokens);
+pub fn parse_simple_type<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Type<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
     parser.parse_simple_type()
 }
 
 /// Parse a tuple type
-pub fn parse_tuple_type<T: TokenStream>(tokens: &mut T) -> ParseResult<Type> {
-    let mut parser = TypeParser::new(tokens);
+pub fn parse_tuple_type<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Type<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
     parser.parse_tuple_type()
 }
 
 /// Parse an array type
-pub fn parse_array_type<T: TokenStream>(tokens: &mut T) -> ParseResult<Type> {
-    let mut parser = TypeParser::new(tokens);
+pub fn parse_array_type<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Type<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
     parser.parse_array_type()
 }
 
 /// Parse a function type
-pub fn parse_function_type<T: TokenStream>(tokens: &mut T) -> ParseResult<Type> {
-    let mut parser = TypeParser::new(tokens);
+pub fn parse_function_type<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Type<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
     parser.parse_function_type()
 }
 
+/// Parse a path in type position: `http::Client` or `std::collections::Map<K, V>`
+pub fn parse_type_path<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<Path<'a>> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
+    parser.parse_type_path()
+}
+
+/// Parse a generic argument list `<T, U>`, returning the arguments and the span
+/// of the closing `>`
+pub fn parse_generic_args<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+) -> ParseResult<(&'a [Type<'a>], Span)> {
+    let mut parser = TypeParser::new(arena, tokens, ParseContext::default());
+    parser.parse_generic_args()
+}
+
+/// Parse a type in the context of the parser it is part of
+pub(crate) fn parse_type_with_context<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+    context: ParseContext,
+) -> ParseResult<Type<'a>> {
+    TypeParser::new(arena, tokens, context).parse_type()
+}
+
+/// Parse a generic argument list in the context of the parser it is part of
+pub(crate) fn parse_generic_args_with_context<'a, T: TokenStream>(
+    arena: &'a Arena,
+    tokens: &mut T,
+    context: ParseContext,
+) -> ParseResult<(&'a [Type<'a>], Span)> {
+    TypeParser::new(arena, tokens, context).parse_generic_args()
+}
+
+/// Convert a parsed path into a type node
+///
+/// Plain names become `Type::Identifier`; any other path, qualified or
+/// generic, is kept whole as `Type::Path`, the one shape for such types.
+pub fn type_from_path<'a>(path: Path<'a>) -> Type<'a> {
+    match path.as_ident() {
+        Some(name) => Type::Identifier(name),
+        None => Type::Path(path),
+    }
+}
+
 /// Comprehensive type parser for Phase 2.7
-struct TypeParser<'a, T: TokenStream> {
-    tokens: &'a mut T,
+struct TypeParser<'a, 't, T: TokenStream> {
+    arena: &'a Arena,
+    tokens: &'t mut T,
+    context: ParseContext,
 }
 
-impl<'a, T: TokenStream> TypeParser<'a, T> {
-    fn new(tokens: &'a mut T) -> Self {
-        Self { tokens }
+impl<'a, 't, T: TokenStream> TypeParser<'a, 't, T> {
+    fn new(arena: &'a Arena, tokens: &'t mut T, context: ParseContext) -> Self {
+        Self {
+            arena,
+            tokens,
+            context,
+        }
     }
 
     /// Parse any type expression
-    fn parse_type(&mut self) -> ParseResult<Type> {
+    fn parse_type(&mut self) -> ParseResult<Type<'a>> {
         let current = self.tokens.peek();
+        let _nesting = self.context.enter_nesting(&current.span)?;
 
         match &current.token_type {
             // Function types: fn(T) -> T or extern "C" fn(T) -> T
@@ -66,6 +135,16 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
             // Array types: [T]
             TokenType::LeftBracket => self.parse_array_type(),
 
+            // Macro invocations in type position: Pair!(Int)
+            TokenType::Identifier(_)
+                if matches!(
+                    self.tokens.peek_ahead(1).map(|t| &t.token_type),
+                    Some(TokenType::Bang)
+                ) =>
+            {
+                self.parse_macro_type()
+            }
+
             // Simple identifier types
             TokenType::Identifier(_) => self.parse_identifier_type(),
 
@@ -73,43 +152,96 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
         }
     }
 
-    /// Parse simple identifier type or qualified identifier
-    fn parse_identifier_type(&mut self) -> ParseResult<Type> {
-        let token = self.tokens.consume();
-        match token.token_type {
-            TokenType::Identifier(name) => {
-                // Check for generic type parameters: Name<T>
-                if matches!(self.tokens.peek().token_type, TokenType::Less) {
-                    self.parse_generic_type(name)
-                } else {
-                    Ok(Type::Identifier(name))
-                }
+    /// Parse `name!(...)` as a type to be expanded later
+    fn parse_macro_type(&mut self) -> ParseResult<Type<'a>> {
+        let name_token = self.tokens.consume();
+        let TokenType::Identifier(name) = name_token.token_type else {
+            return Err(ParseError::unexpected_token("macro name", &name_token));
+        };
+        let mut invocation = MacroParser::new(self.arena, &mut *self.tokens)
+            .with_context(self.context.clone())
+            .parse_macro_invocation(name)?
+            .clone();
+        invocation.span = name_token.span.combine(invocation.span);
+        Ok(Type::Macro(invocation))
+    }
+
+    /// Parse simple identifier type, qualified identifier or generic type
+    fn parse_identifier_type(&mut self) -> ParseResult<Type<'a>> {
+        let path = self.parse_type_path()?;
+        Ok(type_from_path(path))
+    }
+
+    /// Parse a `::`-separated path where each segment may take `<...>` arguments
+    fn parse_type_path(&mut self) -> ParseResult<Path<'a>> {
+        let mut segments = Vec::new();
+
+        loop {
+            let token = self.tokens.consume();
+            let name = match token.token_type {
+                TokenType::Identifier(name) => name,
+                _ => return Err(ParseError::unexpected_token("identifier", &token)),
+            };
+
+            let mut span = token.span;
+            let generic_args = if matches!(self.tokens.peek().token_type, TokenType::Less) {
+                let (args, end_span) = self.parse_generic_args()?;
+                span = span.combine(end_span);
+                args
+            } else {
+                &[]
+            };
+
+            segments.push(PathSegment {
+                name,
+                generic_args,
+                span,
+            });
+
+            let continues = matches!(self.tokens.peek().token_type, TokenType::DoubleColon)
+                && matches!(
+                    self.tokens.peek_ahead(1).map(|t| &t.token_type),
+                    Some(TokenType::Identifier(_))
+                );
+            if !continues {
+                break;
             }
-            _ => Err(ParseError::unexpected_token("identifier", &token)),
+            self.tokens.consume(); // consume '::'
         }
+
+        let span = segments[0]
+            .span
+            .combine(segments[segments.len() - 1].span.clone());
+        Ok(Path {
+            segments: self.arena.alloc_vec(segments),
+            span,
+        })
     }
 
-    /// Parse generic type: Name<T, U, ...>
-    fn parse_generic_type(&mut self, base_name: String) -> ParseResult<Type> {
-        // For now, we'll represent generic types as qualified identifiers
-        // In future phases, we'll have a proper Generic variant
-        let _open_bracket = self.tokens.consume(); // consume '<'
+    /// Parse generic arguments: <T, U, ...>
+    fn parse_generic_args(&mut self) -> ParseResult<(&'a [Type<'a>], Span)> {
+        let open_bracket = self.tokens.consume();
+        if !matches!(open_bracket.token_type, TokenType::Less) {
+            return Err(ParseError::unexpected_token("'<'", &open_bracket));
+        }
 
         let mut type_args = Vec::new();
 
         loop {
-            if matches!(self.tokens.peek().token_type, TokenType::Greater) {
+            if at_closing_angle(self.tokens) {
                 break;
             }
 
             let type_arg = self.parse_type()?;
             type_args.push(type_arg);
 
+            if at_closing_angle(self.tokens) {
+                break;
+            }
             match self.tokens.peek().token_type {
                 TokenType::Comma => {
                     self.tokens.consume(); // consume ','
                 }
-                TokenType::Greater => break,
                 _ => {
                     return Err(ParseError::unexpected_token(
                         "',' or '>'",
@@ -119,19 +251,16 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
             }
         }
 
-        let _close_bracket = self.tokens.consume(); // consume '>'
+        let close_bracket = self.tokens.consume(); // consume '>'
 
-        // For now, represent as a structured identifier
-        // Future phases will have proper generic type support
-        Ok(Type::Identifier(format!(
-            "{}<{}>",
-            base_name,
-            type_args.len()
-        )))
+        Ok((
+            self.arena.alloc_vec(type_args),
+            open_bracket.span.combine(close_bracket.span),
+        ))
     }
 
     /// Parse simple type (just identifier)
-    fn parse_simple_type(&mut self) -> ParseResult<Type> {
+    fn parse_simple_type(&mut self) -> ParseResult<Type<'a>> {
         let token = self.tokens.consume();
         match token.token_type {
             TokenType::Identifier(name) => Ok(Type::Identifier(name)),
@@ -140,7 +269,7 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
     }
 
     /// Parse tuple type: (T, T, ...)
-    fn parse_tuple_type(&mut self) -> ParseResult<Type> {
+    fn parse_tuple_type(&mut self) -> ParseResult<Type<'a>> {
         let open_paren = self.tokens.consume();
         if !matches!(open_paren.token_type, TokenType::LeftParen) {
             return Err(ParseError::unexpected_token("'('", &open_paren));
@@ -151,7 +280,7 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
         // Handle empty tuple: ()
         if matches!(self.tokens.peek().token_type, TokenType::RightParen) {
             self.tokens.consume(); // consume ')'
-            return Ok(Type::Tuple(types));
+            return Ok(Type::Tuple(&[]));
         }
 
         // Parse type list
@@ -182,11 +311,11 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
             return Err(ParseError::unexpected_token("')'", &close_paren));
         }
 
-        Ok(Type::Tuple(types))
+        Ok(Type::Tuple(self.arena.alloc_vec(types)))
     }
 
     /// Parse array type: [T]
-    fn parse_array_type(&mut self) -> ParseResult<Type> {
+    fn parse_array_type(&mut self) -> ParseResult<Type<'a>> {
         let open_bracket = self.tokens.consume();
         if !matches!(open_bracket.token_type, TokenType::LeftBracket) {
             return Err(ParseError::unexpected_token("'['", &open_bracket));
@@ -199,11 +328,11 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
             return Err(ParseError::unexpected_token("']'", &close_bracket));
         }
 
-        Ok(Type::Array(Box::new(element_type)))
+        Ok(Type::Array(self.arena.alloc(element_type)))
     }
 
     /// Parse function type: fn(T, U) -> V
-    fn parse_function_type(&mut self) -> ParseResult<Type> {
+    fn parse_function_type(&mut self) -> ParseResult<Type<'a>> {
         let fn_token = self.tokens.consume();
         if !matches!(fn_token.token_type, TokenType::Fn) {
             return Err(ParseError::unexpected_token("'fn'", &fn_token));
@@ -250,14 +379,14 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
         // Parse return type (if present)
         let return_type = if matches!(self.tokens.peek().token_type, TokenType::Arrow) {
             self.tokens.consume(); // consume '->'
-            Box::new(self.parse_type()?)
+            self.arena.alloc(self.parse_type()?)
         } else {
             // Default to unit type if no return type specified
-            Box::new(Type::Tuple(Vec::new()))
+            self.arena.alloc(Type::Tuple(&[]))
         };
 
         Ok(Type::Function(FunctionType {
-            parameters,
+            parameters: self.arena.alloc_vec(parameters),
             return_type,
             is_extern: false,
             abi: None,
@@ -265,7 +394,7 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
     }
 
     /// Parse extern function type: extern "C" fn(T) -> U
-    fn parse_extern_function_type(&mut self) -> ParseResult<Type> {
+    fn parse_extern_function_type(&mut self) -> ParseResult<Type<'a>> {
         let extern_token = self.tokens.consume();
         if !matches!(extern_token.token_type, TokenType::Extern) {
             return Err(ParseError::unexpected_token("'extern'", &extern_token));
@@ -327,14 +456,14 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
         // Parse return type (if present)
         let return_type = if matches!(self.tokens.peek().token_type, TokenType::Arrow) {
             self.tokens.consume(); // consume '->'
-            Box::new(self.parse_type()?)
+            self.arena.alloc(self.parse_type()?)
         } else {
             // Default to unit type if no return type specified
-            Box::new(Type::Tuple(Vec::new()))
+            self.arena.alloc(Type::Tuple(&[]))
         };
 
         Ok(Type::Function(FunctionType {
-            parameters,
+            parameters: self.arena.alloc_vec(parameters),
             return_type,
             is_extern: true,
             abi,
@@ -342,7 +471,7 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
     }
 
     /// Parse pointer type: *Type (simplified without const/mut for now)
-    fn parse_pointer_type(&mut self) -> ParseResult<Type> {
+    fn parse_pointer_type(&mut self) -> ParseResult<Type<'a>> {
         let star_token = self.tokens.consume();
         if !matches!(star_token.token_type, TokenType::Star) {
             return Err(ParseError::unexpected_token("'*'", &star_token));
@@ -352,7 +481,7 @@ impl<'a, T: TokenStream> TypeParser<'a, T> {
 
         // For now, default to mutable pointers since we don't have const/mut tokens
         Ok(Type::Pointer(PointerType {
-            target: Box::new(target_type),
+            target: self.arena.alloc(target_type),
             is_mutable: true, // Default to mutable for now
         }))
     }
@@ -369,9 +498,10 @@ mod tests {
 
     #[test]
     fn test_simple_identifier_type() {
-        let mut tokens = create_token_stream(vec![TokenType::Identifier("int".to_string())]);
+        let arena = Arena::new();
+        let mut tokens = create_token_stream(vec![TokenType::Identifier("int".into())]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Identifier(name) => assert_eq!(name, "int"),
             _ => panic!("Expected identifier type"),
@@ -380,15 +510,16 @@ mod tests {
 
     #[test]
     fn test_tuple_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::LeftParen,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::Comma,
-            TokenType::Identifier("string".to_string()),
+            TokenType::Identifier("string".into()),
             TokenType::RightParen,
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Tuple(types) => {
                 assert_eq!(types.len(), 2);
@@ -406,9 +537,10 @@ mod tests {
 
     #[test]
     fn test_empty_tuple_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![TokenType::LeftParen, TokenType::RightParen]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Tuple(types) => assert_eq!(types.len(), 0),
             _ => panic!("Expected empty tuple type"),
@@ -417,15 +549,16 @@ mod tests {
 
     #[test]
     fn test_array_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::LeftBracket,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::RightBracket,
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
-            Type::Array(element_type) => match element_type.as_ref() {
+            Type::Array(element_type) => match element_type {
                 Type::Identifier(name) => assert_eq!(name, "int"),
                 _ => panic!("Expected identifier type in array"),
             },
@@ -435,25 +568,26 @@ mod tests {
 
     #[test]
     fn test_function_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Fn,
             TokenType::LeftParen,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::Comma,
-            TokenType::Identifier("string".to_string()),
+            TokenType::Identifier("string".into()),
             TokenType::RightParen,
             TokenType::Arrow,
-            TokenType::Identifier("bool".to_string()),
+            TokenType::Identifier("bool".into()),
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Function(func_type) => {
                 assert_eq!(func_type.parameters.len(), 2);
                 assert!(!func_type.is_extern);
                 assert!(func_type.abi.is_none());
 
-                match func_type.return_type.as_ref() {
+                match func_type.return_type {
                     Type::Identifier(name) => assert_eq!(name, "bool"),
                     _ => panic!("Expected bool return type"),
                 }
@@ -464,18 +598,19 @@ mod tests {
 
     #[test]
     fn test_extern_function_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Extern,
             TokenType::StringLiteral("C".to_string()),
             TokenType::Fn,
             TokenType::LeftParen,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::RightParen,
             TokenType::Arrow,
-            TokenType::Identifier("void".to_string()),
+            TokenType::Identifier("void".into()),
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Function(func_type) => {
                 assert_eq!(func_type.parameters.len(), 1);
@@ -488,16 +623,15 @@ mod tests {
 
     #[test]
     fn test_pointer_type() {
-        let mut tokens = create_token_stream(vec![
-            TokenType::Star,
-            TokenType::Identifier("int".to_string()),
-        ]);
+        let arena = Arena::new();
+        let mut tokens =
+            create_token_stream(vec![TokenType::Star, TokenType::Identifier("int".into())]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Pointer(ptr_type) => {
                 assert!(ptr_type.is_mutable); // Default to mutable for now
-                match ptr_type.target.as_ref() {
+                match ptr_type.target {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int pointer target"),
                 }
@@ -508,16 +642,15 @@ mod tests {
 
     #[test]
     fn test_mutable_pointer_type() {
-        let mut tokens = create_token_stream(vec![
-            TokenType::Star,
-            TokenType::Identifier("int".to_string()),
-        ]);
+        let arena = Arena::new();
+        let mut tokens =
+            create_token_stream(vec![TokenType::Star, TokenType::Identifier("int".into())]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Pointer(ptr_type) => {
                 assert!(ptr_type.is_mutable);
-                match ptr_type.target.as_ref() {
+                match ptr_type.target {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int pointer target"),
                 }
@@ -528,18 +661,19 @@ mod tests {
 
     #[test]
     fn test_nested_array_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::LeftBracket,
             TokenType::LeftBracket,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::RightBracket,
             TokenType::RightBracket,
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
-            Type::Array(outer_element) => match outer_element.as_ref() {
-                Type::Array(inner_element) => match inner_element.as_ref() {
+            Type::Array(outer_element) => match outer_element {
+                Type::Array(inner_element) => match inner_element {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int in nested array"),
                 },
@@ -551,32 +685,33 @@ mod tests {
 
     #[test]
     fn test_complex_function_type() {
+        let arena = Arena::new();
         let mut tokens = create_token_stream(vec![
             TokenType::Fn,
             TokenType::LeftParen,
             TokenType::LeftBracket,
-            TokenType::Identifier("int".to_string()),
+            TokenType::Identifier("int".into()),
             TokenType::RightBracket,
             TokenType::Comma,
             TokenType::LeftParen,
-            TokenType::Identifier("string".to_string()),
+            TokenType::Identifier("string".into()),
             TokenType::Comma,
-            TokenType::Identifier("bool".to_string()),
+            TokenType::Identifier("bool".into()),
             TokenType::RightParen,
             TokenType::RightParen,
             TokenType::Arrow,
             TokenType::Star,
-            TokenType::Identifier("char".to_string()),
+            TokenType::Identifier("char".into()),
         ]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Function(func_type) => {
                 assert_eq!(func_type.parameters.len(), 2);
 
                 // First parameter: [int]
                 match &func_type.parameters[0] {
-                    Type::Array(elem) => match elem.as_ref() {
+                    Type::Array(elem) => match elem {
                         Type::Identifier(name) => assert_eq!(name, "int"),
                         _ => panic!("Expected int array"),
                     },
@@ -592,10 +727,10 @@ mod tests {
                 }
 
                 // Return type: *char (simplified)
-                match func_type.return_type.as_ref() {
+                match func_type.return_type {
                     Type::Pointer(ptr) => {
                         assert!(ptr.is_mutable); // Default to mutable
-                        match ptr.target.as_ref() {
+                        match ptr.target {
                             Type::Identifier(name) => assert_eq!(name, "char"),
                             _ => panic!("Expected char pointer"),
                         }
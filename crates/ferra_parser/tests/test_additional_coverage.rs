@@ -14,17 +14,17 @@ fn test_compilation_unit_with_multiple_items() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("func1".to_string()),
+        TokenType::Identifier("func1".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Data,
-        TokenType::Identifier("Point".to_string()),
+        TokenType::Identifier("Point".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -79,15 +79,15 @@ fn test_complex_precedence_chain() {
         assert!(matches!(and_expr.operator, BinaryOperator::And));
 
         // Left side should be equality comparison
-        if let Expression::Binary(eq_expr) = and_expr.left.as_ref() {
+        if let Expression::Binary(eq_expr) = and_expr.left {
             assert!(matches!(eq_expr.operator, BinaryOperator::Equal));
 
             // Left of equality should be addition
-            if let Expression::Binary(add_expr) = eq_expr.left.as_ref() {
+            if let Expression::Binary(add_expr) = eq_expr.left {
                 assert!(matches!(add_expr.operator, BinaryOperator::Add));
 
                 // Right of addition should be multiplication
-                if let Expression::Binary(mul_expr) = add_expr.right.as_ref() {
+                if let Expression::Binary(mul_expr) = add_expr.right {
                     assert!(matches!(mul_expr.operator, BinaryOperator::Mul));
                 } else {
                     panic!("Expected multiplication in precedence chain");
@@ -108,13 +108,13 @@ fn test_complex_precedence_chain() {
 fn test_nested_function_calls_with_member_access() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("get_inner".to_string()),
+        TokenType::Identifier("get_inner".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("process".to_string()),
+        TokenType::Identifier("process".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(42),
         TokenType::RightParen,
@@ -131,15 +131,15 @@ fn test_nested_function_calls_with_member_access() {
     // Should parse as: obj.get_inner().process(42)[0]
     if let Ok(Expression::Index(index_expr)) = result {
         // Object should be obj.get_inner().process(42)
-        if let Expression::Call(call_expr) = index_expr.object.as_ref() {
+        if let Expression::Call(call_expr) = index_expr.object {
             assert_eq!(call_expr.arguments.len(), 1);
 
             // Callee should be obj.get_inner().process
-            if let Expression::MemberAccess(member_expr) = call_expr.callee.as_ref() {
+            if let Expression::MemberAccess(member_expr) = call_expr.callee {
                 assert_eq!(member_expr.member, "process");
 
                 // Object should be obj.get_inner()
-                if let Expression::Call(inner_call) = member_expr.object.as_ref() {
+                if let Expression::Call(inner_call) = member_expr.object {
                     assert_eq!(inner_call.arguments.len(), 0);
                 } else {
                     panic!("Expected inner function call");
@@ -165,7 +165,7 @@ fn test_array_with_complex_expressions() {
         TokenType::Plus,
         TokenType::IntegerLiteral(2),
         TokenType::Comma,
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(3),
         TokenType::Star,
@@ -222,9 +222,9 @@ fn test_variable_declaration_with_complex_initializer() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("calculate".to_string()),
+        TokenType::Identifier("calculate".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(1),
         TokenType::Plus,
@@ -248,7 +248,7 @@ fn test_variable_declaration_with_complex_initializer() {
             assert!(matches!(binary.operator, BinaryOperator::Mul));
 
             // Left should be calculate(1 + 2)
-            if let Expression::Call(call) = binary.left.as_ref() {
+            if let Expression::Call(call) = binary.left {
                 assert_eq!(call.arguments.len(), 1);
                 if let Expression::Binary(arg_binary) = &call.arguments[0] {
                     assert!(matches!(arg_binary.operator, BinaryOperator::Add));
@@ -293,7 +293,7 @@ fn test_nested_if_with_complex_conditions() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::If,
-        TokenType::Identifier("condition".to_string()),
+        TokenType::Identifier("condition".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::BooleanLiteral(true),
@@ -350,10 +350,10 @@ fn test_multiple_unary_operators() {
     if let Ok(Expression::Unary(outer_not)) = result {
         assert!(matches!(outer_not.operator, UnaryOperator::Not));
 
-        if let Expression::Unary(inner_not) = outer_not.operand.as_ref() {
+        if let Expression::Unary(inner_not) = outer_not.operand {
             assert!(matches!(inner_not.operator, UnaryOperator::Not));
 
-            if let Expression::Literal(Literal::Boolean(true)) = inner_not.operand.as_ref() {
+            if let Expression::Literal(Literal::Boolean(true)) = inner_not.operand {
                 // Success
             } else {
                 panic!("Expected boolean literal true");
@@ -371,7 +371,7 @@ fn test_multiple_unary_operators() {
 fn test_chained_member_access_with_indexing() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("matrix".to_string()),
+        TokenType::Identifier("matrix".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(0),
         TokenType::RightBracket,
@@ -379,7 +379,7 @@ fn test_chained_member_access_with_indexing() {
         TokenType::IntegerLiteral(1),
         TokenType::RightBracket,
         TokenType::Dot,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Eof,
     ]);
 
@@ -392,11 +392,11 @@ fn test_chained_member_access_with_indexing() {
         assert_eq!(member.member, "value");
 
         // Object should be matrix[0][1]
-        if let Expression::Index(outer_index) = member.object.as_ref() {
+        if let Expression::Index(outer_index) = member.object {
             // Object should be matrix[0]
-            if let Expression::Index(inner_index) = outer_index.object.as_ref() {
+            if let Expression::Index(inner_index) = outer_index.object {
                 // Object should be matrix
-                if let Expression::Identifier(name) = inner_index.object.as_ref() {
+                if let Expression::Identifier(name) = inner_index.object {
                     assert_eq!(name, "matrix");
                 } else {
                     panic!("Expected matrix identifier");
@@ -418,11 +418,11 @@ fn test_parser_sequential_parsing() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(1),
         TokenType::Let,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(2),
         TokenType::Eof,
@@ -458,18 +458,18 @@ fn test_comprehensive_function_with_all_features() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Pub,
         TokenType::Fn,
-        TokenType::Identifier("advanced_func".to_string()),
+        TokenType::Identifier("advanced_func".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param1".to_string()),
+        TokenType::Identifier("param1".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Comma,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::BooleanLiteral(true),
@@ -555,8 +555,8 @@ fn test_large_expression_tree() {
         assert!(matches!(outer_add.operator, BinaryOperator::Add));
 
         // Left should be ((1 + 2) * (3 + 4))
-        if let Expression::Grouped(left_grouped) = outer_add.left.as_ref() {
-            if let Expression::Binary(left_mul) = left_grouped.as_ref() {
+        if let Expression::Grouped(left_grouped) = outer_add.left {
+            if let Expression::Binary(left_mul) = left_grouped {
                 assert!(matches!(left_mul.operator, BinaryOperator::Mul));
             } else {
                 panic!("Expected multiplication in left grouped expression");
@@ -566,8 +566,8 @@ fn test_large_expression_tree() {
         }
 
         // Right should be (5 * 6)
-        if let Expression::Grouped(right_grouped) = outer_add.right.as_ref() {
-            if let Expression::Binary(right_mul) = right_grouped.as_ref() {
+        if let Expression::Grouped(right_grouped) = outer_add.right {
+            if let Expression::Binary(right_mul) = right_grouped {
                 assert!(matches!(right_mul.operator, BinaryOperator::Mul));
             } else {
                 panic!("Expected multiplication in right grouped expression");
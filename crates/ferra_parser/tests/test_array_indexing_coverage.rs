@@ -15,7 +15,7 @@ fn test_arena() -> Arena {
 }
 
 /// Helper function to create parser for testing
-fn test_parser(arena: &Arena, tokens: Vec<TokenType>) -> PrattParser<VecTokenStream> {
+fn test_parser(arena: &Arena, tokens: Vec<TokenType>) -> PrattParser<'_, VecTokenStream> {
     let token_stream = VecTokenStream::from_token_types(tokens);
     PrattParser::new(arena, token_stream)
 }
@@ -31,7 +31,7 @@ mod array_indexing_tests {
 
         // Integer index
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(42),
             TokenType::RightBracket,
@@ -47,9 +47,9 @@ mod array_indexing_tests {
 
         // Variable index
         let tokens = vec![
-            TokenType::Identifier("data".to_string()),
+            TokenType::Identifier("data".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("index".to_string()),
+            TokenType::Identifier("index".into()),
             TokenType::RightBracket,
             TokenType::Eof,
         ];
@@ -63,7 +63,7 @@ mod array_indexing_tests {
 
         // String index (for hash maps/objects)
         let tokens = vec![
-            TokenType::Identifier("map".to_string()),
+            TokenType::Identifier("map".into()),
             TokenType::LeftBracket,
             TokenType::StringLiteral("key".to_string()),
             TokenType::RightBracket,
@@ -81,12 +81,12 @@ mod array_indexing_tests {
 
         // Two-dimensional indexing: matrix[row][col]
         let tokens = vec![
-            TokenType::Identifier("matrix".to_string()),
+            TokenType::Identifier("matrix".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("row".to_string()),
+            TokenType::Identifier("row".into()),
             TokenType::RightBracket,
             TokenType::LeftBracket,
-            TokenType::Identifier("col".to_string()),
+            TokenType::Identifier("col".into()),
             TokenType::RightBracket,
             TokenType::Eof,
         ];
@@ -100,15 +100,15 @@ mod array_indexing_tests {
 
         // Three-dimensional indexing: cube[x][y][z]
         let tokens = vec![
-            TokenType::Identifier("cube".to_string()),
+            TokenType::Identifier("cube".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::RightBracket,
             TokenType::LeftBracket,
-            TokenType::Identifier("y".to_string()),
+            TokenType::Identifier("y".into()),
             TokenType::RightBracket,
             TokenType::LeftBracket,
-            TokenType::Identifier("z".to_string()),
+            TokenType::Identifier("z".into()),
             TokenType::RightBracket,
             TokenType::Eof,
         ];
@@ -128,9 +128,9 @@ mod array_indexing_tests {
 
         // Binary expression as index: arr[i + 1]
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::Plus,
             TokenType::IntegerLiteral(1),
             TokenType::RightBracket,
@@ -146,9 +146,9 @@ mod array_indexing_tests {
 
         // Function call as index: arr[compute_index()]
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("compute_index".to_string()),
+            TokenType::Identifier("compute_index".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::RightBracket,
@@ -164,14 +164,14 @@ mod array_indexing_tests {
 
         // Complex nested expression: arr[hash(key) % table_size]
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("hash".to_string()),
+            TokenType::Identifier("hash".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("key".to_string()),
+            TokenType::Identifier("key".into()),
             TokenType::RightParen,
             TokenType::Percent,
-            TokenType::Identifier("table_size".to_string()),
+            TokenType::Identifier("table_size".into()),
             TokenType::RightBracket,
             TokenType::Eof,
         ];
@@ -191,12 +191,12 @@ mod array_indexing_tests {
 
         // Member access after indexing: arr[i].field
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::RightBracket,
             TokenType::Dot,
-            TokenType::Identifier("field".to_string()),
+            TokenType::Identifier("field".into()),
             TokenType::Eof,
         ];
         let mut parser = test_parser(&arena, tokens);
@@ -209,12 +209,12 @@ mod array_indexing_tests {
 
         // Function call after indexing: arr[i].method()
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::RightBracket,
             TokenType::Dot,
-            TokenType::Identifier("method".to_string()),
+            TokenType::Identifier("method".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::Eof,
@@ -229,11 +229,11 @@ mod array_indexing_tests {
 
         // Indexing after function call: get_array()[index]
         let tokens = vec![
-            TokenType::Identifier("get_array".to_string()),
+            TokenType::Identifier("get_array".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBracket,
-            TokenType::Identifier("index".to_string()),
+            TokenType::Identifier("index".into()),
             TokenType::RightBracket,
             TokenType::Eof,
         ];
@@ -247,16 +247,16 @@ mod array_indexing_tests {
 
         // Complex chaining: obj.get_array()[index].process()
         let tokens = vec![
-            TokenType::Identifier("obj".to_string()),
+            TokenType::Identifier("obj".into()),
             TokenType::Dot,
-            TokenType::Identifier("get_array".to_string()),
+            TokenType::Identifier("get_array".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBracket,
-            TokenType::Identifier("index".to_string()),
+            TokenType::Identifier("index".into()),
             TokenType::RightBracket,
             TokenType::Dot,
-            TokenType::Identifier("process".to_string()),
+            TokenType::Identifier("process".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::Eof,
@@ -277,7 +277,7 @@ mod array_indexing_tests {
 
         // Missing closing bracket
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(0),
             TokenType::Eof, // Missing RightBracket
@@ -288,7 +288,7 @@ mod array_indexing_tests {
 
         // Empty index expression
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::RightBracket, // Empty index
             TokenType::Eof,
@@ -299,7 +299,7 @@ mod array_indexing_tests {
 
         // Invalid index expression
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::Plus, // Invalid start of expression
             TokenType::IntegerLiteral(1),
@@ -318,9 +318,9 @@ mod array_indexing_tests {
 
         // Indexing has higher precedence than arithmetic: arr[i] + 1
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::RightBracket,
             TokenType::Plus,
             TokenType::IntegerLiteral(1),
@@ -336,12 +336,12 @@ mod array_indexing_tests {
 
         // Indexing has higher precedence than member access: arr[i].field
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::RightBracket,
             TokenType::Dot,
-            TokenType::Identifier("field".to_string()),
+            TokenType::Identifier("field".into()),
             TokenType::Eof,
         ];
         let mut parser = test_parser(&arena, tokens);
@@ -354,16 +354,16 @@ mod array_indexing_tests {
 
         // Multiple operations with precedence: obj.arr[i] + other.val
         let tokens = vec![
-            TokenType::Identifier("obj".to_string()),
+            TokenType::Identifier("obj".into()),
             TokenType::Dot,
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("i".to_string()),
+            TokenType::Identifier("i".into()),
             TokenType::RightBracket,
             TokenType::Plus,
-            TokenType::Identifier("other".to_string()),
+            TokenType::Identifier("other".into()),
             TokenType::Dot,
-            TokenType::Identifier("val".to_string()),
+            TokenType::Identifier("val".into()),
             TokenType::Eof,
         ];
         let mut parser = test_parser(&arena, tokens);
@@ -381,7 +381,7 @@ mod array_indexing_tests {
         let arena = test_arena();
 
         // Test parsing performance with deeply nested indexing
-        let mut tokens = vec![TokenType::Identifier("base".to_string())];
+        let mut tokens = vec![TokenType::Identifier("base".into())];
 
         // Create 50 levels of indexing: base[0][1][2]...[49]
         for i in 0..50 {
@@ -415,7 +415,7 @@ mod array_indexing_tests {
 
         // Test simple indexing AST structure
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(42),
             TokenType::RightBracket,
@@ -429,12 +429,12 @@ mod array_indexing_tests {
             match expr {
                 Expression::Index(index_expr) => {
                     // Verify object is correct
-                    match index_expr.object.as_ref() {
+                    match index_expr.object {
                         Expression::Identifier(name) => assert_eq!(name, "arr"),
                         _ => panic!("Expected object to be identifier"),
                     }
                     // Verify index is correct
-                    match index_expr.index.as_ref() {
+                    match index_expr.index {
                         Expression::Literal(Literal::Integer(42)) => {}
                         _ => panic!("Expected index to be integer 42"),
                     }
@@ -451,7 +451,7 @@ mod array_indexing_tests {
 
         // Test with whitespace variations
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(0),
             TokenType::RightBracket,
@@ -467,7 +467,7 @@ mod array_indexing_tests {
 
         // Test with maximum integer index
         let tokens = vec![
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(i64::MAX),
             TokenType::RightBracket,
@@ -484,7 +484,7 @@ mod array_indexing_tests {
         // Test with long identifier names
         let long_name = "very_long_array_name_that_exceeds_normal_length".to_string();
         let tokens = vec![
-            TokenType::Identifier(long_name.clone()),
+            TokenType::Identifier(long_name.as_str().into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(0),
             TokenType::RightBracket,
@@ -508,11 +508,11 @@ mod integration_tests {
 
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier("value".to_string()),
+            TokenType::Identifier("value".into()),
             TokenType::Equal,
-            TokenType::Identifier("data".to_string()),
+            TokenType::Identifier("data".into()),
             TokenType::LeftBracket,
-            TokenType::Identifier("index".to_string()),
+            TokenType::Identifier("index".into()),
             TokenType::RightBracket,
             TokenType::Semicolon,
             TokenType::Eof,
@@ -535,14 +535,14 @@ mod integration_tests {
         let arena = test_arena();
 
         let tokens = vec![
-            TokenType::Identifier("process".to_string()),
+            TokenType::Identifier("process".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("data".to_string()),
+            TokenType::Identifier("data".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(0),
             TokenType::RightBracket,
             TokenType::Comma,
-            TokenType::Identifier("data".to_string()),
+            TokenType::Identifier("data".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(1),
             TokenType::RightBracket,
@@ -568,12 +568,12 @@ mod integration_tests {
         // Create a simple program with array indexing
         let tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("main".to_string()),
+            TokenType::Identifier("main".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
             TokenType::Let,
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::Equal,
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(1),
@@ -584,9 +584,9 @@ mod integration_tests {
             TokenType::RightBracket,
             TokenType::Semicolon,
             TokenType::Let,
-            TokenType::Identifier("value".to_string()),
+            TokenType::Identifier("value".into()),
             TokenType::Equal,
-            TokenType::Identifier("arr".to_string()),
+            TokenType::Identifier("arr".into()),
             TokenType::LeftBracket,
             TokenType::IntegerLiteral(0),
             TokenType::RightBracket,
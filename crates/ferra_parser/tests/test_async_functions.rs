@@ -20,7 +20,7 @@ mod tests {
         let tokens = vec![
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -54,11 +54,11 @@ mod tests {
         let tokens = vec![
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("fetch_data".to_string()),
+            TokenType::Identifier("fetch_data".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("url".to_string()),
+            TokenType::Identifier("url".into()),
             TokenType::Colon,
-            TokenType::Identifier("String".to_string()),
+            TokenType::Identifier("String".into()),
             TokenType::RightParen,
             TokenType::LeftBrace,
             TokenType::RightBrace,
@@ -91,11 +91,11 @@ mod tests {
         let tokens = vec![
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("compute".to_string()),
+            TokenType::Identifier("compute".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::Arrow,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::LeftBrace,
             TokenType::RightBrace,
             TokenType::Eof,
@@ -130,7 +130,7 @@ mod tests {
             TokenType::Pub,
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("api_call".to_string()),
+            TokenType::Identifier("api_call".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -164,7 +164,7 @@ mod tests {
             TokenType::Unsafe,
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("dangerous_async".to_string()),
+            TokenType::Identifier("dangerous_async".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -199,7 +199,7 @@ mod tests {
             TokenType::Unsafe,
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("public_dangerous_async".to_string()),
+            TokenType::Identifier("public_dangerous_async".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -234,7 +234,7 @@ mod tests {
             // First async function
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("first".to_string()),
+            TokenType::Identifier("first".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -242,7 +242,7 @@ mod tests {
             // Second async function
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("second".to_string()),
+            TokenType::Identifier("second".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -262,7 +262,7 @@ mod tests {
         let compilation_unit = result.unwrap();
         assert_eq!(compilation_unit.items.len(), 2);
 
-        for item in &compilation_unit.items {
+        for item in compilation_unit.items {
             if let ast::Item::FunctionDecl(func) = item {
                 assert!(func.is_async, "All functions should be marked as async");
             } else {
@@ -277,12 +277,12 @@ mod tests {
         let tokens = vec![
             TokenType::Async,
             TokenType::Fn,
-            TokenType::Identifier("with_body".to_string()),
+            TokenType::Identifier("with_body".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Semicolon,
@@ -346,7 +346,7 @@ mod tests {
             let arena = Arena::new();
             let mut tokens = prefix_tokens;
             tokens.extend(vec![
-                TokenType::Identifier("test".to_string()),
+                TokenType::Identifier("test".into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
@@ -0,0 +1,256 @@
+//! Attribute placement tests
+//!
+//! Tests for outer attributes beyond items, fields and parameters:
+//! - Statements: `#[allow(unused)] call()`
+//! - Match arms: `#[cold] _ => fallback`
+//! - Extern blocks and extern items: `#[link_name = "puts"] fn c_puts(s: *u8);`
+//! - Block expressions: `#[cfg(test)] { ... }`
+
+use ferra_parser::{
+    ast::{Arena, Expression, ExternItem, Item, Statement},
+    block::parser::BlockParser,
+    pratt::PrattParser,
+    program::parser::ProgramParser,
+    statement::parser::StatementParser,
+    token::{TokenType, VecTokenStream},
+};
+
+fn ident(name: &str) -> TokenType {
+    TokenType::Identifier(name.into())
+}
+
+/// `#[name]`
+fn attribute(name: &str) -> Vec<TokenType> {
+    vec![
+        TokenType::Hash,
+        TokenType::LeftBracket,
+        ident(name),
+        TokenType::RightBracket,
+    ]
+}
+
+/// `#[name(arg)]`
+fn attribute_with_arg(name: &str, arg: &str) -> Vec<TokenType> {
+    vec![
+        TokenType::Hash,
+        TokenType::LeftBracket,
+        ident(name),
+        TokenType::LeftParen,
+        ident(arg),
+        TokenType::RightParen,
+        TokenType::RightBracket,
+    ]
+}
+
+#[test]
+fn test_attribute_on_expression_statement() {
+    let arena = Arena::new();
+    // #[allow(unused)] compute();
+    let mut token_types = attribute_with_arg("allow", "unused");
+    token_types.extend([
+        ident("compute"),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::Semicolon,
+    ]);
+    let mut parser = StatementParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    let statement = parser.parse_statement().unwrap();
+    assert_eq!(statement.attributes().len(), 1);
+    assert_eq!(statement.attributes()[0].name, "allow");
+    match statement {
+        Statement::Attributed(attributed) => {
+            assert!(matches!(attributed.statement, Statement::Expression(_)));
+        }
+        other => panic!("Expected attributed statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attribute_on_variable_statement_stays_on_declaration() {
+    let arena = Arena::new();
+    // #[allow(unused)] let x = 1
+    let mut token_types = attribute_with_arg("allow", "unused");
+    token_types.extend([
+        TokenType::Let,
+        ident("x"),
+        TokenType::Equal,
+        TokenType::IntegerLiteral(1),
+    ]);
+    let mut parser = StatementParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    match parser.parse_statement().unwrap() {
+        Statement::VariableDecl(var_decl) => {
+            assert_eq!(var_decl.attributes.len(), 1);
+            assert_eq!(var_decl.attributes[0].name, "allow");
+        }
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attributes_on_statements_in_block() {
+    let arena = Arena::new();
+    // { #[allow(unused)] let x = 1; #[cfg(debug)] return x; }
+    let mut token_types = vec![TokenType::LeftBrace];
+    token_types.extend(attribute_with_arg("allow", "unused"));
+    token_types.extend([
+        TokenType::Let,
+        ident("x"),
+        TokenType::Equal,
+        TokenType::IntegerLiteral(1),
+        TokenType::Semicolon,
+    ]);
+    token_types.extend(attribute_with_arg("cfg", "debug"));
+    token_types.extend([
+        TokenType::Return,
+        ident("x"),
+        TokenType::Semicolon,
+        TokenType::RightBrace,
+    ]);
+    let mut tokens = VecTokenStream::from_token_types(token_types);
+    let mut parser = BlockParser::new(&arena);
+
+    let block = parser.parse_block(&mut tokens).unwrap();
+    assert_eq!(block.statements.len(), 2);
+    assert!(matches!(
+        &block.statements[0],
+        Statement::VariableDecl(var_decl) if var_decl.attributes[0].name == "allow"
+    ));
+    match &block.statements[1] {
+        Statement::Attributed(attributed) => {
+            assert_eq!(attributed.attributes[0].name, "cfg");
+            assert!(matches!(attributed.statement, Statement::Return(_)));
+        }
+        other => panic!("Expected attributed return, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attribute_on_match_arm() {
+    let arena = Arena::new();
+    // match code { 0 => ok, #[cold] _ => fail }
+    let mut token_types = vec![
+        TokenType::Match,
+        ident("code"),
+        TokenType::LeftBrace,
+        TokenType::IntegerLiteral(0),
+        TokenType::FatArrow,
+        ident("ok"),
+        TokenType::Comma,
+    ];
+    token_types.extend(attribute("cold"));
+    token_types.extend([
+        ident("_"),
+        TokenType::FatArrow,
+        ident("fail"),
+        TokenType::RightBrace,
+    ]);
+    let mut parser = PrattParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    match parser.parse_expression(0).unwrap() {
+        Expression::Match(match_expr) => {
+            assert_eq!(match_expr.arms.len(), 2);
+            assert!(match_expr.arms[0].attributes.is_empty());
+            assert_eq!(match_expr.arms[1].attributes.len(), 1);
+            assert_eq!(match_expr.arms[1].attributes[0].name, "cold");
+        }
+        other => panic!("Expected match expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attributes_on_extern_items() {
+    let arena = Arena::new();
+    // #[cfg(unix)] extern "C" {
+    //     #[link_name = "puts"] fn c_puts(s: String);
+    //     #[cfg(debug)] static errno: i32;
+    // }
+    let mut token_types = attribute_with_arg("cfg", "unix");
+    token_types.extend([
+        TokenType::Extern,
+        TokenType::StringLiteral("C".to_string()),
+        TokenType::LeftBrace,
+        TokenType::Hash,
+        TokenType::LeftBracket,
+        ident("link_name"),
+        TokenType::Equal,
+        TokenType::StringLiteral("puts".to_string()),
+        TokenType::RightBracket,
+        TokenType::Fn,
+        ident("c_puts"),
+        TokenType::LeftParen,
+        ident("s"),
+        TokenType::Colon,
+        ident("String"),
+        TokenType::RightParen,
+        TokenType::Semicolon,
+    ]);
+    token_types.extend(attribute_with_arg("cfg", "debug"));
+    token_types.extend([
+        TokenType::Static,
+        ident("errno"),
+        TokenType::Colon,
+        ident("i32"),
+        TokenType::Semicolon,
+        TokenType::RightBrace,
+        TokenType::Eof,
+    ]);
+    let mut parser = ProgramParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    let unit = parser.parse_compilation_unit().unwrap();
+    match &unit.items[0] {
+        Item::ExternBlock(extern_block) => {
+            assert_eq!(extern_block.attributes[0].name, "cfg");
+            match &extern_block.items[0] {
+                ExternItem::Function(function) => {
+                    assert_eq!(function.attributes[0].name, "link_name");
+                    assert_eq!(function.attributes[0].value_str(), Some("puts"));
+                }
+                other => panic!("Expected extern function, got {:?}", other),
+            }
+            match &extern_block.items[1] {
+                ExternItem::Variable(variable) => {
+                    assert_eq!(variable.attributes[0].name, "cfg");
+                }
+                other => panic!("Expected extern variable, got {:?}", other),
+            }
+        }
+        other => panic!("Expected extern block, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attribute_on_block_expression() {
+    let arena = Arena::new();
+    // #[cfg(test)] { check() }
+    let mut token_types = attribute_with_arg("cfg", "test");
+    token_types.extend([
+        TokenType::LeftBrace,
+        ident("check"),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::RightBrace,
+    ]);
+    let mut parser = PrattParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    match parser.parse_expression(0).unwrap() {
+        Expression::Block(block_expr) => {
+            assert_eq!(block_expr.attributes.len(), 1);
+            assert_eq!(block_expr.attributes[0].name, "cfg");
+            assert_eq!(block_expr.block.statements.len(), 1);
+        }
+        other => panic!("Expected block expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_attribute_on_plain_expression_is_rejected() {
+    let arena = Arena::new();
+    // #[cold] a + b
+    let mut token_types = attribute("cold");
+    token_types.extend([ident("a"), TokenType::Plus, ident("b")]);
+    let mut parser = PrattParser::new(&arena, VecTokenStream::from_token_types(token_types));
+
+    assert!(parser.parse_expression(0).is_err());
+}
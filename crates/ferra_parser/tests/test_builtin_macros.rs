@@ -0,0 +1,264 @@
+//! Built-in macro tests
+//!
+//! Tests for the compiler-known `println!`, `format!`, `vec!`, `assert!` and
+//! `assert_eq!` macros:
+//! - Arguments parsed into expressions during expansion
+//! - Format string placeholders, named arguments and escaped braces
+//! - Diagnostics pointing inside the format string literal
+
+use ferra_parser::{
+    ast::{
+        Arena, BinaryOperator, BuiltinMacro, BuiltinMacroKind, CompilationUnit, Expression,
+        FormatArgumentRef, FormatPiece, Item, Statement,
+    },
+    expand::expand_macros,
+    program::parser::ProgramParser,
+    test_utils::mock_tokens_from_source,
+};
+
+fn parse<'a>(arena: &'a Arena, source: &str) -> CompilationUnit<'a> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser.parse_compilation_unit().unwrap().clone()
+}
+
+fn main_with<'a>(arena: &'a Arena, body: &str) -> CompilationUnit<'a> {
+    parse(arena, &format!("fn main() {{ {} }}", body))
+}
+
+/// Expand `fn main` containing `body` and return its statements
+fn expand_main<'a>(arena: &'a Arena, body: &str) -> Vec<Statement<'a>> {
+    let unit = expand_macros(arena, &main_with(arena, body)).unwrap();
+    unit.items
+        .iter()
+        .find_map(|item| match item {
+            Item::FunctionDecl(function) => function.body.map(|body| body.statements.to_vec()),
+            _ => None,
+        })
+        .expect("fn main")
+}
+
+/// Expansion error message for `fn main` containing `body`
+fn expand_error(body: &str) -> String {
+    let arena = Arena::new();
+    expand_macros(&arena, &main_with(&arena, body))
+        .unwrap_err()
+        .to_string()
+}
+
+/// The built-in macro in the first statement of `body`
+fn builtin<'a>(arena: &'a Arena, body: &str) -> BuiltinMacro<'a> {
+    match expand_main(arena, body).remove(0) {
+        Statement::Expression(Expression::BuiltinMacro(builtin)) => builtin.clone(),
+        Statement::VariableDecl(variable) => match variable.initializer {
+            Some(Expression::BuiltinMacro(builtin)) => builtin.clone(),
+            other => panic!("Expected built-in macro, got {:?}", other),
+        },
+        other => panic!("Expected built-in macro, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_println_parses_arguments_into_expressions() {
+    let arena = Arena::new();
+    let builtin = builtin(&arena, r#"println!("{} and {}", a + 1, f(b));"#);
+    let BuiltinMacroKind::Print {
+        newline,
+        stderr,
+        format,
+    } = builtin.kind
+    else {
+        panic!("Expected print, got {:?}", builtin.kind);
+    };
+
+    assert!(newline && !stderr);
+    assert_eq!(format.arguments.len(), 2);
+    assert!(matches!(
+        &format.arguments[0].value,
+        Expression::Binary(binary) if matches!(binary.operator, BinaryOperator::Add)
+    ));
+    assert!(matches!(&format.arguments[1].value, Expression::Call(_)));
+    assert_eq!(format.template.pieces.len(), 3);
+}
+
+#[test]
+fn test_format_string_pieces_and_escapes() {
+    let arena = Arena::new();
+    let builtin = builtin(
+        &arena,
+        r#"let s = format!("{{x}} = {0:>4} {name} {value}", x, name = n);"#,
+    );
+    let BuiltinMacroKind::Format(format) = builtin.kind else {
+        panic!("Expected format, got {:?}", builtin.kind);
+    };
+
+    let placeholders: Vec<_> = format
+        .template
+        .pieces
+        .iter()
+        .filter_map(|piece| match piece {
+            FormatPiece::Placeholder(placeholder) => Some(placeholder),
+            FormatPiece::Text(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        format.template.pieces[0],
+        FormatPiece::Text("{x} = ".to_string())
+    );
+    assert_eq!(placeholders[0].argument, FormatArgumentRef::Index(0));
+    assert_eq!(placeholders[0].spec.as_deref(), Some(">4"));
+    assert_eq!(
+        placeholders[1].argument,
+        FormatArgumentRef::Named("name".into())
+    );
+    // No `value = ...` argument, so the variable in scope is captured
+    assert_eq!(
+        placeholders[2].argument,
+        FormatArgumentRef::Captured("value".into())
+    );
+}
+
+#[test]
+fn test_vec_and_assert_forms() {
+    let arena = Arena::new();
+    let elements = builtin(&arena, "let v = vec![1, 2, 3,];");
+    assert!(matches!(elements.kind, BuiltinMacroKind::Vec(items) if items.len() == 3));
+
+    let repeat = builtin(&arena, "let v = vec![0; n * 2];");
+    match repeat.kind {
+        BuiltinMacroKind::VecRepeat { count, .. } => {
+            assert!(matches!(count, Expression::Binary(_)))
+        }
+        other => panic!("Expected repeat form, got {:?}", other),
+    }
+
+    let assert = builtin(&arena, r#"assert!(x > 0, "x was {}", x);"#);
+    assert!(matches!(
+        assert.kind,
+        BuiltinMacroKind::Assert { message: Some(ref message), .. } if message.arguments.len() == 1
+    ));
+
+    let assert_eq = builtin(&arena, "assert_eq!(left(), 4);");
+    assert!(matches!(
+        assert_eq.kind,
+        BuiltinMacroKind::AssertEq { message: None, .. }
+    ));
+}
+
+#[test]
+fn test_nested_builtins_are_expanded() {
+    let arena = Arena::new();
+    let builtin = builtin(&arena, r#"println!("{}", format!("{}", vec![1]));"#);
+    let BuiltinMacroKind::Print { format, .. } = builtin.kind else {
+        panic!("Expected print, got {:?}", builtin.kind);
+    };
+    assert!(matches!(
+        &format.arguments[0].value,
+        Expression::BuiltinMacro(inner) if inner.name == "format"
+    ));
+}
+
+#[test]
+fn test_placeholder_count_diagnostics_point_inside_literal() {
+    let arena = Arena::new();
+    let source = r#"fn main() { println!("{} and {}", a); }"#;
+    let error = expand_macros(&arena, &parse(&arena, source)).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("invalid reference to positional argument 1 (there is 1 argument)"),
+        "{}",
+        error
+    );
+    let span = error.span();
+    assert_eq!(&source[span.start..span.end], "{}");
+    assert_eq!(span.start, source.rfind("{}").unwrap());
+}
+
+#[test]
+fn test_diagnostics_point_inside_literals_with_escapes() {
+    let cases = [
+        (r#"fn main() { println!("a\tb \u{e9} {} {}", x); }"#, "{}"),
+        (
+            r#"fn main() { println!("\"quoted\" {0:?} {1}", x); }"#,
+            "{1}",
+        ),
+        (r##"fn main() { println!(r#"raw\n {} {}"#, x); }"##, "{}"),
+    ];
+    for (source, placeholder) in cases {
+        let arena = Arena::new();
+        let error = expand_macros(&arena, &parse(&arena, source)).unwrap_err();
+        let span = error.span();
+        assert_eq!(&source[span.start..span.end], placeholder, "{}", source);
+        assert_eq!(span.start, source.rfind(placeholder).unwrap(), "{}", source);
+        assert_eq!(span.column, span.start + 1, "{}", source);
+    }
+}
+
+#[test]
+fn test_unmatched_braces_are_reported() {
+    let arena = Arena::new();
+    let source = r#"fn main() { println!("value: }", a); }"#;
+    let error = expand_macros(&arena, &parse(&arena, source)).unwrap_err();
+    assert!(error.to_string().contains("unmatched `}`"), "{}", error);
+    let span = error.span();
+    assert_eq!(&source[span.start..span.end], "}");
+    assert_eq!(span.start, source.find(": }").unwrap() + 2);
+
+    let error = expand_error(r#"println!("value: {", a);"#);
+    assert!(error.to_string().contains("expected `}`"), "{}", error);
+}
+
+#[test]
+fn test_argument_errors() {
+    let cases = [
+        (r#"println!("{}", a, b);"#, "argument never used"),
+        (
+            r#"println!("{}", a, extra = b);"#,
+            "named argument `extra` never used",
+        ),
+        (
+            r#"println!("{x}", x = a, b);"#,
+            "positional arguments cannot follow named arguments",
+        ),
+        (
+            r#"println!("{x}", x = a, x = b);"#,
+            "duplicate argument named `x`",
+        ),
+        (r#"println!("{1x}", a);"#, "invalid argument name `1x`"),
+        (
+            "println!(message);",
+            "format argument must be a string literal",
+        ),
+        (
+            "assert_eq!(a);",
+            "`assert_eq!` requires two values to compare",
+        ),
+    ];
+
+    for (body, expected) in cases {
+        let error = expand_error(body);
+        assert!(error.contains(expected), "{}: {}", body, error);
+    }
+}
+
+#[test]
+fn test_user_macros_shadow_builtins() {
+    let arena = Arena::new();
+    let unit = expand_macros(
+        &arena,
+        &parse(
+            &arena,
+            "macro vec { ($x:expr) => { $x } } fn main() { let v = vec!(7); }",
+        ),
+    )
+    .unwrap();
+    match &unit.items[1] {
+        Item::FunctionDecl(function) => match &function.body.unwrap().statements[0] {
+            Statement::VariableDecl(variable) => {
+                assert!(matches!(variable.initializer, Some(Expression::Literal(_))))
+            }
+            other => panic!("Expected variable declaration, got {:?}", other),
+        },
+        other => panic!("Expected function, got {:?}", other),
+    }
+}
@@ -0,0 +1,328 @@
+//! Conditional compilation tests
+//!
+//! Tests for the `#[cfg(...)]` stripping pass:
+//! - Items selected per target and feature
+//! - Fields, statements, match arms and extern items
+//! - Inner `#![cfg(...)]` on a whole file
+//! - Diagnostics for malformed predicates
+
+use ferra_parser::{
+    ast::{Arena, CompilationUnit, Expression, ExternItem, Item, Statement},
+    cfg::{strip_cfg, CfgConfig},
+    program::parser::ProgramParser,
+    token::{TokenType, VecTokenStream},
+};
+
+fn ident(name: &str) -> TokenType {
+    TokenType::Identifier(name.into())
+}
+
+fn string(value: &str) -> TokenType {
+    TokenType::StringLiteral(value.to_string())
+}
+
+/// `#[cfg(<predicate>)]`
+fn cfg(predicate: Vec<TokenType>) -> Vec<TokenType> {
+    let mut tokens = vec![
+        TokenType::Hash,
+        TokenType::LeftBracket,
+        ident("cfg"),
+        TokenType::LeftParen,
+    ];
+    tokens.extend(predicate);
+    tokens.extend([TokenType::RightParen, TokenType::RightBracket]);
+    tokens
+}
+
+/// `key = "value"`
+fn option(key: &str, value: &str) -> Vec<TokenType> {
+    vec![ident(key), TokenType::Equal, string(value)]
+}
+
+/// `fn name() { body }`
+fn function(name: &str, body: Vec<TokenType>) -> Vec<TokenType> {
+    let mut tokens = vec![
+        TokenType::Fn,
+        ident(name),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::LeftBrace,
+    ];
+    tokens.extend(body);
+    tokens.push(TokenType::RightBrace);
+    tokens
+}
+
+/// `name();`
+fn call(name: &str) -> Vec<TokenType> {
+    vec![
+        ident(name),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::Semicolon,
+    ]
+}
+
+fn parse(arena: &Arena, mut token_types: Vec<TokenType>) -> CompilationUnit<'_> {
+    token_types.push(TokenType::Eof);
+    let mut parser = ProgramParser::new(arena, VecTokenStream::from_token_types(token_types));
+    parser.parse_compilation_unit().unwrap().clone()
+}
+
+fn function_names<'a>(unit: &'a CompilationUnit<'_>) -> Vec<&'a str> {
+    unit.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::FunctionDecl(function) => Some(function.name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// #[cfg(target = "wasm")] fn web() {}
+/// #[cfg(not(target = "wasm"))] fn native() {}
+/// fn main() {}
+fn per_target_program(arena: &Arena) -> CompilationUnit<'_> {
+    let mut tokens = cfg(option("target", "wasm"));
+    tokens.extend(function("web", vec![]));
+    tokens.extend(cfg([
+        vec![ident("not"), TokenType::LeftParen],
+        option("target", "wasm"),
+        vec![TokenType::RightParen],
+    ]
+    .concat()));
+    tokens.extend(function("native", vec![]));
+    tokens.extend(function("main", vec![]));
+    parse(arena, tokens)
+}
+
+#[test]
+fn test_items_selected_per_target() {
+    let arena = Arena::new();
+    let unit = per_target_program(&arena);
+
+    let wasm = strip_cfg(&arena, &unit, &CfgConfig::new().with_target("wasm")).unwrap();
+    assert_eq!(function_names(&wasm), vec!["web", "main"]);
+
+    let native = strip_cfg(
+        &arena,
+        &unit,
+        &CfgConfig::new().with_target("aarch64-apple-ios"),
+    )
+    .unwrap();
+    assert_eq!(function_names(&native), vec!["native", "main"]);
+}
+
+#[test]
+fn test_kept_items_lose_cfg_attributes() {
+    let arena = Arena::new();
+    let unit = per_target_program(&arena);
+    let wasm = strip_cfg(&arena, &unit, &CfgConfig::new().with_target("wasm")).unwrap();
+
+    match &wasm.items[0] {
+        Item::FunctionDecl(function) => assert!(function.attributes.is_empty()),
+        other => panic!("Expected function, got {:?}", other),
+    }
+    // The original AST is left untouched
+    assert_eq!(function_names(&unit).len(), 3);
+}
+
+#[test]
+fn test_any_and_all_predicates() {
+    let arena = Arena::new();
+    // #[cfg(all(unix, any(feature = "simd", feature = "gpu")))] fn fast() {}
+    let mut tokens = cfg([
+        vec![
+            ident("all"),
+            TokenType::LeftParen,
+            ident("unix"),
+            TokenType::Comma,
+        ],
+        vec![ident("any"), TokenType::LeftParen],
+        option("feature", "simd"),
+        vec![TokenType::Comma],
+        option("feature", "gpu"),
+        vec![TokenType::RightParen, TokenType::RightParen],
+    ]
+    .concat());
+    tokens.extend(function("fast", vec![]));
+    let unit = parse(&arena, tokens);
+
+    let plain_unix = CfgConfig::new().with_flag("unix");
+    assert!(strip_cfg(&arena, &unit, &plain_unix)
+        .unwrap()
+        .items
+        .is_empty());
+
+    let gpu_unix = CfgConfig::new().with_flag("unix").with_feature("gpu");
+    assert_eq!(
+        function_names(&strip_cfg(&arena, &unit, &gpu_unix).unwrap()),
+        vec!["fast"]
+    );
+
+    let gpu_only = CfgConfig::new().with_feature("gpu");
+    assert!(strip_cfg(&arena, &unit, &gpu_only)
+        .unwrap()
+        .items
+        .is_empty());
+}
+
+#[test]
+fn test_statements_and_fields_are_stripped() {
+    let arena = Arena::new();
+    // fn main() { #[cfg(feature = "log")] trace(); run(); }
+    let mut body = cfg(option("feature", "log"));
+    body.extend(call("trace"));
+    body.extend(call("run"));
+    let mut tokens = function("main", body);
+
+    // data Config { #[cfg(feature = "gpu")] device: Int, name: String }
+    tokens.extend([TokenType::Data, ident("Config"), TokenType::LeftBrace]);
+    tokens.extend(cfg(option("feature", "gpu")));
+    tokens.extend([
+        ident("device"),
+        TokenType::Colon,
+        ident("Int"),
+        TokenType::Comma,
+        ident("name"),
+        TokenType::Colon,
+        ident("String"),
+        TokenType::RightBrace,
+    ]);
+    let unit = parse(&arena, tokens);
+
+    let stripped = strip_cfg(&arena, &unit, &CfgConfig::new()).unwrap();
+    match &stripped.items[0] {
+        Item::FunctionDecl(function) => {
+            let body = function.body.as_ref().unwrap();
+            assert_eq!(body.statements.len(), 1);
+            assert!(matches!(&body.statements[0], Statement::Expression(_)));
+        }
+        other => panic!("Expected function, got {:?}", other),
+    }
+    match &stripped.items[1] {
+        Item::DataClassDecl(data_class) => {
+            assert_eq!(data_class.fields.len(), 1);
+            assert_eq!(data_class.fields[0].name, "name");
+        }
+        other => panic!("Expected data class, got {:?}", other),
+    }
+
+    // Enabled statements are unwrapped once their cfg attribute is evaluated
+    let logging = strip_cfg(&arena, &unit, &CfgConfig::new().with_feature("log")).unwrap();
+    match &logging.items[0] {
+        Item::FunctionDecl(function) => {
+            let body = function.body.as_ref().unwrap();
+            assert_eq!(body.statements.len(), 2);
+            assert!(body.statements[0].attributes().is_empty());
+        }
+        other => panic!("Expected function, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_match_arms_are_stripped() {
+    let arena = Arena::new();
+    // fn main() { match os { #[cfg(unix)] 0 => posix(), _ => other() } }
+    let mut body = vec![TokenType::Match, ident("os"), TokenType::LeftBrace];
+    body.extend(cfg(vec![ident("unix")]));
+    body.extend([
+        TokenType::IntegerLiteral(0),
+        TokenType::FatArrow,
+        ident("posix"),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::Comma,
+        ident("_"),
+        TokenType::FatArrow,
+        ident("other"),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::RightBrace,
+    ]);
+    let unit = parse(&arena, function("main", body));
+
+    let arm_count = |config: &CfgConfig| match &strip_cfg(&arena, &unit, config).unwrap().items[0] {
+        Item::FunctionDecl(function) => match &function.body.as_ref().unwrap().statements[0] {
+            Statement::Expression(Expression::Match(match_expr)) => match_expr.arms.len(),
+            other => panic!("Expected match statement, got {:?}", other),
+        },
+        other => panic!("Expected function, got {:?}", other),
+    };
+
+    assert_eq!(arm_count(&CfgConfig::new()), 1);
+    assert_eq!(arm_count(&CfgConfig::new().with_flag("unix")), 2);
+}
+
+#[test]
+fn test_extern_items_are_stripped() {
+    let arena = Arena::new();
+    // extern "C" { #[cfg(unix)] fn getpid() -> Int; static errno: Int; }
+    let mut tokens = vec![TokenType::Extern, string("C"), TokenType::LeftBrace];
+    tokens.extend(cfg(vec![ident("unix")]));
+    tokens.extend([
+        TokenType::Fn,
+        ident("getpid"),
+        TokenType::LeftParen,
+        TokenType::RightParen,
+        TokenType::Arrow,
+        ident("Int"),
+        TokenType::Semicolon,
+        TokenType::Static,
+        ident("errno"),
+        TokenType::Colon,
+        ident("Int"),
+        TokenType::Semicolon,
+        TokenType::RightBrace,
+    ]);
+    let unit = parse(&arena, tokens);
+
+    match &strip_cfg(&arena, &unit, &CfgConfig::new()).unwrap().items[0] {
+        Item::ExternBlock(extern_block) => {
+            assert_eq!(extern_block.items.len(), 1);
+            assert!(matches!(&extern_block.items[0], ExternItem::Variable(_)));
+        }
+        other => panic!("Expected extern block, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_inner_cfg_disables_whole_file() {
+    let arena = Arena::new();
+    // #![cfg(target = "wasm")] fn main() {}
+    let mut tokens = vec![
+        TokenType::Hash,
+        TokenType::Bang,
+        TokenType::LeftBracket,
+        ident("cfg"),
+        TokenType::LeftParen,
+    ];
+    tokens.extend(option("target", "wasm"));
+    tokens.extend([TokenType::RightParen, TokenType::RightBracket]);
+    tokens.extend(function("main", vec![]));
+    let unit = parse(&arena, tokens);
+
+    assert!(strip_cfg(&arena, &unit, &CfgConfig::new())
+        .unwrap()
+        .items
+        .is_empty());
+    let wasm = strip_cfg(&arena, &unit, &CfgConfig::new().with_target("wasm")).unwrap();
+    assert_eq!(function_names(&wasm), vec!["main"]);
+    assert!(wasm.attributes.is_empty());
+}
+
+#[test]
+fn test_malformed_predicate_is_reported() {
+    let arena = Arena::new();
+    // #[cfg(target = 64)] fn main() {}
+    let mut tokens = cfg(vec![
+        ident("target"),
+        TokenType::Equal,
+        TokenType::IntegerLiteral(64),
+    ]);
+    tokens.extend(function("main", vec![]));
+    let unit = parse(&arena, tokens);
+
+    let error = strip_cfg(&arena, &unit, &CfgConfig::new()).unwrap_err();
+    assert!(error.to_string().contains("string literals"));
+}
@@ -9,19 +9,19 @@ fn create_attribute_tokens() -> Vec<TokenType> {
     vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("TestStruct".to_string()),
+        TokenType::Identifier("TestStruct".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]
@@ -33,45 +33,45 @@ fn create_complex_attribute_tokens() -> Vec<TokenType> {
         // Multiple attributes
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("cfg".to_string()),
+        TokenType::Identifier("cfg".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("advanced".to_string()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("ComplexStruct".to_string()),
+        TokenType::Identifier("ComplexStruct".into()),
         TokenType::LeftBrace,
         // Field with attributes
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("skip".to_string()),
+        TokenType::Identifier("skip".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
-        TokenType::Identifier("field1".to_string()),
+        TokenType::Identifier("field1".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("field2".to_string()),
+        TokenType::Identifier("field2".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]
@@ -83,38 +83,38 @@ fn create_malformed_attribute_tokens() -> Vec<TokenType> {
         // Malformed attribute - missing closing bracket
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma, // Trailing comma
         TokenType::RightParen,
         // Missing closing bracket - should cause error but allow recovery
         TokenType::Data,
-        TokenType::Identifier("StructWithBadAttr".to_string()),
+        TokenType::Identifier("StructWithBadAttr".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         // Another malformed attribute
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("cfg".to_string()),
+        TokenType::Identifier("cfg".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("test".to_string()),
         // Missing closing paren and bracket
 
         // Valid function after errors - should parse successfully
         TokenType::Fn,
-        TokenType::Identifier("valid_function".to_string()),
+        TokenType::Identifier("valid_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::IntegerLiteral(42),
@@ -129,32 +129,32 @@ fn create_function_attribute_tokens() -> Vec<TokenType> {
     vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("always".to_string()),
+        TokenType::Identifier("always".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("cfg".to_string()),
+        TokenType::Identifier("cfg".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("optimized".to_string()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Fn,
-        TokenType::Identifier("attributed_function".to_string()),
+        TokenType::Identifier("attributed_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::LeftBrace,
         TokenType::Return,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Star,
         TokenType::IntegerLiteral(2),
         TokenType::Semicolon,
@@ -168,21 +168,21 @@ fn create_nested_conditional_attribute_tokens() -> Vec<TokenType> {
     vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("cfg".to_string()),
+        TokenType::Identifier("cfg".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("all".to_string()),
+        TokenType::Identifier("all".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("level1".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("any".to_string()),
+        TokenType::Identifier("any".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("level2a".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("feature".to_string()),
+        TokenType::Identifier("feature".into()),
         TokenType::Equal,
         TokenType::StringLiteral("level2b".to_string()),
         TokenType::RightParen,
@@ -190,11 +190,11 @@ fn create_nested_conditional_attribute_tokens() -> Vec<TokenType> {
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("NestedConditionalStruct".to_string()),
+        TokenType::Identifier("NestedConditionalStruct".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]
@@ -279,9 +279,9 @@ mod complex_nested_attribute_tests {
             tokens.extend(vec![
                 TokenType::Hash,
                 TokenType::LeftBracket,
-                TokenType::Identifier("derive".to_string()),
+                TokenType::Identifier("derive".into()),
                 TokenType::LeftParen,
-                TokenType::Identifier(format!("Trait{i}")),
+                TokenType::Identifier(format!("Trait{i}").into()),
                 TokenType::RightParen,
                 TokenType::RightBracket,
             ]);
@@ -290,11 +290,11 @@ mod complex_nested_attribute_tests {
         // Add the actual data structure
         tokens.extend(vec![
             TokenType::Data,
-            TokenType::Identifier("MassiveAttributeStruct".to_string()),
+            TokenType::Identifier("MassiveAttributeStruct".into()),
             TokenType::LeftBrace,
-            TokenType::Identifier("field".to_string()),
+            TokenType::Identifier("field".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::RightBrace,
             TokenType::Eof,
         ]);
@@ -308,34 +308,34 @@ mod complex_nested_attribute_tests {
         let tokens = vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("serde".to_string()),
+            TokenType::Identifier("serde".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("rename_all".to_string()),
+            TokenType::Identifier("rename_all".into()),
             TokenType::Equal,
             TokenType::StringLiteral("camelCase".to_string()),
             TokenType::Comma,
-            TokenType::Identifier("deny_unknown_fields".to_string()),
+            TokenType::Identifier("deny_unknown_fields".into()),
             TokenType::Comma,
-            TokenType::Identifier("tag".to_string()),
+            TokenType::Identifier("tag".into()),
             TokenType::Equal,
             TokenType::StringLiteral("type".to_string()),
             TokenType::RightParen,
             TokenType::RightBracket,
             TokenType::Data,
-            TokenType::Identifier("ComplexSerdeStruct".to_string()),
+            TokenType::Identifier("ComplexSerdeStruct".into()),
             TokenType::LeftBrace,
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("serde".to_string()),
+            TokenType::Identifier("serde".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("serialize_with".to_string()),
+            TokenType::Identifier("serialize_with".into()),
             TokenType::Equal,
             TokenType::StringLiteral("custom_serializer".to_string()),
             TokenType::RightParen,
             TokenType::RightBracket,
-            TokenType::Identifier("complex_field".to_string()),
+            TokenType::Identifier("complex_field".into()),
             TokenType::Colon,
-            TokenType::Identifier("CustomType".to_string()),
+            TokenType::Identifier("CustomType".into()),
             TokenType::RightBrace,
             TokenType::Eof,
         ];
@@ -350,29 +350,29 @@ mod complex_nested_attribute_tests {
             // Compact attribute
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::Comma,
-            TokenType::Identifier("Clone".to_string()),
+            TokenType::Identifier("Clone".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
             // Attribute with extra spacing (simulated by parser handling)
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("cfg".to_string()),
+            TokenType::Identifier("cfg".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("feature".to_string()),
+            TokenType::Identifier("feature".into()),
             TokenType::Equal,
             TokenType::StringLiteral("test".to_string()),
             TokenType::RightParen,
             TokenType::RightBracket,
             TokenType::Data,
-            TokenType::Identifier("WhitespaceTestStruct".to_string()),
+            TokenType::Identifier("WhitespaceTestStruct".into()),
             TokenType::LeftBrace,
-            TokenType::Identifier("field".to_string()),
+            TokenType::Identifier("field".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::RightBrace,
             TokenType::Eof,
         ];
@@ -386,30 +386,30 @@ mod complex_nested_attribute_tests {
         let tokens = vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("derive".to_string()),
+            TokenType::Identifier("derive".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("Debug".to_string()),
+            TokenType::Identifier("Debug".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
             TokenType::Pub,
             TokenType::Data,
-            TokenType::Identifier("PublicStruct".to_string()),
+            TokenType::Identifier("PublicStruct".into()),
             TokenType::LeftBrace,
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("serde".to_string()),
+            TokenType::Identifier("serde".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("skip".to_string()),
+            TokenType::Identifier("skip".into()),
             TokenType::RightParen,
             TokenType::RightBracket,
-            TokenType::Identifier("private_field".to_string()),
+            TokenType::Identifier("private_field".into()),
             TokenType::Colon,
-            TokenType::Identifier("String".to_string()),
+            TokenType::Identifier("String".into()),
             TokenType::Comma,
             TokenType::Pub,
-            TokenType::Identifier("public_field".to_string()),
+            TokenType::Identifier("public_field".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::RightBrace,
             TokenType::Eof,
         ];
@@ -427,7 +427,7 @@ mod complex_nested_attribute_tests {
             tokens.extend(vec![
                 TokenType::Hash,
                 TokenType::LeftBracket,
-                TokenType::Identifier(format!("attr_{i}")),
+                TokenType::Identifier(format!("attr_{i}").into()),
                 TokenType::LeftParen,
                 TokenType::StringLiteral(format!("value_{i}")),
                 TokenType::RightParen,
@@ -437,7 +437,7 @@ mod complex_nested_attribute_tests {
 
         tokens.extend(vec![
             TokenType::Fn,
-            TokenType::Identifier("heavily_attributed_function".to_string()),
+            TokenType::Identifier("heavily_attributed_function".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -454,28 +454,28 @@ mod complex_nested_attribute_tests {
         let tokens = vec![
             TokenType::Hash,
             TokenType::LeftBracket,
-            TokenType::Identifier("cfg".to_string()),
+            TokenType::Identifier("cfg".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("all".to_string()),
+            TokenType::Identifier("all".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("feature".to_string()),
+            TokenType::Identifier("feature".into()),
             TokenType::Equal,
             TokenType::StringLiteral("level1".to_string()),
             TokenType::Comma,
-            TokenType::Identifier("any".to_string()),
+            TokenType::Identifier("any".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("target_os".to_string()),
+            TokenType::Identifier("target_os".into()),
             TokenType::Equal,
             TokenType::StringLiteral("linux".to_string()),
             TokenType::Comma,
-            TokenType::Identifier("target_os".to_string()),
+            TokenType::Identifier("target_os".into()),
             TokenType::Equal,
             TokenType::StringLiteral("macos".to_string()),
             TokenType::RightParen,
             TokenType::Comma,
-            TokenType::Identifier("not".to_string()),
+            TokenType::Identifier("not".into()),
             TokenType::LeftParen,
-            TokenType::Identifier("target_os".to_string()),
+            TokenType::Identifier("target_os".into()),
             TokenType::Equal,
             TokenType::StringLiteral("windows".to_string()),
             TokenType::RightParen,
@@ -483,11 +483,11 @@ mod complex_nested_attribute_tests {
             TokenType::RightParen,
             TokenType::RightBracket,
             TokenType::Data,
-            TokenType::Identifier("DeeplyNestedConditionStruct".to_string()),
+            TokenType::Identifier("DeeplyNestedConditionStruct".into()),
             TokenType::LeftBrace,
-            TokenType::Identifier("field".to_string()),
+            TokenType::Identifier("field".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::RightBrace,
             TokenType::Eof,
         ];
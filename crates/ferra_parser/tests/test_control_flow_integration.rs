@@ -32,7 +32,7 @@ fn convert_token(token: Token) -> TokenType {
         TokenKind::Continue => TokenType::Continue,
         TokenKind::Pub => TokenType::Pub,
         TokenKind::Unsafe => TokenType::Unsafe,
-        TokenKind::Identifier => TokenType::Identifier(token.lexeme.clone()),
+        TokenKind::Identifier => TokenType::Identifier(token.lexeme.as_str().into()),
         TokenKind::IntegerLiteral => match token.literal {
             Some(ferra_lexer::LiteralValue::Integer(i)) => TokenType::IntegerLiteral(i),
             _ => TokenType::IntegerLiteral(42),
@@ -86,7 +86,7 @@ fn convert_token(token: Token) -> TokenType {
         TokenKind::DotDot => TokenType::DotDot,
         TokenKind::DotDotEqual => TokenType::DotDotEqual,
         TokenKind::PathSep => TokenType::DoubleColon,
-        TokenKind::Underscore => TokenType::Identifier("_".to_string()),
+        TokenKind::Underscore => TokenType::Identifier("_".into()),
         TokenKind::Eof => TokenType::Eof,
         _ => TokenType::Eof, // Fallback for any unhandled tokens
     }
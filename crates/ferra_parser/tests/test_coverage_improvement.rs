@@ -35,7 +35,7 @@ fn test_basic_expression_coverage() {
             "boolean false",
         ),
         (
-            vec![TokenType::Identifier("var".to_string()), TokenType::Eof],
+            vec![TokenType::Identifier("var".into()), TokenType::Eof],
             "identifier",
         ),
     ];
@@ -167,7 +167,7 @@ fn test_basic_statement_coverage() {
         (
             vec![
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
@@ -178,7 +178,7 @@ fn test_basic_statement_coverage() {
         (
             vec![
                 TokenType::Var,
-                TokenType::Identifier("y".to_string()),
+                TokenType::Identifier("y".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(10),
                 TokenType::Semicolon,
@@ -229,7 +229,7 @@ fn test_program_parsing_coverage() {
         (
             vec![
                 TokenType::Fn,
-                TokenType::Identifier("main".to_string()),
+                TokenType::Identifier("main".into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
@@ -244,7 +244,7 @@ fn test_program_parsing_coverage() {
         (
             vec![
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
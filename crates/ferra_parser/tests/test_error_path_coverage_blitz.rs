@@ -25,9 +25,9 @@ fn test_comprehensive_error_recovery() {
     // Test recovery from malformed function signature
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("broken_func".to_string()),
+        TokenType::Identifier("broken_func".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
         // Missing type, test recovery
         TokenType::RightParen,
@@ -47,7 +47,7 @@ fn test_comprehensive_error_recovery() {
     // Test recovery from malformed variable declaration
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("broken_var".to_string()),
+        TokenType::Identifier("broken_var".into()),
         TokenType::Colon,
         // Missing type identifier, test recovery
         TokenType::Equal,
@@ -64,15 +64,15 @@ fn test_comprehensive_error_recovery() {
     // Test recovery from malformed data class
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Data,
-        TokenType::Identifier("BrokenClass".to_string()),
+        TokenType::Identifier("BrokenClass".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field1".to_string()),
+        TokenType::Identifier("field1".into()),
         TokenType::Colon,
         // Missing field type, test recovery
         TokenType::Comma,
-        TokenType::Identifier("field2".to_string()),
+        TokenType::Identifier("field2".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -127,7 +127,7 @@ fn test_expression_error_boundaries() {
 
     // Test malformed function call with recovery
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(1),
         TokenType::Comma,
@@ -201,9 +201,9 @@ fn test_statement_error_paths() {
     // Test malformed for loop with missing 'in' keyword
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::For,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         // Missing 'in' keyword
-        TokenType::Identifier("items".to_string()),
+        TokenType::Identifier("items".into()),
         TokenType::LeftBrace,
         TokenType::Continue,
         TokenType::Semicolon,
@@ -219,11 +219,11 @@ fn test_statement_error_paths() {
     // Test malformed function declaration with missing return arrow
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("broken_return".to_string()),
+        TokenType::Identifier("broken_return".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         // Missing arrow before return type
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::IntegerLiteral(0),
@@ -241,7 +241,7 @@ fn test_statement_error_paths() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
         TokenType::Async, // Wrong order: async should come before fn
-        TokenType::Identifier("wrong_order".to_string()),
+        TokenType::Identifier("wrong_order".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -268,7 +268,7 @@ fn test_program_error_paths() {
         // Missing ABI string like "C"
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("external_func".to_string()),
+        TokenType::Identifier("external_func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
@@ -284,18 +284,18 @@ fn test_program_error_paths() {
     // Test malformed generic function with unclosed angle bracket
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("generic_broken".to_string()),
+        TokenType::Identifier("generic_broken".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         // Missing closing angle bracket
         TokenType::LeftParen,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Return,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -309,15 +309,15 @@ fn test_program_error_paths() {
     // Test malformed data class with missing field separator
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Data,
-        TokenType::Identifier("BadData".to_string()),
+        TokenType::Identifier("BadData".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field1".to_string()),
+        TokenType::Identifier("field1".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         // Missing comma between fields
-        TokenType::Identifier("field2".to_string()),
+        TokenType::Identifier("field2".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -337,10 +337,10 @@ fn test_complex_error_scenarios() {
     let tokens = VecTokenStream::from_token_types(vec![
         // First broken function
         TokenType::Fn,
-        TokenType::Identifier("broken1".to_string()),
+        TokenType::Identifier("broken1".into()),
         TokenType::LeftParen,
         // Missing parameter type
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Return,
@@ -349,14 +349,14 @@ fn test_complex_error_scenarios() {
         TokenType::RightBrace,
         // Second broken function
         TokenType::Fn,
-        TokenType::Identifier("broken2".to_string()),
+        TokenType::Identifier("broken2".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         // Missing function body
         TokenType::Semicolon,
         // Third valid function that should parse correctly
         TokenType::Fn,
-        TokenType::Identifier("valid".to_string()),
+        TokenType::Identifier("valid".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -380,9 +380,9 @@ fn test_complex_expression_edge_cases() {
 
     // Test chained assignment-like expressions (not supported, should error)
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Equal,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Equal, // Chained assignment not supported
         TokenType::IntegerLiteral(1),
         TokenType::Eof,
@@ -395,11 +395,11 @@ fn test_complex_expression_edge_cases() {
 
     // Test complex member access with missing identifiers
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
         // Missing member name
         TokenType::Dot,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -410,7 +410,7 @@ fn test_complex_expression_edge_cases() {
 
     // Test array indexing with malformed index expression
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("arr".to_string()),
+        TokenType::Identifier("arr".into()),
         TokenType::LeftBracket,
         TokenType::Plus, // Invalid: unary operator without operand in index
         TokenType::RightBracket,
@@ -424,7 +424,7 @@ fn test_complex_expression_edge_cases() {
 
     // Test macro invocation with mismatched delimiters
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("macro_test".to_string()),
+        TokenType::Identifier("macro_test".into()),
         TokenType::Bang,
         TokenType::LeftParen,
         TokenType::StringLiteral("test".to_string()),
@@ -469,18 +469,18 @@ fn test_error_recovery_stress() {
     // Test recovery from malformed function with complex parameter list
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_params".to_string()),
+        TokenType::Identifier("complex_params".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param1".to_string()),
+        TokenType::Identifier("param1".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Comma,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         // Missing colon and type
         TokenType::Comma,
-        TokenType::Identifier("param3".to_string()),
+        TokenType::Identifier("param3".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Return,
@@ -504,7 +504,7 @@ fn test_error_message_quality() {
     // Test that error messages contain useful information
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("test_var".to_string()),
+        TokenType::Identifier("test_var".into()),
         TokenType::Colon,
         // Missing type, but with context for good error message
         TokenType::Equal,
@@ -528,18 +528,18 @@ fn test_error_message_quality() {
 
     // Test error message for complex expression context
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("complex_expr".to_string()),
+        TokenType::Identifier("complex_expr".into()),
         TokenType::Equal,
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(1),
         TokenType::Comma,
         // Missing argument
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Semicolon,
         TokenType::Eof,
     ]);
@@ -11,17 +11,17 @@ fn create_error_tokens(scenario: &str) -> Vec<TokenType> {
     match scenario {
         "missing_semicolon" => vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             // Missing semicolon here
             TokenType::Let,
-            TokenType::Identifier("y".to_string()),
+            TokenType::Identifier("y".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(24),
             TokenType::Semicolon,
@@ -30,12 +30,12 @@ fn create_error_tokens(scenario: &str) -> Vec<TokenType> {
         ],
         "unmatched_brace" => vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Semicolon,
@@ -44,15 +44,15 @@ fn create_error_tokens(scenario: &str) -> Vec<TokenType> {
         ],
         "multiple_errors" => vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             // Missing open paren
             TokenType::LeftBrace,
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             // Missing equal sign and value
             TokenType::Semicolon,
             TokenType::Let,
-            TokenType::Identifier("y".to_string()),
+            TokenType::Identifier("y".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             // Missing semicolon and closing brace
@@ -60,7 +60,7 @@ fn create_error_tokens(scenario: &str) -> Vec<TokenType> {
         ],
         _ => vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -79,36 +79,36 @@ fn generate_error_cascade_tokens(func_count: usize) -> Vec<TokenType> {
             // Every 10th function has errors
             tokens.extend(vec![
                 TokenType::Fn,
-                TokenType::Identifier(format!("error_func_{i}")),
+                TokenType::Identifier(format!("error_func_{i}").into()),
                 // Missing parameter list
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 // Missing expression
                 TokenType::Semicolon,
                 TokenType::If,
-                TokenType::Identifier("condition".to_string()),
+                TokenType::Identifier("condition".into()),
                 // Missing opening brace
                 TokenType::Let,
-                TokenType::Identifier("y".to_string()),
+                TokenType::Identifier("y".into()),
                 // Missing everything else - should cause cascading errors
             ]);
         } else {
             // Valid functions
             tokens.extend(vec![
                 TokenType::Fn,
-                TokenType::Identifier(format!("valid_func_{i}")),
+                TokenType::Identifier(format!("valid_func_{i}").into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
                 TokenType::Return,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Semicolon,
                 TokenType::RightBrace,
             ]);
@@ -193,7 +193,7 @@ mod error_recovery_stress_tests {
         // Create deeply nested structures with errors
         let mut tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("test_deep_nesting".to_string()),
+            TokenType::Identifier("test_deep_nesting".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -205,7 +205,7 @@ mod error_recovery_stress_tests {
                 // Introduce errors at certain depths - missing opening brace
                 tokens.extend(vec![
                     TokenType::If,
-                    TokenType::Identifier(format!("condition_{depth}")),
+                    TokenType::Identifier(format!("condition_{depth}").into()),
                     // Missing opening brace
                 ]);
             } else if depth % 13 == 0 {
@@ -215,7 +215,7 @@ mod error_recovery_stress_tests {
                     // Missing condition
                     TokenType::LeftBrace,
                     TokenType::Let,
-                    TokenType::Identifier("x".to_string()),
+                    TokenType::Identifier("x".into()),
                     TokenType::Equal,
                     // Missing value
                     TokenType::Semicolon,
@@ -224,10 +224,10 @@ mod error_recovery_stress_tests {
                 // Valid nesting
                 tokens.extend(vec![
                     TokenType::If,
-                    TokenType::Identifier(format!("condition_{depth}")),
+                    TokenType::Identifier(format!("condition_{depth}").into()),
                     TokenType::LeftBrace,
                     TokenType::Let,
-                    TokenType::Identifier(format!("var_{depth}")),
+                    TokenType::Identifier(format!("var_{depth}").into()),
                     TokenType::Equal,
                     TokenType::IntegerLiteral(depth as i64),
                     TokenType::Semicolon,
@@ -265,7 +265,7 @@ mod error_recovery_stress_tests {
         // Generate many malformed expressions in sequence
         let mut tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("expression_storm".to_string()),
+            TokenType::Identifier("expression_storm".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -277,7 +277,7 @@ mod error_recovery_stress_tests {
                     // Invalid operator sequence
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier("x".to_string()),
+                        TokenType::Identifier("x".into()),
                         TokenType::Equal,
                         TokenType::Plus,
                         TokenType::Star,
@@ -290,7 +290,7 @@ mod error_recovery_stress_tests {
                     // Unmatched parentheses
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier("y".to_string()),
+                        TokenType::Identifier("y".into()),
                         TokenType::Equal,
                         TokenType::LeftParen,
                         TokenType::LeftParen,
@@ -304,7 +304,7 @@ mod error_recovery_stress_tests {
                     // Unmatched brackets
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier("z".to_string()),
+                        TokenType::Identifier("z".into()),
                         TokenType::Equal,
                         TokenType::LeftBracket,
                         TokenType::LeftBracket,
@@ -318,12 +318,12 @@ mod error_recovery_stress_tests {
                     // Invalid member access
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier("b".to_string()),
+                        TokenType::Identifier("b".into()),
                         TokenType::Equal,
                         TokenType::Dot,
-                        TokenType::Identifier("member".to_string()),
+                        TokenType::Identifier("member".into()),
                         TokenType::Dot,
-                        TokenType::Identifier("access".to_string()),
+                        TokenType::Identifier("access".into()),
                         TokenType::Dot,
                         TokenType::Semicolon,
                     ]);
@@ -332,7 +332,7 @@ mod error_recovery_stress_tests {
                     // Unclosed string (simulated with identifier)
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier("d".to_string()),
+                        TokenType::Identifier("d".into()),
                         TokenType::Equal,
                         TokenType::StringLiteral("unclosed string".to_string()),
                         // Missing semicolon to create error
@@ -342,7 +342,7 @@ mod error_recovery_stress_tests {
                     // Valid statement
                     tokens.extend(vec![
                         TokenType::Let,
-                        TokenType::Identifier(format!("valid_{i}")),
+                        TokenType::Identifier(format!("valid_{i}").into()),
                         TokenType::Equal,
                         TokenType::IntegerLiteral(i as i64),
                         TokenType::Plus,
@@ -474,17 +474,17 @@ mod error_recovery_stress_tests {
             // Valid code section
             tokens.extend(vec![
                 TokenType::Fn,
-                TokenType::Identifier(format!("valid_function_{burst}")),
+                TokenType::Identifier(format!("valid_function_{burst}").into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
                 TokenType::Return,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Semicolon,
                 TokenType::RightBrace,
             ]);
@@ -550,7 +550,7 @@ mod error_recovery_stress_tests {
                         // Missing params
                         tokens.extend(vec![
                             TokenType::Fn,
-                            TokenType::Identifier(format!("error_{i}")),
+                            TokenType::Identifier(format!("error_{i}").into()),
                             // Missing parameter list
                             TokenType::LeftBrace,
                             TokenType::RightBrace,
@@ -560,7 +560,7 @@ mod error_recovery_stress_tests {
                         // Missing value
                         tokens.extend(vec![
                             TokenType::Let,
-                            TokenType::Identifier(format!("x_{i}")),
+                            TokenType::Identifier(format!("x_{i}").into()),
                             TokenType::Equal,
                             // Missing value
                             TokenType::Semicolon,
@@ -570,7 +570,7 @@ mod error_recovery_stress_tests {
                         // Unclosed if
                         tokens.extend(vec![
                             TokenType::If,
-                            TokenType::Identifier(format!("condition_{i}")),
+                            TokenType::Identifier(format!("condition_{i}").into()),
                             TokenType::LeftBrace,
                             // Missing close brace
                         ]);
@@ -14,7 +14,7 @@ fn convert_token(token: LexerToken) -> TokenType {
         TokenKind::Fn => TokenType::Fn,
         TokenKind::Equal => TokenType::Equal,
         TokenKind::Semicolon => TokenType::Semicolon,
-        TokenKind::Identifier => TokenType::Identifier(token.lexeme.clone()),
+        TokenKind::Identifier => TokenType::Identifier(token.lexeme.as_str().into()),
         TokenKind::IntegerLiteral => match token.literal {
             Some(LiteralValue::Integer(i)) => TokenType::IntegerLiteral(i),
             _ => TokenType::IntegerLiteral(0),
@@ -52,7 +52,7 @@ fn test_string_literal_parsing() {
 fn test_identifier_parsing() {
     let arena = Arena::new();
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("variable".to_string()),
+        TokenType::Identifier("variable".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -90,13 +90,13 @@ fn test_simple_binary_expression() {
                 assert!(matches!(binary.operator, BinaryOperator::Add));
 
                 // Check left operand
-                match binary.left.as_ref() {
+                match binary.left {
                     Expression::Literal(Literal::Integer(value)) => assert_eq!(*value, 1),
                     _ => panic!("Expected left operand to be integer 1"),
                 }
 
                 // Check right operand
-                match binary.right.as_ref() {
+                match binary.right {
                     Expression::Literal(Literal::Integer(value)) => assert_eq!(*value, 2),
                     _ => panic!("Expected right operand to be integer 2"),
                 }
@@ -125,7 +125,7 @@ fn test_unary_expression() {
                 assert!(matches!(unary.operator, UnaryOperator::Minus));
 
                 // Check operand
-                match unary.operand.as_ref() {
+                match unary.operand {
                     Expression::Literal(Literal::Integer(value)) => assert_eq!(*value, 42),
                     _ => panic!("Expected operand to be integer 42"),
                 }
@@ -151,7 +151,7 @@ fn test_grouped_expression() {
 
     if let Ok(expr) = result {
         match expr {
-            Expression::Grouped(inner) => match inner.as_ref() {
+            Expression::Grouped(inner) => match inner {
                 Expression::Literal(Literal::Integer(value)) => assert_eq!(*value, 42),
                 _ => panic!("Expected grouped expression to contain integer 42"),
             },
@@ -183,15 +183,15 @@ fn test_precedence_parsing() {
             Expression::Binary(binary) => {
                 assert!(matches!(binary.operator, BinaryOperator::Add));
                 // Left should be 1
-                match binary.left.as_ref() {
+                match binary.left {
                     Expression::Literal(Literal::Integer(1)) => {}
                     _ => panic!("Expected left operand to be 1"),
                 }
                 // Right should be (2 * 3)
-                match binary.right.as_ref() {
+                match binary.right {
                     Expression::Binary(right_binary) => {
                         assert!(matches!(right_binary.operator, BinaryOperator::Mul));
-                        match (right_binary.left.as_ref(), right_binary.right.as_ref()) {
+                        match (right_binary.left, right_binary.right) {
                             (
                                 Expression::Literal(Literal::Integer(2)),
                                 Expression::Literal(Literal::Integer(3)),
@@ -230,10 +230,10 @@ fn test_left_associativity() {
             Expression::Binary(binary) => {
                 assert!(matches!(binary.operator, BinaryOperator::Sub));
                 // Left should be (1 - 2)
-                match binary.left.as_ref() {
+                match binary.left {
                     Expression::Binary(left_binary) => {
                         assert!(matches!(left_binary.operator, BinaryOperator::Sub));
-                        match (left_binary.left.as_ref(), left_binary.right.as_ref()) {
+                        match (left_binary.left, left_binary.right) {
                             (
                                 Expression::Literal(Literal::Integer(1)),
                                 Expression::Literal(Literal::Integer(2)),
@@ -244,7 +244,7 @@ fn test_left_associativity() {
                     _ => panic!("Expected left operand to be binary expression"),
                 }
                 // Right should be 3
-                match binary.right.as_ref() {
+                match binary.right {
                     Expression::Literal(Literal::Integer(3)) => {}
                     _ => panic!("Expected right operand to be 3"),
                 }
@@ -260,9 +260,9 @@ fn test_comparison_operators() {
 
     // Test: a == b
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::EqualEqual,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -286,11 +286,11 @@ fn test_logical_operators() {
 
     // Test: a && b || c
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::AmpAmp,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::PipePipe,
-        TokenType::Identifier("c".to_string()),
+        TokenType::Identifier("c".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -303,7 +303,7 @@ fn test_logical_operators() {
             Expression::Binary(binary) => {
                 assert!(matches!(binary.operator, BinaryOperator::Or));
                 // Left should be (a && b)
-                match binary.left.as_ref() {
+                match binary.left {
                     Expression::Binary(left_binary) => {
                         assert!(matches!(left_binary.operator, BinaryOperator::And));
                     }
@@ -340,8 +340,8 @@ fn test_complex_nested_expression() {
             Expression::Binary(binary) => {
                 assert!(matches!(binary.operator, BinaryOperator::Mul));
                 // Left should be (1 + 2)
-                match binary.left.as_ref() {
-                    Expression::Grouped(grouped) => match grouped.as_ref() {
+                match binary.left {
+                    Expression::Grouped(grouped) => match grouped {
                         Expression::Binary(inner_binary) => {
                             assert!(matches!(inner_binary.operator, BinaryOperator::Add));
                         }
@@ -350,7 +350,7 @@ fn test_complex_nested_expression() {
                     _ => panic!("Expected left operand to be grouped expression"),
                 }
                 // Right should be 3
-                match binary.right.as_ref() {
+                match binary.right {
                     Expression::Literal(Literal::Integer(3)) => {}
                     _ => panic!("Expected right operand to be 3"),
                 }
@@ -381,10 +381,10 @@ fn test_multiple_unary_operators() {
             Expression::Unary(unary) => {
                 assert!(matches!(unary.operator, UnaryOperator::Minus));
                 // Operand should be another unary expression
-                match unary.operand.as_ref() {
+                match unary.operand {
                     Expression::Unary(inner_unary) => {
                         assert!(matches!(inner_unary.operator, UnaryOperator::Minus));
-                        match inner_unary.operand.as_ref() {
+                        match inner_unary.operand {
                             Expression::Literal(Literal::Integer(42)) => {}
                             _ => panic!("Expected inner operand to be 42"),
                         }
@@ -464,9 +464,9 @@ fn test_qualified_identifier() {
 
     // Test: module.function
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("module".to_string()),
+        TokenType::Identifier("module".into()),
         TokenType::Dot,
-        TokenType::Identifier("function".to_string()),
+        TokenType::Identifier("function".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -478,7 +478,7 @@ fn test_qualified_identifier() {
         match expr {
             Expression::MemberAccess(member_access) => {
                 assert_eq!(member_access.member, "function");
-                match member_access.object.as_ref() {
+                match member_access.object {
                     Expression::Identifier(name) => assert_eq!(name, "module"),
                     _ => panic!("Expected object to be identifier 'module'"),
                 }
@@ -494,11 +494,11 @@ fn test_deeply_qualified_identifier() {
 
     // Test: std.collections.HashMap
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("std".to_string()),
+        TokenType::Identifier("std".into()),
         TokenType::Dot,
-        TokenType::Identifier("collections".to_string()),
+        TokenType::Identifier("collections".into()),
         TokenType::Dot,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -510,10 +510,10 @@ fn test_deeply_qualified_identifier() {
         match expr {
             Expression::MemberAccess(outer_access) => {
                 assert_eq!(outer_access.member, "HashMap");
-                match outer_access.object.as_ref() {
+                match outer_access.object {
                     Expression::MemberAccess(inner_access) => {
                         assert_eq!(inner_access.member, "collections");
-                        match inner_access.object.as_ref() {
+                        match inner_access.object {
                             Expression::Identifier(name) => assert_eq!(name, "std"),
                             _ => panic!("Expected base object to be 'std'"),
                         }
@@ -618,7 +618,7 @@ fn test_function_calls() {
 
     // Test: func()
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Eof,
@@ -632,7 +632,7 @@ fn test_function_calls() {
         match expr {
             Expression::Call(call) => {
                 assert_eq!(call.arguments.len(), 0);
-                match call.callee.as_ref() {
+                match call.callee {
                     Expression::Identifier(name) => assert_eq!(name, "func"),
                     _ => panic!("Expected callee to be identifier 'func'"),
                 }
@@ -643,7 +643,7 @@ fn test_function_calls() {
 
     // Test: func(1, 2)
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(1),
         TokenType::Comma,
@@ -680,9 +680,9 @@ fn test_member_access() {
 
     // Test: obj.field
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -694,7 +694,7 @@ fn test_member_access() {
         match expr {
             Expression::MemberAccess(member_access) => {
                 assert_eq!(member_access.member, "field");
-                match member_access.object.as_ref() {
+                match member_access.object {
                     Expression::Identifier(name) => assert_eq!(name, "obj"),
                     _ => panic!("Expected object to be identifier 'obj'"),
                 }
@@ -710,7 +710,7 @@ fn test_index_expressions() {
 
     // Test: arr[0]
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("arr".to_string()),
+        TokenType::Identifier("arr".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(0),
         TokenType::RightBracket,
@@ -724,11 +724,11 @@ fn test_index_expressions() {
     if let Ok(expr) = result {
         match expr {
             Expression::Index(index) => {
-                match index.object.as_ref() {
+                match index.object {
                     Expression::Identifier(name) => assert_eq!(name, "arr"),
                     _ => panic!("Expected object to be identifier 'arr'"),
                 }
-                match index.index.as_ref() {
+                match index.index {
                     Expression::Literal(Literal::Integer(0)) => {}
                     _ => panic!("Expected index to be integer 0"),
                 }
@@ -744,9 +744,9 @@ fn test_chained_postfix_operations() {
 
     // Test: obj.method()[0]
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBracket,
@@ -763,13 +763,13 @@ fn test_chained_postfix_operations() {
         match expr {
             Expression::Index(index) => {
                 // The object should be a function call
-                match index.object.as_ref() {
+                match index.object {
                     Expression::Call(call) => {
                         // The callee should be member access
-                        match call.callee.as_ref() {
+                        match call.callee {
                             Expression::MemberAccess(member_access) => {
                                 assert_eq!(member_access.member, "method");
-                                match member_access.object.as_ref() {
+                                match member_access.object {
                                     Expression::Identifier(name) => assert_eq!(name, "obj"),
                                     _ => panic!("Expected base object to be 'obj'"),
                                 }
@@ -829,10 +829,8 @@ fn test_identifier_patterns() {
     let arena = Arena::new();
 
     // Test simple identifier pattern
-    let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("x".to_string()),
-        TokenType::Eof,
-    ]);
+    let tokens =
+        VecTokenStream::from_token_types(vec![TokenType::Identifier("x".into()), TokenType::Eof]);
     let mut parser = PrattParser::new(&arena, tokens);
 
     let result = parser.parse_pattern();
@@ -851,10 +849,8 @@ fn test_wildcard_pattern() {
     let arena = Arena::new();
 
     // Test wildcard pattern
-    let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("_".to_string()),
-        TokenType::Eof,
-    ]);
+    let tokens =
+        VecTokenStream::from_token_types(vec![TokenType::Identifier("_".into()), TokenType::Eof]);
     let mut parser = PrattParser::new(&arena, tokens);
 
     let result = parser.parse_pattern();
@@ -874,7 +870,7 @@ fn test_data_class_patterns() {
 
     // Test empty data class pattern: Person {}
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -897,11 +893,11 @@ fn test_data_class_patterns() {
 
     // Test data class pattern with fields: Person { name, age }
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Comma,
-        TokenType::Identifier("age".to_string()),
+        TokenType::Identifier("age".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -931,13 +927,13 @@ fn test_data_class_pattern_with_bindings() {
 
     // Test: Person { name: n, age: 25 }
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Colon,
-        TokenType::Identifier("n".to_string()),
+        TokenType::Identifier("n".into()),
         TokenType::Comma,
-        TokenType::Identifier("age".to_string()),
+        TokenType::Identifier("age".into()),
         TokenType::Colon,
         TokenType::IntegerLiteral(25),
         TokenType::RightBrace,
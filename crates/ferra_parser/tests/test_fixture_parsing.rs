@@ -43,7 +43,7 @@ fn test_syntax_error_recovery() {
     // This exercises the file reading path for coverage
 
     // Create a parser with minimal valid tokens to test error handling
-    let tokens = vec![TokenType::Identifier("test".to_string()), TokenType::Eof];
+    let tokens = vec![TokenType::Identifier("test".into()), TokenType::Eof];
 
     let arena = Arena::new();
     let stream = VecTokenStream::from_token_types(tokens);
@@ -65,7 +65,7 @@ fn test_deep_nesting_parsing() {
     // Test reasonable nesting depth (5 levels) - this should work
     let tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("nested_func".to_string()),
+        TokenType::Identifier("nested_func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -125,7 +125,7 @@ fn test_comprehensive_pattern_parsing() {
     // Simplified pattern test to avoid complexity
     let pattern_tokens = vec![
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -146,7 +146,7 @@ fn test_comprehensive_statement_parsing() {
     // Very simple statement that won't cause infinite loops
     let statement_tokens = vec![
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -190,21 +190,21 @@ fn create_tokens_for_comprehensive_program() -> Vec<TokenType> {
     vec![
         // Function declaration
         TokenType::Fn,
-        TokenType::Identifier("comprehensive_test".to_string()),
+        TokenType::Identifier("comprehensive_test".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         // Variable declaration
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
         // Another variable declaration instead of return
         TokenType::Let,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Equal,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::Semicolon,
@@ -12,7 +12,8 @@ fn test_compilation_unit_creation() {
 
     // Test creating a compilation unit
     let compilation_unit = CompilationUnit {
-        items: vec![],
+        attributes: &[],
+        items: &[],
         span,
     };
 
@@ -23,7 +24,7 @@ fn test_compilation_unit_creation() {
 fn test_simple_program_tokens() {
     let tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -45,15 +46,15 @@ fn test_simple_program_tokens() {
 fn test_program_with_data_class_tokens() {
     let tokens = vec![
         TokenType::Data,
-        TokenType::Identifier("Point".to_string()),
+        TokenType::Identifier("Point".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ];
@@ -72,14 +73,14 @@ fn test_extern_block_tokens() {
         TokenType::StringLiteral("C".to_string()), // Need ABI string for extern blocks
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("printf".to_string()),
+        TokenType::Identifier("printf".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("format".to_string()),
+        TokenType::Identifier("format".into()),
         TokenType::Colon,
-        TokenType::Identifier("char".to_string()), // Simplified - should be *const char
+        TokenType::Identifier("char".into()), // Simplified - should be *const char
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -98,12 +99,12 @@ fn test_simple_program() {
     // Test parsing of simple complete programs like: fn main() { let x = 0; }
     let tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(0),
         TokenType::Semicolon,
@@ -141,29 +142,29 @@ fn test_program_with_functions() {
     let tokens = vec![
         // First function: fn add(a, b) { let result = a + b; }
         TokenType::Fn,
-        TokenType::Identifier("add".to_string()),
+        TokenType::Identifier("add".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Comma,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Plus,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Second function: fn main() { let x = 42; }
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -209,24 +210,24 @@ fn test_program_with_data_classes() {
     let tokens = vec![
         // Data class: data Point { x: int, y: int }
         TokenType::Data,
-        TokenType::Identifier("Point".to_string()),
+        TokenType::Identifier("Point".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBrace,
         // Function using the data class
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(0),
         TokenType::Semicolon,
@@ -274,24 +275,24 @@ fn test_program_with_extern_blocks() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("printf".to_string()),
+        TokenType::Identifier("printf".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("format".to_string()),
+        TokenType::Identifier("format".into()),
         TokenType::Colon,
-        TokenType::Identifier("char".to_string()),
+        TokenType::Identifier("char".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Function using extern
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(0),
         TokenType::Semicolon,
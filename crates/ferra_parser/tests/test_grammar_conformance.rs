@@ -0,0 +1,156 @@
+//! Grammar conformance tests
+//!
+//! Items and statements have one grammar, so:
+//! - Every fixture parses to the same AST through `Parser` and `ProgramParser`
+//! - A statement parses to the same AST at top level and inside a block
+
+use ferra_parser::{
+    ast::{Arena, Item},
+    test_utils::{fixtures, mock_tokens_from_source},
+    Feature, Parser, ParserOptions, ProgramParser, StatementParser,
+};
+
+const FIXTURE_CATEGORIES: [&str; 3] = ["valid", "edge_cases", "invalid"];
+
+/// The prefix that puts a snippet inside a function body
+const BODY_PREFIX: &str = "fn main() {\n";
+
+fn options() -> ParserOptions {
+    ParserOptions::new().with_feature(Feature::MacroDefinitions)
+}
+
+/// `source` parsed by `Parser`: the AST, or the error
+fn parse_with_parser(source: &str) -> Result<String, String> {
+    let arena = Arena::new();
+    Parser::new(&arena, mock_tokens_from_source(source))
+        .with_options(options())
+        .parse_compilation_unit()
+        .map(|unit| format!("{:?}", unit))
+        .map_err(|error| format!("{} at {:?}", error, error.span()))
+}
+
+/// `source` parsed by `ProgramParser`: the AST, or the first error
+fn parse_with_program_parser(source: &str) -> Result<String, String> {
+    let arena = Arena::new();
+    ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .with_options(options())
+        .parse_compilation_unit()
+        .map(|unit| format!("{:?}", unit))
+        .map_err(|errors| format!("{} at {:?}", errors[0], errors[0].span()))
+}
+
+/// `debug` without block scope depths, which depend on where a block is
+fn without_scope_depths(debug: &str) -> String {
+    let mut rest = debug;
+    let mut result = String::new();
+    while let Some(index) = rest.find("scope_depth: ") {
+        result.push_str(&rest[..index]);
+        rest = rest[index..].trim_start_matches("scope_depth: ");
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+    result.push_str(rest);
+    result
+}
+
+/// The first statement of `snippet` inside a function body, and `snippet`
+/// parsed as a statement on its own at the same offsets
+fn statement_in_both_positions(snippet: &str) -> (String, String) {
+    let arena = Arena::new();
+    let source = format!("{}{}\n}}", BODY_PREFIX, snippet);
+    let unit = ProgramParser::new(&arena, mock_tokens_from_source(&source))
+        .parse_compilation_unit()
+        .unwrap_or_else(|errors| panic!("{:?} failed in a block: {:?}", snippet, errors));
+    let in_block = match &unit.items[0] {
+        Item::FunctionDecl(function) => format!("{:?}", function.body.unwrap().statements[0]),
+        other => panic!("Expected function, got {:?}", other),
+    };
+
+    // Blank out the prefix so spans line up
+    let padded = format!("{}\n{}", " ".repeat(BODY_PREFIX.len() - 1), snippet);
+    let on_its_own = StatementParser::new(&arena, mock_tokens_from_source(&padded))
+        .parse_statement()
+        .map(|statement| format!("{:?}", statement))
+        .unwrap_or_else(|error| panic!("{:?} failed on its own: {}", snippet, error));
+
+    (in_block, on_its_own)
+}
+
+#[test]
+fn test_fixtures_parse_identically_through_every_entry_point() {
+    let mut checked = 0;
+    for category in FIXTURE_CATEGORIES {
+        for name in fixtures::list_fixtures(category) {
+            let source = fixtures::load_fixture(category, &name);
+            assert_eq!(
+                parse_with_parser(&source),
+                parse_with_program_parser(&source),
+                "{}/{} parses differently through Parser and ProgramParser",
+                category,
+                name
+            );
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "No fixtures found");
+}
+
+#[test]
+fn test_snippets_parse_identically_through_every_entry_point() {
+    let snippets = [
+        "fn add<T>(a: T, b: T) -> T where T: Add { return a + b }",
+        "async fn fetch(url: String) -> Result<String, Error> { let body = get(url).await? }",
+        "data Pair<T> where T: Clone { first: T, second: T }",
+        "extern { fn abs(x: i32) -> i32; }",
+        "let limit: u64 = 10 * 1024\nfn main() { if total > limit && !done { log(total) } }",
+        "#![allow(dead_code)]\n\n#[inline]\npub fn id(x) { x }",
+        "macro twice { ($e:expr) => { $e; $e } }",
+        "fn broken( { }",
+    ];
+    for snippet in snippets {
+        assert_eq!(
+            parse_with_parser(snippet),
+            parse_with_program_parser(snippet),
+            "{:?} parses differently through Parser and ProgramParser",
+            snippet
+        );
+    }
+}
+
+#[test]
+fn test_statements_parse_identically_at_top_level_and_in_blocks() {
+    let snippets = [
+        "let scale: f64 = base * 2.0 + offset(1, 2)",
+        "var items: Vec<String> = load()",
+        "if count > limit && !done { return count * 2 } else if count == 0 { stop() } else { wait() }",
+        "while queue.len() > 0 { process(queue.pop()) }",
+        "for item in items.iter() { total = total + item.price }",
+        "return compute(a, b) * 2;",
+        "{ let x = 1\n x + 1 }",
+        "#[allow(unused)] let unused = 0",
+        "println!(\"{}\", value);",
+        "vec! { 1, 2 }",
+    ];
+    for snippet in snippets {
+        let (in_block, on_its_own) = statement_in_both_positions(snippet);
+        assert_eq!(
+            without_scope_depths(&in_block),
+            without_scope_depths(&on_its_own),
+            "{:?}",
+            snippet
+        );
+    }
+}
+
+#[test]
+fn test_block_statements_use_the_full_grammar() {
+    // Types and conditions used to be simplified inside blocks
+    let (in_block, _) = statement_in_both_positions("let pairs: [(i32, String)] = []");
+    assert!(in_block.contains("Tuple"), "{}", in_block);
+    assert!(in_block.contains("String"), "{}", in_block);
+
+    let (in_block, _) = statement_in_both_positions("if a.ready() { go() }");
+    assert!(in_block.contains("MemberAccess"), "{}", in_block);
+
+    let (in_block, _) = statement_in_both_positions("if a { } else if b { }");
+    assert!(in_block.contains("else_block: Some"), "{}", in_block);
+}
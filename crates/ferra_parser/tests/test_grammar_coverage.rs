@@ -232,7 +232,7 @@ fn convert_token(token: LexerToken) -> TokenType {
         TokenKind::Continue => TokenType::Continue,
         TokenKind::True => TokenType::BooleanLiteral(true),
         TokenKind::False => TokenType::BooleanLiteral(false),
-        TokenKind::Identifier => TokenType::Identifier(token.lexeme.clone()),
+        TokenKind::Identifier => TokenType::Identifier(token.lexeme.as_str().into()),
         TokenKind::IntegerLiteral => match token.literal {
             Some(LiteralValue::Integer(i)) => TokenType::IntegerLiteral(i),
             _ => TokenType::IntegerLiteral(0),
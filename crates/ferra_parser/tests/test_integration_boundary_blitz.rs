@@ -34,19 +34,19 @@ fn test_complex_cross_module_integration() {
     // Test complex function with generics and constraints
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_function".to_string()),
+        TokenType::Identifier("complex_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -81,25 +81,25 @@ fn test_deep_nested_block_boundaries() {
     // Test deeply nested blocks with control flow
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("nested_function".to_string()),
+        TokenType::Identifier("nested_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("outer".to_string()),
+        TokenType::Identifier("outer".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(1),
         TokenType::Semicolon,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("middle".to_string()),
+        TokenType::Identifier("middle".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(2),
         TokenType::Semicolon,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("inner".to_string()),
+        TokenType::Identifier("inner".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(3),
         TokenType::Semicolon,
@@ -137,25 +137,25 @@ fn test_complex_type_system_integration() {
     // Test complex function signatures with multiple type parameters
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_types".to_string()),
+        TokenType::Identifier("complex_types".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("input".to_string()),
+        TokenType::Identifier("input".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftBracket,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightBracket,
         TokenType::LeftBrace,
         TokenType::RightBrace,
@@ -191,17 +191,17 @@ fn test_pratt_parser_precedence_boundaries() {
     // Test complex operator precedence chains
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Plus,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Star,
-        TokenType::Identifier("c".to_string()),
+        TokenType::Identifier("c".into()),
         TokenType::Minus,
-        TokenType::Identifier("d".to_string()),
+        TokenType::Identifier("d".into()),
         TokenType::Slash,
-        TokenType::Identifier("e".to_string()),
+        TokenType::Identifier("e".into()),
         TokenType::Semicolon,
         TokenType::Eof,
     ]);
@@ -235,17 +235,17 @@ fn test_statement_parser_control_flow_integration() {
         TokenType::BooleanLiteral(true),
         TokenType::LeftBrace,
         TokenType::If,
-        TokenType::Identifier("condition".to_string()),
+        TokenType::Identifier("condition".into()),
         TokenType::LeftBrace,
         TokenType::For,
-        TokenType::Identifier("item".to_string()),
+        TokenType::Identifier("item".into()),
         TokenType::In,
-        TokenType::Identifier("collection".to_string()),
+        TokenType::Identifier("collection".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Equal,
-        TokenType::Identifier("item".to_string()),
+        TokenType::Identifier("item".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::RightBrace,
@@ -279,16 +279,16 @@ fn test_generic_parser_constraint_satisfaction() {
     // Test generic functions with constraints
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("generic_function".to_string()),
+        TokenType::Identifier("generic_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -322,34 +322,34 @@ fn test_program_parser_large_scale_integration() {
     let tokens = VecTokenStream::from_token_types(vec![
         // First function
         TokenType::Fn,
-        TokenType::Identifier("function_one".to_string()),
+        TokenType::Identifier("function_one".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(1),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Second function
         TokenType::Fn,
-        TokenType::Identifier("function_two".to_string()),
+        TokenType::Identifier("function_two".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::RightBrace,
         // Third function with complex body
         TokenType::Fn,
-        TokenType::Identifier("function_three".to_string()),
+        TokenType::Identifier("function_three".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -404,7 +404,7 @@ fn test_parser_boundary_limits() {
         // Empty function
         vec![
             TokenType::Fn,
-            TokenType::Identifier("empty".to_string()),
+            TokenType::Identifier("empty".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -414,7 +414,7 @@ fn test_parser_boundary_limits() {
         // Single statement
         vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Semicolon,
@@ -430,7 +430,7 @@ fn test_parser_boundary_limits() {
         // Missing semicolon (should error)
         vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Eof,
@@ -438,7 +438,7 @@ fn test_parser_boundary_limits() {
         // Incomplete function (should error)
         vec![
             TokenType::Fn,
-            TokenType::Identifier("incomplete".to_string()),
+            TokenType::Identifier("incomplete".into()),
             TokenType::LeftParen,
             TokenType::Eof,
         ],
@@ -0,0 +1,89 @@
+//! Attribute, lifetime, extern and where-clause syntax lexed from source
+//!
+//! These constructs used to need hand-built token vectors because the lexer
+//! never produced `#`, `@`, `'`, `extern`, `static` or `where` tokens.
+
+use ferra_parser::{
+    ast::{print_identifier, Arena, CompilationUnit, Expression, ExternItem, Item, Pattern},
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    ProgramParser, StatementParser,
+};
+
+fn parse_program<'a>(arena: &'a Arena, source: &str) -> CompilationUnit<'a> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser.parse_compilation_unit().unwrap().clone()
+}
+
+#[test]
+fn test_attributes_from_source() {
+    let arena = Arena::new();
+    let unit = parse_program(
+        &arena,
+        "#[inline] #[doc = \"first\"] pub fn first(items: [Int]) -> Int { return items[0]; }",
+    );
+    let Item::FunctionDecl(function) = &unit.items[0] else {
+        panic!("Expected function, got {:?}", unit.items[0]);
+    };
+    assert_eq!(function.name, "first");
+    assert_eq!(function.attributes.len(), 2);
+    assert_eq!(function.attributes[0].name.segments, vec!["inline"]);
+    assert_eq!(function.attributes[1].value_str(), Some("first"));
+}
+
+#[test]
+fn test_lifetimes_and_where_clause_from_source() {
+    let arena = Arena::new();
+    let source = "fn first<'a, T: Clone>(items: [T]) -> T where T: Display;";
+    let mut parser = StatementParser::new(&arena, mock_tokens_from_source(source));
+    let Item::FunctionDecl(function) = parser.parse_item().unwrap() else {
+        panic!("Expected function");
+    };
+    let generics = function.generics.as_ref().unwrap();
+    assert_eq!(generics.params[0].name, "'a");
+    assert!(generics.params[0].is_lifetime);
+    assert_eq!(generics.params[1].name, "T");
+    assert_eq!(generics.params[1].bounds[0].trait_name, "Clone");
+    let where_clause = generics.where_clause.as_ref().unwrap();
+    assert_eq!(where_clause.constraints[0].type_name, "T");
+}
+
+#[test]
+fn test_extern_block_from_source() {
+    let arena = Arena::new();
+    let unit = parse_program(
+        &arena,
+        r#"extern "C" { fn abs(x: Int) -> Int; static ERRNO: Int; }"#,
+    );
+    let Item::ExternBlock(block) = &unit.items[0] else {
+        panic!("Expected extern block, got {:?}", unit.items[0]);
+    };
+    assert_eq!(block.abi, "C");
+    assert!(matches!(&block.items[0], ExternItem::Function(f) if f.name == "abs"));
+    assert!(matches!(&block.items[1], ExternItem::Variable(v) if v.name == "ERRNO"));
+}
+
+#[test]
+fn test_binding_pattern_from_source() {
+    let arena = Arena::new();
+    let source = "match n { small @ 1..=9 => small, _ => 0 }";
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    let Expression::Match(match_expr) = parser.parse_expression(0).unwrap() else {
+        panic!("Expected match expression");
+    };
+    match &match_expr.arms[0].pattern {
+        Pattern::Binding(binding) => {
+            assert_eq!(binding.name, "small");
+            assert!(matches!(binding.pattern, Pattern::Range(range) if range.inclusive));
+        }
+        other => panic!("Expected binding pattern, got {:?}", other),
+    }
+    assert!(matches!(match_expr.arms[1].pattern, Pattern::Wildcard));
+}
+
+#[test]
+fn test_reserved_keywords_print_as_raw_identifiers() {
+    assert_eq!(print_identifier("loop"), "r#loop");
+    assert_eq!(print_identifier("static"), "r#static");
+    assert_eq!(print_identifier("looper"), "looper");
+}
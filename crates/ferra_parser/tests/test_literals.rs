@@ -0,0 +1,83 @@
+//! Literal tests
+//!
+//! Tests for literals that flow from the lexer through the token bridge into
+//! the AST:
+//! - Character and byte literals, including escapes
+//! - Byte strings
+//! - Integers too wide for `i64`, with their original radix
+
+use ferra_parser::{
+    ast::{Arena, Expression, Literal, Pattern},
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+};
+
+fn literal(source: &str) -> Literal {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Literal(literal) => literal.clone(),
+        other => panic!("Expected literal, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_char_and_byte_literals() {
+    assert!(matches!(literal("'x'"), Literal::Char('x')));
+    assert!(matches!(literal("'\\n'"), Literal::Char('\n')));
+    assert!(matches!(literal("b'a'"), Literal::Byte(b'a')));
+    assert!(matches!(literal("b'\\x7f'"), Literal::Byte(0x7f)));
+}
+
+#[test]
+fn test_byte_string_literal() {
+    match literal("b\"GET \\r\\n\"") {
+        Literal::ByteString(bytes) => assert_eq!(bytes, b"GET \r\n"),
+        other => panic!("Expected byte string, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_wide_integer_literals_keep_radix() {
+    assert!(matches!(
+        literal("9223372036854775807"),
+        Literal::Integer(i64::MAX)
+    ));
+
+    match literal("0xFFFF_FFFF_FFFF_FFFF") {
+        Literal::WideInteger(value) => {
+            assert_eq!(value.radix, 16);
+            assert_eq!(value.digits, "ffffffffffffffff");
+            assert_eq!(value.to_u128(), Some(u64::MAX as u128));
+        }
+        other => panic!("Expected wide integer, got {:?}", other),
+    }
+
+    match literal("340282366920938463463374607431768211456") {
+        Literal::WideInteger(value) => {
+            assert_eq!(value.radix, 10);
+            // 2^128 does not fit u128, but the digits are preserved
+            assert_eq!(value.to_u128(), None);
+        }
+        other => panic!("Expected wide integer, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_literals_in_binary_expressions_and_patterns() {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("c == 'a'"));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Binary(binary) => assert!(matches!(
+            binary.right,
+            Expression::Literal(Literal::Char('a'))
+        )),
+        other => panic!("Expected comparison, got {:?}", other),
+    }
+
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("b'\\0'"));
+    assert!(matches!(
+        parser.parse_pattern().unwrap(),
+        Pattern::Literal(Literal::Byte(0))
+    ));
+}
@@ -0,0 +1,238 @@
+//! Declarative macro definition tests
+//!
+//! Tests for `macro name { (matcher) => { transcriber }; ... }` items:
+//! - Fragment specifiers `expr`, `ident`, `ty`, `pat`, `block` and `tt`
+//! - `$( ... ),*` repetitions and their nesting depth
+//! - Definition-time errors for unbound and mis-nested metavariables
+
+use ferra_parser::{
+    ast::{
+        Arena, CompilationUnit, FragmentKind, GroupDelimiter, Item, MacroDefinition, MacroMatcher,
+        MacroTranscriber, RepetitionKind,
+    },
+    program::parser::ProgramParser,
+    statement::parser::StatementParser,
+    test_utils::mock_tokens_from_source,
+    token::{TokenStream, TokenType, VecTokenStream},
+};
+
+/// Parse a program, reporting the first error as its message
+fn parse<'a>(arena: &'a Arena, source: &str) -> Result<CompilationUnit<'a>, String> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser
+        .parse_compilation_unit()
+        .cloned()
+        .map_err(|errors| errors[0].to_string())
+}
+
+fn parse_macro<'a>(arena: &'a Arena, source: &str) -> MacroDefinition<'a> {
+    match parse(arena, source).unwrap().items.first() {
+        Some(Item::MacroDefinition(macro_def)) => macro_def.clone(),
+        other => panic!("Expected macro definition, got {:?}", other),
+    }
+}
+
+fn parse_error(source: &str) -> String {
+    let arena = Arena::new();
+    parse(&arena, source).unwrap_err()
+}
+
+#[test]
+fn test_macro_item_with_fragments() {
+    let arena = Arena::new();
+    let macro_def = parse_macro(&arena, "macro square { ($x:expr) => { $x * $x } }");
+
+    assert_eq!(macro_def.name, "square");
+    assert_eq!(macro_def.rules.len(), 1);
+    let rule = &macro_def.rules[0];
+    match rule.matcher {
+        [MacroMatcher::Fragment { name, kind, .. }] => {
+            assert_eq!(name, "x");
+            assert_eq!(*kind, FragmentKind::Expr);
+        }
+        other => panic!("Expected a single fragment, got {:?}", other),
+    }
+    assert_eq!(rule.transcriber.len(), 3);
+    assert!(matches!(
+        &rule.transcriber[0],
+        MacroTranscriber::Variable { name, .. } if name == "x"
+    ));
+    assert!(matches!(
+        &rule.transcriber[1],
+        MacroTranscriber::Token(token) if token.token_type == TokenType::Star
+    ));
+}
+
+#[test]
+fn test_all_fragment_kinds() {
+    let arena = Arena::new();
+    let macro_def = parse_macro(&arena,
+        "macro all { ($e:expr, $i:ident, $t:ty, $p:pat, $b:block, $tt:tt) => { $e $i $t $p $b $tt } }",
+    );
+
+    let kinds: Vec<_> = macro_def.rules[0]
+        .matcher
+        .iter()
+        .filter_map(|matcher| match matcher {
+            MacroMatcher::Fragment { kind, .. } => Some(*kind),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            FragmentKind::Expr,
+            FragmentKind::Ident,
+            FragmentKind::Ty,
+            FragmentKind::Pat,
+            FragmentKind::Block,
+            FragmentKind::Tt,
+        ]
+    );
+}
+
+#[test]
+fn test_multiple_rules_and_nested_groups() {
+    let arena = Arena::new();
+    let macro_def = parse_macro(
+        &arena,
+        "macro maybe { () => { none() }; ([$x:expr]) => { some($x) } }",
+    );
+
+    assert_eq!(macro_def.rules.len(), 2);
+    assert!(macro_def.rules[0].matcher.is_empty());
+    match macro_def.rules[1].matcher {
+        [MacroMatcher::Group {
+            delimiter,
+            matchers,
+            ..
+        }] => {
+            assert_eq!(*delimiter, GroupDelimiter::Brackets);
+            assert!(matches!(matchers[0], MacroMatcher::Fragment { .. }));
+        }
+        other => panic!("Expected bracketed group, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_repetition_with_separator() {
+    let arena = Arena::new();
+    let macro_def = parse_macro(&arena, "macro sum { ($($x:expr),+) => { 0 $(+ $x)* } }");
+
+    let rule = &macro_def.rules[0];
+    match rule.matcher {
+        [MacroMatcher::Repetition {
+            matchers,
+            separator,
+            kind,
+            ..
+        }] => {
+            assert_eq!(matchers.len(), 1);
+            assert_eq!(
+                separator.as_ref().map(|t| t.token_type.clone()),
+                Some(TokenType::Comma)
+            );
+            assert_eq!(*kind, RepetitionKind::OneOrMore);
+        }
+        other => panic!("Expected repetition, got {:?}", other),
+    }
+    assert!(matches!(
+        &rule.transcriber[1],
+        MacroTranscriber::Repetition {
+            separator: None,
+            kind: RepetitionKind::ZeroOrMore,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_nested_repetition_depth() {
+    let arena = Arena::new();
+    // Each `$x` is bound two repetitions deep and used two repetitions deep
+    let source = "macro rows { ($([$($x:expr),*]);*) => { $($(cell($x);)*)* } }";
+    assert!(parse(&arena, source).is_ok());
+
+    let error = parse_error("macro rows { ($([$($x:expr),*]);*) => { $(cell($x);)* } }");
+    assert!(error.contains("still repeating"), "{}", error);
+}
+
+#[test]
+fn test_unbound_metavariable_is_rejected() {
+    let error = parse_error("macro twice { ($x:expr) => { $y + $y } }");
+    assert!(error.contains("unknown macro variable `$y`"), "{}", error);
+
+    // Inside a repetition the variable is still reported as unbound
+    let error = parse_error("macro all { ($($x:expr),*) => { $(f($y);)* } }");
+    assert!(error.contains("unknown macro variable `$y`"), "{}", error);
+    let error = parse_error("macro all { ($($x:expr),*) => { $(f($x, $y);)* } }");
+    assert!(error.contains("unknown macro variable `$y`"), "{}", error);
+}
+
+#[test]
+fn test_repetition_without_repeating_variable_is_rejected() {
+    let error = parse_error("macro bad { ($x:expr) => { $(print($x))* } }");
+    assert!(error.contains("no metavariables that repeat"), "{}", error);
+}
+
+#[test]
+fn test_invalid_matchers_are_rejected() {
+    let missing = parse_error("macro bad { ($x) => { $x } }");
+    assert!(
+        missing.contains("missing fragment specifier"),
+        "{}",
+        missing
+    );
+
+    let unknown = parse_error("macro bad { ($x:item) => { $x } }");
+    assert!(
+        unknown.contains("unknown fragment specifier"),
+        "{}",
+        unknown
+    );
+
+    let duplicate = parse_error("macro bad { ($x:expr, $x:expr) => { $x } }");
+    assert!(
+        duplicate.contains("duplicate macro variable"),
+        "{}",
+        duplicate
+    );
+
+    let operator = parse_error("macro bad { ($($x:expr),) => { $($x)* } }");
+    assert!(operator.contains("repetition operator"), "{}", operator);
+}
+
+#[test]
+fn test_macro_attributes_and_statement_parser() {
+    // #[export] macro id { ($x:tt) => { $x } }
+    let mut token_types = vec![
+        TokenType::Hash,
+        TokenType::LeftBracket,
+        TokenType::Identifier("export".into()),
+        TokenType::RightBracket,
+    ];
+    let mut source_tokens = mock_tokens_from_source("macro id { ($x:tt) => { $x } }");
+    while !source_tokens.is_at_end() {
+        token_types.push(source_tokens.consume().token_type);
+    }
+    token_types.push(TokenType::Eof);
+    let arena = Arena::new();
+    let mut parser = ProgramParser::new(&arena, VecTokenStream::from_token_types(token_types));
+    match &parser.parse_compilation_unit().unwrap().items[0] {
+        Item::MacroDefinition(macro_def) => {
+            assert_eq!(macro_def.attributes.len(), 1);
+            assert_eq!(macro_def.attributes[0].name, "export");
+        }
+        other => panic!("Expected macro definition, got {:?}", other),
+    }
+
+    let arena = Arena::new();
+    let mut parser = StatementParser::new(
+        &arena,
+        mock_tokens_from_source("macro id { ($x:tt) => { $x } }"),
+    );
+    assert!(matches!(
+        parser.parse_item().unwrap(),
+        Item::MacroDefinition(macro_def) if macro_def.name == "id"
+    ));
+}
@@ -0,0 +1,360 @@
+//! Macro expansion tests
+//!
+//! Tests for expanding invocations of user-defined `macro` items:
+//! - Re-parsing in expression, statement, item, pattern and type positions,
+//!   including pattern and type invocations written in source
+//! - Repetitions and precedence of substituted expressions
+//! - Hygiene for macro-introduced bindings
+//! - Expansion backtraces in error spans
+
+use ferra_parser::{
+    ast::{Arena, BinaryOperator, CompilationUnit, Expression, Item, Pattern, Statement, Type},
+    expand::{expand_macros, hygienic_name, Expansion, MacroExpander, MacroPosition},
+    pratt::PrattParser,
+    program::parser::ProgramParser,
+    test_utils::mock_tokens_from_source,
+};
+
+fn parse<'a>(arena: &'a Arena, source: &str) -> CompilationUnit<'a> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser.parse_compilation_unit().unwrap().clone()
+}
+
+/// Statements of `fn main` after expansion
+fn expanded_main<'a>(arena: &'a Arena, source: &str) -> Vec<Statement<'a>> {
+    let unit = expand_macros(arena, &parse(arena, source)).unwrap();
+    unit.items
+        .iter()
+        .find_map(|item| match item {
+            Item::FunctionDecl(function) if function.name == "main" => {
+                Some(function.body.unwrap().statements.to_vec())
+            }
+            _ => None,
+        })
+        .expect("fn main")
+}
+
+/// Parse `name!(...)` as an invocation
+fn invocation(arena: &Arena, source: &str) -> ferra_parser::ast::MacroInvocation {
+    let mut parser = PrattParser::new(arena, mock_tokens_from_source(source));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Macro(invocation) => invocation.clone(),
+        other => panic!("Expected macro invocation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_expression_position_keeps_argument_precedence() {
+    let arena = Arena::new();
+    let statements = expanded_main(
+        &arena,
+        "macro square { ($x:expr) => { $x * $x } } fn main() { let y = square!(a + 1); }",
+    );
+
+    match &statements[0] {
+        Statement::VariableDecl(variable) => match variable.initializer.as_ref().unwrap() {
+            Expression::Binary(binary) => {
+                assert!(matches!(binary.operator, BinaryOperator::Mul));
+                assert!(matches!(binary.left, Expression::Grouped(_)));
+                assert!(matches!(binary.right, Expression::Grouped(_)));
+            }
+            other => panic!("Expected multiplication, got {:?}", other),
+        },
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_statement_position_splices_repetition() {
+    let arena = Arena::new();
+    let statements = expanded_main(&arena,
+        "macro log_all { ($($e:expr),*) => { $(print($e);)* } } fn main() { log_all!(a, b, c); done(); }",
+    );
+
+    assert_eq!(statements.len(), 4);
+    for statement in &statements[..3] {
+        assert!(matches!(
+            statement,
+            Statement::Expression(Expression::Call(call))
+                if matches!(call.callee, Expression::Identifier(name) if name == "print")
+        ));
+    }
+}
+
+#[test]
+fn test_macro_bindings_do_not_capture_caller_names() {
+    let arena = Arena::new();
+    let statements = expanded_main(
+        &arena,
+        "macro with_tmp { ($e:expr) => { let tmp = 1; $e + tmp } } fn main() { with_tmp!(tmp); }",
+    );
+
+    let renamed = hygienic_name("tmp", 1);
+    match &statements[0] {
+        Statement::VariableDecl(variable) => assert_eq!(variable.name, renamed),
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+    match &statements[1] {
+        Statement::Expression(Expression::Binary(binary)) => {
+            // The caller's `tmp` is untouched; the macro's `tmp` is renamed
+            assert!(matches!(binary.left, Expression::Identifier(name) if name == "tmp"));
+            assert!(matches!(binary.right, Expression::Identifier(name) if *name == renamed));
+        }
+        other => panic!("Expected addition, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_hygiene_leaves_fields_and_methods_alone() {
+    let arena = Arena::new();
+    let statements = expanded_main(
+        &arena,
+        "macro m { ($v:expr) => { let len = $v.len(); len + $v.len } } fn main() { m!(xs); }",
+    );
+
+    let renamed = hygienic_name("len", 1);
+    match &statements[0] {
+        Statement::VariableDecl(variable) => {
+            assert_eq!(variable.name, renamed);
+            let call = match variable.initializer.unwrap() {
+                Expression::Call(call) => call,
+                other => panic!("Expected method call, got {:?}", other),
+            };
+            assert!(matches!(
+                call.callee,
+                Expression::MemberAccess(access) if access.member == "len"
+            ));
+        }
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+    match &statements[1] {
+        Statement::Expression(Expression::Binary(binary)) => {
+            assert!(matches!(binary.left, Expression::Identifier(name) if *name == renamed));
+            assert!(matches!(
+                binary.right,
+                Expression::MemberAccess(access) if access.member == "len"
+            ));
+        }
+        other => panic!("Expected addition, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_expansion() {
+    let arena = Arena::new();
+    let statements = expanded_main(&arena,
+        "macro double { ($x:expr) => { $x + $x } } macro quad { ($x:expr) => { double!(double!($x)) } } fn main() { quad!(n); }",
+    );
+
+    match &statements[0] {
+        Statement::Expression(Expression::Binary(binary)) => {
+            assert!(matches!(binary.operator, BinaryOperator::Add));
+            // (double!(n)) + (double!(n)), with the inner invocations expanded
+            match binary.left {
+                Expression::Grouped(inner) => {
+                    assert!(matches!(inner, Expression::Binary(_)))
+                }
+                other => panic!("Expected grouped expansion, got {:?}", other),
+            }
+        }
+        other => panic!("Expected expanded addition, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_error_inside_expansion_has_backtrace() {
+    let arena = Arena::new();
+    let unit = parse(&arena,
+        "macro broken { () => { 1 + } } macro outer { () => { broken!() } } fn main() { outer!(); }",
+    );
+
+    let error = expand_macros(&arena, &unit).unwrap_err();
+    let backtrace: Vec<_> = error
+        .span()
+        .expansion_backtrace()
+        .iter()
+        .map(|expansion| expansion.macro_name.as_str())
+        .collect();
+    assert_eq!(backtrace, vec!["broken", "outer"]);
+
+    let diagnostic = error.format_diagnostic(None);
+    assert!(
+        diagnostic.contains("in expansion of `broken!`"),
+        "{}",
+        diagnostic
+    );
+    assert!(
+        diagnostic.contains("in expansion of `outer!`"),
+        "{}",
+        diagnostic
+    );
+}
+
+#[test]
+fn test_pattern_and_type_positions_from_source() {
+    let arena = Arena::new();
+    let unit = expand_macros(
+        &arena,
+        &parse(
+            &arena,
+            "macro both { ($p:pat) => { [$p, $p] } } macro pair { ($t:tt) => { ($t, $t) } } \
+             fn sum(points: Vec<pair!(Int)>) -> pair!(Int) { } \
+             fn main() { let p: pair!(Int) = q; let r = match p { both!(0) => 1, _ => 0 }; }",
+        ),
+    )
+    .unwrap();
+
+    let functions: Vec<_> = unit
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::FunctionDecl(function) => Some(function),
+            _ => None,
+        })
+        .collect();
+    match functions[0].parameters[0].param_type {
+        Type::Path(path) => {
+            assert!(matches!(path.generic_args(), [Type::Tuple(types)] if types.len() == 2))
+        }
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+    assert!(matches!(functions[0].return_type, Some(Type::Tuple(_))));
+
+    let statements = functions[1].body.unwrap().statements;
+    match &statements[0] {
+        Statement::VariableDecl(variable) => {
+            assert!(matches!(variable.var_type, Some(Type::Tuple(types)) if types.len() == 2))
+        }
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+    match &statements[1] {
+        Statement::VariableDecl(variable) => match variable.initializer {
+            Some(Expression::Match(match_expr)) => assert!(matches!(
+                match_expr.arms[0].pattern,
+                Pattern::Slice(slice) if slice.prefix.len() == 2
+            )),
+            other => panic!("Expected match expression, got {:?}", other),
+        },
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_pattern_and_type_invocations_parse() {
+    let arena = Arena::new();
+    let unit = parse(
+        &arena,
+        "fn main() { let x: T!() = 1; let y = match x { p!() => 1, q![a] if a => 2, _ => 0 }; }",
+    );
+    let Some(Item::FunctionDecl(function)) = unit.items.first() else {
+        panic!("Expected function, got {:?}", unit.items);
+    };
+    let statements = function.body.unwrap().statements;
+    match &statements[0] {
+        Statement::VariableDecl(variable) => assert!(matches!(
+            variable.var_type,
+            Some(Type::Macro(invocation)) if invocation.name == "T"
+        )),
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+    match &statements[1] {
+        Statement::VariableDecl(variable) => match variable.initializer {
+            Some(Expression::Match(match_expr)) => {
+                assert!(matches!(
+                    match_expr.arms[0].pattern,
+                    Pattern::Macro(invocation) if invocation.name == "p"
+                ));
+                assert!(matches!(
+                    match_expr.arms[1].pattern,
+                    Pattern::Guard(guard)
+                        if matches!(guard.pattern, Pattern::Macro(invocation) if invocation.name == "q")
+                ));
+            }
+            other => panic!("Expected match expression, got {:?}", other),
+        },
+        other => panic!("Expected variable declaration, got {:?}", other),
+    }
+
+    // Undefined macros are left in place
+    let expanded = expand_macros(&arena, &unit).unwrap();
+    assert_eq!(expanded.items.len(), 1);
+}
+
+#[test]
+fn test_call_site_spans_the_whole_invocation() {
+    let arena = Arena::new();
+    let source = "macro broken { ($x:expr) => { $x + } } fn main() { let y = broken![a * 2]; }";
+    let unit = parse(&arena, source);
+
+    let error = expand_macros(&arena, &unit).unwrap_err();
+    let backtrace = error.span().expansion_backtrace();
+    let call_site = &backtrace[0].call_site;
+    assert_eq!(&source[call_site.start..call_site.end], "broken![a * 2]");
+    assert_eq!(call_site.column, source.find("broken![").unwrap() + 1);
+}
+
+#[test]
+fn test_unmatched_invocation_and_recursion_limit() {
+    let arena = Arena::new();
+    let unmatched = parse(&arena, "macro one { (1) => { 1 } } fn main() { one!(2); }");
+    let error = expand_macros(&arena, &unmatched).unwrap_err().to_string();
+    assert!(
+        error.contains("no rules of macro `one` matched"),
+        "{}",
+        error
+    );
+
+    let forever = parse(
+        &arena,
+        "macro forever { () => { forever!() } } fn main() { forever!(); }",
+    );
+    let error = expand_macros(&arena, &forever).unwrap_err().to_string();
+    assert!(error.contains("recursion limit"), "{}", error);
+}
+
+#[test]
+fn test_undefined_macros_are_left_untouched() {
+    let arena = Arena::new();
+    let statements = expanded_main(&arena, "fn main() { trace!(a); }");
+    assert!(matches!(
+        &statements[0],
+        Statement::Expression(Expression::Macro(invocation)) if invocation.name == "trace"
+    ));
+}
+
+#[test]
+fn test_item_pattern_and_type_positions() {
+    let arena = Arena::new();
+    let unit = parse(&arena,
+        "macro getter { ($name:ident) => { fn $name() { } } } macro pair { ($t:tt) => { ($t, $t) } } macro both { ($p:pat) => { [$p, $p] } }",
+    );
+    let mut expander = MacroExpander::from_compilation_unit(&arena, &unit);
+
+    match expander
+        .expand_invocation(&invocation(&arena, "getter!(value)"), MacroPosition::Item)
+        .unwrap()
+    {
+        Expansion::Items(items) => {
+            assert!(
+                matches!(&items[..], [Item::FunctionDecl(function)] if function.name == "value")
+            );
+        }
+        other => panic!("Expected items, got {:?}", other),
+    }
+
+    match expander
+        .expand_invocation(&invocation(&arena, "pair!(Int)"), MacroPosition::Type)
+        .unwrap()
+    {
+        Expansion::Type(Type::Tuple(types)) => assert_eq!(types.len(), 2),
+        other => panic!("Expected tuple type, got {:?}", other),
+    }
+
+    match expander
+        .expand_invocation(&invocation(&arena, "both!(x)"), MacroPosition::Pattern)
+        .unwrap()
+    {
+        Expansion::Pattern(Pattern::Slice(slice)) => assert_eq!(slice.prefix.len(), 2),
+        other => panic!("Expected slice pattern, got {:?}", other),
+    }
+}
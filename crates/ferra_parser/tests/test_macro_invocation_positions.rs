@@ -0,0 +1,150 @@
+//! Macro invocation position tests
+//!
+//! Tests for macro invocations outside expression position:
+//! - Item-position invocations with `()`, `[]` and `{}` delimiters
+//! - Statement termination: the brace form needs no semicolon
+//! - Item macros expanding into several items
+
+use ferra_parser::{
+    ast::{Arena, CompilationUnit, Expression, GroupDelimiter, Item, Statement},
+    block::BlockParser,
+    expand::expand_macros,
+    program::parser::ProgramParser,
+    statement::StatementParser,
+    test_utils::mock_tokens_from_source,
+};
+
+fn parse<'a>(arena: &'a Arena, source: &str) -> Result<CompilationUnit<'a>, String> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser
+        .parse_compilation_unit()
+        .cloned()
+        .map_err(|errors| errors[0].to_string())
+}
+
+/// Name and delimiter of each item-position invocation
+fn item_macros(unit: &CompilationUnit) -> Vec<(String, Option<GroupDelimiter>)> {
+    unit.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Macro(macro_item) => Some((
+                macro_item.invocation.name.to_string(),
+                macro_item.invocation.delimiter(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_item_position_delimiters() {
+    let arena = Arena::new();
+    let unit = parse(
+        &arena,
+        "json! { \"a\": 1 } getters!(Point); table![1, 2]; fn main() { }",
+    )
+    .unwrap();
+
+    assert_eq!(
+        item_macros(&unit),
+        vec![
+            ("json".to_string(), Some(GroupDelimiter::Braces)),
+            ("getters".to_string(), Some(GroupDelimiter::Parentheses)),
+            ("table".to_string(), Some(GroupDelimiter::Brackets)),
+        ]
+    );
+    assert!(matches!(&unit.items[3], Item::FunctionDecl(_)));
+}
+
+#[test]
+fn test_item_position_requires_semicolon_without_braces() {
+    let arena = Arena::new();
+    let error = parse(&arena, "getters!(Point) fn main() { }").unwrap_err();
+    assert!(error.contains("`;` after macro invocation"), "{}", error);
+
+    // A trailing semicolon after the brace form is accepted
+    let unit = parse(&arena, "json! { } ; fn main() { }").unwrap();
+    assert_eq!(unit.items.len(), 2);
+}
+
+#[test]
+fn test_statement_parser_items_and_statements() {
+    let arena = Arena::new();
+    let mut parser = StatementParser::new(&arena, mock_tokens_from_source("getters![Point];"));
+    assert!(matches!(
+        parser.parse_item().unwrap(),
+        Item::Macro(macro_item) if macro_item.invocation.name == "getters"
+    ));
+
+    let mut parser = StatementParser::new(&arena, mock_tokens_from_source("log! { a } done;"));
+    assert!(matches!(
+        parser.parse_statement().unwrap(),
+        Statement::Expression(Expression::Macro(invocation)) if invocation.name == "log"
+    ));
+    assert!(matches!(
+        parser.parse_statement().unwrap(),
+        Statement::Expression(Expression::Identifier(name)) if name == "done"
+    ));
+}
+
+#[test]
+fn test_brace_form_statement_needs_no_semicolon() {
+    let arena = Arena::new();
+    let mut tokens = mock_tokens_from_source("{ log! { a } done(); }");
+    let block = BlockParser::new(&arena)
+        .parse_braced_block(&mut tokens)
+        .unwrap();
+
+    assert_eq!(block.statements.len(), 2);
+    assert!(matches!(
+        &block.statements[0],
+        Statement::Expression(Expression::Macro(invocation))
+            if invocation.delimiter() == Some(GroupDelimiter::Braces)
+    ));
+    assert!(matches!(
+        &block.statements[1],
+        Statement::Expression(Expression::Call(_))
+    ));
+}
+
+#[test]
+fn test_item_macro_expands_into_several_items() {
+    let arena = Arena::new();
+    let unit = parse(&arena,
+        "macro accessors { ($($name:ident),*) => { $(fn $name() { })* } } accessors!(x, y, z); fn main() { }",
+    )
+    .unwrap();
+    let expanded = expand_macros(&arena, &unit).unwrap();
+
+    let functions: Vec<_> = expanded
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::FunctionDecl(function) => Some(function.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(functions, vec!["x", "y", "z", "main"]);
+    assert!(!expanded
+        .items
+        .iter()
+        .any(|item| matches!(item, Item::Macro(_))));
+}
+
+#[test]
+fn test_brace_form_statement_macro_expands_in_place() {
+    let arena = Arena::new();
+    let unit = parse(
+        &arena,
+        "macro twice { ($e:expr) => { $e; $e; } } fn main() { twice! { tick() } done(); }",
+    )
+    .unwrap();
+    let expanded = expand_macros(&arena, &unit).unwrap();
+
+    match &expanded.items[1] {
+        Item::FunctionDecl(function) => {
+            assert_eq!(function.body.as_ref().unwrap().statements.len(), 3)
+        }
+        other => panic!("Expected function, got {:?}", other),
+    }
+}
@@ -24,102 +24,102 @@ fn test_comprehensive_program_coverage() {
         // Public generic function with where clause
         TokenType::Pub,
         TokenType::Fn,
-        TokenType::Identifier("process_data".to_string()),
+        TokenType::Identifier("process_data".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("input".to_string()),
+        TokenType::Identifier("input".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("processor".to_string()),
+        TokenType::Identifier("processor".into()),
         TokenType::Colon,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("Error".to_string()),
+        TokenType::Identifier("Error".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("FnOnce".to_string()),
+        TokenType::Identifier("FnOnce".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("processor".to_string()),
+        TokenType::Identifier("processor".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("input".to_string()),
+        TokenType::Identifier("input".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::Return,
-        TokenType::Identifier("Ok".to_string()),
+        TokenType::Identifier("Ok".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Data class with generic parameters and attributes
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
-        TokenType::Identifier("PartialEq".to_string()),
+        TokenType::Identifier("PartialEq".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("serialize".to_string()),
+        TokenType::Identifier("serialize".into()),
         TokenType::Comma,
-        TokenType::Identifier("deserialize".to_string()),
+        TokenType::Identifier("deserialize".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("ComplexData".to_string()),
+        TokenType::Identifier("ComplexData".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::LeftBrace,
         TokenType::Pub,
-        TokenType::Identifier("id".to_string()),
+        TokenType::Identifier("id".into()),
         TokenType::Colon,
-        TokenType::Identifier("u64".to_string()),
+        TokenType::Identifier("u64".into()),
         TokenType::Comma,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("metadata".to_string()),
+        TokenType::Identifier("metadata".into()),
         TokenType::Colon,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Greater,
         TokenType::RightBrace,
         // Extern block with function and static declarations
@@ -127,29 +127,29 @@ fn test_comprehensive_program_coverage() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("malloc".to_string()),
+        TokenType::Identifier("malloc".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::Colon,
-        TokenType::Identifier("usize".to_string()),
+        TokenType::Identifier("usize".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
-        TokenType::Identifier("c_void".to_string()),
+        TokenType::Identifier("c_void".into()),
         TokenType::Semicolon,
         TokenType::Fn,
-        TokenType::Identifier("free".to_string()),
+        TokenType::Identifier("free".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("c_void".to_string()),
+        TokenType::Identifier("c_void".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::Static,
-        TokenType::Identifier("errno".to_string()),
+        TokenType::Identifier("errno".into()),
         TokenType::Colon,
-        TokenType::Identifier("c_int".to_string()),
+        TokenType::Identifier("c_int".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -172,82 +172,82 @@ fn test_comprehensive_statement_coverage() {
         TokenType::Async,
         TokenType::Unsafe,
         TokenType::Fn,
-        TokenType::Identifier("complex_function".to_string()),
+        TokenType::Identifier("complex_function".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Plus,
-        TokenType::Identifier("Sync".to_string()),
+        TokenType::Identifier("Sync".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("param1".to_string()),
+        TokenType::Identifier("param1".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Colon,
         TokenType::Ampersand,
-        TokenType::Identifier("Vec".to_string()),
+        TokenType::Identifier("Vec".into()),
         TokenType::Less,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("Box".to_string()),
+        TokenType::Identifier("Box".into()),
         TokenType::Less,
-        TokenType::Identifier("dyn".to_string()),
-        TokenType::Identifier("Error".to_string()),
+        TokenType::Identifier("dyn".into()),
+        TokenType::Identifier("Error".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::LeftBrace,
         // Variable declarations with complex types
         TokenType::Let,
-        TokenType::Identifier("local_map".to_string()),
+        TokenType::Identifier("local_map".into()),
         TokenType::Colon,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("Vec".to_string()),
+        TokenType::Identifier("Vec".into()),
         TokenType::Less,
         TokenType::LeftBracket,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::IntegerLiteral(16),
         TokenType::RightBracket,
         TokenType::Greater,
         TokenType::Greater,
         TokenType::Equal,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::DoubleColon,
-        TokenType::Identifier("new".to_string()),
+        TokenType::Identifier("new".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
         // For loop with complex pattern
         TokenType::For,
         TokenType::LeftParen,
-        TokenType::Identifier("key".to_string()),
+        TokenType::Identifier("key".into()),
         TokenType::Comma,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::RightParen,
         TokenType::In,
-        TokenType::Identifier("local_map".to_string()),
+        TokenType::Identifier("local_map".into()),
         TokenType::Dot,
-        TokenType::Identifier("iter".to_string()),
+        TokenType::Identifier("iter".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         // If-else with complex conditions
         TokenType::If,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Dot,
-        TokenType::Identifier("len".to_string()),
+        TokenType::Identifier("len".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Greater,
@@ -258,9 +258,9 @@ fn test_comprehensive_statement_coverage() {
         TokenType::RightBrace,
         TokenType::Else,
         TokenType::If,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Dot,
-        TokenType::Identifier("is_empty".to_string()),
+        TokenType::Identifier("is_empty".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -271,48 +271,48 @@ fn test_comprehensive_statement_coverage() {
         TokenType::LeftBrace,
         // Match statement with complex patterns
         TokenType::Match,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Dot,
-        TokenType::Identifier("get".to_string()),
+        TokenType::Identifier("get".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(0),
         TokenType::RightParen,
         TokenType::LeftBrace,
-        TokenType::Identifier("Some".to_string()),
+        TokenType::Identifier("Some".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::RightParen,
         TokenType::If,
         TokenType::Star,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(10),
         TokenType::FatArrow,
         TokenType::LeftBrace,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Dot,
-        TokenType::Identifier("push".to_string()),
+        TokenType::Identifier("push".into()),
         TokenType::LeftParen,
         TokenType::Star,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::RightBrace,
-        TokenType::Identifier("Some".to_string()),
+        TokenType::Identifier("Some".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("_".to_string()),
+        TokenType::Identifier("_".into()),
         TokenType::RightParen,
         TokenType::FatArrow,
         TokenType::LeftBrace,
         TokenType::RightBrace,
-        TokenType::Identifier("None".to_string()),
+        TokenType::Identifier("None".into()),
         TokenType::FatArrow,
         TokenType::Return,
-        TokenType::Identifier("Err".to_string()),
+        TokenType::Identifier("Err".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("Empty value".to_string()),
         TokenType::Dot,
-        TokenType::Identifier("into".to_string()),
+        TokenType::Identifier("into".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::RightParen,
@@ -322,24 +322,24 @@ fn test_comprehensive_statement_coverage() {
         TokenType::RightBrace,
         // While loop with complex condition
         TokenType::While,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Dot,
-        TokenType::Identifier("len".to_string()),
+        TokenType::Identifier("len".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Less,
         TokenType::IntegerLiteral(100),
         TokenType::AmpAmp,
         TokenType::Bang,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Dot,
-        TokenType::Identifier("is_empty".to_string()),
+        TokenType::Identifier("is_empty".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Dot,
-        TokenType::Identifier("push".to_string()),
+        TokenType::Identifier("push".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(42),
         TokenType::RightParen,
@@ -368,21 +368,21 @@ fn test_advanced_function_signatures() {
         TokenType::Extern,
         TokenType::StringLiteral("C".to_string()),
         TokenType::Fn,
-        TokenType::Identifier("complex_ffi".to_string()),
+        TokenType::Identifier("complex_ffi".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Copy".to_string()),
+        TokenType::Identifier("Copy".into()),
         TokenType::Plus,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Unsafe,
         TokenType::Extern,
@@ -390,17 +390,17 @@ fn test_advanced_function_signatures() {
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Star,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("c_int".to_string()),
+        TokenType::Identifier("c_int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("c_int".to_string()),
+        TokenType::Identifier("c_int".into()),
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Sized".to_string()),
+        TokenType::Identifier("Sized".into()),
         TokenType::Semicolon,
         TokenType::Eof,
     ]);
@@ -421,113 +421,113 @@ fn test_complex_data_classes() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
-        TokenType::Identifier("PartialEq".to_string()),
+        TokenType::Identifier("PartialEq".into()),
         TokenType::Comma,
-        TokenType::Identifier("Eq".to_string()),
+        TokenType::Identifier("Eq".into()),
         TokenType::Comma,
-        TokenType::Identifier("Hash".to_string()),
+        TokenType::Identifier("Hash".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("repr".to_string()),
+        TokenType::Identifier("repr".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("C".to_string()),
+        TokenType::Identifier("C".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Pub,
         TokenType::Data,
-        TokenType::Identifier("ComplexEntity".to_string()),
+        TokenType::Identifier("ComplexEntity".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Plus,
-        TokenType::Identifier("Sync".to_string()),
+        TokenType::Identifier("Sync".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::Colon,
-        TokenType::Identifier("BaseEntity".to_string()),
+        TokenType::Identifier("BaseEntity".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Plus,
-        TokenType::Identifier("Cloneable".to_string()),
+        TokenType::Identifier("Cloneable".into()),
         TokenType::LeftBrace,
         // Complex field types
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("skip_serializing_if".to_string()),
+        TokenType::Identifier("skip_serializing_if".into()),
         TokenType::Equal,
         TokenType::StringLiteral("Option::is_none".to_string()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Pub,
-        TokenType::Identifier("id".to_string()),
+        TokenType::Identifier("id".into()),
         TokenType::Colon,
-        TokenType::Identifier("Option".to_string()),
+        TokenType::Identifier("Option".into()),
         TokenType::Less,
-        TokenType::Identifier("Uuid".to_string()),
+        TokenType::Identifier("Uuid".into()),
         TokenType::Greater,
         TokenType::Comma,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
-        TokenType::Identifier("Arc".to_string()),
+        TokenType::Identifier("Arc".into()),
         TokenType::Less,
-        TokenType::Identifier("Mutex".to_string()),
+        TokenType::Identifier("Mutex".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::Comma,
-        TokenType::Identifier("processor".to_string()),
+        TokenType::Identifier("processor".into()),
         TokenType::Colon,
-        TokenType::Identifier("Box".to_string()),
+        TokenType::Identifier("Box".into()),
         TokenType::Less,
-        TokenType::Identifier("dyn".to_string()),
-        TokenType::Identifier("Fn".to_string()),
+        TokenType::Identifier("dyn".into()),
+        TokenType::Identifier("Fn".into()),
         TokenType::LeftParen,
         TokenType::Ampersand,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Plus,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Greater,
         TokenType::Comma,
-        TokenType::Identifier("metadata".to_string()),
+        TokenType::Identifier("metadata".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("Value".to_string()),
+        TokenType::Identifier("Value".into()),
         TokenType::Greater,
         TokenType::Semicolon,
         TokenType::IntegerLiteral(64),
         TokenType::RightBracket,
         TokenType::RightBrace,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("Default".to_string()),
+        TokenType::Identifier("Default".into()),
         TokenType::Semicolon,
         TokenType::Eof,
     ]);
@@ -547,45 +547,45 @@ fn test_advanced_control_flow() {
     let tokens = VecTokenStream::from_token_types(vec![
         // Complex nested control flow
         TokenType::For,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         TokenType::In,
         TokenType::IntegerLiteral(0),
         TokenType::DotDot,
         TokenType::IntegerLiteral(100),
         TokenType::LeftBrace,
         TokenType::Match,
-        TokenType::Identifier("complex_computation".to_string()),
+        TokenType::Identifier("complex_computation".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::DoubleColon,
-        TokenType::Identifier("Ok".to_string()),
+        TokenType::Identifier("Ok".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::RightParen,
         TokenType::If,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(50),
         TokenType::FatArrow,
         TokenType::Break,
         TokenType::Semicolon,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::DoubleColon,
-        TokenType::Identifier("Ok".to_string()),
+        TokenType::Identifier("Ok".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("_".to_string()),
+        TokenType::Identifier("_".into()),
         TokenType::RightParen,
         TokenType::FatArrow,
         TokenType::Continue,
         TokenType::Semicolon,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::DoubleColon,
-        TokenType::Identifier("Err".to_string()),
+        TokenType::Identifier("Err".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("e".to_string()),
+        TokenType::Identifier("e".into()),
         TokenType::RightParen,
         TokenType::FatArrow,
         TokenType::LeftBrace,
@@ -611,61 +611,61 @@ fn test_comprehensive_type_system() {
 
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("complex_var".to_string()),
+        TokenType::Identifier("complex_var".into()),
         TokenType::Colon,
         // Complex function pointer type
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Ampersand,
         TokenType::LeftBracket,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("Box".to_string()),
+        TokenType::Identifier("Box".into()),
         TokenType::Less,
-        TokenType::Identifier("dyn".to_string()),
-        TokenType::Identifier("Future".to_string()),
+        TokenType::Identifier("dyn".into()),
+        TokenType::Identifier("Future".into()),
         TokenType::Less,
-        TokenType::Identifier("Output".to_string()),
+        TokenType::Identifier("Output".into()),
         TokenType::Equal,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::Less,
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Comma,
-        TokenType::Identifier("Box".to_string()),
+        TokenType::Identifier("Box".into()),
         TokenType::Less,
-        TokenType::Identifier("dyn".to_string()),
-        TokenType::Identifier("Error".to_string()),
+        TokenType::Identifier("dyn".into()),
+        TokenType::Identifier("Error".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::Greater,
         TokenType::Plus,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::Semicolon,
         TokenType::IntegerLiteral(256),
         TokenType::RightBracket,
         TokenType::Comma,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
-        TokenType::Identifier("Option".to_string()),
+        TokenType::Identifier("Option".into()),
         TokenType::Less,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Ampersand,
-        TokenType::Identifier("str".to_string()),
+        TokenType::Identifier("str".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::BooleanLiteral(true),
         TokenType::Greater,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Equal,
-        TokenType::Identifier("create_complex_processor".to_string()),
+        TokenType::Identifier("create_complex_processor".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
@@ -686,43 +686,43 @@ fn test_advanced_expression_combinations() {
 
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
         // Complex chained method calls and operations
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Dot,
-        TokenType::Identifier("iter".to_string()),
+        TokenType::Identifier("iter".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("filter".to_string()),
+        TokenType::Identifier("filter".into()),
         TokenType::LeftParen,
         TokenType::Pipe,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Pipe,
         TokenType::Star,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(10),
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("map".to_string()),
+        TokenType::Identifier("map".into()),
         TokenType::LeftParen,
         TokenType::Pipe,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Pipe,
         TokenType::Star,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Star,
         TokenType::IntegerLiteral(2),
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("collect".to_string()),
+        TokenType::Identifier("collect".into()),
         TokenType::DoubleColon,
         TokenType::Less,
-        TokenType::Identifier("Vec".to_string()),
+        TokenType::Identifier("Vec".into()),
         TokenType::Less,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::LeftParen,
@@ -1,8 +1,10 @@
 use ferra_parser::{
+    ast::Item,
     program::ProgramParser,
-    token::{stream::VecTokenStream, types::TokenType},
+    token::{stream::VecTokenStream, types::TokenType, Symbol},
     Arena,
 };
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -50,12 +52,12 @@ pub struct MemoryStats {
 fn create_test_tokens() -> Vec<TokenType> {
     vec![
         TokenType::Fn,
-        TokenType::Identifier("test".to_string()),
+        TokenType::Identifier("test".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -81,15 +83,15 @@ fn generate_large_program_tokens(size_category: &str) -> Vec<TokenType> {
     for i in 0..func_count / 10 {
         tokens.extend(vec![
             TokenType::Data,
-            TokenType::Identifier(format!("User{i}")),
+            TokenType::Identifier(format!("User{i}").into()),
             TokenType::LeftBrace,
-            TokenType::Identifier("id".to_string()),
+            TokenType::Identifier("id".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::Comma,
-            TokenType::Identifier("name".to_string()),
+            TokenType::Identifier("name".into()),
             TokenType::Colon,
-            TokenType::Identifier("String".to_string()),
+            TokenType::Identifier("String".into()),
             TokenType::RightBrace,
         ]);
     }
@@ -99,18 +101,18 @@ fn generate_large_program_tokens(size_category: &str) -> Vec<TokenType> {
         // Function signature
         tokens.extend(vec![
             TokenType::Fn,
-            TokenType::Identifier(format!("function_{i}")),
+            TokenType::Identifier(format!("function_{i}").into()),
             TokenType::LeftParen,
-            TokenType::Identifier("param1".to_string()),
+            TokenType::Identifier("param1".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::Comma,
-            TokenType::Identifier("param2".to_string()),
+            TokenType::Identifier("param2".into()),
             TokenType::Colon,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::RightParen,
             TokenType::Arrow,
-            TokenType::Identifier("i32".to_string()),
+            TokenType::Identifier("i32".into()),
             TokenType::LeftBrace,
         ]);
 
@@ -118,11 +120,11 @@ fn generate_large_program_tokens(size_category: &str) -> Vec<TokenType> {
         for stmt in 0..statements_per_func {
             tokens.extend(vec![
                 TokenType::Let,
-                TokenType::Identifier(format!("var_{stmt}")),
+                TokenType::Identifier(format!("var_{stmt}").into()),
                 TokenType::Equal,
-                TokenType::Identifier("param1".to_string()),
+                TokenType::Identifier("param1".into()),
                 TokenType::Plus,
-                TokenType::Identifier("param2".to_string()),
+                TokenType::Identifier("param2".into()),
                 TokenType::Star,
                 TokenType::IntegerLiteral(stmt as i64),
                 TokenType::Semicolon,
@@ -132,7 +134,7 @@ fn generate_large_program_tokens(size_category: &str) -> Vec<TokenType> {
         // Function close
         tokens.extend(vec![
             TokenType::Return,
-            TokenType::Identifier("param1".to_string()),
+            TokenType::Identifier("param1".into()),
             TokenType::Semicolon,
             TokenType::RightBrace,
         ]);
@@ -382,7 +384,7 @@ mod memory_profiling_tests {
         // Test deeply nested structures using tokens
         let mut deep_nesting_tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -392,7 +394,7 @@ mod memory_profiling_tests {
         for i in 0..50 {
             deep_nesting_tokens.extend(vec![
                 TokenType::If,
-                TokenType::Identifier(format!("condition_{i}")),
+                TokenType::Identifier(format!("condition_{i}").into()),
                 TokenType::LeftBrace,
             ]);
         }
@@ -418,7 +420,7 @@ mod memory_profiling_tests {
         // Test very wide structures (many statements)
         let mut wide_structure_tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
             TokenType::LeftBrace,
@@ -427,7 +429,7 @@ mod memory_profiling_tests {
         for i in 0..500 {
             wide_structure_tokens.extend(vec![
                 TokenType::Let,
-                TokenType::Identifier(format!("var_{i}")),
+                TokenType::Identifier(format!("var_{i}").into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(i as i64),
                 TokenType::Plus,
@@ -451,4 +453,89 @@ mod memory_profiling_tests {
             "Wide structure parsing too slow"
         );
     }
+
+    #[test]
+    fn test_interned_identifier_footprint() {
+        let tokens = generate_large_program_tokens("large");
+        let names: Vec<Symbol> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                TokenType::Identifier(name) => Some(*name),
+                _ => None,
+            })
+            .collect();
+        let distinct: HashSet<Symbol> = names.iter().copied().collect();
+
+        // One owned `String` per occurrence, versus one 4-byte handle per
+        // occurrence plus a single copy of each distinct name
+        let owned_bytes: usize = names
+            .iter()
+            .map(|name| std::mem::size_of::<String>() + name.len())
+            .sum();
+        let interned_bytes = names.len() * std::mem::size_of::<Symbol>()
+            + distinct.iter().map(|name| name.len()).sum::<usize>();
+
+        println!("Identifier Footprint:");
+        println!("  Occurrences: {}", names.len());
+        println!("  Distinct Names: {}", distinct.len());
+        println!("  Owned Strings: {} KB", owned_bytes / 1024);
+        println!("  Interned Symbols: {} KB", interned_bytes / 1024);
+
+        assert_eq!(std::mem::size_of::<Symbol>(), 4);
+        assert!(
+            interned_bytes * 4 < owned_bytes,
+            "Interning should cut identifier memory by more than 4x"
+        );
+
+        // The AST keeps the lexer's symbols instead of copying names
+        let arena = Arena::new();
+        let mut parser = ProgramParser::new(&arena, VecTokenStream::from_token_types(tokens));
+        let unit = parser.parse_compilation_unit().unwrap();
+        let function = unit
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::FunctionDecl(function) => Some(function),
+                _ => None,
+            })
+            .unwrap();
+        assert!(distinct.contains(&function.name));
+    }
+
+    #[test]
+    fn test_symbol_lookup_throughput() {
+        let tokens = generate_large_program_tokens("large");
+        let symbols: Vec<Symbol> = tokens
+            .iter()
+            .filter_map(|token| match token {
+                TokenType::Identifier(name) => Some(*name),
+                _ => None,
+            })
+            .collect();
+        let strings: Vec<String> = symbols.iter().map(|name| name.to_string()).collect();
+
+        // Count occurrences per name, as a resolver would look names up
+        let start = Instant::now();
+        let mut by_string: HashMap<&str, usize> = HashMap::new();
+        for name in &strings {
+            *by_string.entry(name.as_str()).or_default() += 1;
+        }
+        let string_time = start.elapsed();
+
+        let start = Instant::now();
+        let mut by_symbol: HashMap<Symbol, usize> = HashMap::new();
+        for name in &symbols {
+            *by_symbol.entry(*name).or_default() += 1;
+        }
+        let symbol_time = start.elapsed();
+
+        println!("Name Lookup Throughput ({} lookups):", symbols.len());
+        println!("  String keys: {:?}", string_time);
+        println!("  Symbol keys: {:?}", symbol_time);
+
+        assert_eq!(by_string.len(), by_symbol.len());
+        for (symbol, count) in &by_symbol {
+            assert_eq!(by_string[symbol.as_str()], *count);
+        }
+    }
 }
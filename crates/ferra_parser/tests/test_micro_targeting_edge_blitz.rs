@@ -55,7 +55,7 @@ fn test_pratt_parser_micro_targeting() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Async,
         TokenType::LeftBrace,
-        TokenType::Identifier("async_operation".to_string()),
+        TokenType::Identifier("async_operation".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::RightBrace,
@@ -69,12 +69,12 @@ fn test_pratt_parser_micro_targeting() {
 
     // Test complex macro invocation edge cases
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("complex_macro".to_string()),
+        TokenType::Identifier("complex_macro".into()),
         TokenType::Bang,
         TokenType::LeftBrace,
-        TokenType::Identifier("nested".to_string()),
+        TokenType::Identifier("nested".into()),
         TokenType::Colon,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Comma,
         TokenType::IntegerLiteral(42),
         TokenType::RightBrace,
@@ -90,7 +90,7 @@ fn test_pratt_parser_micro_targeting() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::IntegerLiteral(1),
         TokenType::DotDot,
-        TokenType::Identifier("variable".to_string()),
+        TokenType::Identifier("variable".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(10),
         TokenType::Eof,
@@ -103,12 +103,12 @@ fn test_pratt_parser_micro_targeting() {
 
     // Test question mark operator for error propagation
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("fallible_operation".to_string()),
+        TokenType::Identifier("fallible_operation".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Question,
         TokenType::Dot,
-        TokenType::Identifier("unwrap".to_string()),
+        TokenType::Identifier("unwrap".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Eof,
@@ -134,25 +134,25 @@ fn test_program_parser_micro_targeting() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("complex_extern".to_string()),
+        TokenType::Identifier("complex_extern".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("u8".to_string()),
+        TokenType::Identifier("u8".into()),
         TokenType::Comma,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::Colon,
-        TokenType::Identifier("usize".to_string()),
+        TokenType::Identifier("usize".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::Static,
-        TokenType::Identifier("GLOBAL_VAR".to_string()),
+        TokenType::Identifier("GLOBAL_VAR".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -166,28 +166,28 @@ fn test_program_parser_micro_targeting() {
     // Test nested data class with complex field types
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Data,
-        TokenType::Identifier("ComplexData".to_string()),
+        TokenType::Identifier("ComplexData".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::LeftBracket,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftParen,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Comma,
-        TokenType::Identifier("V".to_string()),
+        TokenType::Identifier("V".into()),
         TokenType::RightParen,
         TokenType::Comma,
-        TokenType::Identifier("nested_array".to_string()),
+        TokenType::Identifier("nested_array".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
         TokenType::LeftBracket,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBracket,
         TokenType::RightBracket,
         TokenType::RightBrace,
@@ -202,36 +202,36 @@ fn test_program_parser_micro_targeting() {
     // Test generic functions with where clauses
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("generic_with_where".to_string()),
+        TokenType::Identifier("generic_with_where".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::Colon,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftParen,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightParen,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::LeftBrace,
         TokenType::LeftParen,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::RightParen,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -255,11 +255,11 @@ fn test_statement_parser_micro_targeting() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
         TokenType::LeftParen,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::Comma,
-        TokenType::Identifier("_".to_string()), // Use underscore as identifier
+        TokenType::Identifier("_".into()), // Use underscore as identifier
         TokenType::RightParen,
         TokenType::Equal,
         TokenType::LeftParen,
@@ -281,7 +281,7 @@ fn test_statement_parser_micro_targeting() {
     // Test match statements with complex patterns
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Match,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::LeftBrace,
         TokenType::IntegerLiteral(1),
         TokenType::DotDotEqual,
@@ -289,15 +289,15 @@ fn test_statement_parser_micro_targeting() {
         TokenType::FatArrow,
         TokenType::StringLiteral("small".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::If,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(100),
         TokenType::FatArrow,
         TokenType::StringLiteral("large".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("_".to_string()), // Use underscore as identifier
+        TokenType::Identifier("_".into()), // Use underscore as identifier
         TokenType::FatArrow,
         TokenType::StringLiteral("default".to_string()),
         TokenType::RightBrace,
@@ -313,27 +313,27 @@ fn test_statement_parser_micro_targeting() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("async_trait".to_string()),
+        TokenType::Identifier("async_trait".into()),
         TokenType::RightBracket,
         TokenType::Pub,
         TokenType::Async,
         TokenType::Unsafe,
         TokenType::Fn,
-        TokenType::Identifier("complex_async".to_string()),
+        TokenType::Identifier("complex_async".into()),
         TokenType::Less,
         TokenType::Apostrophe,
-        TokenType::Identifier("lifetime".to_string()),
+        TokenType::Identifier("lifetime".into()),
         TokenType::Greater,
         TokenType::LeftParen,
         TokenType::Ampersand,
         TokenType::Apostrophe,
-        TokenType::Identifier("lifetime".to_string()),
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("lifetime".into()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -380,12 +380,12 @@ fn test_block_parser_micro_targeting() {
         TokenType::Unsafe,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("raw_ptr".to_string()),
+        TokenType::Identifier("raw_ptr".into()),
         TokenType::Equal,
         TokenType::Star,
-        TokenType::Identifier("data_ptr".to_string()),
+        TokenType::Identifier("data_ptr".into()),
         TokenType::Semicolon,
-        TokenType::Identifier("raw_ptr".to_string()),
+        TokenType::Identifier("raw_ptr".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -403,13 +403,13 @@ fn test_block_parser_micro_targeting() {
         TokenType::Async,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("async_operation".to_string()),
+        TokenType::Identifier("async_operation".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -431,10 +431,10 @@ fn test_advanced_error_recovery_micro_targeting() {
     // Test error recovery with multiple sync points
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("broken_func".to_string()),
+        TokenType::Identifier("broken_func".into()),
         TokenType::LeftParen,
         // Missing parameter type - first error
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
@@ -467,7 +467,7 @@ fn test_advanced_error_recovery_micro_targeting() {
         TokenType::BooleanLiteral(true),
         TokenType::LeftBrace,
         TokenType::For,
-        TokenType::Identifier("item".to_string()),
+        TokenType::Identifier("item".into()),
         TokenType::In,
         TokenType::LeftBrace, // Error: missing collection expression
         TokenType::If,
@@ -499,33 +499,33 @@ fn test_complex_type_system_edge_cases() {
     // Test extremely complex nested type expressions
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("type_complexity".to_string()),
+        TokenType::Identifier("type_complexity".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::LeftBracket,
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftParen,
         TokenType::LeftBracket,
         TokenType::LeftParen,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Comma,
-        TokenType::Identifier("V".to_string()),
+        TokenType::Identifier("V".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::Star,
-        TokenType::Identifier("W".to_string()),
+        TokenType::Identifier("W".into()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -541,25 +541,25 @@ fn test_complex_type_system_edge_cases() {
     // Test generic constraints with multiple bounds
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("multi_bound".to_string()),
+        TokenType::Identifier("multi_bound".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Plus,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Plus,
-        TokenType::Identifier("Sync".to_string()),
+        TokenType::Identifier("Sync".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -582,7 +582,7 @@ fn test_performance_critical_path_stress() {
     // Generate a complex expression with many nested operations
     let mut tokens = vec![
         TokenType::Let,
-        TokenType::Identifier("complex_result".to_string()),
+        TokenType::Identifier("complex_result".into()),
         TokenType::Equal,
     ];
 
@@ -612,7 +612,7 @@ fn test_performance_critical_path_stress() {
     // Test large function parameter lists
     let mut tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("many_params".to_string()),
+        TokenType::Identifier("many_params".into()),
         TokenType::LeftParen,
     ];
 
@@ -620,9 +620,9 @@ fn test_performance_critical_path_stress() {
         if i > 1 {
             tokens.push(TokenType::Comma);
         }
-        tokens.push(TokenType::Identifier(format!("param{}", i)));
+        tokens.push(TokenType::Identifier(format!("param{}", i).into()));
         tokens.push(TokenType::Colon);
-        tokens.push(TokenType::Identifier("i32".to_string()));
+        tokens.push(TokenType::Identifier("i32".into()));
     }
 
     tokens.extend(vec![
@@ -655,27 +655,27 @@ fn test_advanced_pattern_matching_edge_cases() {
     // Test complex slice patterns with nested destructuring
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Match,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::LeftBrace,
         TokenType::LeftBracket,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::At,
         TokenType::LeftBracket,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Comma,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::RightBracket,
         TokenType::Comma,
-        TokenType::Identifier("rest".to_string()),
+        TokenType::Identifier("rest".into()),
         TokenType::At,
         TokenType::DotDot,
         TokenType::RightBracket,
         TokenType::FatArrow,
         TokenType::StringLiteral("complex pattern".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("_".to_string()), // Use underscore as identifier
+        TokenType::Identifier("_".into()), // Use underscore as identifier
         TokenType::FatArrow,
         TokenType::StringLiteral("default".to_string()),
         TokenType::RightBrace,
@@ -690,7 +690,7 @@ fn test_advanced_pattern_matching_edge_cases() {
     // Test or patterns with nested guards
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Match,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::LeftBrace,
         TokenType::IntegerLiteral(1),
         TokenType::Pipe,
@@ -698,17 +698,17 @@ fn test_advanced_pattern_matching_edge_cases() {
         TokenType::Pipe,
         TokenType::IntegerLiteral(3),
         TokenType::If,
-        TokenType::Identifier("condition".to_string()),
+        TokenType::Identifier("condition".into()),
         TokenType::FatArrow,
         TokenType::StringLiteral("small numbers".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::At,
         TokenType::IntegerLiteral(10),
         TokenType::DotDotEqual,
         TokenType::IntegerLiteral(20),
         TokenType::If,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Percent,
         TokenType::IntegerLiteral(2),
         TokenType::EqualEqual,
@@ -716,7 +716,7 @@ fn test_advanced_pattern_matching_edge_cases() {
         TokenType::FatArrow,
         TokenType::StringLiteral("even teens".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("_".to_string()), // Use underscore as identifier
+        TokenType::Identifier("_".into()), // Use underscore as identifier
         TokenType::FatArrow,
         TokenType::StringLiteral("other".to_string()),
         TokenType::RightBrace,
@@ -20,7 +20,7 @@ fn convert_token(token: Token) -> TokenType {
         TokenKind::In => TokenType::In,
         TokenKind::Break => TokenType::Break,
         TokenKind::Continue => TokenType::Continue,
-        TokenKind::Identifier => TokenType::Identifier(token.lexeme.clone()),
+        TokenKind::Identifier => TokenType::Identifier(token.lexeme.as_str().into()),
         TokenKind::IntegerLiteral => match token.literal {
             Some(LiteralValue::Integer(i)) => TokenType::IntegerLiteral(i),
             _ => TokenType::IntegerLiteral(0),
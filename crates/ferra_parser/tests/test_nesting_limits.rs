@@ -0,0 +1,86 @@
+//! Nesting limits keep adversarial input from overflowing the stack
+
+use ferra_parser::{
+    ast::Arena, error::ParseError, options::DEFAULT_MAX_NESTING_DEPTH,
+    test_utils::mock_tokens_from_source, ParserOptions, ProgramParser,
+};
+
+/// Parse `body` inside `fn main`, returning the first error
+fn first_error(arena: &Arena, body: &str) -> Option<ParseError> {
+    first_error_with(arena, body, ParserOptions::new())
+}
+
+fn first_error_with(arena: &Arena, body: &str, options: ParserOptions) -> Option<ParseError> {
+    let source = format!("fn main() {{ {} }}", body);
+    let mut parser =
+        ProgramParser::new(arena, mock_tokens_from_source(&source)).with_options(options);
+    parser
+        .parse_compilation_unit()
+        .err()
+        .map(|errors| errors[0].clone())
+}
+
+fn assert_too_deep(body: &str, limit: usize) {
+    let arena = Arena::new();
+    match first_error(&arena, body) {
+        Some(error @ ParseError::NestingTooDeep { .. }) => {
+            assert_eq!(error.error_code(), Some("E101"));
+            assert_eq!(
+                error.to_string(),
+                format!("Nesting deeper than the limit of {} levels", limit)
+            );
+        }
+        other => panic!("Expected nesting error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_pathological_nesting_reports_error() {
+    let n = 100_000;
+    let bodies = [
+        format!("let x = {}1{};", "(".repeat(n), ")".repeat(n)),
+        format!("let x = {}1;", "-".repeat(n)),
+        format!("let x = {}a;", "!".repeat(n)),
+        format!("let x = {}1{};", "[".repeat(n), "]".repeat(n)),
+        format!("let x = {}1{};", "f(".repeat(n), ")".repeat(n)),
+        format!("let x = {}1{};", "a[".repeat(n), "]".repeat(n)),
+        format!("let x = {}1{};", "[{ ".repeat(n), " }]".repeat(n)),
+        format!("x = {}1;", "a = ".repeat(n)),
+        format!("{}{}", "{ ".repeat(n), "}".repeat(n)),
+        format!("{}{}", "if a { ".repeat(n), "}".repeat(n)),
+        format!("let x: {}i32{} = 1;", "[".repeat(n), "]".repeat(n)),
+        format!("match a {{ {}x{} => 1 }}", "[".repeat(n), "]".repeat(n)),
+        format!("m!{}{};", "(".repeat(n), ")".repeat(n)),
+        format!("#[cfg({}x{})] let y = 1;", "not(".repeat(n), ")".repeat(n)),
+    ];
+    for body in &bodies {
+        assert_too_deep(body, DEFAULT_MAX_NESTING_DEPTH);
+    }
+}
+
+#[test]
+fn test_nesting_limit_is_configurable() {
+    let nested = |depth: usize| format!("let x = {}1{};", "(".repeat(depth), ")".repeat(depth));
+
+    let arena = Arena::new();
+    let options = ParserOptions::new().with_max_nesting_depth(8);
+    assert_eq!(options.max_nesting_depth, 8);
+    assert!(first_error_with(&arena, &nested(4), options).is_none());
+
+    let error = first_error_with(&arena, &nested(20), options).unwrap();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 8, .. }));
+    assert_eq!(error.span().column, 28);
+}
+
+#[test]
+fn test_nesting_depth_is_released() {
+    // Siblings do not add up, and a failed parse leaves no depth behind
+    let arena = Arena::new();
+    let deep = DEFAULT_MAX_NESTING_DEPTH - 8;
+    let statement = format!("let x = {}1{};", "(".repeat(deep), ")".repeat(deep));
+    assert!(first_error(&arena, &statement.repeat(20)).is_none());
+
+    let too_deep = "(".repeat(DEFAULT_MAX_NESTING_DEPTH * 2);
+    assert!(first_error(&arena, &format!("let x = {};", too_deep)).is_some());
+    assert!(first_error(&arena, &statement).is_none());
+}
@@ -0,0 +1,193 @@
+//! Newline filtering tests
+//!
+//! Line breaks inside brackets, after incomplete lines and before `.` or
+//! binary-operator continuation lines must not end a statement
+//! (SYNTAX_GRAMMAR §1.1 rule 3). The filter runs between the lexer and the parser.
+
+use ferra_parser::{
+    ast::{print_compilation_unit, print_expression, Arena, Expression, Item, Statement},
+    block::BlockParser,
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    token::{TokenStream, TokenType},
+    CompilationUnit, ProgramParser,
+};
+
+fn parse_program<'a>(arena: &'a Arena, source: &str) -> &'a CompilationUnit<'a> {
+    let mut parser = ProgramParser::new(arena, mock_tokens_from_source(source));
+    parser
+        .parse_compilation_unit()
+        .unwrap_or_else(|errors| panic!("failed to parse {source:?}: {errors:?}"))
+}
+
+fn token_types(source: &str) -> Vec<TokenType> {
+    let mut stream = mock_tokens_from_source(source);
+    let mut types = Vec::new();
+    while !stream.is_at_end() {
+        types.push(stream.consume().token_type);
+    }
+    types
+}
+
+fn initializer<'a>(item: &Item<'a>) -> &'a Expression<'a> {
+    match item {
+        Item::VariableDecl(decl) => decl.initializer.unwrap(),
+        other => panic!("Expected variable declaration, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_newline_terminates_statements() {
+    let arena = Arena::new();
+    let program = parse_program(&arena, "let x = 1\nlet y = 2\n\n\nlet z = x + y\n");
+    assert_eq!(program.items.len(), 3);
+    assert_eq!(print_expression(initializer(&program.items[2])), "x + y");
+}
+
+#[test]
+fn test_multiline_argument_list() {
+    let source = "let total = add(\n    1,\n    2\n)\nlet next = total\n";
+    assert!(!token_types(source)[..9].contains(&TokenType::Newline));
+    assert!(!token_types(source).contains(&TokenType::Indent));
+
+    let arena = Arena::new();
+    let program = parse_program(&arena, source);
+    assert_eq!(program.items.len(), 2);
+    assert_eq!(
+        print_expression(initializer(&program.items[0])),
+        "add(1, 2)"
+    );
+}
+
+#[test]
+fn test_multiline_array_literal() {
+    let arena = Arena::new();
+    let program = parse_program(&arena, "let xs = [\n    1,\n    2,\n    3,\n]\n");
+    assert_eq!(program.items.len(), 1);
+    assert_eq!(
+        print_expression(initializer(&program.items[0])),
+        "[1, 2, 3]"
+    );
+}
+
+#[test]
+fn test_trailing_operator_continues_line() {
+    let arena = Arena::new();
+    let program = parse_program(&arena, "let total = a +\n    b *\n    c\nlet done = true\n");
+    assert_eq!(program.items.len(), 2);
+    assert_eq!(
+        print_expression(initializer(&program.items[0])),
+        "a + b * c"
+    );
+}
+
+#[test]
+fn test_leading_operator_continues_line() {
+    let arena = Arena::new();
+    let program = parse_program(&arena, "let total = a\n    + b\n    + c\nlet done = true\n");
+    assert_eq!(program.items.len(), 2);
+    assert_eq!(
+        print_expression(initializer(&program.items[0])),
+        "a + b + c"
+    );
+}
+
+#[test]
+fn test_leading_dot_method_chain() {
+    let source = "let body = fetch(url)\n    .json()\n    .unwrap()\nlet done = true\n";
+    let types = token_types(source);
+    assert_eq!(
+        types.iter().filter(|t| **t == TokenType::Newline).count(),
+        1,
+        "only the statement-ending newline survives: {types:?}"
+    );
+
+    let arena = Arena::new();
+    let program = parse_program(&arena, source);
+    assert_eq!(program.items.len(), 2);
+    assert_eq!(
+        print_expression(initializer(&program.items[0])),
+        "fetch(url).json().unwrap()"
+    );
+}
+
+#[test]
+fn test_function_body_without_semicolons() {
+    let source = "fn area(w: Int, h: Int) -> Int {\n    let a = w *\n        h\n    return a\n}\n\nfn main() {\n    area(\n        2,\n        3,\n    )\n}\n";
+    let arena = Arena::new();
+    let program = parse_program(&arena, source);
+    assert_eq!(program.items.len(), 2);
+    match &program.items[0] {
+        Item::FunctionDecl(function) => {
+            let body = function.body.as_ref().unwrap();
+            assert_eq!(body.statements.len(), 2);
+        }
+        other => panic!("Expected function, got {other:?}"),
+    }
+    assert_eq!(
+        print_compilation_unit(program),
+        "fn area(w: Int, h: Int) -> Int {\n    let a = w * h;\n    return a;\n}\n\nfn main() {\n    area(2, 3);\n}\n"
+    );
+}
+
+#[test]
+fn test_attribute_on_its_own_line() {
+    let arena = Arena::new();
+    let program = parse_program(&arena, "#[inline]\nfn f() {\n}\n");
+    match &program.items[0] {
+        Item::FunctionDecl(function) => assert_eq!(function.attributes.len(), 1),
+        other => panic!("Expected function, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_indented_block_keeps_layout_tokens() {
+    let source = ":\n    let x = 1\n    let y = 2\nlet z = 3\n";
+    let types = token_types(source);
+    assert!(types.contains(&TokenType::Indent));
+    assert!(types.contains(&TokenType::Dedent));
+
+    let arena = Arena::new();
+    let mut tokens = mock_tokens_from_source(source);
+    let block = BlockParser::new(&arena)
+        .parse_indented_block(&mut tokens)
+        .unwrap();
+    assert_eq!(block.statements.len(), 2);
+    assert!(matches!(block.statements[1], Statement::VariableDecl(_)));
+    assert_eq!(tokens.peek().token_type, TokenType::Let);
+}
+
+#[test]
+fn test_continuation_inside_indented_block() {
+    let source = ":\n    let total = a\n        + b\n    let next = total\nlet z = 3\n";
+    let arena = Arena::new();
+    let mut tokens = mock_tokens_from_source(source);
+    let block = BlockParser::new(&arena)
+        .parse_indented_block(&mut tokens)
+        .unwrap();
+    assert_eq!(block.statements.len(), 2);
+}
+
+#[test]
+fn test_expression_spanning_lines_in_parens() {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("(a\n    - b\n    - c)"));
+    let expression = parser.parse_expression(0).unwrap();
+    assert_eq!(print_expression(expression), "(a - b - c)");
+}
+
+#[test]
+fn test_indentation_errors_only_count_outside_brackets() {
+    // Inside braces indentation is insignificant, and so is a dedent to a
+    // level the lexer has not seen
+    let arena = Arena::new();
+    parse_program(&arena, "fn main() {\n        a();\n    b();\n}");
+    parse_program(&arena, "let x = f(\n        1,\n      2)");
+
+    // Where indentation delimits blocks, the lexer's error reaches the parser
+    let source = "fn main():\n    if a:\n        b()\n      c()\n";
+    let errors = ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .parse_compilation_unit()
+        .unwrap_err();
+    assert_eq!(errors[0].error_code(), Some("E005"), "{errors:?}");
+}
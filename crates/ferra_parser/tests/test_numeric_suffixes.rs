@@ -0,0 +1,146 @@
+//! Numeric suffix tests
+//!
+//! Tests for suffixed numeric literals (`255u8`, `1.5f32`) and the range
+//! diagnostics for literals that do not fit their type, including negated
+//! literals at the bottom of a signed range and the ones the lexer reports.
+
+use ferra_parser::{
+    ast::{Arena, Expression, Literal, UnaryOperator},
+    pratt::PrattParser,
+    program::ProgramParser,
+    test_utils::mock_tokens_from_source,
+    token::NumericSuffix,
+    ParseError,
+};
+
+fn parse<'a>(arena: &'a Arena, source: &str) -> Expression<'a> {
+    let mut parser = PrattParser::new(arena, mock_tokens_from_source(source));
+    parser.parse_expression(0).unwrap().clone()
+}
+
+fn parse_error(source: &str) -> ParseError {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    match parser.parse_expression(0) {
+        Ok(expr) => panic!("Expected error for {}, got {:?}", source, expr),
+        Err(error) => error,
+    }
+}
+
+#[test]
+fn test_suffixed_literals() {
+    let arena = Arena::new();
+    assert!(matches!(
+        parse(&arena, "255u8"),
+        Expression::Literal(Literal::TypedInteger {
+            value: 255,
+            suffix: NumericSuffix::U8
+        })
+    ));
+    assert!(matches!(
+        parse(&arena, "1_000i64"),
+        Expression::Literal(Literal::TypedInteger {
+            value: 1000,
+            suffix: NumericSuffix::I64
+        })
+    ));
+    match parse(&arena, "1.5f32") {
+        Expression::Literal(Literal::TypedFloat { value, suffix }) => {
+            assert_eq!(value, 1.5);
+            assert_eq!(suffix, NumericSuffix::F32);
+        }
+        other => panic!("Expected typed float, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_out_of_range_literal_has_dedicated_code() {
+    // 128 fits the magnitude of i8::MIN, so only the parser can reject it
+    let error = parse_error("128i8");
+    assert!(matches!(error, ParseError::LiteralOutOfRange { .. }));
+    assert_eq!(error.error_code(), Some("E100"));
+    assert_eq!(error.suggestion(), Some("the range of `i8` is -128..=127"));
+    assert_eq!(error.span().start, 0);
+    assert_eq!(error.span().end, 5);
+}
+
+#[test]
+fn test_negated_literals_at_range_minimum() {
+    let arena = Arena::new();
+    match parse(&arena, "-128i8") {
+        Expression::Unary(unary) => {
+            assert!(matches!(unary.operator, UnaryOperator::Minus));
+            assert!(matches!(
+                unary.operand,
+                Expression::Literal(Literal::TypedInteger {
+                    value: 128,
+                    suffix: NumericSuffix::I8
+                })
+            ));
+        }
+        other => panic!("Expected negated literal, got {:?}", other),
+    }
+
+    assert!(matches!(
+        parse(&arena, "-9223372036854775808"),
+        Expression::Literal(Literal::Integer(i64::MIN))
+    ));
+    assert!(matches!(
+        parse(&arena, "-9223372036854775808i64"),
+        Expression::Unary(_)
+    ));
+}
+
+#[test]
+fn test_negated_unsigned_literal_is_rejected() {
+    let error = parse_error("-1u8");
+    assert_eq!(error.error_code(), Some("E100"));
+    assert!(error.to_string().contains("`-1u8`"));
+    assert_eq!(error.span().start, 0);
+    assert_eq!(error.span().end, 4);
+}
+
+#[test]
+fn test_postfix_binds_before_negation() {
+    let arena = Arena::new();
+    // `-128i8.abs()` negates the call, so the literal itself is out of range
+    let error = parse_error("-128i8.abs()");
+    assert_eq!(error.error_code(), Some("E100"));
+
+    match parse(&arena, "1 - 9223372036854775808") {
+        Expression::Binary(binary) => assert!(matches!(
+            binary.right,
+            Expression::Literal(Literal::WideInteger(_))
+        )),
+        other => panic!("Expected subtraction, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lexer_range_diagnostics_reach_the_parser() {
+    for (source, message) in [
+        ("256u8", "Literal out of range for 'u8'"),
+        ("1e400", "Literal out of range for 'f64'"),
+    ] {
+        let error = parse_error(source);
+        assert_eq!(error.error_code(), Some("E011"), "{}", source);
+        assert!(error.to_string().contains(message), "{}", error);
+        assert_eq!(error.span().start, 0);
+        assert_eq!(error.span().end, source.len());
+    }
+
+    // Inside a statement the literal's diagnostic replaces a confusing
+    // complaint about the token after it
+    let arena = Arena::new();
+    let errors = ProgramParser::new(
+        &arena,
+        mock_tokens_from_source("fn main() { let x = 256u8; }"),
+    )
+    .parse_compilation_unit()
+    .unwrap_err();
+    assert_eq!(errors[0].error_code(), Some("E011"), "{:?}", errors);
+    assert_eq!(
+        errors[0].suggestion(),
+        Some("use a wider suffix type or a smaller value")
+    );
+}
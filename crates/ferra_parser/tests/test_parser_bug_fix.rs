@@ -33,7 +33,7 @@ fn convert_token_kind(kind: TokenKind) -> TokenType {
         TokenKind::Continue => TokenType::Continue,
         TokenKind::Pub => TokenType::Pub,
         TokenKind::Unsafe => TokenType::Unsafe,
-        TokenKind::Identifier => TokenType::Identifier("dummy".to_string()),
+        TokenKind::Identifier => TokenType::Identifier("dummy".into()),
         TokenKind::IntegerLiteral => TokenType::IntegerLiteral(42),
         TokenKind::FloatLiteral => TokenType::FloatLiteral(3.15),
         TokenKind::StringLiteral => TokenType::StringLiteral("dummy".to_string()),
@@ -72,7 +72,7 @@ fn convert_token_kind(kind: TokenKind) -> TokenType {
         TokenKind::DotDot => TokenType::DotDot,
         TokenKind::DotDotEqual => TokenType::DotDotEqual,
         TokenKind::PathSep => TokenType::DoubleColon,
-        TokenKind::Underscore => TokenType::Identifier("_".to_string()),
+        TokenKind::Underscore => TokenType::Identifier("_".into()),
         TokenKind::Eof => TokenType::Eof,
         _ => TokenType::Eof,
     }
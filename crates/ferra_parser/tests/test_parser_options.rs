@@ -0,0 +1,198 @@
+//! ParserOptions tests
+//!
+//! Tests for the options every parser entry point accepts:
+//! - Error limits and strict versus tolerant recovery
+//! - Feature gates for experimental syntax
+//! - Nesting limits applied through the options
+//! - Trivia retention, including in parsers created for nested syntax
+//! - Options reaching the parsers macro expansion runs
+
+use ferra_parser::{
+    ast::Arena, block::BlockParser, error::ParseError, expand::MacroExpander,
+    test_utils::mock_tokens_from_source, Feature, FeatureSet, Parser, ParserOptions, PrattParser,
+    ProgramParser, RecoveryStrategy, StatementParser,
+};
+
+const BROKEN_ITEMS: &str = "1; 2; 3; 4; 5; fn ok() {}";
+
+fn program_errors(source: &str, options: ParserOptions) -> Vec<ParseError> {
+    let arena = Arena::new();
+    ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .with_options(options)
+        .parse_compilation_unit()
+        .err()
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_tolerant_recovery_collects_several_errors() {
+    let errors = program_errors(BROKEN_ITEMS, ParserOptions::new());
+    assert!(errors.len() > 1, "{:?}", errors);
+}
+
+#[test]
+fn test_max_errors_caps_collected_errors() {
+    let errors = program_errors(BROKEN_ITEMS, ParserOptions::new().with_max_errors(2));
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+}
+
+#[test]
+fn test_strict_recovery_stops_at_first_error() {
+    let errors = program_errors(BROKEN_ITEMS, ParserOptions::strict());
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(ParserOptions::strict().recovery, RecoveryStrategy::Strict);
+}
+
+#[test]
+fn test_macro_definitions_are_feature_gated() {
+    let source = "macro id { ($x:expr) => { $x } }";
+    // Macro items shipped before the gate existed, so they are on by default
+    assert!(ParserOptions::new().is_enabled(Feature::MacroDefinitions));
+    assert!(program_errors(source, ParserOptions::new()).is_empty());
+
+    let disabled = ParserOptions::new().without_feature(Feature::MacroDefinitions);
+    let errors = program_errors(source, disabled);
+    assert_eq!(errors[0].error_code(), Some("E102"));
+    assert!(errors[0]
+        .to_string()
+        .contains("`macro` definitions are disabled"));
+    assert!(errors[0]
+        .suggestion()
+        .unwrap()
+        .contains("Feature::MacroDefinitions"));
+
+    let none = ParserOptions::new().with_features(FeatureSet::none());
+    assert!(!none.is_enabled(Feature::MacroDefinitions));
+    let all = ParserOptions::new().with_features(FeatureSet::all());
+    assert!(all.is_enabled(Feature::MacroDefinitions));
+
+    // The gate applies whichever entry point sees the definition
+    let arena = Arena::new();
+    let error = Parser::new(&arena, mock_tokens_from_source(source))
+        .with_options(disabled)
+        .parse_compilation_unit()
+        .unwrap_err();
+    assert_eq!(error.error_code(), Some("E102"));
+
+    let arena = Arena::new();
+    let mut parser =
+        StatementParser::new(&arena, mock_tokens_from_source(source)).with_options(disabled);
+    assert!(parser.parse_item().is_err());
+    let mut parser = StatementParser::new(&arena, mock_tokens_from_source(source));
+    assert!(parser.parse_item().is_ok());
+}
+
+#[test]
+fn test_nesting_limit_from_options() {
+    let options = ParserOptions::new().with_max_nesting_depth(4);
+    let source = "((((((1))))))";
+
+    let arena = Arena::new();
+    let error = Parser::new(&arena, mock_tokens_from_source(source))
+        .with_options(options)
+        .parse_expression()
+        .unwrap_err();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 4, .. }));
+
+    // The limit belongs to that parse: a later parser on the same arena
+    // keeps the default
+    assert!(PrattParser::new(&arena, mock_tokens_from_source(source))
+        .parse_expression(0)
+        .is_ok());
+
+    // Types, attributes and token trees parsed for a statement follow its limit
+    let nested_type = "let x: [[[[[[i32]]]]]] = y";
+    let error = StatementParser::new(&arena, mock_tokens_from_source(nested_type))
+        .with_options(options)
+        .parse_statement()
+        .unwrap_err();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 4, .. }));
+    let nested_attribute = "#[cfg(not(not(not(not(not(x))))))] let y = 1";
+    let error = StatementParser::new(&arena, mock_tokens_from_source(nested_attribute))
+        .with_options(options)
+        .parse_statement()
+        .unwrap_err();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 4, .. }));
+}
+
+#[test]
+fn test_trivia_is_only_retained_on_request() {
+    let source = "fn a() {}\n\nfn b() {}\n";
+
+    let arena = Arena::new();
+    let mut parser = ProgramParser::new(&arena, mock_tokens_from_source(source));
+    parser.parse_compilation_unit().unwrap();
+    assert!(parser.trivia().is_empty());
+
+    let mut parser = ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .with_options(ParserOptions::new().with_retain_trivia(true));
+    parser.parse_compilation_unit().unwrap();
+    let trivia = parser.trivia();
+    assert!(!trivia.is_empty());
+    assert!(trivia
+        .iter()
+        .all(|span| &source[span.start..span.end] == "\n"));
+
+    // Trivia belongs to the parser that skipped it, not to the arena
+    let mut parser = ProgramParser::new(&arena, mock_tokens_from_source(source));
+    parser.parse_compilation_unit().unwrap();
+    assert!(parser.trivia().is_empty());
+}
+
+#[test]
+fn test_options_reach_nested_parsers() {
+    // The newline inside the block is skipped by a block parser that the
+    // expression parser creates
+    let source = "{\n    a\n    b\n}";
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source))
+        .with_options(ParserOptions::new().with_retain_trivia(true));
+    parser.parse_expression(0).unwrap();
+    assert!(!parser.trivia().is_empty());
+
+    let mut tokens = mock_tokens_from_source(source);
+    let mut parser =
+        BlockParser::new(&arena).with_options(ParserOptions::new().with_retain_trivia(true));
+    parser.parse_block(&mut tokens).unwrap();
+    assert!(!parser.trivia().is_empty());
+
+    // A block inside an expression inside a function body
+    let source = "fn main() {\n    let x = {\n        a\n        b\n    }\n}\n";
+    let mut parser = Parser::new(&arena, mock_tokens_from_source(source))
+        .with_options(ParserOptions::new().with_retain_trivia(true));
+    parser.parse_compilation_unit().unwrap();
+    let trivia = parser.trivia();
+    assert!(trivia.iter().any(|span| span.line == 3), "{:?}", trivia);
+}
+
+#[test]
+fn test_options_reach_macro_expansion() {
+    let options = ParserOptions::new().with_max_nesting_depth(4);
+    // The first error of expanding `source`
+    let expansion_error = |source: &str, options: ParserOptions| {
+        let arena = Arena::new();
+        let unit = ProgramParser::new(&arena, mock_tokens_from_source(source))
+            .parse_compilation_unit()
+            .unwrap();
+        MacroExpander::from_compilation_unit(&arena, unit)
+            .with_options(options)
+            .expand_compilation_unit(&mut unit.clone())
+            .err()
+    };
+
+    // The re-parsed expansion is too deep only under the lower limit
+    let source = "macro wrap { ($e:expr) => { (((($e)))) } } fn main() { wrap!(x); }";
+    assert!(expansion_error(source, ParserOptions::new()).is_none());
+    let error = expansion_error(source, options).unwrap();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 4, .. }));
+
+    // Built-in macro arguments follow the same limit
+    let source = format!(
+        "fn main() {{ println!(\"{{}}\", {}y{}); }}",
+        "(".repeat(6),
+        ")".repeat(6)
+    );
+    assert!(expansion_error(&source, ParserOptions::new()).is_none());
+    let error = expansion_error(&source, options).unwrap();
+    assert!(matches!(error, ParseError::NestingTooDeep { limit: 4, .. }));
+}
@@ -15,7 +15,7 @@ fn test_arena() -> Arena {
 }
 
 /// Helper function to create parser for testing
-fn test_parser(arena: &Arena, tokens: Vec<TokenType>) -> PrattParser<VecTokenStream> {
+fn test_parser(arena: &Arena, tokens: Vec<TokenType>) -> PrattParser<'_, VecTokenStream> {
     let token_stream = VecTokenStream::from_token_types(tokens);
     PrattParser::new(arena, token_stream)
 }
@@ -34,7 +34,7 @@ mod stress_tests {
 
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier(long_identifier.clone()),
+            TokenType::Identifier(long_identifier.as_str().into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Semicolon,
@@ -67,7 +67,7 @@ mod stress_tests {
         }
 
         // Add identifier
-        tokens.push(TokenType::Identifier("a".to_string()));
+        tokens.push(TokenType::Identifier("a".into()));
 
         // Add closing parentheses
         for _ in 0..nesting_depth {
@@ -103,7 +103,7 @@ mod stress_tests {
         for number in test_cases {
             let tokens = vec![
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(number),
                 TokenType::Semicolon,
@@ -137,12 +137,12 @@ mod stress_tests {
             // Test in a function context (which uses ProgramParser)
             let tokens = vec![
                 TokenType::Fn,
-                TokenType::Identifier("test_fn".to_string()),
+                TokenType::Identifier("test_fn".into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("s".to_string()),
+                TokenType::Identifier("s".into()),
                 TokenType::Equal,
                 TokenType::StringLiteral(long_string.clone()),
                 TokenType::Semicolon,
@@ -177,7 +177,7 @@ mod stress_tests {
 
         let mut tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
         ];
 
@@ -186,9 +186,9 @@ mod stress_tests {
             if i > 0 {
                 tokens.push(TokenType::Comma);
             }
-            tokens.push(TokenType::Identifier(format!("param_{}", i)));
+            tokens.push(TokenType::Identifier(format!("param_{}", i).into()));
             tokens.push(TokenType::Colon);
-            tokens.push(TokenType::Identifier("i32".to_string()));
+            tokens.push(TokenType::Identifier("i32".into()));
         }
 
         tokens.extend(vec![
@@ -223,7 +223,7 @@ mod stress_tests {
 
         let mut tokens = vec![
             TokenType::Fn,
-            TokenType::Identifier("test".to_string()),
+            TokenType::Identifier("test".into()),
             TokenType::LeftParen,
             TokenType::RightParen,
         ];
@@ -233,7 +233,7 @@ mod stress_tests {
         for _ in 0..nesting_depth {
             tokens.push(TokenType::LeftBrace);
             tokens.push(TokenType::Let);
-            tokens.push(TokenType::Identifier("x".to_string()));
+            tokens.push(TokenType::Identifier("x".into()));
             tokens.push(TokenType::Equal);
             tokens.push(TokenType::IntegerLiteral(1));
             tokens.push(TokenType::Semicolon);
@@ -276,12 +276,12 @@ mod stress_tests {
         for i in 0..100 {
             tokens.extend(vec![
                 TokenType::Fn,
-                TokenType::Identifier(format!("func_{}", i)),
+                TokenType::Identifier(format!("func_{}", i).into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(i),
                 TokenType::Semicolon,
@@ -344,7 +344,7 @@ mod boundary_tests {
         let arena = test_arena();
 
         let single_tokens = vec![
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::IntegerLiteral(42),
             TokenType::StringLiteral("hello".to_string()),
             TokenType::LeftParen,
@@ -376,7 +376,7 @@ mod boundary_tests {
         for _ in 0..max_safe_depth {
             tokens.extend(vec![
                 TokenType::If,
-                TokenType::Identifier("true".to_string()),
+                TokenType::Identifier("true".into()),
                 TokenType::LeftBrace,
             ]);
         }
@@ -384,7 +384,7 @@ mod boundary_tests {
         // Add innermost statement
         tokens.extend(vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(1),
             TokenType::Semicolon,
@@ -424,7 +424,7 @@ mod boundary_tests {
         for identifier in unicode_identifiers {
             let tokens = vec![
                 TokenType::Let,
-                TokenType::Identifier(identifier.clone()),
+                TokenType::Identifier(identifier.as_str().into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
@@ -458,7 +458,7 @@ mod error_boundary_tests {
             // Unmatched delimiters
             vec![
                 TokenType::LeftParen,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Eof,
             ],
             // Invalid operator sequences
@@ -472,7 +472,7 @@ mod error_boundary_tests {
             vec![
                 TokenType::Let,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Eof,
             ],
         ];
@@ -496,7 +496,7 @@ mod error_boundary_tests {
         // Test incomplete expression - this should fail
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::Eof, // Missing expression
         ];
@@ -511,7 +511,7 @@ mod error_boundary_tests {
         // Test missing semicolon - this might be handled gracefully
         let tokens = vec![
             TokenType::Let,
-            TokenType::Identifier("x".to_string()),
+            TokenType::Identifier("x".into()),
             TokenType::Equal,
             TokenType::IntegerLiteral(42),
             TokenType::Eof, // Missing semicolon
@@ -537,19 +537,19 @@ mod performance_regression_tests {
 
         // Create complex expression: a + b * c - d / e + f % g
         let tokens = vec![
-            TokenType::Identifier("a".to_string()),
+            TokenType::Identifier("a".into()),
             TokenType::Plus,
-            TokenType::Identifier("b".to_string()),
+            TokenType::Identifier("b".into()),
             TokenType::Star,
-            TokenType::Identifier("c".to_string()),
+            TokenType::Identifier("c".into()),
             TokenType::Minus,
-            TokenType::Identifier("d".to_string()),
+            TokenType::Identifier("d".into()),
             TokenType::Slash,
-            TokenType::Identifier("e".to_string()),
+            TokenType::Identifier("e".into()),
             TokenType::Plus,
-            TokenType::Identifier("f".to_string()),
+            TokenType::Identifier("f".into()),
             TokenType::Percent,
-            TokenType::Identifier("g".to_string()),
+            TokenType::Identifier("g".into()),
             TokenType::Eof,
         ];
 
@@ -586,12 +586,12 @@ mod performance_regression_tests {
 
             let tokens = vec![
                 TokenType::Fn,
-                TokenType::Identifier("test".to_string()),
+                TokenType::Identifier("test".into()),
                 TokenType::LeftParen,
                 TokenType::RightParen,
                 TokenType::LeftBrace,
                 TokenType::Let,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(42),
                 TokenType::Semicolon,
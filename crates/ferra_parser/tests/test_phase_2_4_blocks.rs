@@ -20,7 +20,7 @@ fn test_simple_braced_block() {
     let tokens = vec![
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -59,7 +59,7 @@ fn test_simple_indented_block() {
         Token::new(TokenType::Colon, create_test_span()),
         Token::new(TokenType::Newline, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -85,7 +85,7 @@ fn test_mixed_block_styles_error() {
     let tokens = vec![
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -123,7 +123,7 @@ fn test_unsafe_block() {
         Token::new(TokenType::Unsafe, create_test_span()),
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("ptr".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("ptr".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(0), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -153,10 +153,7 @@ fn test_async_block() {
         Token::new(TokenType::Async, create_test_span()),
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(
-            TokenType::Identifier("result".to_string()),
-            create_test_span(),
-        ),
+        Token::new(TokenType::Identifier("result".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -185,7 +182,7 @@ fn test_labeled_block() {
     let tokens = vec![
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -196,12 +193,12 @@ fn test_labeled_block() {
     let mut stream = VecTokenStream::new(tokens);
     let mut parser = BlockParser::new(&arena);
 
-    let result = parser.parse_labeled_block(&mut stream, "outer".to_string());
+    let result = parser.parse_labeled_block(&mut stream, "outer".into());
     assert!(result.is_ok());
 
     let block = result.unwrap();
     assert!(block.is_braced);
-    assert_eq!(block.label, Some("outer".to_string()));
+    assert_eq!(block.label, Some("outer".into()));
     assert_eq!(block.statements.len(), 1);
 }
 
@@ -213,10 +210,7 @@ fn test_nested_blocks_scope_depth() {
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::LeftBrace, create_test_span()),
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(
-            TokenType::Identifier("inner".to_string()),
-            create_test_span(),
-        ),
+        Token::new(TokenType::Identifier("inner".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(1), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
@@ -245,7 +239,7 @@ fn test_scope_validation() {
     // Test valid scope
     let valid_scope = ScopeInfo {
         depth: 1,
-        variables: vec!["x".to_string(), "y".to_string()],
+        variables: vec!["x".into(), "y".into()],
         is_unsafe: false,
         is_async: false,
         label: None,
@@ -256,7 +250,7 @@ fn test_scope_validation() {
     // Test invalid scope with duplicate variables
     let invalid_scope = ScopeInfo {
         depth: 1,
-        variables: vec!["x".to_string(), "y".to_string(), "x".to_string()],
+        variables: vec!["x".into(), "y".into(), "x".into()],
         is_unsafe: false,
         is_async: false,
         label: None,
@@ -318,16 +312,16 @@ fn test_complex_block_parsing() {
         Token::new(TokenType::LeftBrace, create_test_span()),
         // Variable declaration
         Token::new(TokenType::Let, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Equal, create_test_span()),
         Token::new(TokenType::IntegerLiteral(42), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
         // Expression statement
-        Token::new(TokenType::Identifier("y".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("y".into()), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
         // Return statement
         Token::new(TokenType::Return, create_test_span()),
-        Token::new(TokenType::Identifier("x".to_string()), create_test_span()),
+        Token::new(TokenType::Identifier("x".into()), create_test_span()),
         Token::new(TokenType::Semicolon, create_test_span()),
         Token::new(TokenType::RightBrace, create_test_span()),
         Token::new(TokenType::Eof, create_test_span()),
@@ -447,7 +441,7 @@ fn test_complex_expressions_in_blocks() {
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(1),
         TokenType::Plus,
@@ -494,7 +488,7 @@ fn test_function_calls_in_blocks() {
     let mut parser = BlockParser::new(&arena);
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
-        TokenType::Identifier("println".to_string()),
+        TokenType::Identifier("println".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("Hello, world!".to_string()),
         TokenType::RightParen,
@@ -517,7 +511,7 @@ fn test_function_calls_in_blocks() {
     match &block.statements[0] {
         Statement::Expression(expr) => match expr {
             Expression::Call(call) => {
-                match call.callee.as_ref() {
+                match call.callee {
                     Expression::Identifier(name) => assert_eq!(name, "println"),
                     _ => panic!("Expected function name to be identifier"),
                 }
@@ -538,11 +532,11 @@ fn test_member_access_in_blocks() {
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Equal,
-        TokenType::Identifier("object".to_string()),
+        TokenType::Identifier("object".into()),
         TokenType::Dot,
-        TokenType::Identifier("property".to_string()),
+        TokenType::Identifier("property".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -565,7 +559,7 @@ fn test_member_access_in_blocks() {
             assert!(var_decl.initializer.is_some());
             match var_decl.initializer.as_ref().unwrap() {
                 Expression::MemberAccess(access) => {
-                    match access.object.as_ref() {
+                    match access.object {
                         Expression::Identifier(name) => assert_eq!(name, "object"),
                         _ => panic!("Expected object to be identifier"),
                     }
@@ -587,7 +581,7 @@ fn test_array_literals_in_blocks() {
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("numbers".to_string()),
+        TokenType::Identifier("numbers".into()),
         TokenType::Equal,
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(1),
@@ -635,13 +629,13 @@ fn test_complex_nested_expressions_in_blocks() {
     let mut parser = BlockParser::new(&arena);
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("array".to_string()),
+        TokenType::Identifier("array".into()),
         TokenType::LeftBracket,
-        TokenType::Identifier("index".to_string()),
+        TokenType::Identifier("index".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::RightBracket,
@@ -667,7 +661,7 @@ fn test_complex_nested_expressions_in_blocks() {
             match expr {
                 Expression::Call(call) => {
                     // Should be a member access for obj.method
-                    match call.callee.as_ref() {
+                    match call.callee {
                         Expression::MemberAccess(access) => {
                             assert_eq!(access.member, "method");
                         }
@@ -677,12 +671,12 @@ fn test_complex_nested_expressions_in_blocks() {
                     assert_eq!(call.arguments.len(), 1);
                     match &call.arguments[0] {
                         Expression::Index(index) => {
-                            match index.object.as_ref() {
+                            match index.object {
                                 Expression::Identifier(name) => assert_eq!(name, "array"),
                                 _ => panic!("Expected array identifier"),
                             }
                             // Index should be a binary expression (index + 1)
-                            match index.index.as_ref() {
+                            match index.index {
                                 Expression::Binary(binary) => {
                                     assert!(matches!(binary.operator, BinaryOperator::Add));
                                 }
@@ -708,10 +702,10 @@ fn test_unary_expressions_in_blocks() {
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("negated".to_string()),
+        TokenType::Identifier("negated".into()),
         TokenType::Equal,
         TokenType::Minus,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -735,7 +729,7 @@ fn test_unary_expressions_in_blocks() {
             match var_decl.initializer.as_ref().unwrap() {
                 Expression::Unary(unary) => {
                     assert!(matches!(unary.operator, UnaryOperator::Minus));
-                    match unary.operand.as_ref() {
+                    match unary.operand {
                         Expression::Identifier(name) => assert_eq!(name, "value"),
                         _ => panic!("Expected identifier operand"),
                     }
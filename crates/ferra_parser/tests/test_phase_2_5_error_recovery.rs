@@ -12,7 +12,7 @@ use ferra_parser::{
 fn test_error_production_missing_semicolon() {
     let production = ErrorProduction::MissingSemicolon;
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Let, // Should trigger missing semicolon error
@@ -28,7 +28,7 @@ fn test_error_production_missing_semicolon() {
 fn test_error_production_missing_open_paren() {
     let production = ErrorProduction::MissingOpenParen;
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::IntegerLiteral(42), // Should be LeftParen
     ]);
 
@@ -73,7 +73,7 @@ fn test_sync_token_expression_start() {
     let sync_token = SyncToken::ExpressionStart;
 
     // Should match expression starts
-    assert!(sync_token.matches(&Token::dummy(TokenType::Identifier("x".to_string()))));
+    assert!(sync_token.matches(&Token::dummy(TokenType::Identifier("x".into()))));
     assert!(sync_token.matches(&Token::dummy(TokenType::IntegerLiteral(42))));
     assert!(sync_token.matches(&Token::dummy(TokenType::LeftParen)));
     assert!(sync_token.matches(&Token::dummy(TokenType::Minus)));
@@ -95,7 +95,7 @@ fn test_sync_token_expression_terminator() {
 
     // Should not match expression content
     assert!(!sync_token.matches(&Token::dummy(TokenType::Plus)));
-    assert!(!sync_token.matches(&Token::dummy(TokenType::Identifier("x".to_string()))));
+    assert!(!sync_token.matches(&Token::dummy(TokenType::Identifier("x".into()))));
 }
 
 #[test]
@@ -141,7 +141,7 @@ fn test_panic_mode_recovery_to_statement() {
         TokenType::Plus, // Error token
         TokenType::Star, // Error token
         TokenType::Let,  // Statement start (sync point)
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
     ]);
 
     let sync_token = ErrorRecovery::recover_to_statement(&mut tokens);
@@ -195,7 +195,7 @@ fn test_smart_recovery_with_context() {
 #[test]
 fn test_recovery_with_productions() {
     let mut tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Let, // Missing semicolon before next statement
@@ -333,11 +333,11 @@ fn test_multi_error_integration() {
     // Simulate parsing with multiple errors
     let mut tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Plus, // Missing '=' before expression
         TokenType::IntegerLiteral(42),
         TokenType::Let, // Missing semicolon
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Equal,
         TokenType::RightParen, // Unmatched closing paren
         TokenType::IntegerLiteral(24),
@@ -15,62 +15,62 @@ fn test_complex_program_with_all_features() {
     let tokens = vec![
         // Data class: data Vector3 { x: float, y: float, z: float }
         TokenType::Data,
-        TokenType::Identifier("Vector3".to_string()),
+        TokenType::Identifier("Vector3".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::Comma,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Colon,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::Comma,
-        TokenType::Identifier("z".to_string()),
+        TokenType::Identifier("z".into()),
         TokenType::Colon,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::RightBrace,
         // Extern block: extern "C" { fn sqrt(x: float) -> float; }
         TokenType::Extern,
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("sqrt".to_string()),
+        TokenType::Identifier("sqrt".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Function: fn magnitude(v: Vector3) -> float { let result = 0.0; }
         TokenType::Fn,
-        TokenType::Identifier("magnitude".to_string()),
+        TokenType::Identifier("magnitude".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("v".to_string()),
+        TokenType::Identifier("v".into()),
         TokenType::Colon,
-        TokenType::Identifier("Vector3".to_string()),
+        TokenType::Identifier("Vector3".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
         TokenType::FloatLiteral(0.0),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // Main function: fn main() -> int { let x = 0; }
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(0),
         TokenType::Semicolon,
@@ -133,39 +133,39 @@ fn test_multiple_data_classes() {
     let tokens = vec![
         // data Point { x: int, y: int }
         TokenType::Data,
-        TokenType::Identifier("Point".to_string()),
+        TokenType::Identifier("Point".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBrace,
         // data Color { r: int, g: int, b: int, a: int }
         TokenType::Data,
-        TokenType::Identifier("Color".to_string()),
+        TokenType::Identifier("Color".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("r".to_string()),
+        TokenType::Identifier("r".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("g".to_string()),
+        TokenType::Identifier("g".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBrace,
         // data Empty { }
         TokenType::Data,
-        TokenType::Identifier("Empty".to_string()),
+        TokenType::Identifier("Empty".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -217,49 +217,49 @@ fn test_functions_with_different_signatures() {
     let tokens = vec![
         // fn no_params() { }
         TokenType::Fn,
-        TokenType::Identifier("no_params".to_string()),
+        TokenType::Identifier("no_params".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::RightBrace,
         // fn one_param(x: int) -> int { let result = x; }
         TokenType::Fn,
-        TokenType::Identifier("one_param".to_string()),
+        TokenType::Identifier("one_param".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         // fn three_params(a: int, b: float, c: string) -> bool { let flag = true; }
         TokenType::Fn,
-        TokenType::Identifier("three_params".to_string()),
+        TokenType::Identifier("three_params".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Colon,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
         TokenType::Comma,
-        TokenType::Identifier("c".to_string()),
+        TokenType::Identifier("c".into()),
         TokenType::Colon,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("flag".to_string()),
+        TokenType::Identifier("flag".into()),
         TokenType::Equal,
         TokenType::BooleanLiteral(true),
         TokenType::Semicolon,
@@ -319,27 +319,27 @@ fn test_extern_blocks_with_multiple_items() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("malloc".to_string()),
+        TokenType::Identifier("malloc".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Semicolon,
         TokenType::Fn,
-        TokenType::Identifier("free".to_string()),
+        TokenType::Identifier("free".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Colon,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::Static,
-        TokenType::Identifier("errno".to_string()),
+        TokenType::Identifier("errno".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -400,42 +400,42 @@ fn test_mixed_top_level_items() {
     let tokens = vec![
         // fn first_function() { }
         TokenType::Fn,
-        TokenType::Identifier("first_function".to_string()),
+        TokenType::Identifier("first_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::RightBrace,
         // data MyData { value: int }
         TokenType::Data,
-        TokenType::Identifier("MyData".to_string()),
+        TokenType::Identifier("MyData".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBrace,
         // extern "C" { fn external_func(); }
         TokenType::Extern,
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("external_func".to_string()),
+        TokenType::Identifier("external_func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::RightBrace,
         // fn second_function(data: MyData) -> int { let result = 42; }
         TokenType::Fn,
-        TokenType::Identifier("second_function".to_string()),
+        TokenType::Identifier("second_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
-        TokenType::Identifier("MyData".to_string()),
+        TokenType::Identifier("MyData".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Semicolon,
@@ -470,7 +470,7 @@ fn test_error_recovery_in_program_parsing() {
     let tokens = vec![
         // Valid function
         TokenType::Fn,
-        TokenType::Identifier("valid_func".to_string()),
+        TokenType::Identifier("valid_func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -479,7 +479,7 @@ fn test_error_recovery_in_program_parsing() {
         TokenType::Plus, // This should cause an error
         // Another valid function after error
         TokenType::Fn,
-        TokenType::Identifier("another_func".to_string()),
+        TokenType::Identifier("another_func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -529,7 +529,7 @@ fn test_program_with_diagnostics() {
     // Test the diagnostic reporting functionality
     let tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("test".to_string()),
+        TokenType::Identifier("test".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -5,7 +5,7 @@
 //! extern function types, pointer types, and complex nested combinations.
 
 use ferra_parser::{
-    ast::Type,
+    ast::{Arena, Type},
     token::{TokenType, VecTokenStream},
     types::parse_type,
 };
@@ -18,6 +18,7 @@ fn create_token_stream(token_types: Vec<TokenType>) -> VecTokenStream {
 
 #[test]
 fn test_simple_identifier_types() {
+    let arena = Arena::new();
     // Test basic built-in types
     let test_cases = vec![
         "int", "string", "bool", "float", "char", "void", "u8", "u16", "u32", "u64", "i8", "i16",
@@ -25,9 +26,9 @@ fn test_simple_identifier_types() {
     ];
 
     for type_name in test_cases {
-        let mut tokens = create_token_stream(vec![TokenType::Identifier(type_name.to_string())]);
+        let mut tokens = create_token_stream(vec![TokenType::Identifier(type_name.into())]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Identifier(name) => assert_eq!(name, type_name),
             _ => panic!("Expected identifier type for {}", type_name),
@@ -37,6 +38,7 @@ fn test_simple_identifier_types() {
 
 #[test]
 fn test_custom_identifier_types() {
+    let arena = Arena::new();
     // Test user-defined types
     let test_cases = vec![
         "MyStruct",
@@ -47,9 +49,9 @@ fn test_custom_identifier_types() {
     ];
 
     for type_name in test_cases {
-        let mut tokens = create_token_stream(vec![TokenType::Identifier(type_name.to_string())]);
+        let mut tokens = create_token_stream(vec![TokenType::Identifier(type_name.into())]);
 
-        let result = parse_type(&mut tokens).unwrap();
+        let result = parse_type(&arena, &mut tokens).unwrap();
         match result {
             Type::Identifier(name) => assert_eq!(name, type_name),
             _ => panic!("Expected identifier type for {}", type_name),
@@ -59,10 +61,11 @@ fn test_custom_identifier_types() {
 
 #[test]
 fn test_tuple_types_various_lengths() {
+    let arena = Arena::new();
     // Empty tuple: ()
     let mut tokens = create_token_stream(vec![TokenType::LeftParen, TokenType::RightParen]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Tuple(types) => assert_eq!(types.len(), 0),
         _ => panic!("Expected empty tuple type"),
@@ -71,12 +74,12 @@ fn test_tuple_types_various_lengths() {
     // Single element tuple: (int,)
     let mut tokens = create_token_stream(vec![
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
         TokenType::RightParen,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Tuple(types) => assert_eq!(types.len(), 1),
         _ => panic!("Expected single element tuple"),
@@ -85,15 +88,15 @@ fn test_tuple_types_various_lengths() {
     // Triple tuple: (int, string, bool)
     let mut tokens = create_token_stream(vec![
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::Comma,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Tuple(types) => {
             assert_eq!(types.len(), 3);
@@ -112,16 +115,17 @@ fn test_tuple_types_various_lengths() {
 
 #[test]
 fn test_array_types_simple_and_nested() {
+    let arena = Arena::new();
     // Simple array: [int]
     let mut tokens = create_token_stream(vec![
         TokenType::LeftBracket,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Array(element_type) => match element_type.as_ref() {
+        Type::Array(element_type) => match element_type {
             Type::Identifier(name) => assert_eq!(name, "int"),
             _ => panic!("Expected int element type"),
         },
@@ -132,15 +136,15 @@ fn test_array_types_simple_and_nested() {
     let mut tokens = create_token_stream(vec![
         TokenType::LeftBracket,
         TokenType::LeftBracket,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightBracket,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Array(outer_type) => match outer_type.as_ref() {
-            Type::Array(inner_type) => match inner_type.as_ref() {
+        Type::Array(outer_type) => match outer_type {
+            Type::Array(inner_type) => match inner_type {
                 Type::Identifier(name) => assert_eq!(name, "string"),
                 _ => panic!("Expected string in nested array"),
             },
@@ -154,17 +158,17 @@ fn test_array_types_simple_and_nested() {
         TokenType::LeftBracket,
         TokenType::LeftBracket,
         TokenType::LeftBracket,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightBracket,
         TokenType::RightBracket,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Array(l1) => match l1.as_ref() {
-            Type::Array(l2) => match l2.as_ref() {
-                Type::Array(l3) => match l3.as_ref() {
+        Type::Array(l1) => match l1 {
+            Type::Array(l2) => match l2 {
+                Type::Array(l3) => match l3 {
                     Type::Identifier(name) => assert_eq!(name, "bool"),
                     _ => panic!("Expected bool in triple nested array"),
                 },
@@ -180,6 +184,7 @@ fn test_array_types_simple_and_nested() {
 
 #[test]
 fn test_function_types_various_signatures() {
+    let arena = Arena::new();
     // No parameters, no return: fn()
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
@@ -187,14 +192,14 @@ fn test_function_types_various_signatures() {
         TokenType::RightParen,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert_eq!(func_type.parameters.len(), 0);
             assert!(!func_type.is_extern);
             assert!(func_type.abi.is_none());
             // Should default to unit type
-            match func_type.return_type.as_ref() {
+            match func_type.return_type {
                 Type::Tuple(types) => assert_eq!(types.len(), 0),
                 _ => panic!("Expected unit return type"),
             }
@@ -206,13 +211,13 @@ fn test_function_types_various_signatures() {
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert_eq!(func_type.parameters.len(), 1);
@@ -220,7 +225,7 @@ fn test_function_types_various_signatures() {
                 Type::Identifier(name) => assert_eq!(name, "int"),
                 _ => panic!("Expected int parameter"),
             }
-            match func_type.return_type.as_ref() {
+            match func_type.return_type {
                 Type::Identifier(name) => assert_eq!(name, "string"),
                 _ => panic!("Expected string return type"),
             }
@@ -232,17 +237,17 @@ fn test_function_types_various_signatures() {
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::Comma,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("float".to_string()),
+        TokenType::Identifier("float".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert_eq!(func_type.parameters.len(), 3);
@@ -258,7 +263,7 @@ fn test_function_types_various_signatures() {
                 }
                 _ => panic!("Expected identifier parameter types"),
             }
-            match func_type.return_type.as_ref() {
+            match func_type.return_type {
                 Type::Identifier(name) => assert_eq!(name, "float"),
                 _ => panic!("Expected float return type"),
             }
@@ -269,25 +274,26 @@ fn test_function_types_various_signatures() {
 
 #[test]
 fn test_extern_function_types() {
+    let arena = Arena::new();
     // Extern with ABI: extern "C" fn(int) -> void
     let mut tokens = create_token_stream(vec![
         TokenType::Extern,
         TokenType::StringLiteral("C".to_string()),
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("void".to_string()),
+        TokenType::Identifier("void".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert!(func_type.is_extern);
             assert_eq!(func_type.abi, Some("C".to_string()));
             assert_eq!(func_type.parameters.len(), 1);
-            match func_type.return_type.as_ref() {
+            match func_type.return_type {
                 Type::Identifier(name) => assert_eq!(name, "void"),
                 _ => panic!("Expected void return type"),
             }
@@ -302,10 +308,10 @@ fn test_extern_function_types() {
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert!(func_type.is_extern);
@@ -318,17 +324,16 @@ fn test_extern_function_types() {
 
 #[test]
 fn test_pointer_types() {
+    let arena = Arena::new();
     // Simple pointer: *int
-    let mut tokens = create_token_stream(vec![
-        TokenType::Star,
-        TokenType::Identifier("int".to_string()),
-    ]);
+    let mut tokens =
+        create_token_stream(vec![TokenType::Star, TokenType::Identifier("int".into())]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Pointer(ptr_type) => {
             assert!(ptr_type.is_mutable); // Default to mutable for now
-            match ptr_type.target.as_ref() {
+            match ptr_type.target {
                 Type::Identifier(name) => assert_eq!(name, "int"),
                 _ => panic!("Expected int pointer target"),
             }
@@ -340,13 +345,13 @@ fn test_pointer_types() {
     let mut tokens = create_token_stream(vec![
         TokenType::Star,
         TokenType::Star,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Pointer(outer_ptr) => match outer_ptr.target.as_ref() {
-            Type::Pointer(inner_ptr) => match inner_ptr.target.as_ref() {
+        Type::Pointer(outer_ptr) => match outer_ptr.target {
+            Type::Pointer(inner_ptr) => match inner_ptr.target {
                 Type::Identifier(name) => assert_eq!(name, "string"),
                 _ => panic!("Expected string in nested pointer"),
             },
@@ -360,20 +365,21 @@ fn test_pointer_types() {
 
 #[test]
 fn test_array_of_tuples() {
+    let arena = Arena::new();
     // [(int, string)]
     let mut tokens = create_token_stream(vec![
         TokenType::LeftBracket,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Comma,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Array(element_type) => match element_type.as_ref() {
+        Type::Array(element_type) => match element_type {
             Type::Tuple(types) => {
                 assert_eq!(types.len(), 2);
                 match (&types[0], &types[1]) {
@@ -392,30 +398,31 @@ fn test_array_of_tuples() {
 
 #[test]
 fn test_tuple_of_arrays() {
+    let arena = Arena::new();
     // ([int], [string], [bool])
     let mut tokens = create_token_stream(vec![
         TokenType::LeftParen,
         TokenType::LeftBracket,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::LeftBracket,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::LeftBracket,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Tuple(types) => {
             assert_eq!(types.len(), 3);
             for (i, expected) in ["int", "string", "bool"].iter().enumerate() {
                 match &types[i] {
-                    Type::Array(element_type) => match element_type.as_ref() {
+                    Type::Array(element_type) => match element_type {
                         Type::Identifier(name) => assert_eq!(name, expected),
                         _ => panic!("Expected {} array element", expected),
                     },
@@ -429,33 +436,34 @@ fn test_tuple_of_arrays() {
 
 #[test]
 fn test_function_with_complex_parameters() {
+    let arena = Arena::new();
     // fn([int], (string, bool)) -> *int
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::LeftBracket,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::LeftParen,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::Comma,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(func_type) => {
             assert_eq!(func_type.parameters.len(), 2);
 
             // First parameter: [int]
             match &func_type.parameters[0] {
-                Type::Array(element_type) => match element_type.as_ref() {
+                Type::Array(element_type) => match element_type {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int array"),
                 },
@@ -478,8 +486,8 @@ fn test_function_with_complex_parameters() {
             }
 
             // Return type: *int
-            match func_type.return_type.as_ref() {
-                Type::Pointer(ptr_type) => match ptr_type.target.as_ref() {
+            match func_type.return_type {
+                Type::Pointer(ptr_type) => match ptr_type.target {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int pointer"),
                 },
@@ -492,22 +500,23 @@ fn test_function_with_complex_parameters() {
 
 #[test]
 fn test_higher_order_functions() {
+    let arena = Arena::new();
     // fn(fn(int) -> string) -> bool
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Function(outer_func) => {
             assert_eq!(outer_func.parameters.len(), 1);
@@ -520,7 +529,7 @@ fn test_higher_order_functions() {
                         Type::Identifier(name) => assert_eq!(name, "int"),
                         _ => panic!("Expected int parameter in inner function"),
                     }
-                    match inner_func.return_type.as_ref() {
+                    match inner_func.return_type {
                         Type::Identifier(name) => assert_eq!(name, "string"),
                         _ => panic!("Expected string return in inner function"),
                     }
@@ -529,7 +538,7 @@ fn test_higher_order_functions() {
             }
 
             // Return type: bool
-            match outer_func.return_type.as_ref() {
+            match outer_func.return_type {
                 Type::Identifier(name) => assert_eq!(name, "bool"),
                 _ => panic!("Expected bool return type"),
             }
@@ -540,28 +549,29 @@ fn test_higher_order_functions() {
 
 #[test]
 fn test_array_of_function_pointers() {
+    let arena = Arena::new();
     // [fn(int) -> string]
     let mut tokens = create_token_stream(vec![
         TokenType::LeftBracket,
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
-        Type::Array(element_type) => match element_type.as_ref() {
+        Type::Array(element_type) => match element_type {
             Type::Function(func_type) => {
                 assert_eq!(func_type.parameters.len(), 1);
                 match &func_type.parameters[0] {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int parameter"),
                 }
-                match func_type.return_type.as_ref() {
+                match func_type.return_type {
                     Type::Identifier(name) => assert_eq!(name, "string"),
                     _ => panic!("Expected string return type"),
                 }
@@ -574,6 +584,7 @@ fn test_array_of_function_pointers() {
 
 #[test]
 fn test_extremely_complex_type() {
+    let arena = Arena::new();
     // [fn(*[int], (string, bool)) -> *(string, [bool])]
     let mut tokens = create_token_stream(vec![
         TokenType::LeftBracket,
@@ -581,38 +592,38 @@ fn test_extremely_complex_type() {
         TokenType::LeftParen,
         TokenType::Star,
         TokenType::LeftBracket,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::LeftParen,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::Comma,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
         TokenType::LeftParen,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::Comma,
         TokenType::LeftBracket,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_type(&mut tokens).unwrap();
+    let result = parse_type(&arena, &mut tokens).unwrap();
     match result {
         Type::Array(element_type) => {
-            match element_type.as_ref() {
+            match element_type {
                 Type::Function(func_type) => {
                     assert_eq!(func_type.parameters.len(), 2);
 
                     // First parameter: *[int]
                     match &func_type.parameters[0] {
-                        Type::Pointer(ptr_type) => match ptr_type.target.as_ref() {
-                            Type::Array(arr_type) => match arr_type.as_ref() {
+                        Type::Pointer(ptr_type) => match ptr_type.target {
+                            Type::Array(arr_type) => match arr_type {
                                 Type::Identifier(name) => assert_eq!(name, "int"),
                                 _ => panic!("Expected int in array"),
                             },
@@ -630,14 +641,14 @@ fn test_extremely_complex_type() {
                     }
 
                     // Return type: *(string, [bool])
-                    match func_type.return_type.as_ref() {
-                        Type::Pointer(ptr_type) => match ptr_type.target.as_ref() {
+                    match func_type.return_type {
+                        Type::Pointer(ptr_type) => match ptr_type.target {
                             Type::Tuple(types) => {
                                 assert_eq!(types.len(), 2);
                                 match (&types[0], &types[1]) {
                                     (Type::Identifier(t1), Type::Array(arr)) => {
                                         assert_eq!(t1, "string");
-                                        match arr.as_ref() {
+                                        match arr {
                                             Type::Identifier(name) => assert_eq!(name, "bool"),
                                             _ => panic!("Expected bool array"),
                                         }
@@ -661,12 +672,13 @@ fn test_extremely_complex_type() {
 
 #[test]
 fn test_type_parsing_error_cases() {
+    let arena = Arena::new();
     // Invalid token for type
     let mut tokens = create_token_stream(vec![
         TokenType::Plus, // Invalid token for type
     ]);
 
-    let result = parse_type(&mut tokens);
+    let result = parse_type(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Incomplete array type
@@ -675,18 +687,18 @@ fn test_type_parsing_error_cases() {
         // Missing element type and closing bracket
     ]);
 
-    let result = parse_type(&mut tokens);
+    let result = parse_type(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Incomplete function type
     let mut tokens = create_token_stream(vec![
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         // Missing closing paren
     ]);
 
-    let result = parse_type(&mut tokens);
+    let result = parse_type(&arena, &mut tokens);
     assert!(result.is_err());
 }
 
@@ -699,26 +711,26 @@ fn test_type_parsing_integration() {
     // Test that the enhanced type parser works with existing parsers
     let tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_function".to_string()),
+        TokenType::Identifier("complex_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightBracket,
         TokenType::Comma,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
     ];
@@ -740,7 +752,7 @@ fn test_type_parsing_integration() {
 
             // First parameter should be array type
             match &func_decl.parameters[0].param_type {
-                Type::Array(element_type) => match element_type.as_ref() {
+                Type::Array(element_type) => match element_type {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int array parameter"),
                 },
@@ -751,7 +763,7 @@ fn test_type_parsing_integration() {
             match &func_decl.parameters[1].param_type {
                 Type::Function(func_type) => {
                     assert_eq!(func_type.parameters.len(), 1);
-                    match func_type.return_type.as_ref() {
+                    match func_type.return_type {
                         Type::Identifier(name) => assert_eq!(name, "bool"),
                         _ => panic!("Expected bool return type"),
                     }
@@ -761,7 +773,7 @@ fn test_type_parsing_integration() {
 
             // Return type should be pointer
             match func_decl.return_type.as_ref().unwrap() {
-                Type::Pointer(ptr_type) => match ptr_type.target.as_ref() {
+                Type::Pointer(ptr_type) => match ptr_type.target {
                     Type::Identifier(name) => assert_eq!(name, "int"),
                     _ => panic!("Expected int pointer return"),
                 },
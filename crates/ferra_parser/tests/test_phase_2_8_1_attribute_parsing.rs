@@ -8,7 +8,7 @@
 //! - Error handling for malformed attributes
 
 use ferra_parser::{
-    ast::{Arena, Item, Statement},
+    ast::{Arena, AttributeArgument, Expression, Item, Literal, Statement},
     attribute::parser::{parse_attribute, parse_attributes},
     statement::parser::StatementParser,
     token::{stream::VecTokenStream, TokenType},
@@ -21,15 +21,16 @@ fn create_token_stream(token_types: Vec<TokenType>) -> VecTokenStream {
 /// Test simple attribute parsing
 #[test]
 fn test_simple_attribute_parsing() {
+    let arena = Arena::new();
     // #[inline]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "inline");
     assert_eq!(result.arguments.len(), 0);
 }
@@ -37,104 +38,109 @@ fn test_simple_attribute_parsing() {
 /// Test attribute with single argument
 #[test]
 fn test_attribute_with_single_argument() {
+    let arena = Arena::new();
     // #[cfg(test)]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("cfg".to_string()),
+        TokenType::Identifier("cfg".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("test".to_string()),
+        TokenType::Identifier("test".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "cfg");
     assert_eq!(result.arguments.len(), 1);
-    assert_eq!(result.arguments[0], "test");
+    assert_eq!(result.arguments[0].as_ident(), Some("test"));
 }
 
 /// Test derive attribute with multiple arguments
 #[test]
 fn test_derive_attribute_multiple_arguments() {
+    let arena = Arena::new();
     // #[derive(Debug, Clone, PartialEq)]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
-        TokenType::Identifier("PartialEq".to_string()),
+        TokenType::Identifier("PartialEq".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "derive");
     assert_eq!(result.arguments.len(), 3);
-    assert_eq!(result.arguments[0], "Debug");
-    assert_eq!(result.arguments[1], "Clone");
-    assert_eq!(result.arguments[2], "PartialEq");
+    assert_eq!(result.arguments[0].as_ident(), Some("Debug"));
+    assert_eq!(result.arguments[1].as_ident(), Some("Clone"));
+    assert_eq!(result.arguments[2].as_ident(), Some("PartialEq"));
 }
 
 /// Test multiple consecutive attributes
 #[test]
 fn test_multiple_attributes() {
+    let arena = Arena::new();
     // #[inline] #[derive(Debug)]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attributes(&mut tokens).unwrap();
+    let result = parse_attributes(&arena, &mut tokens).unwrap();
     assert_eq!(result.len(), 2);
     assert_eq!(result[0].name, "inline");
     assert_eq!(result[0].arguments.len(), 0);
     assert_eq!(result[1].name, "derive");
     assert_eq!(result[1].arguments.len(), 1);
-    assert_eq!(result[1].arguments[0], "Debug");
+    assert_eq!(result[1].arguments[0].as_ident(), Some("Debug"));
 }
 
 /// Test attribute with string literal argument
 #[test]
 fn test_attribute_with_string_argument() {
+    let arena = Arena::new();
     // #[doc("This is documentation")]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("doc".to_string()),
+        TokenType::Identifier("doc".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("This is documentation".to_string()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "doc");
     assert_eq!(result.arguments.len(), 1);
-    assert_eq!(result.arguments[0], "\"This is documentation\"");
+    assert_eq!(result.arguments[0].as_str(), Some("This is documentation"));
 }
 
 /// Test attribute with mixed argument types
 #[test]
 fn test_attribute_with_mixed_arguments() {
+    let arena = Arena::new();
     // #[test_attr("string", 42, true)]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("test_attr".to_string()),
+        TokenType::Identifier("test_attr".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("string".to_string()),
         TokenType::Comma,
@@ -145,36 +151,49 @@ fn test_attribute_with_mixed_arguments() {
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "test_attr");
     assert_eq!(result.arguments.len(), 3);
-    assert_eq!(result.arguments[0], "\"string\"");
-    assert_eq!(result.arguments[1], "42");
-    assert_eq!(result.arguments[2], "true");
+    assert_eq!(result.arguments[0].as_str(), Some("string"));
+    assert!(matches!(
+        result.arguments[1],
+        AttributeArgument::Expression {
+            value: Expression::Literal(Literal::Integer(42)),
+            ..
+        }
+    ));
+    assert!(matches!(
+        result.arguments[2],
+        AttributeArgument::Expression {
+            value: Expression::Literal(Literal::Boolean(true)),
+            ..
+        }
+    ));
 }
 
 /// Test attribute with trailing comma
 #[test]
 fn test_attribute_with_trailing_comma() {
+    let arena = Arena::new();
     // #[derive(Debug, Clone,)]
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens).unwrap();
+    let result = parse_attribute(&arena, &mut tokens).unwrap();
     assert_eq!(result.name, "derive");
     assert_eq!(result.arguments.len(), 2);
-    assert_eq!(result.arguments[0], "Debug");
-    assert_eq!(result.arguments[1], "Clone");
+    assert_eq!(result.arguments[0].as_ident(), Some("Debug"));
+    assert_eq!(result.arguments[1].as_ident(), Some("Clone"));
 }
 
 /// Test function declaration with attributes
@@ -185,14 +204,14 @@ fn test_function_with_attributes() {
     let tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
         TokenType::RightBracket,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("test".to_string()),
+        TokenType::Identifier("test".into()),
         TokenType::RightBracket,
         TokenType::Fn,
-        TokenType::Identifier("test_function".to_string()),
+        TokenType::Identifier("test_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -221,15 +240,15 @@ fn test_variable_with_attributes() {
     let tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("allow".to_string()),
+        TokenType::Identifier("allow".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("unused".to_string()),
+        TokenType::Identifier("unused".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
     ]);
@@ -242,7 +261,7 @@ fn test_variable_with_attributes() {
             assert_eq!(var.name, "x");
             assert_eq!(var.attributes.len(), 1);
             assert_eq!(var.attributes[0].name, "allow");
-            assert_eq!(var.attributes[0].arguments[0], "unused");
+            assert_eq!(var.attributes[0].arguments[0].as_ident(), Some("unused"));
         }
         _ => panic!("Expected variable declaration"),
     }
@@ -256,17 +275,17 @@ fn test_data_class_with_attributes() {
     let tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Colon,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightBrace,
     ]);
 
@@ -278,7 +297,10 @@ fn test_data_class_with_attributes() {
             assert_eq!(data_class.name, "Person");
             assert_eq!(data_class.attributes.len(), 1);
             assert_eq!(data_class.attributes[0].name, "derive");
-            assert_eq!(data_class.attributes[0].arguments[0], "Debug");
+            assert_eq!(
+                data_class.attributes[0].arguments[0].as_ident(),
+                Some("Debug")
+            );
         }
         _ => panic!("Expected data class declaration"),
     }
@@ -291,18 +313,18 @@ fn test_field_with_attributes() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Data,
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("rename".to_string()),
+        TokenType::Identifier("rename".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Colon,
-        TokenType::Identifier("string".to_string()),
+        TokenType::Identifier("string".into()),
         TokenType::RightBrace,
     ]);
 
@@ -316,7 +338,10 @@ fn test_field_with_attributes() {
             assert_eq!(data_class.fields[0].name, "name");
             assert_eq!(data_class.fields[0].attributes.len(), 1);
             assert_eq!(data_class.fields[0].attributes[0].name, "serde");
-            assert_eq!(data_class.fields[0].attributes[0].arguments[0], "rename");
+            assert_eq!(
+                data_class.fields[0].attributes[0].arguments[0].as_ident(),
+                Some("rename")
+            );
         }
         _ => panic!("Expected data class declaration"),
     }
@@ -329,15 +354,15 @@ fn test_parameter_with_attributes() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("test".to_string()),
+        TokenType::Identifier("test".into()),
         TokenType::LeftParen,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("unused".to_string()),
+        TokenType::Identifier("unused".into()),
         TokenType::RightBracket,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
-        TokenType::Identifier("int".to_string()),
+        TokenType::Identifier("int".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::RightBrace,
@@ -361,23 +386,24 @@ fn test_parameter_with_attributes() {
 /// Test attribute parsing error cases
 #[test]
 fn test_attribute_parsing_errors() {
+    let arena = Arena::new();
     // Missing opening bracket: #identifier
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
     ]);
 
-    let result = parse_attribute(&mut tokens);
+    let result = parse_attribute(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Missing closing bracket: #[inline
     let mut tokens = create_token_stream(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("inline".to_string()),
+        TokenType::Identifier("inline".into()),
     ]);
 
-    let result = parse_attribute(&mut tokens);
+    let result = parse_attribute(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Invalid attribute name: #[123]
@@ -388,13 +414,14 @@ fn test_attribute_parsing_errors() {
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attribute(&mut tokens);
+    let result = parse_attribute(&arena, &mut tokens);
     assert!(result.is_err());
 }
 
 /// Test complex nested attributes
 #[test]
 fn test_complex_attributes() {
+    let arena = Arena::new();
     // #[derive(Debug, Clone, Serialize, Deserialize)]
     // #[serde(rename_all = "camelCase")]
     // #[doc("A complex data structure")]
@@ -402,67 +429,68 @@ fn test_complex_attributes() {
         // First attribute
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
-        TokenType::Identifier("Serialize".to_string()),
+        TokenType::Identifier("Serialize".into()),
         TokenType::Comma,
-        TokenType::Identifier("Deserialize".to_string()),
+        TokenType::Identifier("Deserialize".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         // Second attribute
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("rename_all".to_string()),
+        TokenType::Identifier("rename_all".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         // Third attribute
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("doc".to_string()),
+        TokenType::Identifier("doc".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("A complex data structure".to_string()),
         TokenType::RightParen,
         TokenType::RightBracket,
     ]);
 
-    let result = parse_attributes(&mut tokens).unwrap();
+    let result = parse_attributes(&arena, &mut tokens).unwrap();
     assert_eq!(result.len(), 3);
 
     // First attribute
     assert_eq!(result[0].name, "derive");
     assert_eq!(result[0].arguments.len(), 4);
-    assert_eq!(result[0].arguments[0], "Debug");
-    assert_eq!(result[0].arguments[1], "Clone");
-    assert_eq!(result[0].arguments[2], "Serialize");
-    assert_eq!(result[0].arguments[3], "Deserialize");
+    assert_eq!(result[0].arguments[0].as_ident(), Some("Debug"));
+    assert_eq!(result[0].arguments[1].as_ident(), Some("Clone"));
+    assert_eq!(result[0].arguments[2].as_ident(), Some("Serialize"));
+    assert_eq!(result[0].arguments[3].as_ident(), Some("Deserialize"));
 
     // Second attribute
     assert_eq!(result[1].name, "serde");
     assert_eq!(result[1].arguments.len(), 1);
-    assert_eq!(result[1].arguments[0], "rename_all");
+    assert_eq!(result[1].arguments[0].as_ident(), Some("rename_all"));
 
     // Third attribute
     assert_eq!(result[2].name, "doc");
     assert_eq!(result[2].arguments.len(), 1);
-    assert_eq!(result[2].arguments[0], "\"A complex data structure\"");
+    assert_eq!(
+        result[2].arguments[0].as_str(),
+        Some("A complex data structure")
+    );
 }
 
 /// Test empty attribute list when no attributes present
 #[test]
 fn test_empty_attribute_list() {
+    let arena = Arena::new();
     // fn test() {} (no attributes)
-    let mut tokens = create_token_stream(vec![
-        TokenType::Fn,
-        TokenType::Identifier("test".to_string()),
-    ]);
+    let mut tokens = create_token_stream(vec![TokenType::Fn, TokenType::Identifier("test".into())]);
 
-    let result = parse_attributes(&mut tokens).unwrap();
+    let result = parse_attributes(&arena, &mut tokens).unwrap();
     assert_eq!(result.len(), 0);
 }
 
@@ -473,7 +501,7 @@ fn test_attribute_integration_with_existing_parsing() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("regular_function".to_string()),
+        TokenType::Identifier("regular_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -9,11 +9,11 @@
 //! - Integration with function and data class declarations
 
 use ferra_parser::{
-    ast::{Arena, Item, Type},
+    ast::{Arena, Item, Path, Type},
     generic::parser::{parse_generic_params, parse_generic_type},
     statement::StatementParser,
     token::stream::VecTokenStream,
-    token::{Token, TokenType},
+    token::{Span, Token, TokenType},
 };
 
 fn create_token_stream(token_types: Vec<TokenType>) -> VecTokenStream {
@@ -23,13 +23,14 @@ fn create_token_stream(token_types: Vec<TokenType>) -> VecTokenStream {
 
 #[test]
 fn test_simple_generic_params() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -42,17 +43,18 @@ fn test_simple_generic_params() {
 
 #[test]
 fn test_multiple_generic_params() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Comma,
-        TokenType::Identifier("V".to_string()),
+        TokenType::Identifier("V".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -64,17 +66,18 @@ fn test_multiple_generic_params() {
 
 #[test]
 fn test_lifetime_parameters() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
         TokenType::Apostrophe,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Comma,
         TokenType::Apostrophe,
-        TokenType::Identifier("static".to_string()),
+        TokenType::Identifier("static".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -87,19 +90,20 @@ fn test_lifetime_parameters() {
 
 #[test]
 fn test_mixed_type_and_lifetime_params() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
         TokenType::Apostrophe,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Comma,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
         TokenType::Apostrophe,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -114,15 +118,16 @@ fn test_mixed_type_and_lifetime_params() {
 
 #[test]
 fn test_type_bounds_single_trait() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -133,19 +138,20 @@ fn test_type_bounds_single_trait() {
 
 #[test]
 fn test_type_bounds_multiple_traits() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Plus,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -158,21 +164,22 @@ fn test_type_bounds_multiple_traits() {
 
 #[test]
 fn test_complex_mixed_constraints() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("Default".to_string()),
+        TokenType::Identifier("Default".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -192,17 +199,18 @@ fn test_complex_mixed_constraints() {
 
 #[test]
 fn test_where_clause_simple() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -217,25 +225,26 @@ fn test_where_clause_simple() {
 
 #[test]
 fn test_where_clause_complex() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("Default".to_string()),
+        TokenType::Identifier("Default".into()),
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -258,17 +267,23 @@ fn test_where_clause_complex() {
 
 #[test]
 fn test_generic_type_instantiation_simple() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_type(&mut tokens, "Vec".to_string()).unwrap();
-    assert_eq!(result.base, "Vec");
-    assert_eq!(result.args.len(), 1);
+    let result = parse_generic_type(
+        &arena,
+        &mut tokens,
+        Path::from_ident(&arena, "Vec", Span::dummy()),
+    )
+    .unwrap();
+    assert_eq!(result, "Vec");
+    assert_eq!(result.generic_args().len(), 1);
 
-    if let Type::Identifier(name) = &result.args[0] {
+    if let Type::Identifier(name) = &result.generic_args()[0] {
         assert_eq!(name, "i32");
     } else {
         panic!("Expected identifier type");
@@ -277,25 +292,31 @@ fn test_generic_type_instantiation_simple() {
 
 #[test]
 fn test_generic_type_instantiation_multiple_args() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_type(&mut tokens, "HashMap".to_string()).unwrap();
-    assert_eq!(result.base, "HashMap");
-    assert_eq!(result.args.len(), 2);
+    let result = parse_generic_type(
+        &arena,
+        &mut tokens,
+        Path::from_ident(&arena, "HashMap", Span::dummy()),
+    )
+    .unwrap();
+    assert_eq!(result, "HashMap");
+    assert_eq!(result.generic_args().len(), 2);
 
-    if let Type::Identifier(name) = &result.args[0] {
+    if let Type::Identifier(name) = &result.generic_args()[0] {
         assert_eq!(name, "String");
     } else {
         panic!("Expected identifier type");
     }
 
-    if let Type::Identifier(name) = &result.args[1] {
+    if let Type::Identifier(name) = &result.generic_args()[1] {
         assert_eq!(name, "i32");
     } else {
         panic!("Expected identifier type");
@@ -304,24 +325,30 @@ fn test_generic_type_instantiation_multiple_args() {
 
 #[test]
 fn test_nested_generic_types() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("Vec".to_string()),
+        TokenType::Identifier("Vec".into()),
         TokenType::Less,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_type(&mut tokens, "Option".to_string()).unwrap();
-    assert_eq!(result.base, "Option");
-    assert_eq!(result.args.len(), 1);
+    let result = parse_generic_type(
+        &arena,
+        &mut tokens,
+        Path::from_ident(&arena, "Option", Span::dummy()),
+    )
+    .unwrap();
+    assert_eq!(result, "Option");
+    assert_eq!(result.generic_args().len(), 1);
 
-    if let Type::Generic(inner) = &result.args[0] {
-        assert_eq!(inner.base, "Vec");
-        assert_eq!(inner.args.len(), 1);
+    if let Type::Path(inner) = &result.generic_args()[0] {
+        assert_eq!(inner, "Vec");
+        assert_eq!(inner.generic_args().len(), 1);
 
-        if let Type::Identifier(name) = &inner.args[0] {
+        if let Type::Identifier(name) = &inner.generic_args()[0] {
             assert_eq!(name, "i32");
         } else {
             panic!("Expected identifier type");
@@ -336,23 +363,23 @@ fn test_generic_function_declaration() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("compare".to_string()),
+        TokenType::Identifier("compare".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("PartialOrd".to_string()),
+        TokenType::Identifier("PartialOrd".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::Semicolon,
     ]);
 
@@ -382,14 +409,14 @@ fn test_generic_data_class_declaration() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Data,
-        TokenType::Identifier("Container".to_string()),
+        TokenType::Identifier("Container".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::LeftBrace,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightBrace,
     ]);
 
@@ -416,29 +443,29 @@ fn test_complex_generic_function_with_where_clause() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_fn".to_string()),
+        TokenType::Identifier("complex_fn".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("Default".to_string()),
+        TokenType::Identifier("Default".into()),
         TokenType::Semicolon,
     ]);
 
@@ -476,9 +503,10 @@ fn test_complex_generic_function_with_where_clause() {
 
 #[test]
 fn test_empty_generic_params() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![TokenType::Less, TokenType::Greater]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -488,16 +516,17 @@ fn test_empty_generic_params() {
 
 #[test]
 fn test_trailing_comma_in_generic_params() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Comma,
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -508,18 +537,19 @@ fn test_trailing_comma_in_generic_params() {
 
 #[test]
 fn test_trailing_comma_in_where_clause() {
+    let arena = Arena::new();
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
     ]);
 
-    let result = parse_generic_params(&mut tokens).unwrap();
+    let result = parse_generic_params(&arena, &mut tokens).unwrap();
     assert!(result.is_some());
 
     let generics = result.unwrap();
@@ -532,14 +562,15 @@ fn test_trailing_comma_in_where_clause() {
 
 #[test]
 fn test_generic_parsing_errors() {
+    let arena = Arena::new();
     // Missing closing bracket
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Eof,
     ]);
 
-    let result = parse_generic_params(&mut tokens);
+    let result = parse_generic_params(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Invalid lifetime (missing name)
@@ -549,19 +580,19 @@ fn test_generic_parsing_errors() {
         TokenType::Greater,
     ]);
 
-    let result = parse_generic_params(&mut tokens);
+    let result = parse_generic_params(&arena, &mut tokens);
     assert!(result.is_err());
 
     // Missing colon in where clause
     let mut tokens = create_token_stream(vec![
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("T".into()),
+        TokenType::Identifier("Clone".into()),
     ]);
 
-    let result = parse_generic_params(&mut tokens);
+    let result = parse_generic_params(&arena, &mut tokens);
     assert!(result.is_err());
 }
@@ -106,9 +106,9 @@ fn test_or_pattern_simple() {
 
     // Test Some | None
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("Some".to_string()),
+        TokenType::Identifier("Some".into()),
         TokenType::Pipe,
-        TokenType::Identifier("None".to_string()),
+        TokenType::Identifier("None".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -132,9 +132,9 @@ fn test_guard_pattern_simple() {
 
     // Test x if x > 0 (simplified)
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::If,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(0),
         TokenType::Eof,
@@ -146,7 +146,7 @@ fn test_guard_pattern_simple() {
 
     if let Ok(pattern) = result {
         match pattern {
-            Pattern::Guard(guard) => match guard.pattern.as_ref() {
+            Pattern::Guard(guard) => match guard.pattern {
                 Pattern::Identifier(name) => assert_eq!(name, "x"),
                 _ => panic!("Expected identifier pattern for guard base"),
             },
@@ -161,9 +161,9 @@ fn test_binding_pattern_simple() {
 
     // Test name @ value
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::At,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -175,7 +175,7 @@ fn test_binding_pattern_simple() {
         match pattern {
             Pattern::Binding(binding) => {
                 assert_eq!(binding.name, "name");
-                match binding.pattern.as_ref() {
+                match binding.pattern {
                     Pattern::Identifier(name) => assert_eq!(name, "value"),
                     _ => panic!("Expected identifier pattern for binding"),
                 }
@@ -236,9 +236,9 @@ fn test_advanced_pattern_integration() {
     // Test that advanced patterns work with existing patterns
     // Test simple range in data class field
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("age".to_string()),
+        TokenType::Identifier("age".into()),
         TokenType::Colon,
         TokenType::IntegerLiteral(18),
         TokenType::DotDotEqual,
@@ -29,7 +29,7 @@ fn test_simple_macro_invocation() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("println".to_string());
+    let result = parser.parse_macro_invocation("println".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -64,7 +64,7 @@ fn test_macro_invocation_with_multiple_arguments() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("println".to_string());
+    let result = parser.parse_macro_invocation("println".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -83,7 +83,7 @@ fn test_macro_invocation_with_braces() {
     let tokens = create_token_stream(vec![
         TokenType::Bang,
         TokenType::LeftBrace,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(10),
         TokenType::RightBrace,
@@ -91,7 +91,7 @@ fn test_macro_invocation_with_braces() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("let_var".to_string());
+    let result = parser.parse_macro_invocation("let_var".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -120,7 +120,7 @@ fn test_macro_invocation_with_brackets() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("vec".to_string());
+    let result = parser.parse_macro_invocation("vec".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -151,7 +151,7 @@ fn test_nested_token_groups() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("calculate".to_string());
+    let result = parser.parse_macro_invocation("calculate".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -184,7 +184,7 @@ fn test_empty_macro_invocation() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_invocation("empty".to_string());
+    let result = parser.parse_macro_invocation("empty".into());
     assert!(result.is_ok());
 
     if let Ok(macro_invocation) = result {
@@ -202,9 +202,9 @@ fn test_macro_definition_basic() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::LeftBrace,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::FatArrow,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::RightBrace,
@@ -212,7 +212,7 @@ fn test_macro_definition_basic() {
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_definition("increment".to_string());
+    let result = parser.parse_macro_definition("increment".into());
     assert!(result.is_ok());
 
     if let Ok(macro_def) = result {
@@ -230,25 +230,25 @@ fn test_macro_definition_multiple_rules() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
         TokenType::LeftBrace,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::FatArrow,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::Semicolon,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::Comma,
-        TokenType::Identifier("$y".to_string()),
+        TokenType::Identifier("$y".into()),
         TokenType::FatArrow,
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
         TokenType::Plus,
-        TokenType::Identifier("$y".to_string()),
+        TokenType::Identifier("$y".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
 
-    let result = parser.parse_macro_definition("add".to_string());
+    let result = parser.parse_macro_definition("add".into());
     assert!(result.is_ok());
 
     if let Ok(macro_def) = result {
@@ -271,7 +271,7 @@ fn test_macro_definition_multiple_rules() {
 fn test_macro_in_expression() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("println".to_string()),
+        TokenType::Identifier("println".into()),
         TokenType::Bang,
         TokenType::LeftParen,
         TokenType::StringLiteral("hello".to_string()),
@@ -298,7 +298,7 @@ fn test_macro_in_expression() {
 fn test_macro_in_complex_expression() {
     let arena = Arena::new();
     let tokens = create_token_stream(vec![
-        TokenType::Identifier("format".to_string()),
+        TokenType::Identifier("format".into()),
         TokenType::Bang,
         TokenType::LeftParen,
         TokenType::StringLiteral("Result: {}".to_string()),
@@ -318,7 +318,7 @@ fn test_macro_in_complex_expression() {
         match expression {
             Expression::Binary(binary_expr) => {
                 // Left side should be macro invocation
-                match binary_expr.left.as_ref() {
+                match binary_expr.left {
                     Expression::Macro(macro_invocation) => {
                         assert_eq!(macro_invocation.name, "format");
                     }
@@ -326,7 +326,7 @@ fn test_macro_in_complex_expression() {
                 }
 
                 // Right side should be string literal
-                match binary_expr.right.as_ref() {
+                match binary_expr.right {
                     Expression::Literal(_) => {}
                     _ => panic!("Expected literal on right side"),
                 }
@@ -348,7 +348,7 @@ fn test_macro_parsing_errors() {
         TokenType::Eof,
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
-    let result = parser.parse_macro_invocation("println".to_string());
+    let result = parser.parse_macro_invocation("println".into());
     assert!(result.is_err());
 
     // Test missing bang
@@ -359,21 +359,21 @@ fn test_macro_parsing_errors() {
         TokenType::Eof,
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
-    let result = parser.parse_macro_invocation("println".to_string());
+    let result = parser.parse_macro_invocation("println".into());
     assert!(result.is_err());
 
     // Test missing fat arrow in macro definition
     let tokens = create_token_stream(vec![
         TokenType::LeftBrace,
-        TokenType::Identifier("$x".to_string()),
-        TokenType::Identifier("$x".to_string()),
+        TokenType::Identifier("$x".into()),
+        TokenType::Identifier("$x".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
     let mut parser = MacroParser::new(&arena, tokens);
-    let result = parser.parse_macro_definition("increment".to_string());
+    let result = parser.parse_macro_definition("increment".into());
     assert!(result.is_err());
 }
 
@@ -408,7 +408,7 @@ fn test_macro_integration_comprehensive() {
             vec![
                 TokenType::Bang,
                 TokenType::LeftBrace,
-                TokenType::Identifier("x".to_string()),
+                TokenType::Identifier("x".into()),
                 TokenType::Equal,
                 TokenType::IntegerLiteral(5),
                 TokenType::RightBrace,
@@ -421,7 +421,7 @@ fn test_macro_integration_comprehensive() {
         let tokens = create_token_stream(token_types);
         let mut parser = MacroParser::new(&arena, tokens);
 
-        let result = parser.parse_macro_invocation(macro_name.to_string());
+        let result = parser.parse_macro_invocation(macro_name.into());
         assert!(result.is_ok(), "Failed to parse macro: {}", macro_name);
 
         if let Ok(macro_invocation) = result {
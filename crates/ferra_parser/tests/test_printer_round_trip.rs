@@ -0,0 +1,256 @@
+//! Pretty-printer round-trip tests
+//!
+//! Source is parsed, printed, parsed again and printed again: the two printed
+//! forms must agree, so printing never changes what the code means. Trees
+//! built by hand check that the printer adds the parentheses precedence and
+//! associativity require.
+
+use ferra_parser::{
+    ast::{
+        print_compilation_unit, print_expression, Arena, BinaryExpression, BinaryOperator,
+        Expression, MemberAccessExpression, UnaryExpression, UnaryOperator,
+    },
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    token::Span,
+    ProgramParser,
+};
+use std::mem::discriminant;
+
+fn print_parsed_expression(source: &str) -> String {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    let expr = parser
+        .parse_expression(0)
+        .unwrap_or_else(|error| panic!("{}: {:?}", source, error));
+    print_expression(expr)
+}
+
+fn print_parsed_unit(source: &str) -> String {
+    let arena = Arena::new();
+    let unit = ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .parse_compilation_unit()
+        .unwrap_or_else(|errors| panic!("{}: {:?}", source, errors));
+    print_compilation_unit(unit)
+}
+
+/// Print `source`, check the output prints back to itself, and return it
+fn expression_round_trip(source: &str) -> String {
+    let printed = print_parsed_expression(source);
+    assert_eq!(print_parsed_expression(&printed), printed, "{}", source);
+    printed
+}
+
+fn unit_round_trip(source: &str) -> String {
+    let printed = print_parsed_unit(source);
+    assert_eq!(print_parsed_unit(&printed), printed, "{}", source);
+    printed
+}
+
+fn binary<'a>(
+    arena: &'a Arena,
+    left: &'a Expression<'a>,
+    operator: BinaryOperator,
+    right: &'a Expression<'a>,
+) -> &'a Expression<'a> {
+    arena.alloc(Expression::Binary(BinaryExpression {
+        left,
+        operator,
+        right,
+        span: Span::dummy(),
+    }))
+}
+
+fn name<'a>(arena: &'a Arena, name: &str) -> &'a Expression<'a> {
+    arena.alloc(Expression::Identifier(name.into()))
+}
+
+#[test]
+fn test_expressions_round_trip() {
+    let cases = [
+        ("a + b * c", "a + b * c"),
+        ("(a + b) * c", "(a + b) * c"),
+        ("a - b - c", "a - b - c"),
+        ("a == b && c != d || !e", "a == b && c != d || !e"),
+        ("-x.len()", "-x.len()"),
+        ("f(a, b)(c)[0].field", "f(a, b)(c)[0].field"),
+        ("fetch(id).await?", "fetch(id).await?"),
+        ("[1, 2.5, \"s\", 'c', true]", "[1, 2.5, \"s\", 'c', true]"),
+        ("Point { x: 1, y }", "Point { x: 1, y }"),
+        ("std::mem::swap(a, b)", "std::mem::swap(a, b)"),
+        ("parse::<Int>(s)", "parse::<Int>(s)"),
+        ("\"tab\\t quote\\\"\"", "\"tab\\t quote\\\"\""),
+        ("f\"Hello, {name}!\"", "f\"Hello, {name}!\""),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(expression_round_trip(source), expected);
+    }
+}
+
+#[test]
+fn test_block_expressions_round_trip() {
+    assert_eq!(
+        expression_round_trip("match x { 0 => a, y if y > 1 => b, _ => c }"),
+        "match x {\n    0 => a,\n    y if y > 1 => b,\n    _ => c,\n}"
+    );
+    assert_eq!(
+        expression_round_trip("{ let y = 1; y + 1 }"),
+        "{\n    let y = 1;\n    y + 1;\n}"
+    );
+}
+
+#[test]
+fn test_statements_round_trip() {
+    let printed = unit_round_trip(
+        "fn main() {
+            let x: Int = 1;
+            var total = 0;
+            total = total + x;
+            if x > 0 { total = total - 1; } else if x < 0 { return; } else { total = 1; }
+            while total < 10 { total = total * 2; }
+            for item in items { if item { break; } continue; }
+            { nested(); }
+            return total;
+        }",
+    );
+    for line in [
+        "    let x: Int = 1;",
+        "    var total = 0;",
+        "    total = total + x;",
+        // `else if` is parsed as an `else` block holding an `if`
+        "    } else {\n        if x < 0 {",
+        "    while total < 10 {",
+        "    for item in items {",
+        "    return total;",
+    ] {
+        assert!(printed.contains(line), "{}\n---\n{}", line, printed);
+    }
+}
+
+#[test]
+fn test_items_round_trip() {
+    let printed = unit_round_trip(concat!(
+        "#[derive(Debug)]\n",
+        "data Point { x: Float, y: Float }\n",
+        "pub fn add<T: Add>(a: T, b: T) -> T { a + b }\n",
+        "async fn load(path: String) -> Result<String, Error> { read(path).await }\n",
+        "extern \"C\" { fn abs(x: i32) -> i32; }\n",
+        "let LIMIT: Int = 10;\n",
+        "macro twice { ($e:expr) => { $e + $e } }\n",
+    ));
+    for line in [
+        "#[derive(Debug)]",
+        "data Point {",
+        "pub fn add<T: Add>(a: T, b: T) -> T {",
+        "async fn load(path: String) -> Result<String, Error> {",
+        "extern \"C\" {",
+        "let LIMIT: Int = 10;",
+        "macro twice {",
+    ] {
+        assert!(printed.contains(line), "{}\n---\n{}", line, printed);
+    }
+}
+
+#[test]
+fn test_patterns_round_trip() {
+    let printed = unit_round_trip(
+        "fn main() {
+            match value {
+                0 => a,
+                1..=9 => b,
+                \"s\" | \"t\" => c,
+                [first, rest @ ..] => d,
+                Point { x, y: 0 } => e,
+                Color::Red => f,
+                n @ 10..=20 => g,
+                n if n < 0 => h,
+                _ => i,
+            }
+        }",
+    );
+    for arm in [
+        "0 => a,",
+        "1..=9 => b,",
+        "\"s\" | \"t\" => c,",
+        "[first, rest @ ..] => d,",
+        "Point { x, y: 0 } => e,",
+        "Color::Red => f,",
+        "n @ 10..=20 => g,",
+        "n if n < 0 => h,",
+        "_ => i,",
+    ] {
+        assert!(printed.contains(arm), "{}\n---\n{}", arm, printed);
+    }
+}
+
+#[test]
+fn test_types_round_trip() {
+    let printed = unit_round_trip(
+        "fn f(a: [Int], b: (Int, String), c: Vec<Map<String, Int>>, d: fn(Int) -> Bool, e: *u8) {}",
+    );
+    assert!(
+        printed.starts_with(
+            "fn f(a: [Int], b: (Int, String), c: Vec<Map<String, Int>>, d: fn(Int) -> Bool, e: *u8)"
+        ),
+        "{}",
+        printed
+    );
+}
+
+#[test]
+fn test_printer_adds_parentheses_for_precedence() {
+    let arena = Arena::new();
+    let (a, b, c) = (name(&arena, "a"), name(&arena, "b"), name(&arena, "c"));
+
+    // Lower precedence on either side of a tighter operator
+    let sum = binary(&arena, a, BinaryOperator::Add, b);
+    let product = binary(&arena, sum, BinaryOperator::Mul, c);
+    assert_eq!(print_expression(product), "(a + b) * c");
+    let product = binary(&arena, c, BinaryOperator::Mul, sum);
+    assert_eq!(print_expression(product), "c * (a + b)");
+
+    // Left-associative operators keep a nested right operand grouped
+    let difference = binary(&arena, b, BinaryOperator::Sub, c);
+    let outer = binary(&arena, a, BinaryOperator::Sub, difference);
+    assert_eq!(print_expression(outer), "a - (b - c)");
+    let outer = binary(&arena, difference, BinaryOperator::Sub, a);
+    assert_eq!(print_expression(outer), "b - c - a");
+
+    // Assignment is right-associative
+    let inner = binary(&arena, b, BinaryOperator::Assign, c);
+    let chained = binary(&arena, a, BinaryOperator::Assign, inner);
+    assert_eq!(print_expression(chained), "a = b = c");
+
+    // Prefix and postfix operators bind tighter than any binary operator
+    let negated = arena.alloc(Expression::Unary(UnaryExpression {
+        operator: UnaryOperator::Minus,
+        operand: sum,
+        span: Span::dummy(),
+    }));
+    assert_eq!(print_expression(negated), "-(a + b)");
+    let access = arena.alloc(Expression::MemberAccess(MemberAccessExpression {
+        object: sum,
+        member: "len".into(),
+        span: Span::dummy(),
+    }));
+    assert_eq!(print_expression(access), "(a + b).len");
+
+    // What is printed parses back to the same shape
+    let product = binary(&arena, sum, BinaryOperator::Mul, c);
+    let printed = print_expression(product);
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(&printed));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Binary(outer) => {
+            assert_eq!(
+                discriminant(&outer.operator),
+                discriminant(&BinaryOperator::Mul)
+            );
+            assert!(matches!(
+                outer.left,
+                Expression::Grouped(Expression::Binary(inner))
+                    if discriminant(&inner.operator) == discriminant(&BinaryOperator::Add)
+            ));
+        }
+        other => panic!("Expected multiplication, got {:?}", other),
+    }
+}
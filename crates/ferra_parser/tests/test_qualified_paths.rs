@@ -0,0 +1,498 @@
+//! Qualified and generic path tests
+//!
+//! Tests for the shared `Path` node across:
+//! - Types: `http::Client`, `std::collections::Map<K, V>`
+//! - Expressions: `std::mem::swap`, turbofish `parse::<Int>(s)`
+//! - Resolving `<` as generic arguments or less-than
+//! - Patterns: `Color::Red`, `shapes::Circle { radius }`
+//! - Data class literals: `Point { x: 1, y }`
+
+use ferra_parser::{
+    ast::{Arena, BinaryOperator, Expression, Pattern, Type},
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    token::{TokenType, VecTokenStream},
+    types::parse_type,
+};
+
+fn ident(name: &str) -> TokenType {
+    TokenType::Identifier(name.into())
+}
+
+fn parse_expr(arena: &Arena, token_types: Vec<TokenType>) -> &Expression<'_> {
+    let mut parser = PrattParser::new(arena, VecTokenStream::from_token_types(token_types));
+    parser.parse_expression(0).unwrap()
+}
+
+#[test]
+fn test_qualified_type_path() {
+    let arena = Arena::new();
+    // http::Client
+    let mut tokens = VecTokenStream::from_token_types(vec![
+        ident("http"),
+        TokenType::DoubleColon,
+        ident("Client"),
+    ]);
+
+    match parse_type(&arena, &mut tokens).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path.segments.len(), 2);
+            assert_eq!(path, "http::Client");
+            assert!(!path.has_generic_args());
+        }
+        other => panic!("Expected path type, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_qualified_generic_type() {
+    let arena = Arena::new();
+    // std::collections::Map<K, V>
+    let mut tokens = VecTokenStream::from_token_types(vec![
+        ident("std"),
+        TokenType::DoubleColon,
+        ident("collections"),
+        TokenType::DoubleColon,
+        ident("Map"),
+        TokenType::Less,
+        ident("K"),
+        TokenType::Comma,
+        ident("V"),
+        TokenType::Greater,
+    ]);
+
+    match parse_type(&arena, &mut tokens).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path, "std::collections::Map");
+            assert_eq!(path.generic_args().len(), 2);
+            assert!(matches!(&path.generic_args()[0], Type::Identifier(name) if name == "K"));
+        }
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_generic_type_is_structured() {
+    let arena = Arena::new();
+    // Vec<Option<Int>> keeps its arguments instead of a stringified name
+    let mut tokens = VecTokenStream::from_token_types(vec![
+        ident("Vec"),
+        TokenType::Less,
+        ident("Option"),
+        TokenType::Less,
+        ident("Int"),
+        TokenType::Greater,
+        TokenType::Greater,
+    ]);
+
+    match parse_type(&arena, &mut tokens).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path, "Vec");
+            match &path.generic_args()[0] {
+                Type::Path(inner) => assert_eq!(inner, "Option"),
+                other => panic!("Expected nested generic path type, got {:?}", other),
+            }
+        }
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_generics_close_with_shift_right() {
+    let arena = Arena::new();
+    // `>>` lexes as one token and closes both lists
+    match parse_type(&arena, &mut mock_tokens_from_source("Vec<Vec<T>>")).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path, "Vec");
+            assert!(matches!(&path.generic_args()[0], Type::Path(inner) if inner == "Vec"));
+        }
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+
+    match parse_type(&arena, &mut mock_tokens_from_source("Map<K, Vec<V>>")).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path.generic_args().len(), 2);
+            assert!(matches!(&path.generic_args()[1], Type::Path(inner) if inner == "Vec"));
+        }
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+
+    // Three levels: `>>>` lexes as `>>` then `>`
+    match parse_type(&arena, &mut mock_tokens_from_source("A<B<C<D>>>")).unwrap() {
+        Type::Path(path) => assert_eq!(path, "A"),
+        other => panic!("Expected generic path type, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_nested_turbofish_closes_with_shift_right() {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("Vec::<Vec<T>>::new()"));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Call(call) => match call.callee {
+            Expression::Path(path) => {
+                assert_eq!(path, "Vec::new");
+                assert!(matches!(
+                    path.segments[0].generic_args,
+                    [Type::Path(inner)] if inner == "Vec"
+                ));
+            }
+            other => panic!("Expected path callee, got {:?}", other),
+        },
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("collect::<Vec<i32>>()"));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Call(call) => match call.callee {
+            Expression::Path(path) => {
+                assert_eq!(path.segments[0].name, "collect");
+                assert_eq!(path.segments[0].generic_args.len(), 1);
+            }
+            other => panic!("Expected path callee, got {:?}", other),
+        },
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+
+    // Without a turbofish, `>>` followed by `::` still closes two lists
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("Vec<Vec<T>>::new()"));
+    assert!(matches!(
+        parser.parse_expression(0).unwrap(),
+        Expression::Call(call) if matches!(call.callee, Expression::Path(path) if path == "Vec::new")
+    ));
+}
+
+#[test]
+fn test_generic_type_before_equals() {
+    let arena = Arena::new();
+    // `>=` after a generic type is `>` then `=`
+    let source = "let v: Vec<T>= make()";
+    let unit = ferra_parser::ProgramParser::new(&arena, mock_tokens_from_source(source))
+        .parse_compilation_unit()
+        .unwrap_or_else(|errors| panic!("{}: {:?}", source, errors));
+    assert_eq!(unit.items.len(), 1);
+}
+
+#[test]
+fn test_generic_segment_inside_type_path() {
+    let arena = Arena::new();
+    // Outer<T>::Inner keeps per-segment arguments
+    let mut tokens = VecTokenStream::from_token_types(vec![
+        ident("Outer"),
+        TokenType::Less,
+        ident("T"),
+        TokenType::Greater,
+        TokenType::DoubleColon,
+        ident("Inner"),
+    ]);
+
+    match parse_type(&arena, &mut tokens).unwrap() {
+        Type::Path(path) => {
+            assert_eq!(path.segments[0].generic_args.len(), 1);
+            assert!(path.segments[1].generic_args.is_empty());
+        }
+        other => panic!("Expected path type, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_generic_paths_have_one_shape() {
+    let arena = Arena::new();
+    // Wherever the arguments sit, a qualified or generic type is a path
+    for (source, segment) in [("a::B<T>", 1), ("a<T>::B", 0), ("Vec<T>", 0)] {
+        match parse_type(&arena, &mut mock_tokens_from_source(source)).unwrap() {
+            Type::Path(path) => {
+                assert_eq!(path.segments[segment].generic_args.len(), 1, "{}", source);
+                assert!(path.has_generic_args());
+            }
+            other => panic!("{}: expected path type, got {:?}", source, other),
+        }
+    }
+}
+
+#[test]
+fn test_qualified_expression_path() {
+    let arena = Arena::new();
+    // std::mem::swap(a, b)
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("std"),
+            TokenType::DoubleColon,
+            ident("mem"),
+            TokenType::DoubleColon,
+            ident("swap"),
+            TokenType::LeftParen,
+            ident("a"),
+            TokenType::Comma,
+            ident("b"),
+            TokenType::RightParen,
+        ],
+    );
+
+    match expr {
+        Expression::Call(call) => match call.callee {
+            Expression::Path(path) => assert_eq!(path, "std::mem::swap"),
+            other => panic!("Expected path callee, got {:?}", other),
+        },
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_turbofish_call() {
+    let arena = Arena::new();
+    // parse::<Int>(s)
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("parse"),
+            TokenType::DoubleColon,
+            TokenType::Less,
+            ident("Int"),
+            TokenType::Greater,
+            TokenType::LeftParen,
+            ident("s"),
+            TokenType::RightParen,
+        ],
+    );
+
+    match expr {
+        Expression::Call(call) => match call.callee {
+            Expression::Path(path) => {
+                assert_eq!(path.segments.len(), 1);
+                assert_eq!(path.segments[0].name, "parse");
+                assert!(matches!(
+                    path.segments[0].generic_args,
+                    [Type::Identifier(name)] if name == "Int"
+                ));
+            }
+            other => panic!("Expected path callee, got {:?}", other),
+        },
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_generic_args_followed_by_path() {
+    let arena = Arena::new();
+    // Vec<Int>::new()
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("Vec"),
+            TokenType::Less,
+            ident("Int"),
+            TokenType::Greater,
+            TokenType::DoubleColon,
+            ident("new"),
+            TokenType::LeftParen,
+            TokenType::RightParen,
+        ],
+    );
+
+    match expr {
+        Expression::Call(call) => match call.callee {
+            Expression::Path(path) => {
+                assert_eq!(path, "Vec::new");
+                assert_eq!(path.segments[0].generic_args.len(), 1);
+            }
+            other => panic!("Expected path callee, got {:?}", other),
+        },
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_less_than_is_not_generic() {
+    let arena = Arena::new();
+    // a < b && c > d
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("a"),
+            TokenType::Less,
+            ident("b"),
+            TokenType::AmpAmp,
+            ident("c"),
+            TokenType::Greater,
+            ident("d"),
+        ],
+    );
+
+    match expr {
+        Expression::Binary(binary) => {
+            assert!(matches!(binary.operator, BinaryOperator::And));
+            assert!(matches!(
+                binary.left,
+                Expression::Binary(left) if matches!(left.operator, BinaryOperator::Less)
+            ));
+        }
+        other => panic!("Expected logical and, got {:?}", other),
+    }
+
+    // a < b
+    let expr = parse_expr(&arena, vec![ident("a"), TokenType::Less, ident("b")]);
+    assert!(
+        matches!(expr, Expression::Binary(binary) if matches!(binary.operator, BinaryOperator::Less))
+    );
+}
+
+#[test]
+fn test_comparison_before_parenthesised_expression() {
+    let arena = Arena::new();
+    let is_comparison = |expr: &Expression, expected: BinaryOperator| {
+        matches!(expr, Expression::Binary(binary)
+            if std::mem::discriminant(&binary.operator) == std::mem::discriminant(&expected))
+    };
+
+    // f(a < b, c > (d))
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("f"),
+            TokenType::LeftParen,
+            ident("a"),
+            TokenType::Less,
+            ident("b"),
+            TokenType::Comma,
+            ident("c"),
+            TokenType::Greater,
+            TokenType::LeftParen,
+            ident("d"),
+            TokenType::RightParen,
+            TokenType::RightParen,
+        ],
+    );
+    match expr {
+        Expression::Call(call) => {
+            assert!(matches!(call.callee, Expression::Identifier(name) if name == "f"));
+            assert_eq!(call.arguments.len(), 2);
+            assert!(is_comparison(&call.arguments[0], BinaryOperator::Less));
+            assert!(is_comparison(&call.arguments[1], BinaryOperator::Greater));
+        }
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+
+    // check(x < lo, hi > (y + 1))
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("check"),
+            TokenType::LeftParen,
+            ident("x"),
+            TokenType::Less,
+            ident("lo"),
+            TokenType::Comma,
+            ident("hi"),
+            TokenType::Greater,
+            TokenType::LeftParen,
+            ident("y"),
+            TokenType::Plus,
+            TokenType::IntegerLiteral(1),
+            TokenType::RightParen,
+            TokenType::RightParen,
+        ],
+    );
+    match expr {
+        Expression::Call(call) => {
+            assert!(matches!(call.callee, Expression::Identifier(name) if name == "check"));
+            assert_eq!(call.arguments.len(), 2);
+            assert!(is_comparison(&call.arguments[0], BinaryOperator::Less));
+            assert!(is_comparison(&call.arguments[1], BinaryOperator::Greater));
+        }
+        other => panic!("Expected call expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_qualified_pattern() {
+    let arena = Arena::new();
+    // Color::Red
+    let tokens = VecTokenStream::from_token_types(vec![
+        ident("Color"),
+        TokenType::DoubleColon,
+        ident("Red"),
+    ]);
+    let mut parser = PrattParser::new(&arena, tokens);
+
+    match parser.parse_pattern().unwrap() {
+        Pattern::Path(path) => assert_eq!(path, "Color::Red"),
+        other => panic!("Expected path pattern, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_qualified_data_class_pattern() {
+    let arena = Arena::new();
+    // shapes::Circle { radius }
+    let tokens = VecTokenStream::from_token_types(vec![
+        ident("shapes"),
+        TokenType::DoubleColon,
+        ident("Circle"),
+        TokenType::LeftBrace,
+        ident("radius"),
+        TokenType::RightBrace,
+    ]);
+    let mut parser = PrattParser::new(&arena, tokens);
+
+    match parser.parse_pattern().unwrap() {
+        Pattern::DataClass(data_class) => {
+            assert_eq!(data_class.name, "shapes::Circle");
+            assert_eq!(data_class.fields.len(), 1);
+        }
+        other => panic!("Expected data class pattern, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_data_class_literal() {
+    let arena = Arena::new();
+    // geo::Point { x: 1, y }
+    let expr = parse_expr(
+        &arena,
+        vec![
+            ident("geo"),
+            TokenType::DoubleColon,
+            ident("Point"),
+            TokenType::LeftBrace,
+            ident("x"),
+            TokenType::Colon,
+            TokenType::IntegerLiteral(1),
+            TokenType::Comma,
+            ident("y"),
+            TokenType::RightBrace,
+        ],
+    );
+
+    match expr {
+        Expression::DataClassLiteral(literal) => {
+            assert_eq!(literal.path, "geo::Point");
+            assert_eq!(literal.fields.len(), 2);
+            assert_eq!(literal.fields[0].name, "x");
+            assert!(!literal.fields[0].is_shorthand);
+            assert_eq!(literal.fields[1].name, "y");
+            assert!(literal.fields[1].is_shorthand);
+            assert!(
+                matches!(&literal.fields[1].value, Expression::Identifier(name) if name == "y")
+            );
+        }
+        other => panic!("Expected data class literal, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_condition_does_not_take_data_class_literal() {
+    let arena = Arena::new();
+    // `ready { x }` in a condition leaves the brace for the block
+    let tokens = VecTokenStream::from_token_types(vec![
+        ident("ready"),
+        TokenType::LeftBrace,
+        ident("x"),
+        TokenType::RightBrace,
+    ]);
+    let mut parser = PrattParser::new(&arena, tokens);
+
+    let condition = parser.parse_condition().unwrap();
+    assert!(matches!(condition, Expression::Identifier(name) if name == "ready"));
+}
@@ -0,0 +1,85 @@
+//! Raw identifier tests
+//!
+//! `r#name` lexes as a plain identifier carrying `name`, so keywords can be
+//! used as names anywhere, and the pretty-printer restores the prefix.
+
+use ferra_parser::{
+    ast::{
+        print_compilation_unit, print_expression, print_identifier, Arena, Expression, Item,
+        Statement,
+    },
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    ProgramParser,
+};
+
+fn expression_round_trip(source: &str) -> String {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    print_expression(parser.parse_expression(0).unwrap())
+}
+
+#[test]
+fn test_raw_identifier_is_plain_identifier() {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source("r#match + r#value"));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Binary(binary) => {
+            assert!(matches!(binary.left, Expression::Identifier(name) if name == "match"));
+            assert!(matches!(binary.right, Expression::Identifier(name) if name == "value"));
+        }
+        other => panic!("Expected binary expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_raw_identifiers_in_declarations() {
+    let arena = Arena::new();
+    let source = "fn r#match(r#in: Int) -> Int { return r#in; }";
+    let mut parser = ProgramParser::new(&arena, mock_tokens_from_source(source));
+    let unit = parser.parse_compilation_unit().unwrap();
+
+    let Item::FunctionDecl(function) = &unit.items[0] else {
+        panic!("Expected function, got {:?}", unit.items[0]);
+    };
+    assert_eq!(function.name, "match");
+    assert_eq!(function.parameters[0].name, "in");
+    let body = function.body.as_ref().unwrap();
+    match &body.statements[0] {
+        Statement::Return(ret) => assert!(
+            matches!(ret.value.as_ref(), Some(Expression::Identifier(name)) if name == "in")
+        ),
+        other => panic!("Expected return, got {:?}", other),
+    }
+
+    assert_eq!(
+        print_compilation_unit(unit),
+        "fn r#match(r#in: Int) -> Int {\n    return r#in;\n}\n"
+    );
+}
+
+#[test]
+fn test_print_identifier_adds_prefix_only_for_keywords() {
+    assert_eq!(print_identifier("match"), "r#match");
+    assert_eq!(print_identifier("fn"), "r#fn");
+    assert_eq!(print_identifier("true"), "r#true");
+    assert_eq!(print_identifier("matches"), "matches");
+    assert_eq!(print_identifier("value"), "value");
+}
+
+#[test]
+fn test_raw_identifiers_round_trip() {
+    for (source, expected) in [
+        ("r#if.r#else(r#data, 1)", "r#if.r#else(r#data, 1)"),
+        (
+            "r#fn(r#let)[0] + r#while * 2",
+            "r#fn(r#let)[0] + r#while * 2",
+        ),
+        // Names that are not keywords lose their unnecessary prefix
+        ("r#value.len()", "value.len()"),
+    ] {
+        let printed = expression_round_trip(source);
+        assert_eq!(printed, expected);
+        assert_eq!(expression_round_trip(&printed), printed);
+    }
+}
@@ -22,7 +22,7 @@ fn test_variable_declarations() {
     // Test: let x = 42
     let tokens = create_token_stream(vec![
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::Eof,
@@ -49,9 +49,9 @@ fn test_variable_declarations() {
     // Test: var mut_x: i32 = 10
     let tokens = create_token_stream(vec![
         TokenType::Var,
-        TokenType::Identifier("mut_x".to_string()),
+        TokenType::Identifier("mut_x".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(10),
         TokenType::Eof,
@@ -82,7 +82,7 @@ fn test_function_declarations() {
     // Test: fn hello() { }
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("hello".to_string()),
+        TokenType::Identifier("hello".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -109,18 +109,18 @@ fn test_function_declarations() {
     let tokens = create_token_stream(vec![
         TokenType::Async,
         TokenType::Fn,
-        TokenType::Identifier("calculate".to_string()),
+        TokenType::Identifier("calculate".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Comma,
-        TokenType::Identifier("y".to_string()),
+        TokenType::Identifier("y".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -152,15 +152,15 @@ fn test_data_class_declarations() {
     // Test: data Person { name: String, age: i32 }
     let tokens = create_token_stream(vec![
         TokenType::Data,
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("age".to_string()),
+        TokenType::Identifier("age".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -195,19 +195,19 @@ fn test_extern_blocks() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("printf".to_string()),
+        TokenType::Identifier("printf".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("format".to_string()),
+        TokenType::Identifier("format".into()),
         TokenType::Colon,
-        TokenType::Identifier("char".to_string()),
+        TokenType::Identifier("char".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::Static,
-        TokenType::Identifier("errno".to_string()),
+        TokenType::Identifier("errno".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -234,7 +234,7 @@ fn test_modifiers() {
     let tokens = create_token_stream(vec![
         TokenType::Pub,
         TokenType::Fn,
-        TokenType::Identifier("public_function".to_string()),
+        TokenType::Identifier("public_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -321,9 +321,9 @@ fn test_for_statement() {
     // Test: for item in collection { }
     let tokens = create_token_stream(vec![
         TokenType::For,
-        TokenType::Identifier("item".to_string()),
+        TokenType::Identifier("item".into()),
         TokenType::In,
-        TokenType::Identifier("collection".to_string()),
+        TokenType::Identifier("collection".into()),
         TokenType::LeftBrace,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -439,7 +439,7 @@ fn test_block_statements() {
     let tokens = create_token_stream(vec![
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::RightBrace,
@@ -470,12 +470,12 @@ fn test_compilation_unit() {
     // Test complete program: fn main() { let x = 42; }
     let tokens = create_token_stream(vec![
         TokenType::Fn,
-        TokenType::Identifier("main".to_string()),
+        TokenType::Identifier("main".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::Equal,
         TokenType::IntegerLiteral(42),
         TokenType::RightBrace,
@@ -0,0 +1,139 @@
+//! String interpolation tests
+//!
+//! Tests for `f"..."` literals, whose embedded expressions are parsed into
+//! real AST nodes with spans pointing into the enclosing source.
+
+use ferra_parser::{
+    ast::{
+        Arena, BinaryOperator, CompilationUnit, Expression, InterpolatedPart, InterpolatedString,
+        Item, Literal, Statement,
+    },
+    pratt::PrattParser,
+    test_utils::mock_tokens_from_source,
+    ProgramParser,
+};
+
+fn interpolated<'a>(arena: &'a Arena, source: &str) -> InterpolatedString<'a> {
+    let mut parser = PrattParser::new(arena, mock_tokens_from_source(source));
+    match parser.parse_expression(0).unwrap() {
+        Expression::Interpolated(interpolated) => interpolated.clone(),
+        other => panic!("Expected interpolated string, got {:?}", other),
+    }
+}
+
+fn parse_error(source: &str) -> String {
+    let arena = Arena::new();
+    let mut parser = PrattParser::new(&arena, mock_tokens_from_source(source));
+    match parser.parse_expression(0) {
+        Ok(expr) => panic!("Expected error for {}, got {:?}", source, expr),
+        Err(error) => error.to_string(),
+    }
+}
+
+#[test]
+fn test_interpolated_text_and_expressions() {
+    let arena = Arena::new();
+    let source = r#"f"Hello, {name}!""#;
+    let interpolated = interpolated(&arena, source);
+    assert_eq!(interpolated.span.start, 0);
+    assert_eq!(interpolated.span.end, source.len());
+    assert_eq!(interpolated.parts.len(), 3);
+
+    assert!(matches!(&interpolated.parts[0], InterpolatedPart::Text(text) if text == "Hello, "));
+    match &interpolated.parts[1] {
+        InterpolatedPart::Expression { expression, span } => {
+            assert!(matches!(expression, Expression::Identifier(name) if name == "name"));
+            assert_eq!(&source[span.start..span.end], "name");
+            assert_eq!(span.column, 11);
+        }
+        other => panic!("Expected expression part, got {:?}", other),
+    }
+    assert!(matches!(&interpolated.parts[2], InterpolatedPart::Text(text) if text == "!"));
+}
+
+#[test]
+fn test_embedded_expressions_are_full_ast() {
+    let arena = Arena::new();
+    let source = r#"f"{a + b * 2} and {user.name} and {items[0]}""#;
+    let interpolated = interpolated(&arena, source);
+    let expressions: Vec<_> = interpolated
+        .parts
+        .iter()
+        .filter_map(|part| match part {
+            InterpolatedPart::Expression { expression, span } => Some((expression, span)),
+            InterpolatedPart::Text(_) => None,
+        })
+        .collect();
+    assert_eq!(expressions.len(), 3);
+
+    match expressions[0].0 {
+        Expression::Binary(binary) => {
+            assert!(matches!(binary.operator, BinaryOperator::Add));
+            assert!(matches!(binary.right, Expression::Binary(_)));
+        }
+        other => panic!("Expected binary expression, got {:?}", other),
+    }
+    assert_eq!(
+        &source[expressions[0].1.start..expressions[0].1.end],
+        "a + b * 2"
+    );
+    assert!(matches!(expressions[1].0, Expression::MemberAccess(_)));
+    assert!(matches!(expressions[2].0, Expression::Index(_)));
+    assert_eq!(
+        &source[expressions[2].1.start..expressions[2].1.end],
+        "items[0]"
+    );
+}
+
+#[test]
+fn test_nested_braces_strings_and_interpolations() {
+    let arena = Arena::new();
+    let source = r#"f"{{literal}} {lookup("}")} {f"{x}!"}""#;
+    let interpolated = interpolated(&arena, source);
+    assert!(matches!(&interpolated.parts[0], InterpolatedPart::Text(text) if text == "{literal} "));
+    let expression = |index: usize| match &interpolated.parts[index] {
+        InterpolatedPart::Expression { expression, .. } => expression,
+        other => panic!("Expected expression part, got {:?}", other),
+    };
+    match expression(1) {
+        Expression::Call(call) => assert!(matches!(
+            &call.arguments[0],
+            Expression::Literal(Literal::String(value)) if value == "}"
+        )),
+        other => panic!("Expected call, got {:?}", other),
+    }
+    match expression(3) {
+        Expression::Interpolated(inner) => assert_eq!(inner.parts.len(), 2),
+        other => panic!("Expected nested interpolation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolation_replaces_concatenation_in_programs() {
+    let arena = Arena::new();
+    let source = r#"fn greet(name: String) -> String { return f"Hello, {name}!"; }"#;
+    let mut parser = ProgramParser::new(&arena, mock_tokens_from_source(source));
+    let unit: CompilationUnit = parser.parse_compilation_unit().unwrap().clone();
+    let Item::FunctionDecl(function) = &unit.items[0] else {
+        panic!("Expected function, got {:?}", unit.items[0]);
+    };
+    match &function.body.as_ref().unwrap().statements[0] {
+        Statement::Return(return_stmt) => match return_stmt.value.as_ref().unwrap() {
+            Expression::Interpolated(interpolated) => match &interpolated.parts[1] {
+                InterpolatedPart::Expression { span, .. } => {
+                    assert_eq!(&source[span.start..span.end], "name");
+                    assert_eq!(span.column, 53);
+                }
+                other => panic!("Expected expression part, got {:?}", other),
+            },
+            other => panic!("Expected interpolated string, got {:?}", other),
+        },
+        other => panic!("Expected return, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_interpolated_expression_errors() {
+    assert!(parse_error(r#"f"{a b}""#).contains("`}` after interpolated expression"));
+    assert!(parse_error(r#"f"{a +}""#).contains("Expected expression"));
+}
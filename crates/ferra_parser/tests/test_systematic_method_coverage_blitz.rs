@@ -38,24 +38,24 @@ fn test_deep_program_parser_method_coverage() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Static,
-        TokenType::Identifier("GLOBAL_COUNTER".to_string()),
+        TokenType::Identifier("GLOBAL_COUNTER".into()),
         TokenType::Colon,
-        TokenType::Identifier("u64".to_string()),
+        TokenType::Identifier("u64".into()),
         TokenType::Semicolon,
         TokenType::Fn,
-        TokenType::Identifier("c_function".to_string()),
+        TokenType::Identifier("c_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("c_char".to_string()),
+        TokenType::Identifier("c_char".into()),
         TokenType::Comma,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::Colon,
-        TokenType::Identifier("size_t".to_string()),
+        TokenType::Identifier("size_t".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("c_int".to_string()),
+        TokenType::Identifier("c_int".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -70,45 +70,45 @@ fn test_deep_program_parser_method_coverage() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Comma,
-        TokenType::Identifier("PartialEq".to_string()),
+        TokenType::Identifier("PartialEq".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Pub,
         TokenType::Data,
-        TokenType::Identifier("ComplexData".to_string()),
+        TokenType::Identifier("ComplexData".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Greater,
         TokenType::LeftBrace,
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("serde".to_string()),
+        TokenType::Identifier("serde".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("skip".to_string()),
+        TokenType::Identifier("skip".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
-        TokenType::Identifier("internal_field".to_string()),
+        TokenType::Identifier("internal_field".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
         TokenType::Pub,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Ampersand,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftParen,
@@ -126,18 +126,18 @@ fn test_deep_program_parser_method_coverage() {
     // Test multiple functions with complex signatures
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("first_function".to_string()),
+        TokenType::Identifier("first_function".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("param1".to_string()),
+        TokenType::Identifier("param1".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("param2".to_string()),
+        TokenType::Identifier("param2".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::BooleanLiteral(true),
@@ -145,7 +145,7 @@ fn test_deep_program_parser_method_coverage() {
         TokenType::RightBrace,
         TokenType::Async,
         TokenType::Fn,
-        TokenType::Identifier("async_function".to_string()),
+        TokenType::Identifier("async_function".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -153,11 +153,11 @@ fn test_deep_program_parser_method_coverage() {
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Data,
-        TokenType::Identifier("SimpleData".to_string()),
+        TokenType::Identifier("SimpleData".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -180,37 +180,37 @@ fn test_advanced_statement_parser_method_coverage() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Pub,
         TokenType::Async,
         TokenType::Fn,
-        TokenType::Identifier("complex_async".to_string()),
+        TokenType::Identifier("complex_async".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Option".to_string()),
+        TokenType::Identifier("Option".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::LeftBrace,
         TokenType::Return,
-        TokenType::Identifier("None".to_string()),
+        TokenType::Identifier("None".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -225,11 +225,11 @@ fn test_advanced_statement_parser_method_coverage() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Let,
         TokenType::LeftParen,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::Comma,
-        TokenType::Identifier("third".to_string()),
+        TokenType::Identifier("third".into()),
         TokenType::RightParen,
         TokenType::Equal,
         TokenType::LeftParen,
@@ -251,31 +251,31 @@ fn test_advanced_statement_parser_method_coverage() {
     // Test functions with complex where clauses
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_where".to_string()),
+        TokenType::Identifier("complex_where".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("first".to_string()),
+        TokenType::Identifier("first".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Comma,
-        TokenType::Identifier("second".to_string()),
+        TokenType::Identifier("second".into()),
         TokenType::Colon,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::RightParen,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::Plus,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::Comma,
-        TokenType::Identifier("U".to_string()),
+        TokenType::Identifier("U".into()),
         TokenType::Colon,
-        TokenType::Identifier("Send".to_string()),
+        TokenType::Identifier("Send".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::Semicolon,
@@ -299,24 +299,24 @@ fn test_comprehensive_expression_method_coverage() {
 
     // Test complex chained method calls with array access
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("collection".to_string()),
+        TokenType::Identifier("collection".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(0),
         TokenType::RightBracket,
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("test".to_string()),
         TokenType::RightParen,
         TokenType::Dot,
-        TokenType::Identifier("field".to_string()),
+        TokenType::Identifier("field".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(1),
         TokenType::Plus,
         TokenType::IntegerLiteral(2),
         TokenType::RightBracket,
         TokenType::Dot,
-        TokenType::Identifier("final_method".to_string()),
+        TokenType::Identifier("final_method".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Eof,
@@ -330,28 +330,28 @@ fn test_comprehensive_expression_method_coverage() {
     // Test advanced pattern matching expressions
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Match,
-        TokenType::Identifier("complex_value".to_string()),
+        TokenType::Identifier("complex_value".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("Some".to_string()),
+        TokenType::Identifier("Some".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::RightParen,
         TokenType::If,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Greater,
         TokenType::IntegerLiteral(100),
         TokenType::FatArrow,
         TokenType::LeftBrace,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::Star,
         TokenType::IntegerLiteral(2),
         TokenType::RightBrace,
         TokenType::Comma,
-        TokenType::Identifier("None".to_string()),
+        TokenType::Identifier("None".into()),
         TokenType::Pipe,
-        TokenType::Identifier("Some".to_string()),
+        TokenType::Identifier("Some".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("_".to_string()),
+        TokenType::Identifier("_".into()),
         TokenType::RightParen,
         TokenType::FatArrow,
         TokenType::IntegerLiteral(0),
@@ -408,33 +408,33 @@ fn test_advanced_control_flow_method_coverage() {
     // Test labeled control flow with complex nesting
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Apostrophe,
-        TokenType::Identifier("outer".to_string()),
+        TokenType::Identifier("outer".into()),
         TokenType::Colon,
         TokenType::While,
         TokenType::BooleanLiteral(true),
         TokenType::LeftBrace,
         TokenType::Apostrophe,
-        TokenType::Identifier("inner".to_string()),
+        TokenType::Identifier("inner".into()),
         TokenType::Colon,
         TokenType::For,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         TokenType::In,
         TokenType::IntegerLiteral(0),
         TokenType::DotDot,
         TokenType::IntegerLiteral(10),
         TokenType::LeftBrace,
         TokenType::If,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         TokenType::EqualEqual,
         TokenType::IntegerLiteral(5),
         TokenType::LeftBrace,
         TokenType::Break,
         TokenType::Apostrophe,
-        TokenType::Identifier("outer".to_string()),
+        TokenType::Identifier("outer".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::If,
-        TokenType::Identifier("i".to_string()),
+        TokenType::Identifier("i".into()),
         TokenType::Percent,
         TokenType::IntegerLiteral(2),
         TokenType::EqualEqual,
@@ -442,7 +442,7 @@ fn test_advanced_control_flow_method_coverage() {
         TokenType::LeftBrace,
         TokenType::Continue,
         TokenType::Apostrophe,
-        TokenType::Identifier("inner".to_string()),
+        TokenType::Identifier("inner".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::RightBrace,
@@ -459,33 +459,33 @@ fn test_advanced_control_flow_method_coverage() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Unsafe,
         TokenType::Fn,
-        TokenType::Identifier("dangerous_operation".to_string()),
+        TokenType::Identifier("dangerous_operation".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("u8".to_string()),
+        TokenType::Identifier("u8".into()),
         TokenType::Comma,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::Colon,
-        TokenType::Identifier("usize".to_string()),
+        TokenType::Identifier("usize".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Star,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::LeftBrace,
         TokenType::Let,
-        TokenType::Identifier("raw_value".to_string()),
+        TokenType::Identifier("raw_value".into()),
         TokenType::Equal,
         TokenType::Star,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Semicolon,
         TokenType::Return,
         TokenType::Star,
         TokenType::LeftParen,
-        TokenType::Identifier("ptr".to_string()),
+        TokenType::Identifier("ptr".into()),
         TokenType::Plus,
-        TokenType::Identifier("size".to_string()),
+        TokenType::Identifier("size".into()),
         TokenType::RightParen,
         TokenType::Semicolon,
         TokenType::RightBrace,
@@ -500,17 +500,17 @@ fn test_advanced_control_flow_method_coverage() {
     // Test functions with complex return types and expressions
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_returns".to_string()),
+        TokenType::Identifier("complex_returns".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::LeftParen,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Comma,
         TokenType::LeftBracket,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightBracket,
         TokenType::RightParen,
         TokenType::LeftBrace,
@@ -548,15 +548,15 @@ fn test_error_recovery_enhancement_coverage() {
     // Test multi-level error recovery with sync points
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("broken_function".to_string()),
+        TokenType::Identifier("broken_function".into()),
         TokenType::LeftParen,
         // First error: missing parameter name
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("valid_param".to_string()),
+        TokenType::Identifier("valid_param".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Let,
@@ -592,19 +592,19 @@ fn test_error_recovery_enhancement_coverage() {
     // Test error recovery with deeply nested structures
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Data,
-        TokenType::Identifier("NestedStruct".to_string()),
+        TokenType::Identifier("NestedStruct".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("level1".to_string()),
+        TokenType::Identifier("level1".into()),
         TokenType::Colon,
-        TokenType::Identifier("NestedLevel1".to_string()),
+        TokenType::Identifier("NestedLevel1".into()),
         TokenType::Comma,
         // Error: missing field name
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("valid_field".to_string()),
+        TokenType::Identifier("valid_field".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -628,37 +628,37 @@ fn test_advanced_type_system_method_coverage() {
     // Test higher-order function types with lifetime parameters
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("complex_higher_order".to_string()),
+        TokenType::Identifier("complex_higher_order".into()),
         TokenType::Less,
         TokenType::Apostrophe,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Comma,
         TokenType::Apostrophe,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("factory".to_string()),
+        TokenType::Identifier("factory".into()),
         TokenType::Colon,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Ampersand,
         TokenType::Apostrophe,
-        TokenType::Identifier("a".to_string()),
-        TokenType::Identifier("str".to_string()),
+        TokenType::Identifier("a".into()),
+        TokenType::Identifier("str".into()),
         TokenType::RightParen,
         TokenType::Arrow,
         TokenType::Fn,
         TokenType::LeftParen,
         TokenType::Ampersand,
         TokenType::Apostrophe,
-        TokenType::Identifier("b".to_string()),
-        TokenType::Identifier("str".to_string()),
+        TokenType::Identifier("b".into()),
+        TokenType::Identifier("str".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::LeftBrace,
         TokenType::Return,
         TokenType::BooleanLiteral(true),
@@ -675,22 +675,22 @@ fn test_advanced_type_system_method_coverage() {
     // Test complex nested array and tuple types
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("nested_types".to_string()),
+        TokenType::Identifier("nested_types".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("data".to_string()),
+        TokenType::Identifier("data".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
         TokenType::LeftParen,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
         TokenType::LeftBracket,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBracket,
         TokenType::Comma,
         TokenType::LeftParen,
-        TokenType::Identifier("bool".to_string()),
+        TokenType::Identifier("bool".into()),
         TokenType::Comma,
-        TokenType::Identifier("f64".to_string()),
+        TokenType::Identifier("f64".into()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::RightBracket,
@@ -719,7 +719,7 @@ fn test_performance_critical_method_coverage() {
     // Test large data structure with many fields
     let mut tokens = vec![
         TokenType::Data,
-        TokenType::Identifier("LargeData".to_string()),
+        TokenType::Identifier("LargeData".into()),
         TokenType::LeftBrace,
     ];
 
@@ -727,16 +727,16 @@ fn test_performance_critical_method_coverage() {
         if i > 1 {
             tokens.push(TokenType::Comma);
         }
-        tokens.push(TokenType::Identifier(format!("field{}", i)));
+        tokens.push(TokenType::Identifier(format!("field{}", i).into()));
         tokens.push(TokenType::Colon);
 
         match i % 4 {
-            0 => tokens.push(TokenType::Identifier("String".to_string())),
-            1 => tokens.push(TokenType::Identifier("i32".to_string())),
-            2 => tokens.push(TokenType::Identifier("bool".to_string())),
+            0 => tokens.push(TokenType::Identifier("String".into())),
+            1 => tokens.push(TokenType::Identifier("i32".into())),
+            2 => tokens.push(TokenType::Identifier("bool".into())),
             _ => {
                 tokens.push(TokenType::LeftBracket);
-                tokens.push(TokenType::Identifier("f64".to_string()));
+                tokens.push(TokenType::Identifier("f64".into()));
                 tokens.push(TokenType::RightBracket);
             }
         }
@@ -756,9 +756,9 @@ fn test_performance_critical_method_coverage() {
     // Test deeply nested function types
     let mut tokens = vec![
         TokenType::Fn,
-        TokenType::Identifier("deep_function_type".to_string()),
+        TokenType::Identifier("deep_function_type".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("callback".to_string()),
+        TokenType::Identifier("callback".into()),
         TokenType::Colon,
     ];
 
@@ -767,14 +767,14 @@ fn test_performance_critical_method_coverage() {
         tokens.push(TokenType::Fn);
         tokens.push(TokenType::LeftParen);
         if depth == 4 {
-            tokens.push(TokenType::Identifier("i32".to_string()));
+            tokens.push(TokenType::Identifier("i32".into()));
         }
     }
 
     for _ in 0..5 {
         tokens.push(TokenType::RightParen);
         tokens.push(TokenType::Arrow);
-        tokens.push(TokenType::Identifier("i32".to_string()));
+        tokens.push(TokenType::Identifier("i32".into()));
     }
 
     tokens.extend(vec![
@@ -808,34 +808,34 @@ fn test_advanced_expression_complexity_coverage() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftParen,
         TokenType::LeftParen,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Plus,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::RightParen,
         TokenType::Star,
         TokenType::LeftParen,
-        TokenType::Identifier("c".to_string()),
+        TokenType::Identifier("c".into()),
         TokenType::Minus,
-        TokenType::Identifier("d".to_string()),
+        TokenType::Identifier("d".into()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::EqualEqual,
         TokenType::LeftParen,
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::LeftBracket,
-        TokenType::Identifier("index".to_string()),
+        TokenType::Identifier("index".into()),
         TokenType::Plus,
         TokenType::IntegerLiteral(1),
         TokenType::RightBracket,
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
         TokenType::StringLiteral("test".to_string()),
         TokenType::RightParen,
         TokenType::RightParen,
         TokenType::AmpAmp,
         TokenType::Bang,
-        TokenType::Identifier("flag".to_string()),
+        TokenType::Identifier("flag".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -847,10 +847,10 @@ fn test_advanced_expression_complexity_coverage() {
     // Test complex conditional expressions with nested ternary-like patterns
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::If,
-        TokenType::Identifier("condition1".to_string()),
+        TokenType::Identifier("condition1".into()),
         TokenType::LeftBrace,
         TokenType::If,
-        TokenType::Identifier("condition2".to_string()),
+        TokenType::Identifier("condition2".into()),
         TokenType::LeftBrace,
         TokenType::IntegerLiteral(1),
         TokenType::RightBrace,
@@ -862,7 +862,7 @@ fn test_advanced_expression_complexity_coverage() {
         TokenType::Else,
         TokenType::LeftBrace,
         TokenType::If,
-        TokenType::Identifier("condition3".to_string()),
+        TokenType::Identifier("condition3".into()),
         TokenType::LeftBrace,
         TokenType::IntegerLiteral(3),
         TokenType::RightBrace,
@@ -20,11 +20,11 @@ fn test_pratt_parser_advanced_methods() {
 
     // Test qualified identifier parsing (obj.method.property)
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::Dot,
-        TokenType::Identifier("property".to_string()),
+        TokenType::Identifier("property".into()),
         TokenType::Eof,
     ]);
     let mut parser = PrattParser::new(&arena, tokens);
@@ -35,7 +35,7 @@ fn test_pratt_parser_advanced_methods() {
 
     // Test function call with arguments (func(arg1, arg2))
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(42),
         TokenType::Comma,
@@ -51,7 +51,7 @@ fn test_pratt_parser_advanced_methods() {
 
     // Test array indexing (arr[index])
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("arr".to_string()),
+        TokenType::Identifier("arr".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(0),
         TokenType::RightBracket,
@@ -65,7 +65,7 @@ fn test_pratt_parser_advanced_methods() {
 
     // Test try expression (expr?)
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("result".to_string()),
+        TokenType::Identifier("result".into()),
         TokenType::Question,
         TokenType::Eof,
     ]);
@@ -106,9 +106,9 @@ fn test_pratt_parser_pattern_methods() {
     // Test slice patterns ([head, tail @ ..])
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBracket,
-        TokenType::Identifier("head".to_string()),
+        TokenType::Identifier("head".into()),
         TokenType::Comma,
-        TokenType::Identifier("tail".to_string()),
+        TokenType::Identifier("tail".into()),
         TokenType::At,
         TokenType::DotDot,
         TokenType::RightBracket,
@@ -129,18 +129,18 @@ fn test_program_parser_advanced_methods() {
     // Test function with generic parameters
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Fn,
-        TokenType::Identifier("generic_func".to_string()),
+        TokenType::Identifier("generic_func".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::LeftParen,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Colon,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::RightParen,
         TokenType::LeftBrace,
         TokenType::Return,
-        TokenType::Identifier("param".to_string()),
+        TokenType::Identifier("param".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -155,21 +155,21 @@ fn test_program_parser_advanced_methods() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("derive".to_string()),
+        TokenType::Identifier("derive".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("Debug".to_string()),
+        TokenType::Identifier("Debug".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Data,
-        TokenType::Identifier("Person".to_string()),
+        TokenType::Identifier("Person".into()),
         TokenType::LeftBrace,
-        TokenType::Identifier("name".to_string()),
+        TokenType::Identifier("name".into()),
         TokenType::Colon,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("age".to_string()),
+        TokenType::Identifier("age".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::RightBrace,
         TokenType::Eof,
     ]);
@@ -185,20 +185,20 @@ fn test_program_parser_advanced_methods() {
         TokenType::StringLiteral("C".to_string()),
         TokenType::LeftBrace,
         TokenType::Fn,
-        TokenType::Identifier("printf".to_string()),
+        TokenType::Identifier("printf".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("format".to_string()),
+        TokenType::Identifier("format".into()),
         TokenType::Colon,
         TokenType::Star,
-        TokenType::Identifier("char".to_string()),
+        TokenType::Identifier("char".into()),
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::Static,
-        TokenType::Identifier("errno".to_string()),
+        TokenType::Identifier("errno".into()),
         TokenType::Colon,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Semicolon,
         TokenType::RightBrace,
         TokenType::Eof,
@@ -219,26 +219,26 @@ fn test_statement_parser_advanced_methods() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Async,
         TokenType::Fn,
-        TokenType::Identifier("fetch_data".to_string()),
+        TokenType::Identifier("fetch_data".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Arrow,
-        TokenType::Identifier("Result".to_string()),
+        TokenType::Identifier("Result".into()),
         TokenType::Less,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Greater,
         TokenType::Where,
-        TokenType::Identifier("T".to_string()),
+        TokenType::Identifier("T".into()),
         TokenType::Colon,
-        TokenType::Identifier("Clone".to_string()),
+        TokenType::Identifier("Clone".into()),
         TokenType::LeftBrace,
         TokenType::Return,
-        TokenType::Identifier("Ok".to_string()),
+        TokenType::Identifier("Ok".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("default".to_string()),
+        TokenType::Identifier("default".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::RightParen,
@@ -256,26 +256,26 @@ fn test_statement_parser_advanced_methods() {
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::Hash,
         TokenType::LeftBracket,
-        TokenType::Identifier("thread_local".to_string()),
+        TokenType::Identifier("thread_local".into()),
         TokenType::RightBracket,
         TokenType::Let,
-        TokenType::Identifier("complex_var".to_string()),
+        TokenType::Identifier("complex_var".into()),
         TokenType::Colon,
         TokenType::LeftBracket,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::Less,
-        TokenType::Identifier("String".to_string()),
+        TokenType::Identifier("String".into()),
         TokenType::Comma,
-        TokenType::Identifier("Vec".to_string()),
+        TokenType::Identifier("Vec".into()),
         TokenType::Less,
-        TokenType::Identifier("i32".to_string()),
+        TokenType::Identifier("i32".into()),
         TokenType::Greater,
         TokenType::Greater,
         TokenType::RightBracket,
         TokenType::Equal,
-        TokenType::Identifier("HashMap".to_string()),
+        TokenType::Identifier("HashMap".into()),
         TokenType::DoubleColon,
-        TokenType::Identifier("new".to_string()),
+        TokenType::Identifier("new".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Semicolon,
@@ -295,15 +295,15 @@ fn test_complex_nested_expressions() {
 
     // Test deeply nested member access: obj.a.b.c.method()
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("a".to_string()),
+        TokenType::Identifier("a".into()),
         TokenType::Dot,
-        TokenType::Identifier("b".to_string()),
+        TokenType::Identifier("b".into()),
         TokenType::Dot,
-        TokenType::Identifier("c".to_string()),
+        TokenType::Identifier("c".into()),
         TokenType::Dot,
-        TokenType::Identifier("method".to_string()),
+        TokenType::Identifier("method".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Eof,
@@ -316,16 +316,16 @@ fn test_complex_nested_expressions() {
 
     // Test complex array indexing: arr[obj.index][func(x)]
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("arr".to_string()),
+        TokenType::Identifier("arr".into()),
         TokenType::LeftBracket,
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("index".to_string()),
+        TokenType::Identifier("index".into()),
         TokenType::RightBracket,
         TokenType::LeftBracket,
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
-        TokenType::Identifier("x".to_string()),
+        TokenType::Identifier("x".into()),
         TokenType::RightParen,
         TokenType::RightBracket,
         TokenType::Eof,
@@ -345,15 +345,15 @@ fn test_advanced_array_literals() {
     // Test array with complex expressions: [func(), obj.prop, arr[0]]
     let tokens = VecTokenStream::from_token_types(vec![
         TokenType::LeftBracket,
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::RightParen,
         TokenType::Comma,
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("prop".to_string()),
+        TokenType::Identifier("prop".into()),
         TokenType::Comma,
-        TokenType::Identifier("arr".to_string()),
+        TokenType::Identifier("arr".into()),
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(0),
         TokenType::RightBracket,
@@ -397,14 +397,14 @@ fn test_macro_invocation_variants() {
 
     // Test macro with complex arguments: println!("Value: {}", obj.value)
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("println".to_string()),
+        TokenType::Identifier("println".into()),
         TokenType::Bang,
         TokenType::LeftParen,
         TokenType::StringLiteral("Value: {}".to_string()),
         TokenType::Comma,
-        TokenType::Identifier("obj".to_string()),
+        TokenType::Identifier("obj".into()),
         TokenType::Dot,
-        TokenType::Identifier("value".to_string()),
+        TokenType::Identifier("value".into()),
         TokenType::RightParen,
         TokenType::Eof,
     ]);
@@ -416,7 +416,7 @@ fn test_macro_invocation_variants() {
 
     // Test macro with brackets: vec![1, 2, 3]
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("vec".to_string()),
+        TokenType::Identifier("vec".into()),
         TokenType::Bang,
         TokenType::LeftBracket,
         TokenType::IntegerLiteral(1),
@@ -455,7 +455,7 @@ fn test_error_recovery_paths() {
 
     // Test recovery from unclosed function call
     let tokens = VecTokenStream::from_token_types(vec![
-        TokenType::Identifier("func".to_string()),
+        TokenType::Identifier("func".into()),
         TokenType::LeftParen,
         TokenType::IntegerLiteral(42),
         TokenType::Eof, // Missing closing paren